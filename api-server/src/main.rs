@@ -9,8 +9,8 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tower_http::cors::{Any, CorsLayer};
 use tracing::{info, error};
-use visualsign::{SignablePayload, vsptrait::VisualSignOptions};
-use visualsign_ethereum::transaction_string_to_visual_sign;
+use visualsign::{SignablePayload, vsptrait::{Transaction, VisualSignOptions}};
+use visualsign_ethereum::{transaction_string_to_visual_sign, EthereumTransactionWrapper};
 
 // ============================================================================
 // API Types
@@ -35,7 +35,7 @@ pub struct ParseResponse {
     /// Successfully parsed visual sign payload
     pub payload: SignablePayload,
 
-    /// Transaction hash (if available)
+    /// Transaction hash (if the transaction is signed)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tx_hash: Option<String>,
 }
@@ -186,10 +186,13 @@ async fn parse_transaction(
             ApiError::ParsingError(format!("Failed to parse transaction: {}", e))
         })?;
 
-    Ok(Json(ParseResponse {
-        payload,
-        tx_hash: None, // Could compute this if needed
-    }))
+    // Only signed transactions have a hash
+    let tx_hash = EthereumTransactionWrapper::from_string(tx_hex)
+        .ok()
+        .and_then(|transaction| transaction.tx_hash())
+        .map(|hash| format!("{:?}", hash));
+
+    Ok(Json(ParseResponse { payload, tx_hash }))
 }
 
 // ============================================================================
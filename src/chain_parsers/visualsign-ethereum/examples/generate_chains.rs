@@ -0,0 +1,129 @@
+//! Regenerates `data/chains.json` from a chainlist export.
+//!
+//! ```text
+//! curl -o chainlist.json https://chainid.network/chains.json
+//! cargo run -p visualsign-ethereum --example generate_chains -- chainlist.json > data/chains.json
+//! ```
+//!
+//! The export is the `ethereum-lists/chains` format, which every chainlist
+//! mirror serves. A chain is a testnet when the export says so with
+//! `isTestnet` (chainlist.org's `rpcs.json`), and otherwise when it uses the
+//! SLIP-44 testnet coin type or lists a faucet; names are never consulted.
+
+use std::io;
+use std::process::ExitCode;
+
+use serde::{Deserialize, Serialize};
+use serde_json::ser::Formatter;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportedChain {
+    chain_id: u64,
+    name: String,
+    native_currency: Option<NativeCurrency>,
+    #[serde(default)]
+    explorers: Vec<Explorer>,
+    #[serde(default)]
+    faucets: Vec<String>,
+    slip44: Option<u64>,
+    is_testnet: Option<bool>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct NativeCurrency {
+    symbol: String,
+    decimals: u8,
+}
+
+#[derive(Deserialize)]
+struct Explorer {
+    url: String,
+}
+
+/// An entry of `data/chains.json`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Chain {
+    chain_id: u64,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    native_currency: Option<NativeCurrency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    explorer: Option<String>,
+    testnet: bool,
+}
+
+impl From<ExportedChain> for Chain {
+    fn from(chain: ExportedChain) -> Self {
+        let testnet = chain
+            .is_testnet
+            .unwrap_or(chain.slip44 == Some(1) || !chain.faucets.is_empty());
+        Self {
+            chain_id: chain.chain_id,
+            name: chain.name,
+            native_currency: chain.native_currency,
+            explorer: chain
+                .explorers
+                .into_iter()
+                .next()
+                .map(|explorer| explorer.url.trim_end_matches('/').to_string()),
+            testnet,
+        }
+    }
+}
+
+/// One-line JSON with a space after each `:` and `,`, as `data/chains.json` is laid out.
+struct Spaced;
+
+impl Formatter for Spaced {
+    fn begin_object_key<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> io::Result<()> {
+        if first {
+            Ok(())
+        } else {
+            writer.write_all(b", ")
+        }
+    }
+
+    fn begin_object_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(b": ")
+    }
+}
+
+fn to_line(chain: &Chain) -> String {
+    let mut line = Vec::new();
+    let mut serializer = serde_json::Serializer::with_formatter(&mut line, Spaced);
+    chain.serialize(&mut serializer).expect("chains serialize");
+    String::from_utf8(line).expect("serde_json writes UTF-8")
+}
+
+fn main() -> ExitCode {
+    let Some(path) = std::env::args().nth(1) else {
+        eprintln!("usage: generate_chains <chainlist chains.json>");
+        return ExitCode::FAILURE;
+    };
+    let exported: Vec<ExportedChain> = match std::fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|contents| serde_json::from_str(&contents).map_err(|e| e.to_string()))
+    {
+        Ok(exported) => exported,
+        Err(e) => {
+            eprintln!("{path}: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut chains: Vec<Chain> = exported.into_iter().map(Chain::from).collect();
+    chains.sort_by_key(|chain| chain.chain_id);
+    chains.dedup_by_key(|chain| chain.chain_id);
+    let lines: Vec<String> = chains
+        .iter()
+        .map(|chain| format!("  {}", to_line(chain)))
+        .collect();
+    println!("[\n{}\n]", lines.join(",\n"));
+    ExitCode::SUCCESS
+}
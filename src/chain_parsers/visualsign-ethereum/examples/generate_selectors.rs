@@ -0,0 +1,111 @@
+//! Regenerates `data/selectors.json` from signature database exports.
+//!
+//! ```text
+//! cargo run -p visualsign-ethereum --example generate_selectors -- \
+//!     data/selectors.json openchain-functions.txt > selectors.json
+//! ```
+//!
+//! Each input is either a JSON array of signatures, such as the current
+//! `data/selectors.json`, or a text export with one function per line as
+//! `signature` or `0xselector,signature` (OpenChain's export and 4byte's
+//! `hex_signature,text_signature` dumps both fit). Earlier inputs take
+//! precedence, so list the curated file first to keep the well-known
+//! signature ahead of its collisions.
+//!
+//! Lines whose signature doesn't parse, or doesn't hash to the selector it was
+//! exported with, are dropped. At most `MAX_PER_SELECTOR` signatures are kept
+//! for each selector and `--limit` (default `DEFAULT_LIMIT`) overall.
+
+use std::collections::{HashMap, HashSet};
+use std::process::ExitCode;
+
+use alloy_json_abi::Function;
+use alloy_primitives::Selector;
+
+/// Collisions past the first few are nearly always spam registrations.
+const MAX_PER_SELECTOR: usize = 4;
+const DEFAULT_LIMIT: usize = 20_000;
+
+/// The signatures in `contents`, each with the selector it was exported under.
+fn read_signatures(contents: &str) -> Result<Vec<(Option<String>, String)>, String> {
+    if contents.trim_start().starts_with('[') {
+        let signatures: Vec<String> = serde_json::from_str(contents).map_err(|e| e.to_string())?;
+        return Ok(signatures.into_iter().map(|s| (None, s)).collect());
+    }
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| match line.split_once(',') {
+            Some((selector, signature)) if selector.starts_with("0x") => {
+                (Some(selector.to_lowercase()), signature.trim().to_string())
+            }
+            _ => (None, line.to_string()),
+        })
+        .collect())
+}
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let mut limit = DEFAULT_LIMIT;
+    let mut inputs = Vec::new();
+    while let Some(arg) = args.next() {
+        if arg == "--limit" {
+            match args.next().and_then(|n| n.parse().ok()) {
+                Some(n) => limit = n,
+                None => {
+                    eprintln!("--limit takes a number");
+                    return ExitCode::FAILURE;
+                }
+            }
+        } else {
+            inputs.push(arg);
+        }
+    }
+    if inputs.is_empty() {
+        eprintln!("usage: generate_selectors [--limit N] <signatures>...");
+        return ExitCode::FAILURE;
+    }
+
+    let mut seen = HashSet::new();
+    let mut per_selector: HashMap<Selector, usize> = HashMap::new();
+    let mut output = Vec::new();
+    for input in &inputs {
+        let signatures = match std::fs::read_to_string(input)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| read_signatures(&contents))
+        {
+            Ok(signatures) => signatures,
+            Err(e) => {
+                eprintln!("{input}: {e}");
+                return ExitCode::FAILURE;
+            }
+        };
+        for (exported_selector, signature) in signatures {
+            if output.len() == limit {
+                break;
+            }
+            let Ok(function) = Function::parse(&signature) else {
+                continue;
+            };
+            let selector = function.selector();
+            if exported_selector.is_some_and(|exported| exported != selector.to_string()) {
+                continue;
+            }
+            let signature = function.signature();
+            let count = per_selector.entry(selector).or_default();
+            if *count == MAX_PER_SELECTOR || !seen.insert(signature.clone()) {
+                continue;
+            }
+            *count += 1;
+            output.push(signature);
+        }
+    }
+
+    let lines: Vec<String> = output
+        .iter()
+        .map(|signature| format!("  {}", serde_json::Value::String(signature.clone())))
+        .collect();
+    println!("[\n{}\n]", lines.join(",\n"));
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,326 @@
+//! Calldata decoding driven by a caller-supplied JSON ABI.
+//!
+//! `ParseRequest.chain_metadata` can carry an `EthereumMetadata { abi }` for the
+//! contract being called. When none of the hand-written visualizers recognise
+//! the calldata we look the selector up in that ABI and render every argument
+//! as a typed field.
+
+use alloy_dyn_abi::{DynSolValue, JsonAbiExt};
+use alloy_json_abi::{Function, JsonAbi, Param};
+use visualsign::AnnotatedPayloadField;
+use visualsign::SignablePayloadField;
+use visualsign::vsptrait::{ChainMetadata, chain_metadata};
+
+use crate::fields::{addr, address_field, annotated, number_field, preview_layout, text_field};
+
+#[derive(Debug, thiserror::Error)]
+pub enum AbiError {
+    #[error("Invalid ABI JSON: {0}")]
+    InvalidJson(String),
+}
+
+/// Decodes calldata against a JSON ABI.
+#[derive(Debug, Clone)]
+pub struct AbiVisualizer {
+    abi: JsonAbi,
+}
+
+impl AbiVisualizer {
+    pub fn from_json(json: &str) -> Result<Self, AbiError> {
+        let abi: JsonAbi =
+            serde_json::from_str(json).map_err(|e| AbiError::InvalidJson(e.to_string()))?;
+        Ok(Self { abi })
+    }
+
+    /// Returns a visualizer for the Ethereum ABI carried in `metadata`, if any.
+    ///
+    /// Metadata for other chains, or an ABI that doesn't parse, yields `None` so
+    /// that the caller falls back to the raw input.
+    pub fn from_chain_metadata(metadata: &ChainMetadata) -> Option<Self> {
+        match metadata.metadata.as_ref()? {
+            chain_metadata::Metadata::Ethereum(ethereum) => {
+                Self::from_json(&ethereum.abi.as_ref()?.value).ok()
+            }
+            chain_metadata::Metadata::Solana(_) => None,
+        }
+    }
+
+    /// Finds the ABI function whose selector matches the first four bytes of `input`.
+    pub fn find_function(&self, input: &[u8]) -> Option<&Function> {
+        let selector = input.get(..4)?;
+        self.abi
+            .functions()
+            .find(|function| function.selector().as_slice() == selector)
+    }
+
+    pub fn visualize_tx_commands(&self, input: &[u8]) -> Option<SignablePayloadField> {
+        let function = self.find_function(input)?;
+        visualize_function_call(function, input)
+    }
+}
+
+/// Decodes `input` as a call to `function` and renders its arguments.
+///
+/// Returns `None` when the selector doesn't match or the arguments don't decode.
+pub fn visualize_function_call(function: &Function, input: &[u8]) -> Option<SignablePayloadField> {
+    let fields = argument_fields(function, input)?;
+    let signature = function.signature();
+    Some(preview_layout(
+        &function.name,
+        &function.name,
+        &signature,
+        None,
+        fields,
+    ))
+}
+
+/// One field per argument of `input`, decoded as a call to `function`.
+pub(crate) fn argument_fields(
+    function: &Function,
+    input: &[u8],
+) -> Option<Vec<AnnotatedPayloadField>> {
+    if input.get(..4)? != function.selector().as_slice() {
+        return None;
+    }
+    let values = function.abi_decode_input(&input[4..]).ok()?;
+    Some(
+        function
+            .inputs
+            .iter()
+            .zip(values.iter())
+            .enumerate()
+            .map(|(i, (param, value))| {
+                value_to_field(&param_label(param, i), &param.components, value)
+            })
+            .collect(),
+    )
+}
+
+fn param_label(param: &Param, index: usize) -> String {
+    if param.name.is_empty() {
+        format!("Argument {index}")
+    } else {
+        param.name.clone()
+    }
+}
+
+/// Renders a single decoded value. `components` carries the tuple member
+/// names for tuples and arrays of tuples.
+pub(crate) fn value_to_field(
+    label: &str,
+    components: &[Param],
+    value: &DynSolValue,
+) -> AnnotatedPayloadField {
+    match value {
+        DynSolValue::Address(address) => address_field(label, &addr(*address), None, None),
+        DynSolValue::Uint(number, _) => number_field(label, &number.to_string()),
+        DynSolValue::Int(number, _) => number_field(label, &number.to_string()),
+        DynSolValue::Bool(flag) => text_field(label, &flag.to_string()),
+        DynSolValue::FixedBytes(word, size) => {
+            text_field(label, &format!("0x{}", hex::encode(&word[..*size])))
+        }
+        DynSolValue::Bytes(bytes) => text_field(label, &format!("0x{}", hex::encode(bytes))),
+        DynSolValue::Function(function) => text_field(label, &format!("{function:?}")),
+        DynSolValue::String(text) => text_field(label, text),
+        DynSolValue::Array(items) | DynSolValue::FixedArray(items) => {
+            let fields = items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| value_to_field(&format!("{label}[{i}]"), components, item))
+                .collect();
+            let count = items.len();
+            let subtitle = format!("{count} item{}", if count == 1 { "" } else { "s" });
+            nested_layout(label, &subtitle, fields)
+        }
+        DynSolValue::Tuple(members) => {
+            let fields = members
+                .iter()
+                .enumerate()
+                .map(|(i, member)| match components.get(i) {
+                    Some(component) => {
+                        value_to_field(&param_label(component, i), &component.components, member)
+                    }
+                    None => value_to_field(&format!("Argument {i}"), &[], member),
+                })
+                .collect();
+            let subtitle = format!("{} fields", members.len());
+            nested_layout(label, &subtitle, fields)
+        }
+        // EIP-712 structs carry their own member names
+        DynSolValue::CustomStruct {
+            name,
+            prop_names,
+            tuple,
+        } => {
+            let fields = prop_names
+                .iter()
+                .zip(tuple)
+                .map(|(prop, member)| value_to_field(prop, &[], member))
+                .collect();
+            let subtitle = format!("{} fields", tuple.len());
+            annotated(preview_layout(label, name, &subtitle, None, fields))
+        }
+    }
+}
+
+fn nested_layout(
+    label: &str,
+    subtitle: &str,
+    fields: Vec<AnnotatedPayloadField>,
+) -> AnnotatedPayloadField {
+    annotated(preview_layout(label, label, subtitle, None, fields))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Address, U256};
+    use visualsign::vsptrait::{Abi, EthereumMetadata, SolanaMetadata};
+
+    const VAULT_ABI: &str = r#"[
+        {
+            "type": "function",
+            "name": "configure",
+            "stateMutability": "nonpayable",
+            "inputs": [
+                {"name": "owner", "type": "address"},
+                {"name": "limits", "type": "uint256[]"},
+                {
+                    "name": "entry",
+                    "type": "tuple",
+                    "components": [
+                        {"name": "token", "type": "address"},
+                        {"name": "cap", "type": "uint96"}
+                    ]
+                },
+                {"name": "", "type": "bytes"}
+            ],
+            "outputs": []
+        },
+        {
+            "type": "event",
+            "name": "Configured",
+            "inputs": [{"name": "owner", "type": "address", "indexed": true}],
+            "anonymous": false
+        }
+    ]"#;
+
+    fn ethereum_metadata(abi: &str) -> ChainMetadata {
+        ChainMetadata {
+            metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
+                abi: Some(Abi {
+                    value: abi.to_string(),
+                    signature: None,
+                }),
+                sender: None,
+            })),
+        }
+    }
+
+    fn configure_calldata(visualizer: &AbiVisualizer) -> Vec<u8> {
+        let function = &visualizer.abi.function("configure").unwrap()[0];
+        // abi_encode_input prefixes the selector for functions
+        function
+            .abi_encode_input(&[
+                DynSolValue::Address(Address::repeat_byte(0x11)),
+                DynSolValue::Array(vec![
+                    DynSolValue::Uint(U256::from(5), 256),
+                    DynSolValue::Uint(U256::from(7), 256),
+                ]),
+                DynSolValue::Tuple(vec![
+                    DynSolValue::Address(Address::repeat_byte(0x22)),
+                    DynSolValue::Uint(U256::from(1000), 96),
+                ]),
+                DynSolValue::Bytes(vec![0xde, 0xad]),
+            ])
+            .unwrap()
+    }
+
+    fn expanded_fields(field: &SignablePayloadField) -> &[AnnotatedPayloadField] {
+        match field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => {
+                &preview_layout.expanded.as_ref().unwrap().fields
+            }
+            other => panic!("expected preview layout, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_visualize_call_with_nested_arguments() {
+        let visualizer = AbiVisualizer::from_json(VAULT_ABI).unwrap();
+        let field = visualizer
+            .visualize_tx_commands(&configure_calldata(&visualizer))
+            .unwrap();
+
+        assert_eq!(field.label(), "configure");
+        assert_eq!(
+            field.fallback_text(),
+            "configure(address,uint256[],(address,uint96),bytes)"
+        );
+
+        let args = expanded_fields(&field);
+        assert_eq!(args.len(), 4);
+        assert_eq!(
+            args[0],
+            address_field(
+                "owner",
+                "0x1111111111111111111111111111111111111111",
+                None,
+                None
+            )
+        );
+
+        let limits = expanded_fields(&args[1].signable_payload_field);
+        assert_eq!(args[1].signable_payload_field.fallback_text(), "2 items");
+        assert_eq!(limits[0], number_field("limits[0]", "5"));
+        assert_eq!(limits[1], number_field("limits[1]", "7"));
+
+        let entry = expanded_fields(&args[2].signable_payload_field);
+        assert_eq!(
+            entry[0],
+            address_field(
+                "token",
+                "0x2222222222222222222222222222222222222222",
+                None,
+                None
+            )
+        );
+        assert_eq!(entry[1], number_field("cap", "1000"));
+
+        assert_eq!(args[3], text_field("Argument 3", "0xdead"));
+    }
+
+    #[test]
+    fn test_unknown_selector_and_bad_arguments() {
+        let visualizer = AbiVisualizer::from_json(VAULT_ABI).unwrap();
+        assert!(
+            visualizer
+                .visualize_tx_commands(&[0xaa, 0xbb, 0xcc, 0xdd])
+                .is_none()
+        );
+
+        // Matching selector but truncated arguments
+        let calldata = configure_calldata(&visualizer);
+        assert!(visualizer.visualize_tx_commands(&calldata[..40]).is_none());
+        assert!(visualizer.visualize_tx_commands(&calldata[..3]).is_none());
+    }
+
+    #[test]
+    fn test_from_chain_metadata() {
+        assert!(AbiVisualizer::from_chain_metadata(&ethereum_metadata(VAULT_ABI)).is_some());
+        assert!(AbiVisualizer::from_chain_metadata(&ethereum_metadata("not json")).is_none());
+        assert!(AbiVisualizer::from_chain_metadata(&ChainMetadata { metadata: None }).is_none());
+        assert!(
+            AbiVisualizer::from_chain_metadata(&ChainMetadata {
+                metadata: Some(chain_metadata::Metadata::Solana(SolanaMetadata {
+                    idl: None
+                })),
+            })
+            .is_none()
+        );
+        assert!(matches!(
+            AbiVisualizer::from_json("{"),
+            Err(AbiError::InvalidJson(_))
+        ));
+    }
+}
@@ -0,0 +1,249 @@
+//! Risk checks for calls that let someone else move the signer's tokens.
+//!
+//! An allowance outlives the transaction that grants it, so an unlimited
+//! approval or an approval to an unknown address is worth stating before any
+//! other detail of the payload.
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::SolCall;
+use visualsign::SignablePayloadField;
+
+use crate::contracts::erc20::IERC20;
+use crate::contracts::nft::IERC721;
+use crate::fields::{addr, plain_text_field};
+use crate::registry::ContractRegistry;
+use crate::tokens::{TokenRegistry, format_amount};
+
+/// Tokens that can't store `U256::MAX` (COMP, UNI) treat `type(uint96).max` as
+/// their infinite allowance; anything at least that large can't realistically
+/// be spent down.
+const NEAR_UNLIMITED: U256 = U256::from_limbs([u64::MAX, u32::MAX as u64, 0, 0]);
+
+/// A call that grants `spender` some control over the signer's assets.
+enum Grant {
+    /// `approve` or `increaseAllowance` on the token at the transaction's destination.
+    /// ERC721's `approve(to, tokenId)` shares the selector, so `amount` may be a
+    /// token ID.
+    Allowance { spender: Address, amount: U256 },
+    /// `setApprovalForAll(operator, true)` on an ERC721 or ERC1155 collection.
+    AllTokens { operator: Address },
+}
+
+fn decode_grant(input: &[u8]) -> Option<Grant> {
+    let selector = input.get(..4)?;
+    if selector == IERC20::approveCall::SELECTOR {
+        let call = IERC20::approveCall::abi_decode(input).ok()?;
+        Some(Grant::Allowance {
+            spender: call.spender,
+            amount: call.amount,
+        })
+    } else if selector == IERC20::increaseAllowanceCall::SELECTOR {
+        let call = IERC20::increaseAllowanceCall::abi_decode(input).ok()?;
+        Some(Grant::Allowance {
+            spender: call.spender,
+            amount: call.addedValue,
+        })
+    } else if selector == IERC721::setApprovalForAllCall::SELECTOR {
+        let call = IERC721::setApprovalForAllCall::abi_decode(input).ok()?;
+        call.approved.then_some(Grant::AllTokens {
+            operator: call.operator,
+        })
+    } else {
+        None
+    }
+}
+
+/// A warning for `input` if it grants an allowance worth flagging, sent to the
+/// token or collection at `to` on `chain_id`. Revocations, whether of the
+/// amount or to the zero address, aren't flagged.
+pub(crate) fn approval_warning(
+    input: &[u8],
+    to: Address,
+    chain_id: Option<u64>,
+    contracts: &ContractRegistry,
+    tokens: &TokenRegistry,
+) -> Option<SignablePayloadField> {
+    let grant = decode_grant(input)?;
+    let spender = match &grant {
+        Grant::Allowance { spender, .. } => *spender,
+        Grant::AllTokens { operator } => *operator,
+    };
+    let known_spender = contracts.lookup(chain_id, spender);
+    let spender_name = known_spender.map_or_else(|| addr(spender), |info| info.name.clone());
+
+    let mut risks = Vec::new();
+    match grant {
+        Grant::Allowance { amount, .. } if amount.is_zero() || spender == Address::ZERO => {
+            return None;
+        }
+        // A known collection's `approve` takes a token ID, not an amount
+        Grant::Allowance { .. }
+            if contracts
+                .lookup(chain_id, to)
+                .is_some_and(|info| info.kind.is_nft()) => {}
+        Grant::Allowance { amount, .. } => {
+            let token = tokens.token(chain_id, to);
+            let token_name = token.map_or("tokens", |token| token.symbol);
+            if amount == U256::MAX {
+                risks.push(format!(
+                    "Unlimited approval: {spender_name} can spend all of your {token_name} at {to:?}, now and in the future."
+                ));
+            } else if amount >= NEAR_UNLIMITED {
+                risks.push(format!(
+                    "Near-unlimited approval of {}: {spender_name} can spend effectively all of your {token_name} at {to:?}.",
+                    format_amount(token, to, amount)
+                ));
+            }
+        }
+        Grant::AllTokens { .. } => risks.push(format!(
+            "Approval for all: {spender_name} can transfer every token you hold in the collection at {to:?}."
+        )),
+    }
+    if known_spender.is_none() {
+        risks.push(format!(
+            "Unrecognized spender: {spender:?} is not in the contract registry. Only approve it if you trust it with these assets."
+        ));
+    }
+
+    (!risks.is_empty()).then(|| plain_text_field("Approval Warning", &risks.join(" ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::{ContractInfo, ContractKind};
+    use alloy_primitives::address;
+
+    const USDC: Address = address!("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const ROUTER: Address = address!("0x66a9893cc07d91d95644aedd05d03f95e1dba8af");
+    const PERMIT2: Address = address!("0x000000000022d473030f116ddee9f6b43ac78ba3");
+    const POSITIONS_NFT: Address = address!("0xc36442b4a4522e871399cd717abdd847ab11fe88");
+
+    fn warning(input: &[u8], to: Address) -> Option<String> {
+        approval_warning(
+            input,
+            to,
+            Some(1),
+            &ContractRegistry::default(),
+            &TokenRegistry::default(),
+        )
+        .map(|field| field.fallback_text().clone())
+    }
+
+    fn approve(spender: Address, amount: U256) -> Vec<u8> {
+        IERC20::approveCall { spender, amount }.abi_encode()
+    }
+
+    #[test]
+    fn test_allowance_size() {
+        assert_eq!(
+            warning(&approve(ROUTER, U256::MAX), USDC).unwrap(),
+            "Unlimited approval: Uniswap Universal Router can spend all of your USDC at 0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48, now and in the future."
+        );
+        let increase = IERC20::increaseAllowanceCall {
+            spender: ROUTER,
+            addedValue: NEAR_UNLIMITED,
+        }
+        .abi_encode();
+        assert!(
+            warning(&increase, USDC)
+                .unwrap()
+                .starts_with("Near-unlimited approval of 79228162514264337593543.950335 USDC")
+        );
+
+        // Permit2 is a known spender, and unknown tokens are flagged all the same
+        assert_eq!(
+            warning(&approve(PERMIT2, U256::MAX), USDC).unwrap(),
+            "Unlimited approval: Permit2 can spend all of your USDC at 0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48, now and in the future."
+        );
+        let unknown_token = Address::repeat_byte(0x77);
+        assert_eq!(
+            warning(&approve(PERMIT2, U256::MAX), unknown_token).unwrap(),
+            "Unlimited approval: Permit2 can spend all of your tokens at 0x7777777777777777777777777777777777777777, now and in the future."
+        );
+
+        // A bounded approval to a known contract, or a revocation, is fine
+        assert_eq!(
+            warning(&approve(ROUTER, U256::from(1_000_000u64)), USDC),
+            None
+        );
+        assert_eq!(
+            warning(&approve(Address::repeat_byte(0x42), U256::ZERO), USDC),
+            None
+        );
+    }
+
+    #[test]
+    fn test_nft_approve_and_zero_spender() {
+        // ERC721 `approve(to, tokenId)`: on a known collection a large token ID
+        // isn't an unlimited allowance
+        assert_eq!(warning(&approve(ROUTER, U256::MAX), POSITIONS_NFT), None);
+        assert!(
+            warning(
+                &approve(Address::repeat_byte(0x42), U256::MAX),
+                POSITIONS_NFT
+            )
+            .unwrap()
+            .starts_with("Unrecognized spender")
+        );
+        let collection = Address::repeat_byte(0x77);
+
+        // Approving the zero address clears an NFT's approval
+        assert_eq!(
+            warning(&approve(Address::ZERO, U256::from(42u64)), collection),
+            None
+        );
+        assert_eq!(warning(&approve(Address::ZERO, U256::MAX), USDC), None);
+    }
+
+    #[test]
+    fn test_unknown_spender_and_approval_for_all() {
+        let stranger = Address::repeat_byte(0x42);
+        assert_eq!(
+            warning(&approve(stranger, U256::from(5u64)), USDC).unwrap(),
+            "Unrecognized spender: 0x4242424242424242424242424242424242424242 is not in the contract registry. Only approve it if you trust it with these assets."
+        );
+
+        let collection = Address::repeat_byte(0x77);
+        let approve_all = IERC721::setApprovalForAllCall {
+            operator: stranger,
+            approved: true,
+        }
+        .abi_encode();
+        let text = warning(&approve_all, collection).unwrap();
+        assert!(text.starts_with(
+            "Approval for all: 0x4242424242424242424242424242424242424242 can transfer every token"
+        ));
+        assert!(text.contains("Unrecognized spender"));
+
+        // Operators in the registry are named, and only the blanket grant is flagged
+        let mut contracts = ContractRegistry::empty();
+        contracts.register(
+            1,
+            stranger,
+            ContractInfo {
+                name: "Marketplace".to_string(),
+                kind: ContractKind::UniswapUniversalRouter,
+            },
+        );
+        let field = approval_warning(
+            &approve_all,
+            collection,
+            Some(1),
+            &contracts,
+            &TokenRegistry::empty(),
+        )
+        .unwrap();
+        assert_eq!(
+            field.fallback_text(),
+            "Approval for all: Marketplace can transfer every token you hold in the collection at 0x7777777777777777777777777777777777777777."
+        );
+
+        let revoke = IERC721::setApprovalForAllCall {
+            operator: stranger,
+            approved: false,
+        }
+        .abi_encode();
+        assert_eq!(warning(&revoke, collection), None);
+    }
+}
@@ -1,2288 +1,127 @@
-// Helper function to get network name from chain ID
+//! EVM networks, keyed by chain id.
+//!
+//! The table in `data/chains.json` follows the chainlist format. It is a
+//! checked-in snapshot rather than a full export: every chain has a name and a
+//! testnet flag, but the native currency is filled in only for about 130
+//! widely used mainnets and testnets, and the block explorer for fewer. Other
+//! chains' amounts are shown in a generic unit. Running
+//! `examples/generate_chains.rs` on a current chainlist export fills both in
+//! for every chain that lists them.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+const BUILTIN_CHAINS: &str = include_str!("../data/chains.json");
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct NativeCurrency {
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainInfo {
+    pub chain_id: u64,
+    pub name: String,
+    #[serde(default)]
+    pub native_currency: Option<NativeCurrency>,
+    #[serde(default)]
+    pub explorer: Option<String>,
+    pub testnet: bool,
+}
+
+/// Ether, which transactions without a chain id were originally signed for.
+const ETHER: (&str, u8) = ("ETH", 18);
+
+/// Used for chains whose native currency isn't in the table. Nearly every EVM
+/// chain uses 18 decimals.
+const UNKNOWN_CURRENCY: (&str, u8) = ("native token", 18);
+
+fn chains() -> &'static HashMap<u64, ChainInfo> {
+    static CHAINS: OnceLock<HashMap<u64, ChainInfo>> = OnceLock::new();
+    CHAINS.get_or_init(|| {
+        let chains: Vec<ChainInfo> =
+            serde_json::from_str(BUILTIN_CHAINS).expect("built-in chain registry is valid");
+        chains
+            .into_iter()
+            .map(|chain| (chain.chain_id, chain))
+            .collect()
+    })
+}
+
+/// What the registry knows about `chain_id`.
+pub fn chain_info(chain_id: u64) -> Option<&'static ChainInfo> {
+    chains().get(&chain_id)
+}
+
+/// Display name for the network a transaction is signed for.
 pub fn get_chain_name(chain_id: Option<u64>) -> String {
     match chain_id {
-        Some(1) => "Ethereum Mainnet".to_string(),
-        Some(2) => "Expanse Network".to_string(),
-        Some(3) => "Ropsten".to_string(),
-        Some(4) => "Rinkeby".to_string(),
-        Some(5) => "Goerli".to_string(),
-        Some(7) => "ThaiChain".to_string(),
-        Some(8) => "Ubiq".to_string(),
-        Some(9) => "Ubiq Network Testnet".to_string(),
-        Some(10) => "OP Mainnet".to_string(),
-        Some(11) => "Metadium Mainnet".to_string(),
-        Some(12) => "Metadium Testnet".to_string(),
-        Some(13) => "Diode Testnet Staging".to_string(),
-        Some(14) => "Flare Mainnet".to_string(),
-        Some(15) => "Diode Prenet".to_string(),
-        Some(16) => "Songbird Testnet Coston".to_string(),
-        Some(17) => "ThaiChain 2.0 ThaiFi".to_string(),
-        Some(18) => "ThunderCore Testnet".to_string(),
-        Some(19) => "Songbird Canary-Network".to_string(),
-        Some(20) => "Elastos Smart Chain".to_string(),
-        Some(21) => "Elastos Smart Chain Testnet".to_string(),
-        Some(22) => "ELA-DID-Sidechain Mainnet".to_string(),
-        Some(23) => "ELA-DID-Sidechain Testnet".to_string(),
-        Some(24) => "KardiaChain Mainnet".to_string(),
-        Some(25) => "Cronos Mainnet".to_string(),
-        Some(26) => "Genesis L1 testnet".to_string(),
-        Some(27) => "ShibaChain".to_string(),
-        Some(29) => "Genesis L1".to_string(),
-        Some(30) => "Rootstock Mainnet".to_string(),
-        Some(31) => "Rootstock Testnet".to_string(),
-        Some(32) => "GoodData Testnet".to_string(),
-        Some(33) => "GoodData Mainnet".to_string(),
-        Some(34) => "SecureChain Mainnet".to_string(),
-        Some(35) => "TBWG Chain".to_string(),
-        Some(36) => "Dxchain Mainnet".to_string(),
-        Some(37) => "Xpla Mainnet".to_string(),
-        Some(38) => "Valorbit".to_string(),
-        Some(39) => "U2U Solaris Mainnet".to_string(),
-        Some(40) => "Telos EVM Mainnet".to_string(),
-        Some(41) => "Telos EVM Testnet".to_string(),
-        Some(42) => "LUKSO Mainnet".to_string(),
-        Some(43) => "Darwinia Pangolin Testnet".to_string(),
-        Some(44) => "Crab Network".to_string(),
-        Some(45) => "Darwinia Pangoro Testnet".to_string(),
-        Some(46) => "Darwinia Network".to_string(),
-        Some(47) => "Acria IntelliChain".to_string(),
-        Some(48) => "Ennothem Mainnet Proterozoic".to_string(),
-        Some(49) => "Ennothem Testnet Pioneer".to_string(),
-        Some(50) => "XDC Network".to_string(),
-        Some(51) => "XDC Apothem Network".to_string(),
-        Some(52) => "CoinEx Smart Chain Mainnet".to_string(),
-        Some(53) => "CoinEx Smart Chain Testnet".to_string(),
-        Some(54) => "Openpiece Mainnet".to_string(),
-        Some(55) => "Zyx Mainnet".to_string(),
-        Some(56) => "BNB Smart Chain Mainnet".to_string(),
-        Some(57) => "Syscoin Mainnet".to_string(),
-        Some(58) => "Ontology Mainnet".to_string(),
-        Some(60) => "GoChain".to_string(),
-        Some(61) => "Ethereum Classic".to_string(),
-        Some(63) => "Mordor Testnet".to_string(),
-        Some(64) => "Ellaism".to_string(),
-        Some(65) => "OKExChain Testnet".to_string(),
-        Some(66) => "OKXChain Mainnet".to_string(),
-        Some(67) => "DBChain Testnet".to_string(),
-        Some(68) => "SoterOne Mainnet".to_string(),
-        Some(69) => "Optimism Kovan".to_string(),
-        Some(70) => "Hoo Smart Chain".to_string(),
-        Some(71) => "Conflux eSpace (Testnet)".to_string(),
-        Some(72) => "DxChain Testnet".to_string(),
-        Some(73) => "FNCY".to_string(),
-        Some(74) => "IDChain Mainnet".to_string(),
-        Some(75) => "Decimal Smart Chain Mainnet".to_string(),
-        Some(76) => "Mix".to_string(),
-        Some(77) => "POA Network Sokol".to_string(),
-        Some(78) => "PrimusChain mainnet".to_string(),
-        Some(79) => "Zenith Mainnet".to_string(),
-        Some(80) => "GeneChain".to_string(),
-        Some(81) => "Japan Open Chain Mainnet".to_string(),
-        Some(82) => "Meter Mainnet".to_string(),
-        Some(83) => "Meter Testnet".to_string(),
-        Some(84) => "Linqto Devnet".to_string(),
-        Some(85) => "GateChain Testnet".to_string(),
-        Some(86) => "GateChain Mainnet".to_string(),
-        Some(87) => "Nova Network".to_string(),
-        Some(88) => "Viction".to_string(),
-        Some(89) => "Viction Testnet".to_string(),
-        Some(90) => "Garizon Stage0".to_string(),
-        Some(91) => "Garizon Stage1".to_string(),
-        Some(92) => "Garizon Stage2".to_string(),
-        Some(93) => "Garizon Stage3".to_string(),
-        Some(94) => "SwissDLT".to_string(),
-        Some(95) => "CamDL Mainnet".to_string(),
-        Some(96) => "KUB Mainnet".to_string(),
-        Some(97) => "BNB Smart Chain Testnet".to_string(),
-        Some(98) => "Six Protocol".to_string(),
-        Some(99) => "POA Network Core".to_string(),
-        Some(100) => "Gnosis".to_string(),
-        Some(101) => "EtherInc".to_string(),
-        Some(102) => "Web3Games Testnet".to_string(),
-        Some(103) => "WorldLand Mainnet".to_string(),
-        Some(104) => "Kaiba Lightning Chain Testnet".to_string(),
-        Some(105) => "Web3Games Devnet".to_string(),
-        Some(106) => "Velas EVM Mainnet".to_string(),
-        Some(107) => "Nebula Testnet".to_string(),
-        Some(108) => "ThunderCore Mainnet".to_string(),
-        Some(109) => "Shibarium".to_string(),
-        Some(110) => "Proton Testnet".to_string(),
-        Some(111) => "EtherLite Chain".to_string(),
-        Some(112) => "Coinbit Mainnet".to_string(),
-        Some(113) => "Dehvo".to_string(),
-        Some(114) => "Flare Testnet Coston2".to_string(),
-        Some(117) => "Uptick Mainnet".to_string(),
-        Some(118) => "Arcology Testnet".to_string(),
-        Some(119) => "ENULS Mainnet".to_string(),
-        Some(120) => "ENULS Testnet".to_string(),
-        Some(121) => "Realchain Mainnet".to_string(),
-        Some(122) => "Fuse Mainnet".to_string(),
-        Some(123) => "Fuse Sparknet".to_string(),
-        Some(124) => "Decentralized Web Mainnet".to_string(),
-        Some(125) => "OYchain Testnet".to_string(),
-        Some(126) => "OYchain Mainnet".to_string(),
-        Some(127) => "Factory 127 Mainnet".to_string(),
-        Some(128) => "Huobi ECO Chain Mainnet".to_string(),
-        Some(129) => "Innovator Chain".to_string(),
-        Some(130) => "Unichain".to_string(),
-        Some(131) => "Engram Testnet".to_string(),
-        Some(132) => "Namefi Chain Mainnet".to_string(),
-        Some(133) => "HashKey Chain Testnet".to_string(),
-        Some(134) => "iExec Sidechain".to_string(),
-        Some(135) => "Alyx Chain Testnet".to_string(),
-        Some(136) => "Deamchain Mainnet".to_string(),
-        Some(137) => "Polygon Mainnet".to_string(),
-        Some(138) => "Defi Oracle Meta Mainnet".to_string(),
-        Some(139) => "WoopChain Mainnet".to_string(),
-        Some(140) => "Eteria Mainnet".to_string(),
-        Some(141) => "Openpiece Testnet".to_string(),
-        Some(142) => "DAX CHAIN".to_string(),
-        Some(143) => "Monad Mainnet".to_string(),
-        Some(144) => "PHI Network v2".to_string(),
-        Some(145) => "SoraAI Testnet".to_string(),
-        Some(146) => "Sonic Mainnet".to_string(),
-        Some(147) => "Flag Mainnet".to_string(),
-        Some(148) => "ShimmerEVM".to_string(),
-        Some(150) => "Six Protocol Testnet".to_string(),
-        Some(151) => "Redbelly Network Mainnet".to_string(),
-        Some(152) => "Redbelly Network Devnet".to_string(),
-        Some(153) => "Redbelly Network Testnet".to_string(),
-        Some(154) => "Redbelly Network TGE".to_string(),
-        Some(155) => "Tenet Testnet".to_string(),
-        Some(156) => "OEBlock Testnet".to_string(),
-        Some(157) => "Puppynet".to_string(),
-        Some(158) => "Roburna Mainnet".to_string(),
-        Some(159) => "Roburna Testnet".to_string(),
-        Some(160) => "Armonia Eva Chain Mainnet".to_string(),
-        Some(161) => "Armonia Eva Chain Testnet".to_string(),
-        Some(162) => "Lightstreams Testnet".to_string(),
-        Some(163) => "Lightstreams Mainnet".to_string(),
-        Some(164) => "Omni Omega Testnet".to_string(),
-        Some(166) => "Omni".to_string(),
-        Some(167) => "Atoshi Testnet".to_string(),
-        Some(168) => "AIOZ Network".to_string(),
-        Some(169) => "Manta Pacific Mainnet".to_string(),
-        Some(170) => "HOO Smart Chain Testnet".to_string(),
-        Some(171) => "CO2e Chain".to_string(),
-        Some(172) => "Latam-Blockchain Resil Testnet".to_string(),
-        Some(175) => "OTC".to_string(),
-        Some(176) => "DC Mainnet".to_string(),
-        Some(177) => "HashKey Chain".to_string(),
-        Some(178) => "ABEY Testnet".to_string(),
-        Some(179) => "ABEY Mainnet".to_string(),
-        Some(180) => "AME Chain Mainnet".to_string(),
-        Some(181) => "Waterfall Network".to_string(),
-        Some(182) => "IOST Mainnet".to_string(),
-        Some(183) => "Ethernity".to_string(),
-        Some(184) => "Dojima Testnet".to_string(),
-        Some(185) => "Mint Mainnet".to_string(),
-        Some(186) => "Seele Mainnet".to_string(),
-        Some(187) => "Dojima".to_string(),
-        Some(188) => "BMC Mainnet".to_string(),
-        Some(189) => "BMC Testnet".to_string(),
-        Some(190) => "CMDAO BBQ Chain".to_string(),
-        Some(191) => "FileFileGo".to_string(),
-        Some(193) => "Crypto Emergency".to_string(),
-        Some(194) => "firachain".to_string(),
-        Some(195) => "X Layer Testnet".to_string(),
-        Some(196) => "X Layer Mainnet".to_string(),
-        Some(197) => "Neutrinos TestNet".to_string(),
-        Some(198) => "Bitchain Mainnet".to_string(),
-        Some(199) => "BitTorrent Chain Mainnet".to_string(),
-        Some(200) => "Arbitrum on xDai".to_string(),
-        Some(201) => "MOAC testnet".to_string(),
-        Some(202) => "Edgeless Testnet".to_string(),
-        Some(203) => "WowChain Mainnet".to_string(),
-        Some(204) => "opBNB Mainnet".to_string(),
-        Some(205) => "EKAASH".to_string(),
-        Some(206) => "VinuChain Testnet".to_string(),
-        Some(207) => "VinuChain Network".to_string(),
-        Some(208) => "Structx Mainnet".to_string(),
-        Some(210) => "Bitnet".to_string(),
-        Some(211) => "Freight Trust Network".to_string(),
-        Some(212) => "MAPO Makalu".to_string(),
-        Some(213) => "B2 Hub Mainnet".to_string(),
-        Some(214) => "Shinarium Mainnet".to_string(),
-        Some(215) => "IDN Mainnet".to_string(),
-        Some(216) => "Happychain Testnet".to_string(),
-        Some(217) => "SiriusNet V2".to_string(),
-        Some(220) => "Scalind Testnet".to_string(),
-        Some(221) => "BlockEx Mainnet".to_string(),
-        Some(223) => "B2 Mainnet".to_string(),
-        Some(224) => "Viridis Testnet".to_string(),
-        Some(225) => "LACHAIN Mainnet".to_string(),
-        Some(226) => "LACHAIN Testnet".to_string(),
-        Some(227) => "Prom".to_string(),
-        Some(228) => "Mind Network Mainnet".to_string(),
-        Some(230) => "SwapDEX".to_string(),
-        Some(232) => "Lens".to_string(),
-        Some(233) => "Ethernity Testnet".to_string(),
-        Some(234) => "ProtoJumbo Testnet".to_string(),
-        Some(236) => "Deamchain Testnet".to_string(),
-        Some(238) => "Blast Mainnet".to_string(),
-        Some(239) => "TAC Mainnet".to_string(),
-        Some(240) => "Cronos zkEVM Testnet".to_string(),
-        Some(242) => "Plinga Mainnet".to_string(),
-        Some(246) => "Energy Web Chain".to_string(),
-        Some(247) => "ChooChain".to_string(),
-        Some(248) => "Oasys Mainnet".to_string(),
-        Some(250) => "Fantom Opera".to_string(),
-        Some(251) => "Glide L1 Protocol XP".to_string(),
-        Some(252) => "Fraxtal".to_string(),
-        Some(253) => "Glide L2 Protocol XP".to_string(),
-        Some(254) => "Swan Chain Mainnet".to_string(),
-        Some(255) => "Kroma".to_string(),
-        Some(256) => "Huobi ECO Chain Testnet".to_string(),
-        Some(258) => "Setheum".to_string(),
-        Some(259) => "Neonlink Mainnet".to_string(),
-        Some(260) => "Guru Network".to_string(),
-        Some(261) => "Guru Network Testnet".to_string(),
-        Some(262) => "SUR Blockchain Network".to_string(),
-        Some(266) => "Neura".to_string(),
-        Some(267) => "Neura Testnet".to_string(),
-        Some(268) => "Neura Devnet".to_string(),
-        Some(269) => "High Performance Blockchain".to_string(),
-        Some(271) => "EgonCoin Mainnet".to_string(),
-        Some(273) => "XR One".to_string(),
-        Some(274) => "LaChain".to_string(),
-        Some(278) => "xFair.AI Mainnet".to_string(),
-        Some(279) => "BPX Chain".to_string(),
-        Some(288) => "Boba Network".to_string(),
-        Some(291) => "Orderly Mainnet".to_string(),
-        Some(293) => "DaVinci".to_string(),
-        Some(295) => "Hedera Mainnet".to_string(),
-        Some(296) => "Hedera Testnet".to_string(),
-        Some(297) => "Hedera Previewnet".to_string(),
-        Some(298) => "Hedera Localnet".to_string(),
-        Some(300) => "zkSync Sepolia Testnet".to_string(),
-        Some(302) => "ZKcandy Sepolia Testnet".to_string(),
-        Some(303) => "Neurochain Testnet".to_string(),
-        Some(305) => "ZKSats Mainnet".to_string(),
-        Some(307) => "Lovely Network Testnet".to_string(),
-        Some(308) => "Furtheon".to_string(),
-        Some(309) => "Wyzth Testnet".to_string(),
-        Some(311) => "Omax Mainnet".to_string(),
-        Some(313) => "Neurochain Mainnet".to_string(),
-        Some(314) => "Filecoin - Mainnet".to_string(),
-        Some(315) => "WorldEcoMoney".to_string(),
-        Some(320) => "ZKcandy Mainnet".to_string(),
-        Some(321) => "KCC Mainnet".to_string(),
-        Some(322) => "KCC Testnet".to_string(),
-        Some(323) => "BuyCex Infinity Chain".to_string(),
-        Some(324) => "zkSync Mainnet".to_string(),
-        Some(325) => "GRVT Exchange".to_string(),
-        Some(326) => "GRVT Exchange Testnet".to_string(),
-        Some(331) => "Telos zkEVM Testnet".to_string(),
-        Some(332) => "Omax Testnet".to_string(),
-        Some(333) => "Web3Q Mainnet".to_string(),
-        Some(335) => "DFK Chain Test".to_string(),
-        Some(336) => "Shiden".to_string(),
-        Some(337) => "R5 Network".to_string(),
-        Some(338) => "Cronos Testnet".to_string(),
-        Some(339) => "Pencils Protocol".to_string(),
-        Some(345) => "TSC Mainnet".to_string(),
-        Some(360) => "Shape".to_string(),
-        Some(361) => "Theta Mainnet".to_string(),
-        Some(363) => "Theta Sapphire Testnet".to_string(),
-        Some(364) => "Theta Amber Testnet".to_string(),
-        Some(365) => "Theta Testnet".to_string(),
-        Some(369) => "PulseChain".to_string(),
-        Some(371) => "Consta Testnet".to_string(),
-        Some(380) => "ZKAmoeba Testnet".to_string(),
-        Some(381) => "ZKAmoeba Mainnet".to_string(),
-        Some(385) => "Lisinski".to_string(),
-        Some(388) => "Cronos zkEVM Mainnet".to_string(),
-        Some(395) => "CamDL Testnet".to_string(),
-        Some(397) => "NEAR Protocol".to_string(),
-        Some(398) => "NEAR Protocol Testnet".to_string(),
-        Some(399) => "Nativ3 Mainnet".to_string(),
-        Some(400) => "HyperonChain TestNet".to_string(),
-        Some(401) => "Ozone Chain Testnet".to_string(),
-        Some(404) => "Syndr L3".to_string(),
-        Some(411) => "Pepe Chain Mainnet".to_string(),
-        Some(416) => "SX Network Mainnet".to_string(),
-        Some(418) => "LaTestnet".to_string(),
-        Some(420) => "Optimism Goerli Testnet".to_string(),
-        Some(422) => "Viridis Mainnet".to_string(),
-        Some(424) => "PGN (Public Goods Network)".to_string(),
-        Some(425) => "Stenix Mainnet".to_string(),
-        Some(426) => "The Widows Mite".to_string(),
-        Some(427) => "Zeeth Chain".to_string(),
-        Some(428) => "Geso Verse".to_string(),
-        Some(434) => "Boyaa Mainnet".to_string(),
-        Some(443) => "Ten Testnet".to_string(),
-        Some(444) => "Synapse Chain Testnet".to_string(),
-        Some(456) => "ARZIO Chain".to_string(),
-        Some(462) => "Areon Network Testnet".to_string(),
-        Some(463) => "Areon Network Mainnet".to_string(),
-        Some(466) => "AppChain".to_string(),
-        Some(478) => "Form Network".to_string(),
-        Some(480) => "World Chain".to_string(),
-        Some(486) => "Standard Mainnet".to_string(),
-        Some(488) => "BlackFort Exchange Network".to_string(),
-        Some(495) => "Landstars".to_string(),
-        Some(499) => "Rupaya".to_string(),
-        Some(500) => "Camino C-Chain".to_string(),
-        Some(501) => "Columbus Test Network".to_string(),
-        Some(510) => "Syndicate Chain".to_string(),
-        Some(512) => "Double-A Chain Mainnet".to_string(),
-        Some(513) => "Double-A Chain Testnet".to_string(),
-        Some(516) => "Gear Zero Network Mainnet".to_string(),
-        Some(520) => "XT Smart Chain Mainnet".to_string(),
-        Some(529) => "Firechain Mainnet".to_string(),
-        Some(530) => "Pundi AIFX Omnilayer".to_string(),
-        Some(534) => "Candle".to_string(),
-        Some(537) => "OpTrust Mainnet".to_string(),
-        Some(542) => "PAWCHAIN Testnet".to_string(),
-        Some(545) => "Flow EVM Testnet".to_string(),
-        Some(550) => "River".to_string(),
-        Some(555) => "Vela1 Chain Mainnet".to_string(),
-        Some(558) => "Tao Network".to_string(),
-        Some(565) => "Prometheuz Testnet".to_string(),
-        Some(568) => "Dogechain Testnet".to_string(),
-        Some(570) => "Rollux Mainnet".to_string(),
-        Some(571) => "MetaChain Mainnet".to_string(),
-        Some(579) => "Filenova Mainnet".to_string(),
-        Some(586) => "MarketCapy TestNet 1".to_string(),
-        Some(592) => "Astar".to_string(),
-        Some(595) => "Acala Mandala Testnet TC9".to_string(),
-        Some(596) => "Karura Network Testnet".to_string(),
-        Some(597) => "Acala Network Testnet".to_string(),
-        Some(600) => "Meshnyan testnet".to_string(),
-        Some(601) => "Vine Testnet".to_string(),
-        Some(610) => "Darwin Devnet".to_string(),
-        Some(612) => "EIOB Mainnet".to_string(),
-        Some(614) => "Graphlinq Blockchain Mainnet".to_string(),
-        Some(619) => "Skynet".to_string(),
-        Some(624) => "Binary Mainnet".to_string(),
-        Some(625) => "Binary Sepolia".to_string(),
-        Some(632) => "NFB Chain".to_string(),
-        Some(634) => "Avocado".to_string(),
-        Some(647) => "SX Network Testnet".to_string(),
-        Some(648) => "Endurance Smart Chain Mainnet".to_string(),
-        Some(653) => "Kalichain Testnet".to_string(),
-        Some(654) => "Kalichain".to_string(),
-        Some(662) => "AmaxSmartchain".to_string(),
-        Some(666) => "Pixie Chain Testnet".to_string(),
-        Some(667) => "LAOS Arrakis".to_string(),
-        Some(668) => "JuncaChain".to_string(),
-        Some(669) => "JuncaChain testnet".to_string(),
-        Some(678) => "Janction".to_string(),
-        Some(679) => "Janction Testnet".to_string(),
-        Some(686) => "Karura Network".to_string(),
-        Some(689) => "NERO Testnet".to_string(),
-        Some(690) => "Redstone".to_string(),
-        Some(698) => "Matchain".to_string(),
-        Some(699) => "Matchain Testnet".to_string(),
-        Some(700) => "Star Social Testnet".to_string(),
-        Some(701) => "Darwinia Koi Testnet".to_string(),
-        Some(707) => "BlockChain Station Mainnet".to_string(),
-        Some(708) => "BlockChain Station Testnet".to_string(),
-        Some(710) => "Highbury".to_string(),
-        Some(711) => "Tucana".to_string(),
-        Some(712) => "Birdee-2".to_string(),
-        Some(713) => "Vrcscan Mainnet".to_string(),
-        Some(718) => "UXLINK ONE Mainnet".to_string(),
-        Some(719) => "Shibarium Beta".to_string(),
-        Some(721) => "Lycan Chain".to_string(),
-        Some(727) => "Blucrates".to_string(),
-        Some(730) => "Lovely Network Mainnet".to_string(),
-        Some(741) => "Vention Smart Chain Testnet".to_string(),
-        Some(742) => "Script Testnet".to_string(),
-        Some(743) => "Tranched Mainnet".to_string(),
-        Some(747) => "Flow EVM Mainnet".to_string(),
-        Some(753) => "Rivalz".to_string(),
-        Some(766) => "QL1".to_string(),
-        Some(776) => "OpenChain Testnet".to_string(),
-        Some(777) => "cheapETH".to_string(),
-        Some(785) => "AUTHEO Testnet".to_string(),
-        Some(786) => "MAAL Chain".to_string(),
-        Some(787) => "Acala Network".to_string(),
-        Some(788) => "Aerochain Testnet".to_string(),
-        Some(789) => "Patex".to_string(),
-        Some(799) => "Rupaya Testnet".to_string(),
-        Some(800) => "Lucid Blockchain".to_string(),
-        Some(803) => "Haic".to_string(),
-        Some(805) => "Evoz Mainnet".to_string(),
-        Some(808) => "Portal Fantasy Chain Test".to_string(),
-        Some(810) => "Haven1 Testnet".to_string(),
-        Some(813) => "Qitmeer Network Mainnet".to_string(),
-        Some(814) => "Firechain zkEVM".to_string(),
-        Some(818) => "BeOne Chain Mainnet".to_string(),
-        Some(820) => "Callisto Mainnet".to_string(),
-        Some(822) => "Runic Chain Testnet".to_string(),
-        Some(824) => "Daily Network Mainnet".to_string(),
-        Some(825) => "Daily Network Testnet".to_string(),
-        Some(831) => "CheckDot Blockchain Devnet".to_string(),
-        Some(841) => "Taraxa Mainnet".to_string(),
-        Some(842) => "Taraxa Testnet".to_string(),
-        Some(852) => "HongKong Mainnet".to_string(),
-        Some(859) => "Zeeth Chain Dev".to_string(),
-        Some(861) => "Electra Network".to_string(),
-        Some(863) => "Radius Testnet".to_string(),
-        Some(868) => "Fantasia Chain Mainnet".to_string(),
-        Some(871) => "Electra Test Network".to_string(),
-        Some(876) => "Bandai Namco Research Verse Mainnet".to_string(),
-        Some(877) => "Dexit Network".to_string(),
-        Some(880) => "Ambros Chain Mainnet".to_string(),
-        Some(881) => "Weber Governance Mainnet".to_string(),
-        Some(888) => "Wanchain".to_string(),
-        Some(898) => "MAXI Chain Testnet".to_string(),
-        Some(899) => "MAXI Chain Mainnet".to_string(),
-        Some(900) => "Garizon Testnet Stage0".to_string(),
-        Some(901) => "Garizon Testnet Stage1".to_string(),
-        Some(902) => "Garizon Testnet Stage2".to_string(),
-        Some(903) => "Garizon Testnet Stage3".to_string(),
-        Some(909) => "Portal Fantasy Chain".to_string(),
-        Some(910) => "DecentraBone Layer1 Testnet".to_string(),
-        Some(911) => "TAPROOT Mainnet".to_string(),
-        Some(917) => "Rinia".to_string(),
-        Some(918) => "SlerfChain Mainnet".to_string(),
-        Some(919) => "Mode Testnet".to_string(),
-        Some(927) => "Yidark Chain Mainnet".to_string(),
-        Some(938) => "Haust Mainnet".to_string(),
-        Some(943) => "PulseChain Testnet v4".to_string(),
-        Some(945) => "Subtensor EVM Testnet".to_string(),
-        Some(956) => "muNode Testnet".to_string(),
-        Some(957) => "Lyra Chain".to_string(),
-        Some(963) => "BTC20 Smart Chain".to_string(),
-        Some(964) => "Subtensor EVM".to_string(),
-        Some(969) => "EthXY".to_string(),
-        Some(970) => "Oort Mainnet".to_string(),
-        Some(971) => "Oort Huygens".to_string(),
-        Some(972) => "Oort Ascraeus".to_string(),
-        Some(973) => "Palm Smart Chain".to_string(),
-        Some(977) => "Nepal Blockchain Network".to_string(),
-        Some(979) => "EthXY Testnet".to_string(),
-        Some(980) => "TOP Mainnet EVM".to_string(),
-        Some(984) => "IOPN Testnet".to_string(),
-        Some(985) => "Memo Smart Chain Mainnet".to_string(),
-        Some(986) => "LAGOM Mainnet".to_string(),
-        Some(987) => "BinaryChain Mainnet".to_string(),
-        Some(989) => "TOP Mainnet".to_string(),
-        Some(990) => "eLiberty Mainnet".to_string(),
-        Some(995) => "5ireChain Mainnet".to_string(),
-        Some(996) => "Bifrost Polkadot Mainnet".to_string(),
-        Some(997) => "5ireChain Thunder Testnet".to_string(),
-        Some(998) => "Hyperliquid EVM Testnet".to_string(),
-        Some(999) => "HyperEVM".to_string(),
-        Some(1000) => "GTON Mainnet".to_string(),
-        Some(1001) => "Kaia Kairos Testnet".to_string(),
-        Some(1003) => "Tectum Emission Token".to_string(),
-        Some(1004) => "T-EKTA".to_string(),
-        Some(1005) => "LemonChainTestnet".to_string(),
-        Some(1006) => "LemonChain".to_string(),
-        Some(1007) => "Newton Testnet".to_string(),
-        Some(1008) => "Eurus Mainnet".to_string(),
-        Some(1009) => "Jumbochain Mainnet".to_string(),
-        Some(1010) => "Evrice Network".to_string(),
-        Some(1011) => "Rebus Classic Mainnet".to_string(),
-        Some(1012) => "Newton".to_string(),
-        Some(1022) => "Sakura".to_string(),
-        Some(1023) => "Clover Testnet".to_string(),
-        Some(1024) => "CLV Parachain".to_string(),
-        Some(1029) => "BitTorrent Chain Donau".to_string(),
-        Some(1030) => "Conflux eSpace".to_string(),
-        Some(1031) => "Proxy Network Testnet".to_string(),
-        Some(1038) => "Bronos Testnet".to_string(),
-        Some(1039) => "Bronos Mainnet".to_string(),
-        Some(1071) => "OpenGPU Mainnet".to_string(),
-        Some(1073) => "ShimmerEVM Testnet".to_string(),
-        Some(1075) => "IOTA EVM Testnet".to_string(),
-        Some(1079) => "Mintara Testnet".to_string(),
-        Some(1080) => "Mintara Mainnet".to_string(),
-        Some(1088) => "Metis Andromeda Mainnet".to_string(),
-        Some(1089) => "Humans.ai Mainnet".to_string(),
-        Some(1099) => "MOAC mainnet".to_string(),
-        Some(1100) => "Dymension".to_string(),
-        Some(1101) => "Polygon zkEVM".to_string(),
-        Some(1107) => "BLXq Testnet".to_string(),
-        Some(1108) => "BLXq Mainnet".to_string(),
-        Some(1111) => "WEMIX3.0 Mainnet".to_string(),
-        Some(1112) => "WEMIX3.0 Testnet".to_string(),
-        Some(1113) => "B2 Hub Testnet".to_string(),
-        Some(1114) => "Core Blockchain Testnet2".to_string(),
-        Some(1115) => "Core Blockchain Testnet".to_string(),
-        Some(1116) => "Core Blockchain Mainnet".to_string(),
-        Some(1117) => "Dogcoin Mainnet".to_string(),
-        Some(1123) => "B2 Testnet".to_string(),
-        Some(1125) => "Taker Chain Mainnet".to_string(),
-        Some(1130) => "DeFiChain EVM Network Mainnet".to_string(),
-        Some(1131) => "DeFiChain EVM Network Testnet".to_string(),
-        Some(1133) => "DeFiMetaChain Changi Testnet".to_string(),
-        Some(1134) => "StateMesh".to_string(),
-        Some(1135) => "Lisk".to_string(),
-        Some(1138) => "AmStar Testnet".to_string(),
-        Some(1139) => "MathChain".to_string(),
-        Some(1140) => "MathChain Testnet".to_string(),
-        Some(1147) => "Flag Testnet".to_string(),
-        Some(1149) => "Symplexia Smart Chain".to_string(),
-        Some(1170) => "Origin Testnet".to_string(),
-        Some(1174) => "Litheum Test Network".to_string(),
-        Some(1177) => "Smart Host Teknoloji TESTNET".to_string(),
-        Some(1188) => "ClubMos Mainnet".to_string(),
-        Some(1197) => "Iora Chain".to_string(),
-        Some(1200) => "Cuckoo Chain".to_string(),
-        Some(1201) => "Evanesco Testnet".to_string(),
-        Some(1202) => "World Trade Technical Chain Mainnet".to_string(),
-        Some(1209) => "SaitaBlockChain(SBC)".to_string(),
-        Some(1210) => "Cuckoo Sepolia".to_string(),
-        Some(1212) => "ADF Chain Testnet".to_string(),
-        Some(1213) => "Popcateum Mainnet".to_string(),
-        Some(1214) => "EnterChain Mainnet".to_string(),
-        Some(1215) => "ADF Chain".to_string(),
-        Some(1221) => "Cycle Network Testnet".to_string(),
-        Some(1223) => "Cycle Network Testnet Jellyfish".to_string(),
-        Some(1225) => "Hybrid Testnet".to_string(),
-        Some(1227) => "Bitcoin Protocol Testnet".to_string(),
-        Some(1228) => "Cycle Network Testnet Cuttlefish".to_string(),
-        Some(1229) => "Exzo Network Mainnet".to_string(),
-        Some(1230) => "Ultron Testnet".to_string(),
-        Some(1231) => "Ultron Mainnet".to_string(),
-        Some(1234) => "Step Network".to_string(),
-        Some(1235) => "ITX Mainnet".to_string(),
-        Some(1243) => "ARC Mainnet".to_string(),
-        Some(1244) => "ARC Testnet".to_string(),
-        Some(1246) => "OM Platform Mainnet".to_string(),
-        Some(1248) => "Dogether Mainnet".to_string(),
-        Some(1252) => "CIC Chain Testnet".to_string(),
-        Some(1260) => "Metacces Testnet".to_string(),
-        Some(1280) => "HALO Mainnet".to_string(),
-        Some(1284) => "Moonbeam".to_string(),
-        Some(1285) => "Moonriver".to_string(),
-        Some(1287) => "Moonbase Alpha".to_string(),
-        Some(1288) => "Moonrock".to_string(),
-        Some(1291) => "Swisstronik Testnet".to_string(),
-        Some(1298) => "Argochain Testnet".to_string(),
-        Some(1299) => "Argochain".to_string(),
-        Some(1300) => "Glue Mainnet".to_string(),
-        Some(1301) => "Unichain Sepolia Testnet".to_string(),
-        Some(1310) => "COINZAX".to_string(),
-        Some(1311) => "Dos Fuji Subnet".to_string(),
-        Some(1313) => "JaiHo Chain".to_string(),
-        Some(1314) => "Alyx Mainnet".to_string(),
-        Some(1315) => "Story Aeneid Testnet".to_string(),
-        Some(1319) => "AIA Mainnet".to_string(),
-        Some(1320) => "AIA Testnet".to_string(),
-        Some(1328) => "Sei Testnet".to_string(),
-        Some(1329) => "Sei Network".to_string(),
-        Some(1336) => "Kii Testnet Oro".to_string(),
-        Some(1337) => "Geth Testnet".to_string(),
-        Some(1338) => "Elysium Testnet".to_string(),
-        Some(1339) => "Elysium Mainnet".to_string(),
-        Some(1343) => "Blitz Subnet".to_string(),
-        Some(1353) => "CIC Chain Mainnet".to_string(),
-        Some(1369) => "Zafirium Mainnet".to_string(),
-        Some(1370) => "Ramestta Mainnet".to_string(),
-        Some(1377) => "Pingaksha testnet".to_string(),
-        Some(1379) => "Kalar Chain".to_string(),
-        Some(1388) => "AmStar Mainnet".to_string(),
-        Some(1392) => "Joseon Mainnet".to_string(),
-        Some(1424) => "Perennial".to_string(),
-        Some(1425) => "ONINO Mainnet".to_string(),
-        Some(1433) => "Rikeza Network Mainnet".to_string(),
-        Some(1439) => "Injective Testnet".to_string(),
-        Some(1440) => "Living Assets Mainnet".to_string(),
-        Some(1442) => "Polygon zkEVM Testnet".to_string(),
-        Some(1452) => "GIL Testnet".to_string(),
-        Some(1453) => "MetaChain Istanbul".to_string(),
-        Some(1455) => "Ctex Scan Blockchain".to_string(),
-        Some(1456) => "ZKBase Mainnet".to_string(),
-        Some(1480) => "Vana".to_string(),
-        Some(1490) => "Vitruveo Mainnet".to_string(),
-        Some(1499) => "iDos Games Chain Testnet".to_string(),
-        Some(1501) => "BEVM Canary".to_string(),
-        Some(1506) => "Sherpax Mainnet".to_string(),
-        Some(1507) => "Sherpax Testnet".to_string(),
-        Some(1513) => "Story Testnet".to_string(),
-        Some(1514) => "Story".to_string(),
-        Some(1515) => "Beagle Messaging Chain".to_string(),
-        Some(1516) => "Story Odyssey Testnet".to_string(),
-        Some(1555) => "Datacore Smart Chain".to_string(),
-        Some(1559) => "Tenet".to_string(),
-        Some(1570) => "StarCHAIN Testnet".to_string(),
-        Some(1578) => "StarCHAIN".to_string(),
-        Some(1597) => "Reactive Mainnet".to_string(),
-        Some(1605) => "Betherance".to_string(),
-        Some(1617) => "Ethereum Inscription Mainnet".to_string(),
-        Some(1618) => "Catecoin Chain Mainnet".to_string(),
-        Some(1620) => "Atheios".to_string(),
-        Some(1625) => "Gravity Alpha Mainnet".to_string(),
-        Some(1648) => "Pivotal Mainnet".to_string(),
-        Some(1657) => "Btachain".to_string(),
-        Some(1662) => "Liquichain".to_string(),
-        Some(1663) => "Horizen Gobi Testnet".to_string(),
-        Some(1687) => "Mint Sepolia Testnet".to_string(),
-        Some(1688) => "LUDAN Mainnet".to_string(),
-        Some(1689) => "NERO Mainnet".to_string(),
-        Some(1701) => "Anytype EVM Chain".to_string(),
-        Some(1707) => "TBSI Mainnet".to_string(),
-        Some(1708) => "TBSI Testnet".to_string(),
-        Some(1717) => "Doric Network".to_string(),
-        Some(1718) => "Palette Chain Mainnet".to_string(),
-        Some(1727) => "Ethpar Mainnet".to_string(),
-        Some(1729) => "Reya Network".to_string(),
-        Some(1732) => "Fuga Mainnet".to_string(),
-        Some(1733) => "Fuga Testnet".to_string(),
-        Some(1734) => "Fuga Develop".to_string(),
-        Some(1740) => "Metal L2 Testnet".to_string(),
-        Some(1750) => "Metal L2".to_string(),
-        Some(1773) => "PartyChain".to_string(),
-        Some(1777) => "Gauss Mainnet".to_string(),
-        Some(1789) => "ZKBase Sepolia Testnet".to_string(),
-        Some(1804) => "Kerleano".to_string(),
-        Some(1807) => "Rabbit Analog Testnet Chain".to_string(),
-        Some(1811) => "Lif3 Chain Testnet".to_string(),
-        Some(1818) => "Cube Chain Mainnet".to_string(),
-        Some(1819) => "Cube Chain Testnet".to_string(),
-        Some(1821) => "Ruby Smart Chain MAINNET".to_string(),
-        Some(1829) => "PlayBlock".to_string(),
-        Some(1833) => "Verify testnet".to_string(),
-        Some(1848) => "Swisstronik Mainnet".to_string(),
-        Some(1853) => "HighOctane Subnet".to_string(),
-        Some(1856) => "Teslafunds".to_string(),
-        Some(1868) => "Soneium".to_string(),
-        Some(1875) => "Whitechain".to_string(),
-        Some(1881) => "Gitshock Cartenz Testnet".to_string(),
-        Some(1890) => "Lightlink Phoenix Mainnet".to_string(),
-        Some(1891) => "Lightlink Pegasus Testnet".to_string(),
-        Some(1898) => "BON Network".to_string(),
-        Some(1899) => "ReDeFi Layer 2".to_string(),
-        Some(1904) => "Sports Chain Network".to_string(),
-        Some(1907) => "Bitcichain Mainnet".to_string(),
-        Some(1908) => "Bitcichain Testnet".to_string(),
-        Some(1909) => "Merkle Scan".to_string(),
-        Some(1911) => "Scalind".to_string(),
-        Some(1912) => "Ruby Smart Chain Testnet".to_string(),
-        Some(1918) => "UPB CRESCDI Testnet".to_string(),
-        Some(1923) => "Swellchain".to_string(),
-        Some(1924) => "Swellchain Testnet".to_string(),
-        Some(1927) => "Arvix Testnet".to_string(),
-        Some(1945) => "ONUS Chain Testnet".to_string(),
-        Some(1946) => "Soneium Testnet Minato".to_string(),
-        Some(1949) => "Bionix Testnet".to_string(),
-        Some(1951) => "D-Chain Mainnet".to_string(),
-        Some(1953) => "Selendra Network Testnet".to_string(),
-        Some(1954) => "Dexilla Testnet".to_string(),
-        Some(1956) => "AIW3 Testnet".to_string(),
-        Some(1961) => "Selendra Network Mainnet".to_string(),
-        Some(1962) => "T-Rex Testnet".to_string(),
-        Some(1967) => "Eleanor".to_string(),
-        Some(1969) => "Super Smart Chain Testnet".to_string(),
-        Some(1970) => "Super Smart Chain Mainnet".to_string(),
-        Some(1971) => "Atelier".to_string(),
-        Some(1972) => "RedeCoin".to_string(),
-        Some(1975) => "ONUS Chain Mainnet".to_string(),
-        Some(1984) => "Eurus Testnet".to_string(),
-        Some(1985) => "SatoshIE".to_string(),
-        Some(1986) => "SatoshIE Testnet".to_string(),
-        Some(1987) => "EtherGem".to_string(),
-        Some(1989) => "Lydia Coin".to_string(),
-        Some(1990) => "QIE Mainnet".to_string(),
-        Some(1992) => "Hubble Exchange".to_string(),
-        Some(1993) => "B3 Sepolia Testnet".to_string(),
-        Some(1994) => "Ekta".to_string(),
-        Some(1995) => "edeXa Testnet".to_string(),
-        Some(1996) => "Sanko".to_string(),
-        Some(1997) => "Kyoto".to_string(),
-        Some(1998) => "Kyoto Testnet".to_string(),
-        Some(2000) => "Dogechain Mainnet".to_string(),
-        Some(2001) => "Milkomeda C1 Mainnet".to_string(),
-        Some(2002) => "Milkomeda A1 Mainnet".to_string(),
-        Some(2004) => "MetaLink Network".to_string(),
-        Some(2008) => "CloudWalk Testnet".to_string(),
-        Some(2009) => "CloudWalk Mainnet".to_string(),
-        Some(2013) => "Panarchy".to_string(),
-        Some(2014) => "NOW Chain Testnet".to_string(),
-        Some(2016) => "MainnetZ Mainnet".to_string(),
-        Some(2017) => "Adiri".to_string(),
-        Some(2018) => "PublicMint Devnet".to_string(),
-        Some(2019) => "PublicMint Testnet".to_string(),
-        Some(2020) => "Ronin".to_string(),
-        Some(2021) => "Edgeware EdgeEVM Mainnet".to_string(),
-        Some(2022) => "Beresheet BereEVM Testnet".to_string(),
-        Some(2023) => "Taycan Testnet".to_string(),
-        Some(2024) => "Swan Saturn Testnet".to_string(),
-        Some(2025) => "Rangers Protocol Mainnet".to_string(),
-        Some(2026) => "Edgeless Network".to_string(),
-        Some(2031) => "Centrifuge".to_string(),
-        Some(2032) => "Catalyst".to_string(),
-        Some(2035) => "Phala Network".to_string(),
-        Some(2037) => "Kiwi Subnet".to_string(),
-        Some(2038) => "Shrapnel Testnet".to_string(),
-        Some(2039) => "Aleph Zero".to_string(),
-        Some(2040) => "Vanar Mainnet".to_string(),
-        Some(2043) => "NeuroWeb".to_string(),
-        Some(2044) => "Shrapnel Subnet".to_string(),
-        Some(2045) => "AIW3 Mainnet".to_string(),
-        Some(2047) => "Stratos Testnet".to_string(),
-        Some(2048) => "Stratos".to_string(),
-        Some(2049) => "Movo Smart Chain Mainnet".to_string(),
-        Some(2071) => "Metacces Mainnet".to_string(),
-        Some(2077) => "Quokkacoin Mainnet".to_string(),
-        Some(2088) => "Altair".to_string(),
-        Some(2100) => "Ecoball Mainnet".to_string(),
-        Some(2101) => "Ecoball Testnet Espuma".to_string(),
-        Some(2105) => "IBVM Mainnet".to_string(),
-        Some(2107) => "IBVM Testnet".to_string(),
-        Some(2109) => "Exosama Network".to_string(),
-        Some(2112) => "UCHAIN Mainnet".to_string(),
-        Some(2121) => "Catena Mainnet".to_string(),
-        Some(2122) => "Metaplayerone Mainnet".to_string(),
-        Some(2124) => "Metaplayerone Dubai Testnet".to_string(),
-        Some(2136) => "BigShortBets Testnet".to_string(),
-        Some(2137) => "BigShortBets".to_string(),
-        Some(2138) => "Defi Oracle Meta Testnet".to_string(),
-        Some(2140) => "Oneness Network".to_string(),
-        Some(2141) => "Oneness TestNet".to_string(),
-        Some(2151) => "BOSagora Mainnet".to_string(),
-        Some(2152) => "Findora Mainnet".to_string(),
-        Some(2153) => "Findora Testnet".to_string(),
-        Some(2154) => "Findora Forge".to_string(),
-        Some(2162) => "Animechain Testnet".to_string(),
-        Some(2187) => "Game7".to_string(),
-        Some(2192) => "SnaxChain".to_string(),
-        Some(2199) => "Moonsama Network".to_string(),
-        Some(2202) => "Antofy Mainnet".to_string(),
-        Some(2203) => "Bitcoin EVM".to_string(),
-        Some(2213) => "Evanesco Mainnet".to_string(),
-        Some(2221) => "Kava Testnet".to_string(),
-        Some(2222) => "Kava".to_string(),
-        Some(2223) => "VChain Mainnet".to_string(),
-        Some(2241) => "Krest Network".to_string(),
-        Some(2300) => "BOMB Chain".to_string(),
-        Some(2306) => "Ebro Network".to_string(),
-        Some(2309) => "Arevia".to_string(),
-        Some(2310) => "CratD2C".to_string(),
-        Some(2311) => "Chronicle Vesuvius - Lit Protocol Testnet".to_string(),
-        Some(2323) => "SOMA Network Testnet".to_string(),
-        Some(2330) => "Altcoinchain".to_string(),
-        Some(2331) => "RSS3 VSL Sepolia Testnet".to_string(),
-        Some(2332) => "SOMA Network Mainnet".to_string(),
-        Some(2340) => "Atleta Olympia".to_string(),
-        Some(2342) => "Omnia Chain".to_string(),
-        Some(2345) => "GOAT Network".to_string(),
-        Some(2355) => "Silicon zkEVM".to_string(),
-        Some(2358) => "Kroma Sepolia".to_string(),
-        Some(2368) => "KiteAI Testnet".to_string(),
-        Some(2370) => "Nexis Network Testnet".to_string(),
-        Some(2390) => "TAC Turin".to_string(),
-        Some(2391) => "TAC Saint Petersburg".to_string(),
-        Some(2399) => "BOMB Chain Testnet".to_string(),
-        Some(2400) => "TCG Verse Mainnet".to_string(),
-        Some(2410) => "K2 Mainnet".to_string(),
-        Some(2415) => "XODEX".to_string(),
-        Some(2420) => "Rufus".to_string(),
-        Some(2424) => "inEVM Testnet".to_string(),
-        Some(2425) => "King Of Legends Mainnet".to_string(),
-        Some(2426) => "Standard Testnet".to_string(),
-        Some(2440) => "Atleta Network".to_string(),
-        Some(2442) => "Polygon zkEVM Cardona Testnet".to_string(),
-        Some(2458) => "Hybrid Chain Network Testnet".to_string(),
-        Some(2468) => "Hybrid Chain Network Mainnet".to_string(),
-        Some(2477) => "6Degree of Outreach".to_string(),
-        Some(2484) => "Unicorn Ultra Nebulas Testnet".to_string(),
-        Some(2488) => "NOW Chain Mainnet".to_string(),
-        Some(2512) => "K2 Testnet".to_string(),
-        Some(2522) => "Fraxtal Testnet".to_string(),
-        Some(2525) => "inEVM Mainnet".to_string(),
-        Some(2552) => "Bahamut horizon".to_string(),
-        Some(2559) => "Kortho Mainnet".to_string(),
-        Some(2569) => "TechPay Mainnet".to_string(),
-        Some(2605) => "Pho Blockchain Mainnet".to_string(),
-        Some(2606) => "PoCRNet".to_string(),
-        Some(2611) => "Redlight Chain Mainnet".to_string(),
-        Some(2612) => "EZChain C-Chain Mainnet".to_string(),
-        Some(2613) => "EZChain C-Chain Testnet".to_string(),
-        Some(2625) => "Whitechain Testnet".to_string(),
-        Some(2648) => "AILayer Testnet".to_string(),
-        Some(2649) => "AILayer Mainnet".to_string(),
-        Some(2662) => "APEX".to_string(),
-        Some(2710) => "Morph Testnet".to_string(),
-        Some(2718) => "K-LAOS".to_string(),
-        Some(2730) => "XR Sepolia".to_string(),
-        Some(2731) => "Elizabeth Testnet".to_string(),
-        Some(2741) => "Abstract".to_string(),
-        Some(2748) => "Nanon".to_string(),
-        Some(2777) => "GM Network Mainnet".to_string(),
-        Some(2786) => "Apertum".to_string(),
-        Some(2810) => "Morph Holesky".to_string(),
-        Some(2818) => "Morph".to_string(),
-        Some(2868) => "HyperAGI Mainnet".to_string(),
-        Some(2882) => "Chips Network".to_string(),
-        Some(2889) => "Aarma Mainnet".to_string(),
-        Some(2907) => "Elux Chain".to_string(),
-        Some(2911) => "HYCHAIN".to_string(),
-        Some(2941) => "Xenon Chain Testnet".to_string(),
-        Some(2999) => "BitYuan Mainnet".to_string(),
-        Some(3000) => "CENNZnet Rata".to_string(),
-        Some(3001) => "CENNZnet Nikau".to_string(),
-        Some(3003) => "Canxium Mainnet".to_string(),
-        Some(3011) => "PLAYA3ULL GAMES".to_string(),
-        Some(3030) => "BC Hyper Chain Mainnet".to_string(),
-        Some(3031) => "Orlando Chain".to_string(),
-        Some(3033) => "Rebus Testnet".to_string(),
-        Some(3068) => "Bifrost Mainnet".to_string(),
-        Some(3073) => "Movement EVM".to_string(),
-        Some(3084) => "XL Network Testnet".to_string(),
-        Some(3100) => "Immu3 EVM".to_string(),
-        Some(3102) => "Vulture EVM Beta".to_string(),
-        Some(3109) => "SatoshiVM".to_string(),
-        Some(3110) => "SatoshiVM Testnet".to_string(),
-        Some(3269) => "Dubxcoin network".to_string(),
-        Some(3270) => "Dubxcoin testnet".to_string(),
-        Some(3300) => "Realio Testnet".to_string(),
-        Some(3301) => "Realio".to_string(),
-        Some(3306) => "Debounce Subnet Testnet".to_string(),
-        Some(3331) => "ZCore Testnet".to_string(),
-        Some(3332) => "EthStorage L2 Mainnet".to_string(),
-        Some(3333) => "EthStorage Testnet".to_string(),
-        Some(3334) => "Web3Q Galileo".to_string(),
-        Some(3335) => "QuarkChain L2 Beta Testnet".to_string(),
-        Some(3336) => "EthStorage L2 Testnet".to_string(),
-        Some(3337) => "EthStorage Devnet".to_string(),
-        Some(3338) => "peaq".to_string(),
-        Some(3339) => "EthStorage Mainnet".to_string(),
-        Some(3344) => "Pentagon Chain".to_string(),
-        Some(3366) => "Meroneum".to_string(),
-        Some(3369) => "Meroneum Testnet".to_string(),
-        Some(3400) => "Paribu Net Mainnet".to_string(),
-        Some(3409) => "Pepe Unchained".to_string(),
-        Some(3424) => "EVOLVE Mainnet".to_string(),
-        Some(3434) => "SecureChain Testnet".to_string(),
-        Some(3456) => "LayerEdge testnet".to_string(),
-        Some(3490) => "GTCSCAN".to_string(),
-        Some(3500) => "Paribu Net Testnet".to_string(),
-        Some(3501) => "JFIN Chain".to_string(),
-        Some(3502) => "JFINPOS".to_string(),
-        Some(3601) => "PandoProject Mainnet".to_string(),
-        Some(3602) => "PandoProject Testnet".to_string(),
-        Some(3630) => "Tycooncoin".to_string(),
-        Some(3636) => "Botanix Testnet".to_string(),
-        Some(3637) => "Botanix Mainnet".to_string(),
-        Some(3639) => "iChain Network".to_string(),
-        Some(3645) => "iChain Testnet".to_string(),
-        Some(3666) => "Jouleverse Mainnet".to_string(),
-        Some(3690) => "Bittex Mainnet".to_string(),
-        Some(3693) => "Empire Network".to_string(),
-        Some(3698) => "SenjePowers Testnet".to_string(),
-        Some(3699) => "SenjePowers Mainnet".to_string(),
-        Some(3721) => "Xone Mainnet".to_string(),
-        Some(3737) => "Crossbell".to_string(),
-        Some(3776) => "Astar zkEVM".to_string(),
-        Some(3797) => "AlveyChain Mainnet".to_string(),
-        Some(3799) => "Tangle Testnet".to_string(),
-        Some(3885) => "Firechain zkEVM Ghostrider".to_string(),
-        Some(3888) => "KalyChain Mainnet".to_string(),
-        Some(3889) => "KalyChain Testnet".to_string(),
-        Some(3912) => "DRAC Network".to_string(),
-        Some(3939) => "DOS Testnet".to_string(),
-        Some(3966) => "DYNO Mainnet".to_string(),
-        Some(3967) => "DYNO Testnet".to_string(),
-        Some(3969) => "PayNetwork Mainnet".to_string(),
-        Some(3993) => "APEX Testnet".to_string(),
-        Some(3999) => "YuanChain Mainnet".to_string(),
-        Some(4000) => "Ozone Chain Mainnet".to_string(),
-        Some(4001) => "Peperium Chain Testnet".to_string(),
-        Some(4002) => "Fantom Testnet".to_string(),
-        Some(4003) => "X1 Fastnet".to_string(),
-        Some(4040) => "Carbonium Testnet Network".to_string(),
-        Some(4048) => "GANchain L1".to_string(),
-        Some(4058) => "Bahamut ocean".to_string(),
-        Some(4061) => "Nahmii 3 Mainnet".to_string(),
-        Some(4062) => "Nahmii 3 Testnet".to_string(),
-        Some(4078) => "Muster Mainnet".to_string(),
-        Some(4080) => "Tobe Chain Testnet".to_string(),
-        Some(4088) => "Zeroth Mainnet".to_string(),
-        Some(4090) => "Fastex Chain (Bahamut) Oasis Testnet".to_string(),
-        Some(4096) => "Bitindi Testnet".to_string(),
-        Some(4099) => "Bitindi Mainnet".to_string(),
-        Some(4102) => "AIOZ Network Testnet".to_string(),
-        Some(4139) => "Humans.ai Testnet".to_string(),
-        Some(4141) => "Tipboxcoin Testnet".to_string(),
-        Some(4157) => "CrossFi Testnet".to_string(),
-        Some(4158) => "CrossFi Mainnet".to_string(),
-        Some(4162) => "SX Rollup".to_string(),
-        Some(4181) => "PHI Network V1".to_string(),
-        Some(4200) => "Merlin Mainnet".to_string(),
-        Some(4201) => "LUKSO Testnet".to_string(),
-        Some(4202) => "Lisk Sepolia Testnet".to_string(),
-        Some(4203) => "Merlin Erigon Testnet".to_string(),
-        Some(4207) => "Layer Edge Mainnet".to_string(),
-        Some(4227) => "Hashfire Testnet".to_string(),
-        Some(4242) => "Nexi Mainnet".to_string(),
-        Some(4243) => "Nexi V2 Mainnet".to_string(),
-        Some(4269) => "Laika Testnet".to_string(),
-        Some(4321) => "Echos Chain".to_string(),
-        Some(4337) => "Beam".to_string(),
-        Some(4352) => "MemeCore".to_string(),
-        Some(4400) => "Credit Smart Chain Mainnet".to_string(),
-        Some(4422) => "Testnet Pika".to_string(),
-        Some(4444) => "Htmlcoin Mainnet".to_string(),
-        Some(4460) => "Orderly Sepolia Testnet".to_string(),
-        Some(4488) => "Hydra Chain".to_string(),
-        Some(4544) => "Emoney Network Testnet".to_string(),
-        Some(4545) => "Emoney Network Mainnet".to_string(),
-        Some(4547) => "TRUMPCHAIN".to_string(),
-        Some(4613) => "VERY Mainnet".to_string(),
-        Some(4646) => "MST Chain".to_string(),
-        Some(4653) => "Gold Chain".to_string(),
-        Some(4661) => "AppChain Testnet".to_string(),
-        Some(4689) => "IoTeX Network Mainnet".to_string(),
-        Some(4690) => "IoTeX Network Testnet".to_string(),
-        Some(4759) => "MEVerse Chain Testnet".to_string(),
-        Some(4786) => "Evnode Testnet".to_string(),
-        Some(4801) => "World Chain Sepolia Testnet".to_string(),
-        Some(4888) => "BlackFort Exchange Network Testnet".to_string(),
-        Some(4893) => "Globel Chain".to_string(),
-        Some(4913) => "OEV Network".to_string(),
-        Some(4918) => "Venidium Testnet".to_string(),
-        Some(4919) => "Venidium Mainnet".to_string(),
-        Some(5000) => "Mantle".to_string(),
-        Some(5001) => "Mantle Testnet".to_string(),
-        Some(5002) => "Treasurenet Mainnet Alpha".to_string(),
-        Some(5003) => "Mantle Sepolia Testnet".to_string(),
-        Some(5005) => "Treasurenet Testnet".to_string(),
-        Some(5039) => "ONIGIRI Test Subnet".to_string(),
-        Some(5040) => "ONIGIRI Subnet".to_string(),
-        Some(5050) => "Skate Mainnet".to_string(),
-        Some(5051) => "Nollie Skatechain Testnet".to_string(),
-        Some(5080) => "Pione Zero".to_string(),
-        Some(5090) => "Pione Chain Mainnet".to_string(),
-        Some(5100) => "Syndicate Testnet".to_string(),
-        Some(5101) => "Syndicate Frame Chain".to_string(),
-        Some(5102) => "SIC Testnet".to_string(),
-        Some(5103) => "Coordinape Testnet".to_string(),
-        Some(5104) => "Charmverse Testnet".to_string(),
-        Some(5105) => "Superloyalty Testnet".to_string(),
-        Some(5106) => "Azra Testnet".to_string(),
-        Some(5112) => "Ham".to_string(),
-        Some(5115) => "Citrea Testnet".to_string(),
-        Some(5124) => "Seismic devnet".to_string(),
-        Some(5151) => "Moca Chain Testnet".to_string(),
-        Some(5165) => "Bahamut".to_string(),
-        Some(5169) => "Smart Layer Network".to_string(),
-        Some(5177) => "TLChain Network Mainnet".to_string(),
-        Some(5197) => "EraSwap Mainnet".to_string(),
-        Some(5234) => "Humanode Mainnet".to_string(),
-        Some(5315) => "Uzmi Network Mainnet".to_string(),
-        Some(5317) => "OpTrust Testnet".to_string(),
-        Some(5321) => "ITX Testnet".to_string(),
-        Some(5330) => "Superseed".to_string(),
-        Some(5333) => "Netsbo".to_string(),
-        Some(5353) => "Tritanium Testnet".to_string(),
-        Some(5371) => "Settlus".to_string(),
-        Some(5373) => "Settlus Sepolia Testnet".to_string(),
-        Some(5424) => "edeXa Mainnet".to_string(),
-        Some(5433) => "Inertia Scan".to_string(),
-        Some(5439) => "Egochain".to_string(),
-        Some(5464) => "Saga".to_string(),
-        Some(5511) => "PointPay Mainnet".to_string(),
-        Some(5522) => "VEX EVM TESTNET".to_string(),
-        Some(5545) => "DuckChain Mainnet".to_string(),
-        Some(5551) => "Nahmii 2 Mainnet".to_string(),
-        Some(5555) => "Chain Verse Mainnet".to_string(),
-        Some(5611) => "opBNB Testnet".to_string(),
-        Some(5615) => "Arcturus Testneet".to_string(),
-        Some(5616) => "Arcturus Chain Testnet".to_string(),
-        Some(5656) => "QIE Blockchain".to_string(),
-        Some(5675) => "Filenova Testnet".to_string(),
-        Some(5678) => "Tanssi Demo".to_string(),
-        Some(5700) => "Syscoin Tanenbaum Testnet".to_string(),
-        Some(5729) => "Hika Network Testnet".to_string(),
-        Some(5758) => "SatoshiChain Testnet".to_string(),
-        Some(5777) => "Ganache".to_string(),
-        Some(5845) => "Tangle".to_string(),
-        Some(5851) => "Ontology Testnet".to_string(),
-        Some(5858) => "Chang Chain Foundation Mainnet".to_string(),
-        Some(5869) => "Wegochain Rubidium Mainnet".to_string(),
-        Some(6000) => "BounceBit Testnet".to_string(),
-        Some(6001) => "BounceBit Mainnet".to_string(),
-        Some(6060) => "BC Hyper Chain Testnet".to_string(),
-        Some(6065) => "Tres Testnet".to_string(),
-        Some(6066) => "Tres Mainnet".to_string(),
-        Some(6102) => "Cascadia Testnet".to_string(),
-        Some(6118) => "UPTN Testnet".to_string(),
-        Some(6119) => "UPTN".to_string(),
-        Some(6122) => "Tea Mainnet".to_string(),
-        Some(6278) => "Rails".to_string(),
-        Some(6283) => "LAOS".to_string(),
-        Some(6320) => "NFB Chain Testnet".to_string(),
-        Some(6321) => "Aura Euphoria Testnet".to_string(),
-        Some(6322) => "Aura Mainnet".to_string(),
-        Some(6342) => "MegaETH Testnet".to_string(),
-        Some(6363) => "Digit Soul Smart Chain".to_string(),
-        Some(6398) => "Connext Sepolia".to_string(),
-        Some(6502) => "Peerpay".to_string(),
-        Some(6550) => "Flamma Testnet".to_string(),
-        Some(6552) => "Scolcoin WeiChain Testnet".to_string(),
-        Some(6565) => "Fox Testnet Network".to_string(),
-        Some(6626) => "Pixie Chain Mainnet".to_string(),
-        Some(6660) => "Latest Chain Testnet".to_string(),
-        Some(6661) => "Cybria Mainnet".to_string(),
-        Some(6666) => "Cybria Testnet".to_string(),
-        Some(6667) => "Storchain".to_string(),
-        Some(6678) => "Edge Matrix Chain".to_string(),
-        Some(6688) => "IRIShub".to_string(),
-        Some(6699) => "OX Chain".to_string(),
-        Some(6701) => "PAXB Mainnet".to_string(),
-        Some(6779) => "Compverse Mainnet".to_string(),
-        Some(6789) => "Gold Smart Chain Mainnet".to_string(),
-        Some(6805) => "RACE Mainnet".to_string(),
-        Some(6806) => "RACE Testnet".to_string(),
-        Some(6868) => "Pools Mainnet".to_string(),
-        Some(6880) => "MTT Network".to_string(),
-        Some(6900) => "Nibiru cataclysm-1".to_string(),
-        Some(6911) => "Nibiru testnet-2".to_string(),
-        Some(6934) => "Xylume TestNet".to_string(),
-        Some(6942) => "Laika Mainnet".to_string(),
-        Some(6969) => "Tomb Chain Mainnet".to_string(),
-        Some(6999) => "PolySmartChain".to_string(),
-        Some(7000) => "ZetaChain Mainnet".to_string(),
-        Some(7001) => "ZetaChain Testnet".to_string(),
-        Some(7007) => "BST Chain".to_string(),
-        Some(7027) => "Ella the heart".to_string(),
-        Some(7070) => "Planq Mainnet".to_string(),
-        Some(7077) => "Planq Atlas Testnet".to_string(),
-        Some(7099) => "Bharat Blockchain Network".to_string(),
-        Some(7100) => "Nume".to_string(),
-        Some(7117) => "0XL3".to_string(),
-        Some(7118) => "Help The Homeless".to_string(),
-        Some(7171) => "Bitrock Mainnet".to_string(),
-        Some(7181) => "UXLINK One Testnet".to_string(),
-        Some(7200) => "exSat Mainnet".to_string(),
-        Some(7208) => "Nexera Mainnet".to_string(),
-        Some(7233) => "InitVerse Mainnet".to_string(),
-        Some(7234) => "InitVerse genesis testnet".to_string(),
-        Some(7244) => "ZEUS Testnet".to_string(),
-        Some(7300) => "XPLA Verse".to_string(),
-        Some(7331) => "KLYNTAR".to_string(),
-        Some(7332) => "Horizen EON Mainnet".to_string(),
-        Some(7341) => "Shyft Mainnet".to_string(),
-        Some(7368) => "Rarimo".to_string(),
-        Some(7484) => "Raba Network Mainnet".to_string(),
-        Some(7518) => "MEVerse Chain Mainnet".to_string(),
-        Some(7531) => "Rome Mainnet 0 Palatine".to_string(),
-        Some(7532) => "Rome Mainnet 0 Aventine".to_string(),
-        Some(7560) => "Cyber Mainnet".to_string(),
-        Some(7575) => "ADIL Testnet".to_string(),
-        Some(7576) => "Adil Chain V2 Mainnet".to_string(),
-        Some(7668) => "The Root Network - Mainnet".to_string(),
-        Some(7672) => "The Root Network - Porcini Testnet".to_string(),
-        Some(7700) => "Canto".to_string(),
-        Some(7701) => "Canto Tesnet".to_string(),
-        Some(7744) => "Phron Testnet".to_string(),
-        Some(7770) => "PandaSea Testnet".to_string(),
-        Some(7771) => "Bitrock Testnet".to_string(),
-        Some(7774) => "GDCC MAINNET".to_string(),
-        Some(7775) => "GDCC TESTNET".to_string(),
-        Some(7776) => "PandaSea Mainnet".to_string(),
-        Some(7777) => "Rise of the Warbots Testnet".to_string(),
-        Some(7778) => "Orenium Mainnet Protocol".to_string(),
-        Some(7788) => "Draw Coin".to_string(),
-        Some(7798) => "OpenEX LONG Testnet".to_string(),
-        Some(7860) => "MaalChain Testnet".to_string(),
-        Some(7862) => "MaalChain V2".to_string(),
-        Some(7863) => "MaalChain Testnet V2".to_string(),
-        Some(7865) => "Powerloom Mainnet".to_string(),
-        Some(7869) => "Powerloom Mainnet V2".to_string(),
-        Some(7878) => "Hazlor Testnet".to_string(),
-        Some(7879) => "Vexon Testnet".to_string(),
-        Some(7887) => "Kinto Mainnet".to_string(),
-        Some(7895) => "ARDENIUM Athena".to_string(),
-        Some(7897) => "arena-z".to_string(),
-        Some(7923) => "Dot Blox".to_string(),
-        Some(7924) => "MO Mainnet".to_string(),
-        Some(7957) => "Exorium Testnet".to_string(),
-        Some(7979) => "DOS Chain".to_string(),
-        Some(8000) => "Teleport".to_string(),
-        Some(8001) => "Teleport Testnet".to_string(),
-        Some(8008) => "Polynomial".to_string(),
-        Some(8017) => "iSunCoin Mainnet".to_string(),
-        Some(8029) => "MDGL Testnet".to_string(),
-        Some(8047) => "BOAT Mainnet".to_string(),
-        Some(8054) => "Karak Sepolia".to_string(),
-        Some(8080) => "Shardeum Liberty 1.X".to_string(),
-        Some(8081) => "Shardeum Liberty 2.X".to_string(),
-        Some(8082) => "Shardeum Sphinx 1.X".to_string(),
-        Some(8083) => "Shardeum Testnet".to_string(),
-        Some(8086) => "Bitcoin Chain".to_string(),
-        Some(8087) => "E-Dollar".to_string(),
-        Some(8098) => "StreamuX Blockchain".to_string(),
-        Some(8099) => "Bharat Blockchain Network Mainnet".to_string(),
-        Some(8108) => "ZenChain".to_string(),
-        Some(8118) => "Shardeum".to_string(),
-        Some(8131) => "Qitmeer Network Testnet".to_string(),
-        Some(8132) => "Qitmeer Network Mixnet".to_string(),
-        Some(8133) => "Qitmeer Network Privnet".to_string(),
-        Some(8134) => "Amana".to_string(),
-        Some(8135) => "Flana".to_string(),
-        Some(8136) => "Mizana".to_string(),
-        Some(8181) => "Testnet BeOne Chain".to_string(),
-        Some(8192) => "Torus Mainnet".to_string(),
-        Some(8194) => "Torus Testnet".to_string(),
-        Some(8217) => "Kaia Mainnet".to_string(),
-        Some(8227) => "Space Subnet".to_string(),
-        Some(8272) => "Blockton Blockchain".to_string(),
-        Some(8285) => "KorthoTest".to_string(),
-        Some(8329) => "Lorenzo".to_string(),
-        Some(8333) => "B3".to_string(),
-        Some(8386) => "XProtocol".to_string(),
-        Some(8387) => "Dracones Financial Services".to_string(),
-        Some(8408) => "ZenChain Testnet".to_string(),
-        Some(8428) => "THAT Mainnet".to_string(),
-        Some(8453) => "Base".to_string(),
-        Some(8545) => "Chakra Testnet".to_string(),
-        Some(8569) => "New Reality Blockchain".to_string(),
-        Some(8654) => "Toki Network".to_string(),
-        Some(8655) => "Toki Testnet".to_string(),
-        Some(8668) => "Hela Official Runtime Mainnet".to_string(),
-        Some(8723) => "TOOL Global Mainnet".to_string(),
-        Some(8724) => "TOOL Global Testnet".to_string(),
-        Some(8726) => "Storagechain Mainnet".to_string(),
-        Some(8727) => "Storagechain Testnet".to_string(),
-        Some(8732) => "Bullions Smart Chain".to_string(),
-        Some(8738) => "Alph Network".to_string(),
-        Some(8768) => "TMY Chain".to_string(),
-        Some(8801) => "Okto Testnet".to_string(),
-        Some(8811) => "Haven1".to_string(),
-        Some(8822) => "IOTA EVM".to_string(),
-        Some(8844) => "Hydra Chain Testnet".to_string(),
-        Some(8848) => "MARO Blockchain Mainnet".to_string(),
-        Some(8866) => "SuperLumio".to_string(),
-        Some(8869) => "Lif3 Chain".to_string(),
-        Some(8880) => "Unique".to_string(),
-        Some(8881) => "Quartz by Unique".to_string(),
-        Some(8882) => "Opal testnet by Unique".to_string(),
-        Some(8883) => "Sapphire by Unique".to_string(),
-        Some(8886) => "Avenium Testnet".to_string(),
-        Some(8888) => "XANAChain".to_string(),
-        Some(8889) => "Vyvo Smart Chain".to_string(),
-        Some(8890) => "Orenium Testnet Protocol".to_string(),
-        Some(8898) => "Mammoth Mainnet".to_string(),
-        Some(8899) => "JIBCHAIN L1".to_string(),
-        Some(8911) => "Algen".to_string(),
-        Some(8912) => "Algen Testnet".to_string(),
-        Some(8921) => "Algen Layer2".to_string(),
-        Some(8922) => "Algen Layer2 Testnet".to_string(),
-        Some(8989) => "Giant Mammoth Mainnet".to_string(),
-        Some(8995) => "bloxberg".to_string(),
-        Some(9000) => "Evmos Testnet".to_string(),
-        Some(9001) => "Evmos".to_string(),
-        Some(9003) => "Qubetics Alpha Testnet".to_string(),
-        Some(9007) => "Shido Testnet Block".to_string(),
-        Some(9008) => "Shido Network".to_string(),
-        Some(9012) => "BerylBit Mainnet".to_string(),
-        Some(9024) => "Nexa Testnet Block".to_string(),
-        Some(9025) => "Nexa Mainnet Block".to_string(),
-        Some(9029) => "Qubetics Testnet".to_string(),
-        Some(9030) => "Qubetics Mainnet".to_string(),
-        Some(9069) => "Apex Fusion - Nexus Mainnet".to_string(),
-        Some(9070) => "Apex Fusion - Nexus testnet".to_string(),
-        Some(9090) => "Inco Gentry Testnet".to_string(),
-        Some(9091) => "KPA Smart Chain Testnet".to_string(),
-        Some(9100) => "Genesis Coin".to_string(),
-        Some(9108) => "Destra Dubai Testnet".to_string(),
-        Some(9223) => "Codefin Mainnet".to_string(),
-        Some(9302) => "Galactica-Reticulum".to_string(),
-        Some(9339) => "Dogcoin Testnet".to_string(),
-        Some(9369) => "Z Chain".to_string(),
-        Some(9372) => "Oasys Testnet".to_string(),
-        Some(9393) => "Dela Sepolia Testnet".to_string(),
-        Some(9395) => "Evoke Mainnet".to_string(),
-        Some(9496) => "Load Alphanet".to_string(),
-        Some(9527) => "Rangers Protocol Testnet Robin".to_string(),
-        Some(9528) => "QEasyWeb3 Testnet".to_string(),
-        Some(9559) => "Neonlink Testnet".to_string(),
-        Some(9696) => "Rebus Mainnet".to_string(),
-        Some(9700) => "Oort MainnetDev".to_string(),
-        Some(9728) => "Boba BNB Testnet".to_string(),
-        Some(9746) => "Plasma Testnet".to_string(),
-        Some(9768) => "MainnetZ Testnet".to_string(),
-        Some(9779) => "PepeNetwork Mainnet".to_string(),
-        Some(9788) => "Tabi Testnetv2".to_string(),
-        Some(9789) => "Tabi Testnet".to_string(),
-        Some(9790) => "Carbon EVM".to_string(),
-        Some(9792) => "Carbon EVM Testnet".to_string(),
-        Some(9797) => "OptimusZ7 Mainnet".to_string(),
-        Some(9818) => "IMPERIUM TESTNET".to_string(),
-        Some(9819) => "IMPERIUM MAINNET".to_string(),
-        Some(9876) => "BinaryChain Testnet".to_string(),
-        Some(9888) => "Dogelayer Mainnet".to_string(),
-        Some(9889) => "pointledger".to_string(),
-        Some(9897) => "arena-z-testnet".to_string(),
-        Some(9898) => "Larissa Chain".to_string(),
-        Some(9901) => "Zytron Linea Mainnet".to_string(),
-        Some(9911) => "Espento Mainnet".to_string(),
-        Some(9966) => "UXER TESTNET NETWORK".to_string(),
-        Some(9977) => "Mind Smart Chain Testnet".to_string(),
-        Some(9980) => "Combo Mainnet".to_string(),
-        Some(9981) => "Volley Mainnet".to_string(),
-        Some(9982) => "MFEV CHAIN MAINNET".to_string(),
-        Some(9990) => "Agung Network".to_string(),
-        Some(9996) => "Mind Smart Chain Mainnet".to_string(),
-        Some(9997) => "AltLayer Testnet".to_string(),
-        Some(9998) => "Ztc Mainnet".to_string(),
-        Some(9999) => "myOwn Testnet".to_string(),
-        Some(10000) => "Smart Bitcoin Cash".to_string(),
-        Some(10001) => "ETHW-mainnet".to_string(),
-        Some(10010) => "Warden Testnet".to_string(),
-        Some(10011) => "DeepSafe Beta Mainnet".to_string(),
-        Some(10024) => "Gon Chain".to_string(),
-        Some(10025) => "AEON Chain".to_string(),
-        Some(10066) => "Chain Opera Testnet".to_string(),
-        Some(10081) => "Japan Open Chain Testnet".to_string(),
-        Some(10085) => "Volcano Chain Mainnet".to_string(),
-        Some(10086) => "SJATSH".to_string(),
-        Some(10096) => "MetaNova Verse".to_string(),
-        Some(10101) => "Blockchain Genesis Mainnet".to_string(),
-        Some(10121) => "Ozone Mainnet".to_string(),
-        Some(10143) => "Monad Testnet".to_string(),
-        Some(10200) => "Gnosis Chiado Testnet".to_string(),
-        Some(10201) => "MaxxChain Mainnet".to_string(),
-        Some(10218) => "Tea Sepolia Testnet".to_string(),
-        Some(10222) => "GLScan".to_string(),
-        Some(10242) => "Arthera Mainnet".to_string(),
-        Some(10243) => "Arthera Testnet".to_string(),
-        Some(10248) => "0XTade".to_string(),
-        Some(10321) => "TAO EVM Mainnet".to_string(),
-        Some(10324) => "TAO EVM Testnet".to_string(),
-        Some(10395) => "WorldLand Testnet".to_string(),
-        Some(10507) => "Numbers Mainnet".to_string(),
-        Some(10508) => "Numbers Testnet".to_string(),
-        Some(10823) => "CryptoCoinPay".to_string(),
-        Some(10849) => "Lamina1".to_string(),
-        Some(10850) => "Lamina1 Identity".to_string(),
-        Some(10888) => "GameSwift Chain Testnet".to_string(),
-        Some(10920) => "Fuse Flash Testnet".to_string(),
-        Some(10946) => "Quadrans Blockchain".to_string(),
-        Some(10947) => "Quadrans Blockchain Testnet".to_string(),
-        Some(11000) => "KB Chain".to_string(),
-        Some(11011) => "Shape Sepolia Testnet".to_string(),
-        Some(11100) => "Bool Network Beta Mainnet".to_string(),
-        Some(11110) => "Astra".to_string(),
-        Some(11111) => "WAGMI".to_string(),
-        Some(11115) => "Astra Testnet".to_string(),
-        Some(11119) => "HashBit Mainnet".to_string(),
-        Some(11124) => "Abstract Sepolia Testnet".to_string(),
-        Some(11221) => "Shine Chain".to_string(),
-        Some(11227) => "Jiritsu Testnet Subnet".to_string(),
-        Some(11235) => "Haqq Network".to_string(),
-        Some(11343) => "StateMesh Testnet".to_string(),
-        Some(11437) => "Shyft Testnet".to_string(),
-        Some(11451) => "eGold Chain".to_string(),
-        Some(11501) => "GEB Mainnet".to_string(),
-        Some(11503) => "BEVM Testnet".to_string(),
-        Some(11504) => "GEB Signet".to_string(),
-        Some(11521) => "SatsChain".to_string(),
-        Some(11612) => "Sardis Testnet".to_string(),
-        Some(11820) => "Artela Mainnet".to_string(),
-        Some(11822) => "Artela Testnet".to_string(),
-        Some(11891) => "Polygon Supernet Arianee".to_string(),
-        Some(12001) => "Fuse Testnet".to_string(),
-        Some(12009) => "SatoshiChain Mainnet".to_string(),
-        Some(12020) => "Aternos".to_string(),
-        Some(12051) => "Singularity ZERO Testnet".to_string(),
-        Some(12052) => "Singularity ZERO Mainnet".to_string(),
-        Some(12123) => "BRC Chain Mainnet".to_string(),
-        Some(12306) => "Fibonacci Mainnet".to_string(),
-        Some(12321) => "BLG Testnet".to_string(),
-        Some(12323) => "Huddle01 dRTC Chain".to_string(),
-        Some(12324) => "L3X Protocol".to_string(),
-        Some(12325) => "L3X Protocol Testnet".to_string(),
-        Some(12345) => "Step Testnet".to_string(),
-        Some(12358) => "GDPR Mainnet".to_string(),
-        Some(12553) => "RSS3 VSL Mainnet".to_string(),
-        Some(12715) => "Rikeza Network Testnet".to_string(),
-        Some(12781) => "Playdapp Testnet".to_string(),
-        Some(12890) => "Quantum Chain Testnet".to_string(),
-        Some(12898) => "PlayFair Testnet Subnet".to_string(),
-        Some(13000) => "SPS".to_string(),
-        Some(13308) => "Credit Smart Chain".to_string(),
-        Some(13337) => "Beam Testnet".to_string(),
-        Some(13370) => "Cannon Testnet".to_string(),
-        Some(13371) => "Immutable zkEVM".to_string(),
-        Some(13381) => "Phoenix Mainnet".to_string(),
-        Some(13396) => "Masa".to_string(),
-        Some(13473) => "Immutable zkEVM Testnet".to_string(),
-        Some(13505) => "Gravity Alpha Testnet Sepolia".to_string(),
-        Some(13600) => "Kronobit Mainnet".to_string(),
-        Some(13746) => "Game7 Testnet".to_string(),
-        Some(13812) => "Susono".to_string(),
-        Some(14000) => "SPS Testnet".to_string(),
-        Some(14088) => "Zeroth Testnet".to_string(),
-        Some(14149) => "Bitharvest Chain Mainnet".to_string(),
-        Some(14235) => "Bitlazer".to_string(),
-        Some(14324) => "EVOLVE Testnet".to_string(),
-        Some(14333) => "Vitruveo Testnet".to_string(),
-        Some(14800) => "Vana Moksha Testnet".to_string(),
-        Some(14801) => "Vana Satori Testnet".to_string(),
-        Some(14853) => "Humanode Testnet 5 Israfel".to_string(),
-        Some(15003) => "Immutable zkEVM Devnet".to_string(),
-        Some(15257) => "Poodl Testnet".to_string(),
-        Some(15259) => "Poodl Mainnet".to_string(),
-        Some(15430) => "KYMTC Mainnet".to_string(),
-        Some(15551) => "LoopNetwork Mainnet".to_string(),
-        Some(15555) => "Trust EVM Testnet".to_string(),
-        Some(15557) => "EOS EVM Network Testnet".to_string(),
-        Some(16000) => "MetaDot Mainnet".to_string(),
-        Some(16001) => "MetaDot Testnet".to_string(),
-        Some(16116) => "DeFiVerse Mainnet".to_string(),
-        Some(16166) => "Cypherium Mainnet".to_string(),
-        Some(16180) => "PLYR PHI".to_string(),
-        Some(16182) => "Eventum Testnet".to_string(),
-        Some(16350) => "Incentiv Devnet".to_string(),
-        Some(16481) => "Pivotal Sepolia".to_string(),
-        Some(16507) => "Genesys Mainnet".to_string(),
-        Some(16600) => "0G-Newton-Testnet".to_string(),
-        Some(16688) => "IRIShub Testnet".to_string(),
-        Some(16718) => "AirDAO Mainnet".to_string(),
-        Some(16888) => "IVAR Chain Testnet".to_string(),
-        Some(17000) => "Holesky".to_string(),
-        Some(17069) => "Garnet Holesky".to_string(),
-        Some(17071) => "Onchain Points".to_string(),
-        Some(17117) => "DeFiVerse Testnet".to_string(),
-        Some(17171) => "G8Chain Mainnet".to_string(),
-        Some(17172) => "Eclipse Subnet".to_string(),
-        Some(17180) => "Palette Chain Testnet".to_string(),
-        Some(17217) => "KONET Mainnet".to_string(),
-        Some(17735) => "Esports Chain".to_string(),
-        Some(17777) => "EOS EVM Network".to_string(),
-        Some(18000) => "Frontier of Dreams Testnet".to_string(),
-        Some(18122) => "Smart Trade Networks".to_string(),
-        Some(18159) => "Proof Of Memes".to_string(),
-        Some(18181) => "G8Chain Testnet".to_string(),
-        Some(18233) => "unreal".to_string(),
-        Some(18686) => "MXC zkEVM Moonchain".to_string(),
-        Some(18880) => "EXPchain Testnet".to_string(),
-        Some(18881) => "Ultra EVM Network Testnet".to_string(),
-        Some(18888) => "Titan (TKX)".to_string(),
-        Some(18889) => "Titan (TKX) Testnet".to_string(),
-        Some(19011) => "HOME Verse Mainnet".to_string(),
-        Some(19077) => "BlockX Atlantis Testnet".to_string(),
-        Some(19180) => "LocaChain Mainnet".to_string(),
-        Some(19191) => "BlockX Mainnet".to_string(),
-        Some(19224) => "Decentraconnect Social".to_string(),
-        Some(19515) => "SEC Testnet".to_string(),
-        Some(19516) => "SEC Mainnet".to_string(),
-        Some(19527) => "Magnet Network".to_string(),
-        Some(19546) => "Zytron Linea Testnet".to_string(),
-        Some(19600) => "LBRY Mainnet".to_string(),
-        Some(19777) => "Astra Sepolia".to_string(),
-        Some(19845) => "BTCIX Network".to_string(),
-        Some(19991) => "Ultra EVM Network".to_string(),
-        Some(20001) => "Camelark Mainnet".to_string(),
-        Some(20041) => "Niza Chain Mainnet".to_string(),
-        Some(20073) => "Niza Chain Testnet".to_string(),
-        Some(20143) => "Monad Devnet".to_string(),
-        Some(20441) => "XUSD ONE StableChain Mainnet".to_string(),
-        Some(20729) => "Callisto Testnet".to_string(),
-        Some(20736) => "P12 Chain".to_string(),
-        Some(20765) => "Jono11 Subnet".to_string(),
-        Some(20993) => "Fluent Developer Preview".to_string(),
-        Some(21000) => "Action Mainnet".to_string(),
-        Some(21004) => "C4EI".to_string(),
-        Some(21097) => "Rivest Testnet".to_string(),
-        Some(21133) => "All About Healthy".to_string(),
-        Some(21210) => "1Money Network Mainnet".to_string(),
-        Some(21223) => "DCpay Mainnet".to_string(),
-        Some(21224) => "DCpay Testnet".to_string(),
-        Some(21337) => "CENNZnet Azalea".to_string(),
-        Some(21363) => "Lestnet".to_string(),
-        Some(21816) => "omChain Mainnet".to_string(),
-        Some(21912) => "BSL Mainnet".to_string(),
-        Some(22023) => "Taycan".to_string(),
-        Some(22040) => "AirDAO Testnet".to_string(),
-        Some(22222) => "Nautilus Mainnet".to_string(),
-        Some(22324) => "GoldXChain Testnet".to_string(),
-        Some(22776) => "MAP Protocol".to_string(),
-        Some(23006) => "Antofy Testnet".to_string(),
-        Some(23023) => "PremiumBlock".to_string(),
-        Some(23118) => "Opside Testnet".to_string(),
-        Some(23294) => "Oasis Sapphire".to_string(),
-        Some(23295) => "Oasis Sapphire Testnet".to_string(),
-        Some(23451) => "DreyerX Mainnet".to_string(),
-        Some(23452) => "DreyerX Testnet".to_string(),
-        Some(23888) => "Blast Testnet".to_string(),
-        Some(24076) => "KYMTC Testnet".to_string(),
-        Some(24116) => "Amauti".to_string(),
-        Some(24125) => "Nexurachain".to_string(),
-        Some(24484) => "Webchain".to_string(),
-        Some(24734) => "MintMe.com Coin".to_string(),
-        Some(24816) => "Recall".to_string(),
-        Some(25186) => "LiquidLayer Mainnet".to_string(),
-        Some(25327) => "Everclear Mainnet".to_string(),
-        Some(25839) => "AlveyChain Testnet".to_string(),
-        Some(25888) => "Hammer Chain Mainnet".to_string(),
-        Some(25925) => "KUB Testnet".to_string(),
-        Some(26026) => "Ferrum Testnet".to_string(),
-        Some(26100) => "Ferrum Quantum Portal Network".to_string(),
-        Some(26482) => "DucatusX Testnet".to_string(),
-        Some(26483) => "DucatusX".to_string(),
-        Some(26600) => "Hertz Network Mainnet".to_string(),
-        Some(26863) => "OasisChain Mainnet".to_string(),
-        Some(26888) => "AB Core Testnet".to_string(),
-        Some(26988) => "Newton Finance Testnet".to_string(),
-        Some(27125) => "XferChain Testnet".to_string(),
-        Some(27181) => "KLAOS Nova".to_string(),
-        Some(27483) => "Nanon Sepolia".to_string(),
-        Some(27827) => "zeroone Mainnet Subnet".to_string(),
-        Some(28125) => "XferChain Mainnet".to_string(),
-        Some(28516) => "Vizing Testnet".to_string(),
-        Some(28518) => "Vizing Mainnet".to_string(),
-        Some(28528) => "Optimism Bedrock (Goerli Alpha Testnet)".to_string(),
-        Some(28882) => "Boba Sepolia".to_string(),
-        Some(29112) => "HYCHAIN Testnet".to_string(),
-        Some(29223) => "Nexa MetaNet".to_string(),
-        Some(29225) => "Nexa MetaTest".to_string(),
-        Some(29536) => "KaiChain Testnet".to_string(),
-        Some(29548) => "MCH Verse Mainnet".to_string(),
-        Some(30000) => "qChain Mainnet".to_string(),
-        Some(30067) => "Piece testnet".to_string(),
-        Some(30088) => "MiYou Mainnet".to_string(),
-        Some(30103) => "Cerium Testnet".to_string(),
-        Some(30730) => "Movement EVM Legacy".to_string(),
-        Some(30731) => "Movement EVM Devnet".to_string(),
-        Some(30732) => "Movement EVM Testnet".to_string(),
-        Some(31102) => "Ethersocial Network".to_string(),
-        Some(31223) => "CloudTx Mainnet".to_string(),
-        Some(31224) => "CloudTx Testnet".to_string(),
-        Some(31337) => "GoChain Testnet".to_string(),
-        Some(31414) => "Evoke Testnet".to_string(),
-        Some(31415) => "Wirex Pay Mainnet".to_string(),
-        Some(31611) => "Mezo Testnet".to_string(),
-        Some(31612) => "Mezo".to_string(),
-        Some(32001) => "W3Gamez Holesky Testnet".to_string(),
-        Some(32323) => "BasedAI".to_string(),
-        Some(32382) => "Santiment Intelligence Network".to_string(),
-        Some(32520) => "Bitgert Mainnet".to_string(),
-        Some(32659) => "Fusion Mainnet".to_string(),
-        Some(32769) => "Zilliqa EVM".to_string(),
-        Some(32770) => "Zilliqa 2 EVM proto-mainnet".to_string(),
-        Some(32990) => "Zilliqa EVM Isolated Server".to_string(),
-        Some(33033) => "Entangle Mainnet".to_string(),
-        Some(33101) => "Zilliqa EVM Testnet".to_string(),
-        Some(33103) => "Zilliqa 2 EVM proto-testnet".to_string(),
-        Some(33111) => "Curtis".to_string(),
-        Some(33133) => "Entangle Testnet".to_string(),
-        Some(33139) => "ApeChain".to_string(),
-        Some(33210) => "Cloudverse Subnet".to_string(),
-        Some(33333) => "Aves Mainnet".to_string(),
-        Some(33385) => "Zilliqa EVM Devnet".to_string(),
-        Some(33401) => "SlingShot".to_string(),
-        Some(33469) => "Zilliqa 2 EVM devnet".to_string(),
-        Some(33710) => "R5 Network Testnet".to_string(),
-        Some(33979) => "Funki".to_string(),
-        Some(33999) => "Pencils Protocol Sepolia Testnet".to_string(),
-        Some(34443) => "Mode".to_string(),
-        Some(34504) => "ZEUS Mainnet".to_string(),
-        Some(35011) => "J2O Taro".to_string(),
-        Some(35441) => "Q Mainnet".to_string(),
-        Some(35443) => "Q Testnet".to_string(),
-        Some(37111) => "Lens Testnet".to_string(),
-        Some(38400) => "ConnectorManager".to_string(),
-        Some(38401) => "ConnectorManager Robin".to_string(),
-        Some(39656) => "PRM Mainnet".to_string(),
-        Some(39797) => "Energi Mainnet".to_string(),
-        Some(39815) => "OHO Mainnet".to_string(),
-        Some(40000) => "DIV Chain".to_string(),
-        Some(41455) => "Aleph Zero EVM".to_string(),
-        Some(41500) => "Opulent-X BETA".to_string(),
-        Some(41923) => "EDU Chain".to_string(),
-        Some(42000) => "Helios Chain Testnet".to_string(),
-        Some(42001) => "PMON Chain".to_string(),
-        Some(42026) => "Donatuz".to_string(),
-        Some(42069) => "pegglecoin".to_string(),
-        Some(42070) => "WMC Testnet".to_string(),
-        Some(42072) => "AgentLayer Testnet".to_string(),
-        Some(42096) => "Heurist Testnet".to_string(),
-        Some(42161) => "Arbitrum One".to_string(),
-        Some(42170) => "Arbitrum Nova".to_string(),
-        Some(42220) => "Celo Mainnet".to_string(),
-        Some(42261) => "Oasis Emerald Testnet".to_string(),
-        Some(42262) => "Oasis Emerald".to_string(),
-        Some(42355) => "GoldXChain Mainnet".to_string(),
-        Some(42420) => "Asset Chain Mainnet".to_string(),
-        Some(42421) => "Asset Chain Testnet".to_string(),
-        Some(42766) => "ZKFair Mainnet".to_string(),
-        Some(42793) => "Etherlink Mainnet".to_string(),
-        Some(42801) => "Gesoten Verse Testnet".to_string(),
-        Some(42888) => "Kinto Testnet".to_string(),
-        Some(43110) => "Athereum".to_string(),
-        Some(43111) => "Hemi".to_string(),
-        Some(43113) => "Avalanche Fuji Testnet".to_string(),
-        Some(43114) => "Avalanche C-Chain".to_string(),
-        Some(43419) => "GUNZ".to_string(),
-        Some(43521) => "Formicarium".to_string(),
-        Some(43851) => "ZKFair Testnet".to_string(),
-        Some(44444) => "Frenchain".to_string(),
-        Some(44445) => "Quantum Network".to_string(),
-        Some(44787) => "Celo Alfajores Testnet".to_string(),
-        Some(45000) => "Autobahn Network".to_string(),
-        Some(45003) => "Juneo JUNE-Chain".to_string(),
-        Some(45004) => "Juneo DAI1-Chain".to_string(),
-        Some(45005) => "Juneo USDT1-Chain".to_string(),
-        Some(45006) => "Juneo USD1-Chain".to_string(),
-        Some(45007) => "Juneo mBTC1-Chain".to_string(),
-        Some(45008) => "Juneo GLD1-Chain".to_string(),
-        Some(45009) => "Juneo LTC1-Chain".to_string(),
-        Some(45010) => "Juneo DOGE1-Chain".to_string(),
-        Some(45011) => "Juneo EUR1-Chain".to_string(),
-        Some(45012) => "Juneo SGD1-Chain".to_string(),
-        Some(45013) => "Juneo BCH1-Chain".to_string(),
-        Some(45014) => "Juneo LINK1-Chain".to_string(),
-        Some(45454) => "Swamps L2".to_string(),
-        Some(45510) => "Deelance Mainnet".to_string(),
-        Some(45513) => "Blessnet".to_string(),
-        Some(46688) => "Fusion Testnet".to_string(),
-        Some(47763) => "Neo X Mainnet".to_string(),
-        Some(47803) => "ReDeFi Layer 1".to_string(),
-        Some(47805) => "REI Network".to_string(),
-        Some(48795) => "Space Subnet Testnet".to_string(),
-        Some(48898) => "Zircuit Garfield Testnet".to_string(),
-        Some(48899) => "Zircuit Testnet".to_string(),
-        Some(48900) => "Zircuit Mainnet".to_string(),
-        Some(49049) => "Wireshape Floripa Testnet".to_string(),
-        Some(49088) => "Bifrost Testnet".to_string(),
-        Some(49321) => "GUNZ Testnet".to_string(),
-        Some(49797) => "Energi Testnet".to_string(),
-        Some(50000) => "Citronus".to_string(),
-        Some(50001) => "Liveplex OracleEVM".to_string(),
-        Some(50005) => "Yooldo Verse Mainnet".to_string(),
-        Some(50006) => "Yooldo Verse Testnet".to_string(),
-        Some(50021) => "GTON Testnet".to_string(),
-        Some(50104) => "Sophon".to_string(),
-        Some(50312) => "Somnia Testnet".to_string(),
-        Some(50341) => "Reddio Testnet".to_string(),
-        Some(50342) => "Reddio".to_string(),
-        Some(50505) => "STB Testnet".to_string(),
-        Some(50888) => "Erbie Mainnet".to_string(),
-        Some(51178) => "Lumoz Testnet Alpha".to_string(),
-        Some(51712) => "Sardis Mainnet".to_string(),
-        Some(52014) => "Electroneum Mainnet".to_string(),
-        Some(52225) => "Cytonic Settlement Layer Testnet".to_string(),
-        Some(52226) => "Cytonic Ethereum Testnet".to_string(),
-        Some(53277) => "DOID".to_string(),
-        Some(53302) => "Superseed Sepolia Testnet".to_string(),
-        Some(53456) => "BirdLayer".to_string(),
-        Some(53457) => "DODOchain testnet".to_string(),
-        Some(53935) => "DFK Chain".to_string(),
-        Some(54170) => "Graphite Testnet".to_string(),
-        Some(54176) => "OverProtocol Mainnet".to_string(),
-        Some(54211) => "Haqq Chain Testnet".to_string(),
-        Some(54321) => "Toronet Testnet".to_string(),
-        Some(55004) => "Titan".to_string(),
-        Some(55007) => "Titan Sepolia".to_string(),
-        Some(55244) => "Superposition".to_string(),
-        Some(55551) => "Photon Aurora Testnet".to_string(),
-        Some(55555) => "REI Chain Mainnet".to_string(),
-        Some(55556) => "REI Chain Testnet".to_string(),
-        Some(55614) => "Flamma Mainnet".to_string(),
-        Some(56026) => "Lambda Chain Mainnet".to_string(),
-        Some(56288) => "Boba BNB Mainnet".to_string(),
-        Some(56400) => "Testnet Zeroone Subnet".to_string(),
-        Some(56789) => "VELO Labs Mainnet".to_string(),
-        Some(56797) => "DOID Testnet".to_string(),
-        Some(57000) => "Rollux Testnet".to_string(),
-        Some(57054) => "Sonic Blaze Testnet".to_string(),
-        Some(57073) => "Ink".to_string(),
-        Some(57451) => "COINSEC Network".to_string(),
-        Some(58008) => "Sepolia PGN (Public Goods Network)".to_string(),
-        Some(58680) => "Lumoz Quidditch Testnet".to_string(),
-        Some(59140) => "Linea Goerli".to_string(),
-        Some(59141) => "Linea Sepolia".to_string(),
-        Some(59144) => "Linea".to_string(),
-        Some(59902) => "Metis Sepolia Testnet".to_string(),
-        Some(59971) => "Genesys Code Mainnet".to_string(),
-        Some(60000) => "Thinkium Testnet Chain 0".to_string(),
-        Some(60001) => "Thinkium Testnet Chain 1".to_string(),
-        Some(60002) => "Thinkium Testnet Chain 2".to_string(),
-        Some(60103) => "Thinkium Testnet Chain 103".to_string(),
-        Some(60808) => "BOB".to_string(),
-        Some(60850) => "Perennial Sepolia".to_string(),
-        Some(61022) => "Orange Chain Mainnet".to_string(),
-        Some(61166) => "Treasure".to_string(),
-        Some(61406) => "KaiChain".to_string(),
-        Some(61800) => "AxelChain Dev-Net".to_string(),
-        Some(61803) => "Etica Mainnet".to_string(),
-        Some(61916) => "DoKEN Super Chain Mainnet".to_string(),
-        Some(62049) => "OPTOPIA Testnet".to_string(),
-        Some(62050) => "Optopia Mainnet".to_string(),
-        Some(62092) => "TikTrix Testnet".to_string(),
-        Some(62298) => "Citrea Devnet".to_string(),
-        Some(62320) => "Celo Baklava Testnet".to_string(),
-        Some(62621) => "MultiVAC Mainnet".to_string(),
-        Some(62831) => "PLYR TAU Testnet".to_string(),
-        Some(62850) => "LAOS Sigma Testnet".to_string(),
-        Some(63000) => "eSync Network Mainnet".to_string(),
-        Some(63002) => "eSync Network Testnet".to_string(),
-        Some(63157) => "Geist Mainnet".to_string(),
-        Some(64002) => "XCHAIN Testnet".to_string(),
-        Some(65349) => "CratD2C Testnet".to_string(),
-        Some(65357) => "Vecno Mainnet".to_string(),
-        Some(65450) => "Scolcoin Mainnet".to_string(),
-        Some(65535) => "CyberChain Mainnet".to_string(),
-        Some(65536) => "Automata Mainnet".to_string(),
-        Some(66665) => "Creator Chain Testnet".to_string(),
-        Some(66988) => "Janus Testnet".to_string(),
-        Some(67588) => "Cosmic Chain".to_string(),
-        Some(68770) => "DM2 Verse Mainnet".to_string(),
-        Some(68775) => "DM2 Verse Testnet".to_string(),
-        Some(69000) => "Animechain Mainnet".to_string(),
-        Some(69420) => "Condrieu".to_string(),
-        Some(70000) => "Thinkium Mainnet Chain 0".to_string(),
-        Some(70001) => "Thinkium Mainnet Chain 1".to_string(),
-        Some(70002) => "Thinkium Mainnet Chain 2".to_string(),
-        Some(70103) => "Thinkium Mainnet Chain 103".to_string(),
-        Some(70700) => "Proof of Play - Apex".to_string(),
-        Some(70701) => "Proof of Play - Boss".to_string(),
-        Some(71111) => "GuapcoinX".to_string(),
-        Some(71117) => "Wadzchain Testnet".to_string(),
-        Some(71393) => "Polyjuice Testnet".to_string(),
-        Some(71401) => "Godwoken Testnet v1".to_string(),
-        Some(71402) => "Godwoken Mainnet".to_string(),
-        Some(72080) => "Nexera Testnet".to_string(),
-        Some(72778) => "CAGA crypto Ankara testnet".to_string(),
-        Some(72888) => "CAGA mainnet".to_string(),
-        Some(72992) => "Grok Chain Mainnet".to_string(),
-        Some(73114) => "ICB Testnet".to_string(),
-        Some(73115) => "ICB Network".to_string(),
-        Some(73799) => "Energy Web Volta Testnet".to_string(),
-        Some(73927) => "Mixin Virtual Machine".to_string(),
-        Some(75000) => "ResinCoin Mainnet".to_string(),
-        Some(75338) => "AppLayer Testnet".to_string(),
-        Some(75512) => "GEEK Verse Mainnet".to_string(),
-        Some(75513) => "GEEK Verse Testnet".to_string(),
-        Some(77001) => "BORAchain mainnet".to_string(),
-        Some(77238) => "Foundry Chain Testnet".to_string(),
-        Some(77612) => "Vention Smart Chain Mainnet".to_string(),
-        Some(77652) => "Carrchain Testnet".to_string(),
-        Some(77677) => "Cycle Network Mainnet Sailboat".to_string(),
-        Some(77777) => "Toronet Mainnet".to_string(),
-        Some(78110) => "Firenze test network".to_string(),
-        Some(78281) => "Dragonfly Mainnet (Hexapod)".to_string(),
-        Some(78430) => "Amplify Subnet".to_string(),
-        Some(78431) => "Bulletin Subnet".to_string(),
-        Some(78432) => "Conduit Subnet".to_string(),
-        Some(78600) => "Vanguard".to_string(),
-        Some(79879) => "Gold Smart Chain Testnet".to_string(),
-        Some(80001) => "Mumbai".to_string(),
-        Some(80002) => "Amoy".to_string(),
-        Some(80008) => "Polynomial Sepolia".to_string(),
-        Some(80069) => "Berachain Bepolia".to_string(),
-        Some(80094) => "Berachain".to_string(),
-        Some(80096) => "Hizoco mainnet".to_string(),
-        Some(80451) => "Geo Genesis".to_string(),
-        Some(80931) => "Forta Chain".to_string(),
-        Some(81041) => "Nordek Mainnet".to_string(),
-        Some(81341) => "Amana Testnet".to_string(),
-        Some(81342) => "Amana Mixnet".to_string(),
-        Some(81343) => "Amana Privnet".to_string(),
-        Some(81351) => "Flana Testnet".to_string(),
-        Some(81352) => "Flana Mixnet".to_string(),
-        Some(81353) => "Flana Privnet".to_string(),
-        Some(81361) => "Mizana Testnet".to_string(),
-        Some(81362) => "Mizana Mixnet".to_string(),
-        Some(81363) => "Mizana Privnet".to_string(),
-        Some(81457) => "Blast".to_string(),
-        Some(81720) => "Quantum Chain Mainnet".to_string(),
-        Some(82459) => "Smart Layer Network Testnet".to_string(),
-        Some(82614) => "VEMP Horizon".to_string(),
-        Some(83144) => "Xprotocol Testnet".to_string(),
-        Some(83278) => "Esa".to_string(),
-        Some(83868) => "Xprotocol Sepolia".to_string(),
-        Some(83872) => "ZEDXION".to_string(),
-        Some(84531) => "Base Goerli Testnet".to_string(),
-        Some(84532) => "Base Sepolia Testnet".to_string(),
-        Some(84841) => "O Chain".to_string(),
-        Some(84886) => "Aerie Network".to_string(),
-        Some(85321) => "GDPR Testnet".to_string(),
-        Some(85449) => "CYBERTRUST".to_string(),
-        Some(88002) => "Nautilus Proteus Testnet".to_string(),
-        Some(88559) => "InoAi".to_string(),
-        Some(88800) => "ZKasino Mainnet".to_string(),
-        Some(88811) => "Unit Zero Mainnet".to_string(),
-        Some(88817) => "Unit Zero Testnet".to_string(),
-        Some(88819) => "Unit Zero Stagenet".to_string(),
-        Some(88866) => "Matr1x Testnet".to_string(),
-        Some(88882) => "Chiliz Spicy Testnet".to_string(),
-        Some(88888) => "Chiliz Chain".to_string(),
-        Some(88899) => "Unite".to_string(),
-        Some(90001) => "Pundi AIFX Omnilayer Testnet".to_string(),
-        Some(90002) => "UBIT SMARTCHAIN MAINNET".to_string(),
-        Some(90210) => "Beverly Hills".to_string(),
-        Some(90354) => "Camp Testnet".to_string(),
-        Some(91002) => "Nautilus Trition Chain".to_string(),
-        Some(91111) => "Henez Chain Mainnet".to_string(),
-        Some(91120) => "MetaDAP Enterprise Mainnet".to_string(),
-        Some(91715) => "Combo Testnet".to_string(),
-        Some(92001) => "Lambda Testnet".to_string(),
-        Some(92278) => "Miracle Chain".to_string(),
-        Some(93572) => "LiquidLayer Testnet".to_string(),
-        Some(93747) => "StratoVM Testnet".to_string(),
-        Some(94524) => "XCHAIN".to_string(),
-        Some(95432) => "SRICHAIN".to_string(),
-        Some(96368) => "Lux Testnet".to_string(),
-        Some(96369) => "Lux Mainnet".to_string(),
-        Some(96370) => "Lumoz Chain Mainnet".to_string(),
-        Some(96371) => "Wonder Testnet".to_string(),
-        Some(96970) => "Mantis Testnet (Hexapod)".to_string(),
-        Some(97053) => "Tetron Testnet Smart Chain".to_string(),
-        Some(97055) => "Tetron Smart Chain".to_string(),
-        Some(97435) => "SlingShot Testnet".to_string(),
-        Some(97453) => "Sidra Chain".to_string(),
-        Some(97531) => "Green Chain Testnet".to_string(),
-        Some(97741) => "PEPE Unchained".to_string(),
-        Some(97766) => "MetaBenz CHAIN".to_string(),
-        Some(97970) => "OptimusZ7 Testnet".to_string(),
-        Some(98866) => "Plume Mainnet".to_string(),
-        Some(98867) => "Plume Testnet".to_string(),
-        Some(98881) => "Ebi Chain".to_string(),
-        Some(98964) => "Pay1 Network".to_string(),
-        Some(98985) => "Superposition Testnet".to_string(),
-        Some(99099) => "eLiberty Testnet".to_string(),
-        Some(99876) => "Edge Matrix Chain Testnet".to_string(),
-        Some(99879) => "Edge Matrix Chain Sepolia".to_string(),
-        Some(99998) => "UB Smart Chain(testnet)".to_string(),
-        Some(99999) => "UB Smart Chain".to_string(),
-        Some(100000) => "QuarkChain Mainnet Root".to_string(),
-        Some(100001) => "QuarkChain Mainnet Shard 0".to_string(),
-        Some(100002) => "QuarkChain Mainnet Shard 1".to_string(),
-        Some(100003) => "QuarkChain Mainnet Shard 2".to_string(),
-        Some(100004) => "QuarkChain Mainnet Shard 3".to_string(),
-        Some(100005) => "QuarkChain Mainnet Shard 4".to_string(),
-        Some(100006) => "QuarkChain Mainnet Shard 5".to_string(),
-        Some(100007) => "QuarkChain Mainnet Shard 6".to_string(),
-        Some(100008) => "QuarkChain Mainnet Shard 7".to_string(),
-        Some(100009) => "VeChain".to_string(),
-        Some(100010) => "VeChain Testnet".to_string(),
-        Some(100011) => "QuarkChain L2 Mainnet".to_string(),
-        Some(100501) => "DeInfra Mainnet".to_string(),
-        Some(100610) => "Monsoon ".to_string(),
-        Some(101003) => "Socotra JUNE-Chain".to_string(),
-        Some(101010) => "Global Trust Network".to_string(),
-        Some(101088) => "Xitcoin".to_string(),
-        Some(102030) => "Creditcoin".to_string(),
-        Some(102031) => "Creditcoin Testnet".to_string(),
-        Some(102032) => "Creditcoin Devnet".to_string(),
-        Some(103090) => "Crystaleum".to_string(),
-        Some(103454) => "Masa Testnet".to_string(),
-        Some(104566) => "KaspaClassic Mainnet".to_string(),
-        Some(105105) => "Stratis Mainnet".to_string(),
-        Some(105363) => "Lumoz Chain Testnet".to_string(),
-        Some(108801) => "BROChain Mainnet".to_string(),
-        Some(110000) => "QuarkChain Devnet Root".to_string(),
-        Some(110001) => "QuarkChain Devnet Shard 0".to_string(),
-        Some(110002) => "QuarkChain Devnet Shard 1".to_string(),
-        Some(110003) => "QuarkChain Devnet Shard 2".to_string(),
-        Some(110004) => "QuarkChain Devnet Shard 3".to_string(),
-        Some(110005) => "QuarkChain Devnet Shard 4".to_string(),
-        Some(110006) => "QuarkChain Devnet Shard 5".to_string(),
-        Some(110007) => "QuarkChain Devnet Shard 6".to_string(),
-        Some(110008) => "QuarkChain Devnet Shard 7".to_string(),
-        Some(110011) => "QuarkChain L2 Testnet".to_string(),
-        Some(110110) => "Mars Credit".to_string(),
-        Some(111000) => "Siberium Test Network".to_string(),
-        Some(111111) => "Siberium Network".to_string(),
-        Some(111188) => "re.al".to_string(),
-        Some(111451) => "eGold Chain Testnet".to_string(),
-        Some(112358) => "Metachain One Mainnet".to_string(),
-        Some(119139) => "MetaDAP Enterprise Testnet".to_string(),
-        Some(121212) => "Rome Devnet Esquiline".to_string(),
-        Some(121213) => "Rome Devnet Subura".to_string(),
-        Some(121214) => "Rome Testnet Martius".to_string(),
-        Some(121215) => "Rome Testnet Caelian".to_string(),
-        Some(121224) => "Fushuma".to_string(),
-        Some(123321) => "Gemchain".to_string(),
-        Some(123456) => "ADIL Devnet".to_string(),
-        Some(124832) => "Mitosis Testnet".to_string(),
-        Some(128123) => "Etherlink Testnet".to_string(),
-        Some(129399) => "Tatara Testnet".to_string(),
-        Some(131313) => "Odyssey Chain (Testnet)".to_string(),
-        Some(131419) => "ETND Chain Mainnets".to_string(),
-        Some(132902) => "Form Testnet".to_string(),
-        Some(141319) => "MagApe Testnet".to_string(),
-        Some(141491) => "Bitharvest Chain Testnet".to_string(),
-        Some(142857) => "ICPlaza Mainnet".to_string(),
-        Some(153153) => "Odyssey Chain Mainnet".to_string(),
-        Some(158245) => "CryptoX".to_string(),
-        Some(158345) => "XCOIN".to_string(),
-        Some(161201) => "OpenLedger Testnet".to_string(),
-        Some(161212) => "PlayFi Mainnet".to_string(),
-        Some(161803) => "Eventum Mainnet".to_string(),
-        Some(165279) => "Eclat Mainnet".to_string(),
-        Some(167000) => "Taiko Alethia".to_string(),
-        Some(167009) => "Taiko Hekla".to_string(),
-        Some(168168) => "Zchains".to_string(),
-        Some(168169) => "MUD Chain".to_string(),
-        Some(171000) => "Fair Testnet".to_string(),
-        Some(171717) => "Wadzchain Mainnet".to_string(),
-        Some(175177) => "Chronicle - Lit Protocol Testnet".to_string(),
-        Some(175188) => "Chronicle Yellowstone - Lit Protocol Testnet".to_string(),
-        Some(175190) => "Chronicle Loa - Lit Protocol Testnet".to_string(),
-        Some(188710) => "Bitica Chain Mainnet".to_string(),
-        Some(188881) => "Condor Test Network".to_string(),
-        Some(191919) => "Altblockscan Mainnet".to_string(),
-        Some(192940) => "Mind Network Testnet".to_string(),
-        Some(193939) => "R0AR Chain".to_string(),
-        Some(198989) => "Lydia Coin Testnet".to_string(),
-        Some(199991) => "MAZZE Testnet".to_string(),
-        Some(200000) => "xFair.AI Testnet".to_string(),
-        Some(200101) => "Milkomeda C1 Testnet".to_string(),
-        Some(200200) => "Zoo Mainnet".to_string(),
-        Some(200202) => "Milkomeda A1 Testnet".to_string(),
-        Some(200625) => "Akroma".to_string(),
-        Some(200810) => "Bitlayer Testnet".to_string(),
-        Some(200901) => "Bitlayer Mainnet".to_string(),
-        Some(201018) => "Alaya Mainnet".to_string(),
-        Some(201030) => "Alaya Dev Testnet".to_string(),
-        Some(201804) => "Mythical Chain".to_string(),
-        Some(202020) => "Decimal Smart Chain Testnet".to_string(),
-        Some(202105) => "DuckChain Testnet".to_string(),
-        Some(202202) => "Bethel Sydney".to_string(),
-        Some(202209) => "Alterscope".to_string(),
-        Some(202212) => "X1 Devnet".to_string(),
-        Some(202401) => "YMTECH-BESU Testnet".to_string(),
-        Some(202424) => "Blockfit".to_string(),
-        Some(202624) => "Jellie".to_string(),
-        Some(204005) => "X1 Network".to_string(),
-        Some(205205) => "Auroria Testnet".to_string(),
-        Some(210000) => "JuChain Mainnet".to_string(),
-        Some(210049) => "GitAGI Atlas Testnet".to_string(),
-        Some(210209) => "Sorian".to_string(),
-        Some(210210) => "Sorian Testnet".to_string(),
-        Some(210425) => "PlatON Mainnet".to_string(),
-        Some(212013) => "Heima".to_string(),
-        Some(220315) => "Mas Mainnet".to_string(),
-        Some(221230) => "Reapchain Mainnet".to_string(),
-        Some(221231) => "Reapchain Testnet".to_string(),
-        Some(222222) => "Hydration".to_string(),
-        Some(222555) => "DeepL Mainnet".to_string(),
-        Some(222666) => "DeepL Testnet".to_string(),
-        Some(223344) => "B20 Testnet".to_string(),
-        Some(224168) => "Taf ECO Chain Mainnet".to_string(),
-        Some(224400) => "CONET Mainnet".to_string(),
-        Some(224422) => "CONET Sebolia Testnet".to_string(),
-        Some(224433) => "CONET Cancun".to_string(),
-        Some(229772) => "Abyss Protocol".to_string(),
-        Some(230315) => "HashKey Chain Testnet(discard)".to_string(),
-        Some(234666) => "Haymo Testnet".to_string(),
-        Some(235711) => "Universe Testnet".to_string(),
-        Some(240240) => "Studio Testnet".to_string(),
-        Some(240515) => "Orange Chain Testnet".to_string(),
-        Some(241120) => "Anomaly Andromeda Testnet".to_string(),
-        Some(246529) => "ARTIS sigma1".to_string(),
-        Some(246785) => "ARTIS Testnet tau1".to_string(),
-        Some(247253) => "Saakuru Testnet".to_string(),
-        Some(252525) => "CELESTIUM Network Testnet".to_string(),
-        Some(256256) => "CMP-Mainnet".to_string(),
-        Some(258432) => "Althea L1 Mainnet".to_string(),
-        Some(262371) => "Eclat Testnet".to_string(),
-        Some(266256) => "Gear Zero Network Testnet".to_string(),
-        Some(271271) => "EgonCoin Testnet".to_string(),
-        Some(272247) => "Nxy Area 51".to_string(),
-        Some(272520) => "Nxy Oasis".to_string(),
-        Some(281121) => "Social Smart Chain Mainnet".to_string(),
-        Some(281123) => "Athene Parthenon".to_string(),
-        Some(282828) => "Zillion Sepolia Testnet".to_string(),
-        Some(292003) => "Cipherem Testnet".to_string(),
-        Some(309075) => "One World Chain Mainnet".to_string(),
-        Some(313313) => "Sahara AI Testnet".to_string(),
-        Some(314159) => "Filecoin - Calibration testnet".to_string(),
-        Some(322202) => "Parex Mainnet".to_string(),
-        Some(323213) => "Bloom Genesis Testnet".to_string(),
-        Some(325000) => "Camp Network Testnet V2".to_string(),
-        Some(327126) => "WABA Chain Testnet".to_string(),
-        Some(328527) => "Nal Mainnet".to_string(),
-        Some(330844) => "TTcoin Smart Chain Mainnet".to_string(),
-        Some(333313) => "Bloom Genesis Mainnet".to_string(),
-        Some(333331) => "Aves Testnet".to_string(),
-        Some(333333) => "Nativ3 Testnet".to_string(),
-        Some(333666) => "Oone Chain Testnet".to_string(),
-        Some(333777) => "Oone Chain Devnet".to_string(),
-        Some(333888) => "Polis Testnet".to_string(),
-        Some(333999) => "Polis Mainnet".to_string(),
-        Some(336655) => "UPchain Testnet".to_string(),
-        Some(336666) => "UPchain Mainnet".to_string(),
-        Some(355110) => "Bitfinity Network Mainnet".to_string(),
-        Some(355113) => "Bitfinity Network Testnet".to_string(),
-        Some(360890) => "LAVITA Mainnet".to_string(),
-        Some(363636) => "Digit Soul Smart Chain 2".to_string(),
-        Some(373737) => "HAPchain Testnet".to_string(),
-        Some(381931) => "Metal C-Chain".to_string(),
-        Some(381932) => "Metal Tahoe C-Chain".to_string(),
-        Some(383353) => "CheeseChain".to_string(),
-        Some(404040) => "Tipboxcoin Mainnet".to_string(),
-        Some(413413) => "AIE Testnet".to_string(),
-        Some(420000) => "Infinaeon".to_string(),
-        Some(420042) => "Vector Smart Chain".to_string(),
-        Some(420420) => "Kekchain".to_string(),
-        Some(420666) => "Kekchain (kektest)".to_string(),
-        Some(420692) => "Alterium L2 Testnet".to_string(),
-        Some(421611) => "Arbitrum Rinkeby".to_string(),
-        Some(421613) => "Arbitrum Goerli".to_string(),
-        Some(421614) => "Arbitrum Sepolia".to_string(),
-        Some(424242) => "Fastex Chain testnet".to_string(),
-        Some(431140) => "Markr Go".to_string(),
-        Some(432201) => "Dexalot Subnet Testnet".to_string(),
-        Some(432204) => "Dexalot Subnet".to_string(),
-        Some(440017) => "Graphite Mainnet".to_string(),
-        Some(444444) => "Syndr L3 Sepolia".to_string(),
-        Some(444900) => "Weelink Testnet".to_string(),
-        Some(471100) => "Patex Sepolia Testnet".to_string(),
-        Some(473861) => "Ultra Pro Mainnet".to_string(),
-        Some(474142) => "OpenChain Mainnet".to_string(),
-        Some(486487) => "Gobbl Testnet".to_string(),
-        Some(490000) => "Autonomys Taurus Testnet".to_string(),
-        Some(490092) => "PUMPFI CHAIN TESTNET".to_string(),
-        Some(504441) => "Playdapp Network".to_string(),
-        Some(512512) => "CMP-Testnet".to_string(),
-        Some(513100) => "EthereumFair".to_string(),
-        Some(526916) => "DoCoin Community Chain".to_string(),
-        Some(534351) => "Scroll Sepolia Testnet".to_string(),
-        Some(534352) => "Scroll".to_string(),
-        Some(534849) => "Shinarium Beta".to_string(),
-        Some(535037) => "BeanEco SmartChain".to_string(),
-        Some(541764) => "OverProtocol Testnet".to_string(),
-        Some(543210) => "ZERO Network".to_string(),
-        Some(552981) => "One World Chain Testnet".to_string(),
-        Some(555555) => "Pentagon Testnet".to_string(),
-        Some(555666) => "Eclipse Testnet".to_string(),
-        Some(555777) => "Xsolla ZK Sepolia Testnet".to_string(),
-        Some(555888) => "DustBoy IoT".to_string(),
-        Some(560000) => "Hetu Testnet".to_string(),
-        Some(560013) => "Rogue Chain".to_string(),
-        Some(560048) => "Ethereum Hoodi".to_string(),
-        Some(612044) => "CROSS Testnet".to_string(),
-        Some(612055) => "CROSS Mainnet".to_string(),
-        Some(621847) => "DJT Testnet".to_string(),
-        Some(622277) => "Hypra Mainnet".to_string(),
-        Some(622463) => "Atlas".to_string(),
-        Some(631571) => "Polter Testnet".to_string(),
-        Some(641230) => "Bear Network Chain Mainnet".to_string(),
-        Some(651940) => "ALL Mainnet".to_string(),
-        Some(656476) => "EDU Chain Testnet".to_string(),
-        Some(657468) => "Ethereal Testnet".to_string(),
-        Some(660279) => "Xai Mainnet".to_string(),
-        Some(666666) => "Vision - Vpioneer Test Chain".to_string(),
-        Some(666888) => "Hela Official Runtime Testnet".to_string(),
-        Some(668668) => "Conwai Mainnet".to_string(),
-        Some(685685) => "Gensyn Testnet".to_string(),
-        Some(686868) => "Won Network".to_string(),
-        Some(695569) => "Pyrope Testnet".to_string(),
-        Some(696969) => "Galadriel Devnet".to_string(),
-        Some(698369) => "Primea Network Mainnet".to_string(),
-        Some(706883) => "Fidesinnova".to_string(),
-        Some(710420) => "Tiltyard Mainnet Subnet".to_string(),
-        Some(713715) => "Sei Devnet".to_string(),
-        Some(715131) => "Zether Mainnet".to_string(),
-        Some(721529) => "ERAM Mainnet".to_string(),
-        Some(723107) => "TixChain Testnet".to_string(),
-        Some(743111) => "Hemi Sepolia".to_string(),
-        Some(747474) => "katana".to_string(),
-        Some(751230) => "Bear Network Chain Testnet".to_string(),
-        Some(752024) => "Ternoa Testnet".to_string(),
-        Some(752025) => "Ternoa".to_string(),
-        Some(756689) => "PAYSCAN CHAIN".to_string(),
-        Some(761412) => "Miexs Smartchain".to_string(),
-        Some(763373) => "Ink Sepolia".to_string(),
-        Some(763374) => "Surge Testnet".to_string(),
-        Some(764984) => "Lamina1 Testnet".to_string(),
-        Some(767368) => "Lamina1 Identity Testnet".to_string(),
-        Some(776877) => "Modularium".to_string(),
-        Some(777777) => "Winr Protocol Mainnet".to_string(),
-        Some(777888) => "Oone Chain Mainnet".to_string(),
-        Some(786786) => "Zebro Smart Chain".to_string(),
-        Some(789789) => "Emeraldz".to_string(),
-        Some(800001) => "OctaSpace".to_string(),
-        Some(806582) => "Ethpar Testnet".to_string(),
-        Some(808080) => "BIZ Smart Chain Testnet".to_string(),
-        Some(808813) => "BOB Sepolia".to_string(),
-        Some(810180) => "zkLink Nova Mainnet".to_string(),
-        Some(810181) => "zkLink Nova Sepolia Testnet".to_string(),
-        Some(810182) => "zkLink Nova Goerli Testnet".to_string(),
-        Some(812397) => "SG Verse Mainnet".to_string(),
-        Some(820522) => "TSC Testnet".to_string(),
-        Some(827431) => "CURVE Mainnet".to_string(),
-        Some(839320) => "PRM Testnet".to_string(),
-        Some(839999) => "exSat Testnet".to_string(),
-        Some(840000) => "RUNEVM Testnet".to_string(),
-        Some(846000) => "4GoodNetwork".to_string(),
-        Some(855456) => "Dodao".to_string(),
-        Some(879151) => "BlocX Mainnet".to_string(),
-        Some(888882) => "REXX Mainnet".to_string(),
-        Some(888888) => "Vision - Mainnet".to_string(),
-        Some(888991) => "Unite Testnet".to_string(),
-        Some(900000) => "Posichain Mainnet Shard 0".to_string(),
-        Some(910000) => "Posichain Testnet Shard 0".to_string(),
-        Some(911867) => "Odyssey Testnet".to_string(),
-        Some(912559) => "Astria EVM Dusknet".to_string(),
-        Some(918273) => "Owshen Mainnet".to_string(),
-        Some(920000) => "Posichain Devnet Shard 0".to_string(),
-        Some(920001) => "Posichain Devnet Shard 1".to_string(),
-        Some(923018) => "FNCY Testnet".to_string(),
-        Some(955081) => "Jono12 Subnet".to_string(),
-        Some(955305) => "Eluvio Content Fabric".to_string(),
-        Some(978658) => "Treasure Topaz".to_string(),
-        Some(984122) => "Forma".to_string(),
-        Some(984123) => "Forma Sketchpad".to_string(),
-        Some(988207) => "Ecrox Chain Mainnet".to_string(),
-        Some(998899) => "Supernet Testnet".to_string(),
-        Some(999999) => "AmChain".to_string(),
-        Some(1008686) => "Naga Testnet".to_string(),
-        Some(1100789) => "Netmind Chain Testnet".to_string(),
-        Some(1127469) => "Tiltyard Subnet".to_string(),
-        Some(1212101) => "1Money Network Testnet".to_string(),
-        Some(1234567) => "Sharecle Mainnet".to_string(),
-        Some(1261120) => "zKatana".to_string(),
-        Some(1313114) => "Etho Protocol".to_string(),
-        Some(1313500) => "Xerom".to_string(),
-        Some(1337702) => "Kintsugi".to_string(),
-        Some(1337802) => "Kiln".to_string(),
-        Some(1337803) => "Zhejiang".to_string(),
-        Some(1398243) => "Automata Testnet".to_string(),
-        Some(1398244) => "Automata Orbit Testnet".to_string(),
-        Some(1440000) => "XRPL EVM".to_string(),
-        Some(1440002) => "XRPL EVM Sidechain Devnet".to_string(),
-        Some(1449000) => "XRPL EVM Sidechain Testnet".to_string(),
-        Some(1501869) => "Waterfall 9 Test Network".to_string(),
-        Some(1612127) => "PlayFi Albireo Testnet".to_string(),
-        Some(1637450) => "Xterio Testnet".to_string(),
-        Some(1698369) => "Primea Network Testnet".to_string(),
-        Some(1731313) => "Turkey Demo Dev".to_string(),
-        Some(1998991) => "Xphere Testnet".to_string(),
-        Some(2021398) => "DeBank Testnet".to_string(),
-        Some(2022091) => "Alterscope Testnet".to_string(),
-        Some(2099156) => "Plian Mainnet Main".to_string(),
-        Some(2206132) => "PlatON Dev Testnet2".to_string(),
-        Some(2222222) => "Coinweb BNB shard".to_string(),
-        Some(2481632) => "Recall Testnet".to_string(),
-        Some(2611555) => "DPU Chain".to_string(),
-        Some(2632500) => "COTI".to_string(),
-        Some(2702128) => "Xterio Chain (ETH)".to_string(),
-        Some(3132023) => "Sahara AI".to_string(),
-        Some(3141592) => "Filecoin - Butterfly testnet".to_string(),
-        Some(3397901) => "Funki Sepolia Testnet".to_string(),
-        Some(3441005) => "Manta Pacific Testnet".to_string(),
-        Some(3441006) => "Manta Pacific Sepolia Testnet".to_string(),
-        Some(4000003) => "AltLayer Zero Gas Network".to_string(),
-        Some(4278608) => "Arcadia Mainnet".to_string(),
-        Some(4281033) => "Worlds Caldera".to_string(),
-        Some(4284265) => "Zuux chain testnet".to_string(),
-        Some(4444444) => "Altar Testnet".to_string(),
-        Some(4457845) => "ZERO Testnet (Sepolia)".to_string(),
-        Some(5112023) => "NumBlock Chain".to_string(),
-        Some(5151706) => "Loot Mainnet".to_string(),
-        Some(5167003) => "MXC Wannsee zkEVM Testnet".to_string(),
-        Some(5167004) => "Moonchain Geneva Testnet".to_string(),
-        Some(5201420) => "Electroneum Testnet".to_string(),
-        Some(5318008) => "Reactive Kopli".to_string(),
-        Some(5511555) => "PointPay Testnet".to_string(),
-        Some(5555555) => "Imversed Mainnet".to_string(),
-        Some(5555558) => "Imversed Testnet".to_string(),
-        Some(6038361) => "Astar zKyoto".to_string(),
-        Some(6231991) => "Block Chain LOL Berachain Testnet".to_string(),
-        Some(6666665) => "Safe(AnWang) Mainnet".to_string(),
-        Some(6666666) => "Safe(AnWang) Testnet".to_string(),
-        Some(6666689) => "The Ting Blockchain Testnet Explorer".to_string(),
-        Some(7082400) => "COTI Testnet".to_string(),
-        Some(7225878) => "Saakuru Mainnet".to_string(),
-        Some(7355310) => "OpenVessel".to_string(),
-        Some(7668378) => "QL1 Testnet".to_string(),
-        Some(7762959) => "Musicoin".to_string(),
-        Some(7777777) => "Zora".to_string(),
-        Some(7849306) => "Ozean Poseidon Testnet".to_string(),
-        Some(8007736) => "Plian Mainnet Subchain 1".to_string(),
-        Some(8008135) => "Fhenix Helium".to_string(),
-        Some(8080808) => "Hokum".to_string(),
-        Some(8601152) => "Waterfall 8 Test Network".to_string(),
-        Some(8794598) => "HAPchain".to_string(),
-        Some(8888881) => "Quarix Testnet".to_string(),
-        Some(8888888) => "Quarix".to_string(),
-        Some(9322252) => "XCAP".to_string(),
-        Some(9322253) => "Milvine".to_string(),
-        Some(9999999) => "Fluence".to_string(),
-        Some(10058111) => "Spotlight".to_string(),
-        Some(10058112) => "Spotlight Sepolia Testnet".to_string(),
-        Some(10067275) => "Plian Testnet Subchain 1".to_string(),
-        Some(10101010) => "Soverun Mainnet".to_string(),
-        Some(10241024) => "AlienX Mainnet".to_string(),
-        Some(10241025) => "ALIENX Hal Testnet".to_string(),
-        Some(11145513) => "Blessnet Sepolia".to_string(),
-        Some(11155111) => "Ethereum Sepolia".to_string(),
-        Some(11155420) => "OP Sepolia Testnet".to_string(),
-        Some(11155931) => "RISE Testnet".to_string(),
-        Some(11166111) => "R0AR Testnet".to_string(),
-        Some(12020498) => "Lummio Network".to_string(),
-        Some(12052024) => "Memento Testnet".to_string(),
-        Some(12227332) => "Neo X Testnet T4".to_string(),
-        Some(13068200) => "COTI Devnet".to_string(),
-        Some(13371337) => "PepChain Churchill".to_string(),
-        Some(13863860) => "Symbiosis".to_string(),
-        Some(14288640) => "Anduschain Mainnet".to_string(),
-        Some(16658437) => "Plian Testnet Main".to_string(),
-        Some(16969696) => "Privix Chain Mainnet".to_string(),
-        Some(17000920) => "Lambda Chain Testnet".to_string(),
-        Some(18071918) => "Mande Network Mainnet".to_string(),
-        Some(18289463) => "IOLite".to_string(),
-        Some(19850818) => "DeepBrainChain Testnet".to_string(),
-        Some(19880818) => "DeepBrainChain Mainnet".to_string(),
-        Some(20180427) => "Stability Testnet".to_string(),
-        Some(20180430) => "SmartMesh Mainnet".to_string(),
-        Some(20181205) => "quarkblockchain".to_string(),
-        Some(20201022) => "Pego Network".to_string(),
-        Some(20221001) => "SoonChain Sepolia Devnet".to_string(),
-        Some(20230825) => "Vcity Testnet".to_string(),
-        Some(20240324) => "DeBank Sepolia Testnet".to_string(),
-        Some(20240603) => "DBK Chain".to_string(),
-        Some(20241133) => "Swan Proxima Testnet".to_string(),
-        Some(20250217) => "Xphere Mainnet".to_string(),
-        Some(20250825) => "Vcitychain Mainnet".to_string(),
-        Some(20256789) => "ETP Mainnet".to_string(),
-        Some(20482050) => "Hokum Testnet".to_string(),
-        Some(21000000) => "Corn".to_string(),
-        Some(21000001) => "Corn Testnet".to_string(),
-        Some(22052002) => "Excelon Mainnet".to_string(),
-        Some(24132016) => "XMTP".to_string(),
-        Some(24772477) => "6Degree of Outreach - Testnet".to_string(),
-        Some(27082017) => "Excoincial Chain Volta-Testnet".to_string(),
-        Some(27082022) => "Excoincial Chain Mainnet".to_string(),
-        Some(28122024) => "Ancient8 Testnet".to_string(),
-        Some(28945486) => "Auxilium Network Mainnet".to_string(),
-        Some(29032022) => "Flachain Mainnet".to_string(),
-        Some(31415926) => "Filecoin - Local testnet".to_string(),
-        Some(33626250) => "Toliman Suave Testnet".to_string(),
-        Some(33772211) => "Xone Testnet".to_string(),
-        Some(34949059) => "citronus-citro".to_string(),
-        Some(35855456) => "Joys Digital Mainnet".to_string(),
-        Some(37084624) => "SKALE Nebula Hub Testnet".to_string(),
-        Some(39916801) => "Kingdom Chain".to_string(),
-        Some(43214913) => "maistestsubnet".to_string(),
-        Some(50591822) => "Stavanger Public Testnet".to_string(),
-        Some(52027071) => "Deviant Token Blockchain".to_string(),
-        Some(52027080) => "Deviant Token Blockchain Testnet".to_string(),
-        Some(52164803) => "Fluence Testnet".to_string(),
-        Some(61022448) => "dKargo Warehouse Testnet".to_string(),
-        Some(61717561) => "Aquachain".to_string(),
-        Some(65100004) => "Autonity Piccadilly (Tiber) Testnet".to_string(),
-        Some(66666666) => "Winr Protocol Testnet".to_string(),
-        Some(68840142) => "Frame Testnet".to_string(),
-        Some(77787778) => "0xHash Testnet".to_string(),
-        Some(79479957) => "SX Toronto Rollup".to_string(),
-        Some(88558801) => "Backstop Testnet".to_string(),
-        Some(88888888) => "T.E.A.M Blockchain".to_string(),
-        Some(89346162) => "Reya Cronos".to_string(),
-        Some(94204209) => "Polygon Blackberry".to_string(),
-        Some(96969696) => " Privix Chain Testnet".to_string(),
-        Some(97912060) => "ChadChain".to_string(),
-        Some(99415706) => "Joys Digital TestNet".to_string(),
-        Some(100000000) => "Ethos".to_string(),
-        Some(108160679) => "Oraichain Mainnet".to_string(),
-        Some(111557560) => "Cyber Testnet".to_string(),
-        Some(123420111) => "OP Celestia Raspberry".to_string(),
-        Some(168587773) => "Blast Sepolia Testnet".to_string(),
-        Some(192837465) => "Gather Mainnet Network".to_string(),
-        Some(222000222) => "Kanazawa".to_string(),
-        Some(241320161) => "XMTP Sepolia".to_string(),
-        Some(245022926) => "Neon EVM Devnet".to_string(),
-        Some(245022929) => "Neon EVM Devnet Rollup".to_string(),
-        Some(245022934) => "Neon EVM Mainnet".to_string(),
-        Some(253368190) => "Flame".to_string(),
-        Some(278611351) => "Razor Skale Chain".to_string(),
-        Some(311752642) => "OneLedger Mainnet".to_string(),
-        Some(328527624) => "Nal Sepolia Testnet".to_string(),
-        Some(333000333) => "Meld".to_string(),
-        Some(356256156) => "Gather Testnet Network".to_string(),
-        Some(420420419) => "Asset Hub".to_string(),
-        Some(420420421) => "Westend Asset Hub".to_string(),
-        Some(420420422) => "Paseo PassetHub".to_string(),
-        Some(476462898) => "GPT Testnet".to_string(),
-        Some(486217935) => "Gather Devnet Network".to_string(),
-        Some(531050104) => "Sophon Testnet".to_string(),
-        Some(661898459) => "Smart Mainnet".to_string(),
-        Some(666666666) => "Degen Chain".to_string(),
-        Some(728126428) => "Tron Mainnet".to_string(),
-        Some(888888888) => "Ancient8".to_string(),
-        Some(889910245) => "PTCESCAN Testnet".to_string(),
-        Some(889910246) => "PTCESCAN Mainnet".to_string(),
-        Some(974399131) => "SKALE Calypso Hub Testnet".to_string(),
-        Some(994873017) => "Lumia Mainnet".to_string(),
-        Some(999999999) => "Zora Sepolia Testnet".to_string(),
-        Some(1000000003) => "DeInfra Devnet3".to_string(),
-        Some(1020352220) => "SKALE Titan Hub Testnet".to_string(),
-        Some(1122334455) => "IPOS Network".to_string(),
-        Some(1146703430) => "CyberdeckNet".to_string(),
-        Some(1273227453) => "HUMAN Protocol".to_string(),
-        Some(1278060416) => "OFFICIAL VASYL".to_string(),
-        Some(1313161554) => "Aurora Mainnet".to_string(),
-        Some(1313161555) => "Aurora Testnet".to_string(),
-        Some(1313161556) => "Aurora Betanet".to_string(),
-        Some(1313161560) => "PowerGold".to_string(),
-        Some(1313161567) => "Turbo".to_string(),
-        Some(1313161573) => "Tuxappcoin".to_string(),
-        Some(1350216234) => "SKALE Titan Hub".to_string(),
-        Some(1351057110) => "Chaos (SKALE Testnet)".to_string(),
-        Some(1380012617) => "RARI Chain".to_string(),
-        Some(1380996178) => "RaptorChain".to_string(),
-        Some(1417429182) => "Zephyr Testnet".to_string(),
-        Some(1444673419) => "SKALE Europa Hub Testnet".to_string(),
-        Some(1482601649) => "SKALE Nebula Hub".to_string(),
-        Some(1511670449) => "GPT Mainnet".to_string(),
-        Some(1523903251) => "Haust Network Testnet".to_string(),
-        Some(1564830818) => "SKALE Calypso Hub".to_string(),
-        Some(1570754601) => "Haust Testnet".to_string(),
-        Some(1660990954) => "Status Network Sepolia".to_string(),
-        Some(1666600000) => "Harmony Mainnet Shard 0".to_string(),
-        Some(1666600001) => "Harmony Mainnet Shard 1".to_string(),
-        Some(1666700000) => "Harmony Testnet Shard 0".to_string(),
-        Some(1666700001) => "Harmony Testnet Shard 1".to_string(),
-        Some(1666900000) => "Harmony Devnet Shard 0".to_string(),
-        Some(1666900001) => "Harmony Devnet Shard 1".to_string(),
-        Some(1702448187) => "WITNESS CHAIN".to_string(),
-        Some(1722641160) => "Silicon zkEVM Sepolia Testnet".to_string(),
-        Some(1802203764) => "Kakarot Sepolia (Deprecated)".to_string(),
-        Some(1903648807) => "Gemuchain Testnet".to_string(),
-        Some(1918988905) => "RARI Chain Testnet".to_string(),
-        Some(1952959480) => "Lumia Testnet".to_string(),
-        Some(2021121117) => "DataHopper".to_string(),
-        Some(2030232745) => "Lumia Beam Testnet".to_string(),
-        Some(2046399126) => "SKALE Europa Hub".to_string(),
-        Some(2478899481) => "Accumulate Kermit".to_string(),
-        Some(2494104990) => "Tron Shasta".to_string(),
-        Some(3125659152) => "Pirl".to_string(),
-        Some(3416255149) => "Ultima Mainnet".to_string(),
-        Some(4216137055) => "OneLedger Testnet Frankenstein".to_string(),
-        Some(7078815900) => "Mekong".to_string(),
-        Some(7088110746) => "pectra-devnet-5".to_string(),
-        Some(8691942025) => "ONFA Chain".to_string(),
-        Some(11297108099) => "Palm Testnet".to_string(),
-        Some(11297108109) => "Palm".to_string(),
-        Some(28872323069) => "GitSwarm Test Network".to_string(),
-        Some(37714555429) => "Xai Testnet v2".to_string(),
-        Some(44474237230) => "Deriw Devnet".to_string(),
-        Some(88153591557) => "Arbitrum Blueberry".to_string(),
-        Some(96737205180) => "ALDChain Testnet".to_string(),
-        Some(107107114116) => "Kakarot Sepolia Deprecated".to_string(),
-        Some(111222333444) => "Alphabet Mainnet".to_string(),
-        Some(111551119090) => "Thanos Sepolia".to_string(),
-        Some(123420000220) => "Fluence Stage".to_string(),
-        Some(123420000558) => "PIN".to_string(),
-        Some(123420000586) => "sivo-defi-testnet".to_string(),
-        Some(123420000588) => "volmex".to_string(),
-        Some(123420001114) => "Basecamp".to_string(),
-        Some(197710212030) => "Ntity Mainnet".to_string(),
-        Some(197710212031) => "Haradev Testnet".to_string(),
-        Some(202402181627) => "GM Network Testnet".to_string(),
-        Some(383414847825) => "Zeniq".to_string(),
-        Some(666301171999) => "PDC Mainnet".to_string(),
-        Some(666301179999) => "SmartPay Mobile Money".to_string(),
-        Some(6022140761023) => "Molereum Network".to_string(),
-        Some(16604737732183) => "Flame Testnet".to_string(),
-        Some(428962654539583) => "Yominet".to_string(),
-        Some(920637907288165) => "Kakarot Starknet Sepolia".to_string(),
-        Some(2713017997578000) => "DCHAIN Testnet".to_string(),
-        Some(2716446429837000) => "DCHAIN".to_string(),
-        Some(chain_id) => format!("Unknown Network (Chain ID: {chain_id})"),
+        Some(chain_id) => chain_info(chain_id).map_or_else(
+            || format!("Unknown Network (Chain ID: {chain_id})"),
+            |chain| chain.name.clone(),
+        ),
         None => "Unknown Network".to_string(),
     }
 }
+
+/// Symbol and decimals of the currency `value` and gas are paid in on `chain_id`.
+pub fn native_currency(chain_id: Option<u64>) -> (&'static str, u8) {
+    let Some(chain_id) = chain_id else {
+        return ETHER;
+    };
+    chain_info(chain_id)
+        .and_then(|chain| chain.native_currency.as_ref())
+        .map_or(UNKNOWN_CURRENCY, |currency| {
+            (currency.symbol.as_str(), currency.decimals)
+        })
+}
+
+/// Whether `chain_id` is a known test network.
+pub fn is_testnet(chain_id: Option<u64>) -> bool {
+    chain_id
+        .and_then(chain_info)
+        .is_some_and(|chain| chain.testnet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain_names() {
+        assert_eq!(get_chain_name(Some(1)), "Ethereum Mainnet");
+        assert_eq!(get_chain_name(Some(137)), "Polygon Mainnet");
+        assert_eq!(
+            get_chain_name(Some(999_999_999_999)),
+            "Unknown Network (Chain ID: 999999999999)"
+        );
+        assert_eq!(get_chain_name(None), "Unknown Network");
+        assert!(chains().len() > 2000);
+    }
+
+    #[test]
+    fn test_native_currency_and_testnets() {
+        assert_eq!(native_currency(Some(1)), ("ETH", 18));
+        assert_eq!(native_currency(Some(137)), ("POL", 18));
+        assert_eq!(native_currency(Some(56)), ("BNB", 18));
+        assert_eq!(native_currency(Some(10143)), ("MON", 18));
+        assert_eq!(native_currency(Some(2)), UNKNOWN_CURRENCY);
+        assert_eq!(native_currency(None), ETHER);
+
+        assert!(is_testnet(Some(11155111)));
+        assert!(is_testnet(Some(97)));
+        // Testnets whose names don't say so
+        assert!(is_testnet(Some(5615)));
+        assert!(is_testnet(Some(167009)));
+        assert!(!is_testnet(Some(1)));
+        assert!(!is_testnet(None));
+        assert_eq!(
+            chain_info(8453).unwrap().explorer.as_deref(),
+            Some("https://basescan.org")
+        );
+    }
+}
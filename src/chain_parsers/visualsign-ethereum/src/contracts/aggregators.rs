@@ -0,0 +1,743 @@
+//! DEX aggregators: 1inch's AggregationRouter v5 and v6, 0x's Exchange Proxy
+//! and Settler, and ParaSwap's Augustus v5 and v6.2.
+//!
+//! Each aggregator encodes the route its own way, and some entry points leave
+//! a token to the pools they trade through. Only what the calldata commits to
+//! is shown: the tokens when named, the amount in, the minimum return and who
+//! receives the output. [`recipient_warning`] flags output sent to anyone other
+//! than the signer.
+
+use alloy_primitives::{Address, U256, address};
+use alloy_sol_types::{SolCall, sol};
+use visualsign::{AnnotatedPayloadField, SignablePayloadField};
+
+use crate::fields::{
+    addr, address_field, amount_field, plain_text_field, preview_layout, text_field,
+};
+use crate::fmt::{format_timestamp, format_token_amount};
+use crate::registry::{ContractKind, ContractRegistry};
+use crate::tokens::{Token, TokenRegistry, format_amount, token_name};
+
+sol! {
+    // From: https://etherscan.io/address/0x1111111254eeb25477b68fb85ed929f73a960582#code
+    interface IAggregationRouterV5 {
+        struct SwapDescription { address srcToken; address dstToken; address srcReceiver; address dstReceiver; uint256 amount; uint256 minReturnAmount; uint256 flags; }
+
+        function swap(address executor, SwapDescription calldata desc, bytes calldata permit, bytes calldata data) external payable returns (uint256 returnAmount, uint256 spentAmount);
+        function unoswap(address srcToken, uint256 amount, uint256 minReturn, uint256[] calldata pools) external payable returns (uint256 returnAmount);
+        function unoswapTo(address recipient, address srcToken, uint256 amount, uint256 minReturn, uint256[] calldata pools) external payable returns (uint256 returnAmount);
+        function uniswapV3Swap(uint256 amount, uint256 minReturn, uint256[] calldata pools) external payable returns (uint256 returnAmount);
+        function uniswapV3SwapTo(address recipient, uint256 amount, uint256 minReturn, uint256[] calldata pools) external payable returns (uint256 returnAmount);
+    }
+
+    // From: https://etherscan.io/address/0x111111125421ca6dc452d289314280a0f8842a65#code
+    // v6 packs flags into the high bits of its `Address` arguments
+    interface IAggregationRouterV6 {
+        struct SwapDescription { address srcToken; address dstToken; address srcReceiver; address dstReceiver; uint256 amount; uint256 minReturnAmount; uint256 flags; }
+
+        function swap(address executor, SwapDescription calldata desc, bytes calldata data) external payable returns (uint256 returnAmount, uint256 spentAmount);
+        function unoswap(uint256 token, uint256 amount, uint256 minReturn, uint256 dex) external returns (uint256 returnAmount);
+        function unoswapTo(uint256 to, uint256 token, uint256 amount, uint256 minReturn, uint256 dex) external returns (uint256 returnAmount);
+        function ethUnoswap(uint256 minReturn, uint256 dex) external payable returns (uint256 returnAmount);
+        function ethUnoswapTo(uint256 to, uint256 minReturn, uint256 dex) external payable returns (uint256 returnAmount);
+    }
+
+    // From: https://github.com/0xProject/protocol/blob/development/contracts/zero-ex/contracts/src/features/interfaces/ITransformERC20Feature.sol
+    interface IZeroExProxy {
+        struct Transformation { uint32 deploymentNonce; bytes data; }
+
+        function transformERC20(address inputToken, address outputToken, uint256 inputTokenAmount, uint256 minOutputTokenAmount, Transformation[] calldata transformations) external payable returns (uint256 outputTokenAmount);
+        function sellToUniswap(address[] calldata tokens, uint256 sellAmount, uint256 minBuyAmount, bool isSushi) external payable returns (uint256 buyAmount);
+    }
+
+    // From: https://github.com/0xProject/0x-settler/blob/master/src/SettlerBase.sol
+    interface ISettler {
+        struct AllowedSlippage { address recipient; address buyToken; uint256 minAmountOut; }
+
+        function execute(AllowedSlippage calldata slippage, bytes[] calldata actions, bytes32 zidAndAffiliate) external payable returns (bool);
+    }
+
+    // From: https://github.com/0xProject/0x-settler/blob/master/src/ISettlerActions.sol
+    interface ISettlerActions {
+        struct TokenPermissions { address token; uint256 amount; }
+        struct PermitTransferFrom { TokenPermissions permitted; uint256 nonce; uint256 deadline; }
+
+        function TRANSFER_FROM(address recipient, PermitTransferFrom permit, bytes sig) external;
+    }
+
+    // From: https://github.com/paraswap/paraswap-contracts/blob/master/contracts/lib/Utils.sol
+    interface IAugustusV5 {
+        struct SimpleData { address fromToken; address toToken; uint256 fromAmount; uint256 toAmount; uint256 expectedAmount; address[] callees; bytes exchangeData; uint256[] startIndexes; uint256[] values; address beneficiary; address partner; uint256 feePercent; bytes permit; uint256 deadline; bytes16 uuid; }
+        struct Route { uint256 index; address targetExchange; uint256 percent; bytes payload; uint256 networkFee; }
+        struct Adapter { address adapter; uint256 percent; uint256 networkFee; Route[] route; }
+        struct Path { address to; uint256 totalNetworkFee; Adapter[] adapters; }
+        struct MegaSwapPath { uint256 fromAmountPercent; Path[] path; }
+        struct SellData { address fromToken; uint256 fromAmount; uint256 toAmount; uint256 expectedAmount; address beneficiary; Path[] path; address partner; uint256 feePercent; bytes permit; uint256 deadline; bytes16 uuid; }
+        struct MegaSwapSellData { address fromToken; uint256 fromAmount; uint256 toAmount; uint256 expectedAmount; address beneficiary; MegaSwapPath[] path; address partner; uint256 feePercent; bytes permit; uint256 deadline; bytes16 uuid; }
+
+        function simpleSwap(SimpleData calldata data) external payable returns (uint256 receivedAmount);
+        function simpleBuy(SimpleData calldata data) external payable;
+        function multiSwap(SellData calldata data) external payable returns (uint256);
+        function megaSwap(MegaSwapSellData calldata data) external payable returns (uint256);
+    }
+
+    // From: https://github.com/paraswap/paraswap-contracts-v6/blob/main/src/AugustusV6Types.sol
+    interface IAugustusV6 {
+        struct GenericData { address srcToken; address destToken; uint256 fromAmount; uint256 toAmount; uint256 quotedAmount; bytes32 metadata; address beneficiary; }
+
+        function swapExactAmountIn(address executor, GenericData calldata swapData, uint256 partnerAndFee, bytes calldata permit, bytes calldata executorData) external payable returns (uint256 receivedAmount, uint256 paraswapShare, uint256 partnerShare);
+        function swapExactAmountOut(address executor, GenericData calldata swapData, uint256 partnerAndFee, bytes calldata permit, bytes calldata executorData) external payable returns (uint256 spentAmount, uint256 receivedAmount, uint256 paraswapShare, uint256 partnerShare);
+    }
+}
+
+/// The placeholder all three aggregators use for the chain's native currency.
+const NATIVE: Address = address!("0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee");
+
+/// The address in the low 160 bits of a 1inch v6 `Address` argument.
+fn packed_address(word: U256) -> Address {
+    Address::from_word(word.into())
+}
+
+/// What a swap through an aggregator commits to. Tokens and amounts the
+/// calldata leaves to the pools or to the call's value are `None`.
+struct AggregatorSwap {
+    title: &'static str,
+    token_in: Option<Address>,
+    token_out: Option<Address>,
+    /// The amount sold, or the most that may be sold when `exact_out`.
+    amount_in: Option<U256>,
+    /// The least bought, or the exact amount bought when `exact_out`.
+    amount_out: U256,
+    exact_out: bool,
+    /// `None` when the output goes to whoever sent the transaction.
+    recipient: Option<Address>,
+    extra: Vec<AnnotatedPayloadField>,
+}
+
+impl AggregatorSwap {
+    fn new(title: &'static str, token_in: Address, token_out: Address) -> Self {
+        AggregatorSwap {
+            title,
+            token_in: Some(token_in),
+            token_out: Some(token_out),
+            amount_in: None,
+            amount_out: U256::ZERO,
+            exact_out: false,
+            recipient: None,
+            extra: Vec::new(),
+        }
+    }
+
+    fn layout(self, chain_id: Option<u64>, tokens: &TokenRegistry) -> SignablePayloadField {
+        let known = |address: Option<Address>| {
+            address.map(|address| {
+                let address = if address == NATIVE {
+                    Address::ZERO
+                } else {
+                    address
+                };
+                (address, tokens.currency(chain_id, address))
+            })
+        };
+        let (token_in, token_out) = (known(self.token_in), known(self.token_out));
+        let in_text = match (self.amount_in, token_in) {
+            (Some(amount), Some((address, token))) => format_amount(token, address, amount),
+            (Some(amount), None) => format!("{amount} of the route's input token"),
+            (None, Some((address, token))) => {
+                format!("the {} sent with this call", token_name(token, address))
+            }
+            (None, None) => "the route's input".to_string(),
+        };
+        let out_text = match token_out {
+            Some((address, token)) => format_amount(token, address, self.amount_out),
+            None => format!("{} of the route's output token", self.amount_out),
+        };
+        let summary = if self.exact_out {
+            format!("Swap at most {in_text} for {out_text}")
+        } else {
+            format!("Swap {in_text} for at least {out_text}")
+        };
+
+        let (in_label, out_label) = if self.exact_out {
+            ("Maximum Amount In", "Amount Out")
+        } else {
+            ("Amount In", "Minimum Amount Out")
+        };
+        let mut fields = vec![
+            token_field("Token In", token_in),
+            token_field("Token Out", token_out),
+            match (self.amount_in, token_in) {
+                (Some(amount), _) => amount_of(in_label, token_in, amount),
+                (None, Some((address, token))) => text_field(
+                    in_label,
+                    &format!(
+                        "The {} value sent with this call",
+                        token_name(token, address)
+                    ),
+                ),
+                (None, None) => text_field(in_label, "Set by the route"),
+            },
+            amount_of(out_label, token_out, self.amount_out),
+            match self.recipient {
+                Some(recipient) => address_field("Recipient", &addr(recipient), None, None),
+                None => text_field("Recipient", "Sender"),
+            },
+        ];
+        fields.extend(self.extra);
+        preview_layout(self.title, self.title, &summary, None, fields)
+    }
+}
+
+fn token_field(label: &str, token: Option<(Address, Option<Token<'_>>)>) -> AnnotatedPayloadField {
+    match token {
+        Some((address, token)) => {
+            address_field(label, &addr(address), token.map(|t| t.symbol), None)
+        }
+        None => text_field(label, "Set by the pools the route trades through"),
+    }
+}
+
+fn amount_of(
+    label: &str,
+    token: Option<(Address, Option<Token<'_>>)>,
+    amount: U256,
+) -> AnnotatedPayloadField {
+    match token {
+        Some((_, Some(token))) => amount_field(
+            label,
+            &format_token_amount(amount, token.decimals),
+            Some(token.symbol),
+        ),
+        _ => amount_field(label, &amount.to_string(), None),
+    }
+}
+
+/// A zero recipient stands for the sender in every aggregator here.
+fn recipient(address: Address) -> Option<Address> {
+    (address != Address::ZERO).then_some(address)
+}
+
+fn pools_field(pools: usize) -> AnnotatedPayloadField {
+    text_field("Pools", &pools.to_string())
+}
+
+fn deadline_field(deadline: U256) -> AnnotatedPayloadField {
+    text_field("Deadline", &format_timestamp(deadline))
+}
+
+fn decode_one_inch(input: &[u8]) -> Option<AggregatorSwap> {
+    let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+    let swap = match selector {
+        IAggregationRouterV5::swapCall::SELECTOR => {
+            let desc = IAggregationRouterV5::swapCall::abi_decode(input).ok()?.desc;
+            AggregatorSwap {
+                amount_in: Some(desc.amount),
+                amount_out: desc.minReturnAmount,
+                recipient: recipient(desc.dstReceiver),
+                ..AggregatorSwap::new("1inch Swap", desc.srcToken, desc.dstToken)
+            }
+        }
+        IAggregationRouterV6::swapCall::SELECTOR => {
+            let desc = IAggregationRouterV6::swapCall::abi_decode(input).ok()?.desc;
+            AggregatorSwap {
+                amount_in: Some(desc.amount),
+                amount_out: desc.minReturnAmount,
+                recipient: recipient(desc.dstReceiver),
+                ..AggregatorSwap::new("1inch Swap", desc.srcToken, desc.dstToken)
+            }
+        }
+        // Unoswaps name the tokens they sell, but not what the last pool pays out
+        IAggregationRouterV5::unoswapCall::SELECTOR => {
+            let call = IAggregationRouterV5::unoswapCall::abi_decode(input).ok()?;
+            AggregatorSwap {
+                token_out: None,
+                amount_in: Some(call.amount),
+                amount_out: call.minReturn,
+                extra: vec![pools_field(call.pools.len())],
+                ..AggregatorSwap::new("1inch Unoswap", call.srcToken, Address::ZERO)
+            }
+        }
+        IAggregationRouterV5::unoswapToCall::SELECTOR => {
+            let call = IAggregationRouterV5::unoswapToCall::abi_decode(input).ok()?;
+            AggregatorSwap {
+                token_out: None,
+                amount_in: Some(call.amount),
+                amount_out: call.minReturn,
+                recipient: recipient(call.recipient),
+                extra: vec![pools_field(call.pools.len())],
+                ..AggregatorSwap::new("1inch Unoswap", call.srcToken, Address::ZERO)
+            }
+        }
+        IAggregationRouterV5::uniswapV3SwapCall::SELECTOR => {
+            let call = IAggregationRouterV5::uniswapV3SwapCall::abi_decode(input).ok()?;
+            AggregatorSwap {
+                token_in: None,
+                token_out: None,
+                amount_in: Some(call.amount),
+                amount_out: call.minReturn,
+                extra: vec![pools_field(call.pools.len())],
+                ..AggregatorSwap::new("1inch Uniswap V3 Swap", Address::ZERO, Address::ZERO)
+            }
+        }
+        IAggregationRouterV5::uniswapV3SwapToCall::SELECTOR => {
+            let call = IAggregationRouterV5::uniswapV3SwapToCall::abi_decode(input).ok()?;
+            AggregatorSwap {
+                token_in: None,
+                token_out: None,
+                amount_in: Some(call.amount),
+                amount_out: call.minReturn,
+                recipient: recipient(call.recipient),
+                extra: vec![pools_field(call.pools.len())],
+                ..AggregatorSwap::new("1inch Uniswap V3 Swap", Address::ZERO, Address::ZERO)
+            }
+        }
+        IAggregationRouterV6::unoswapCall::SELECTOR => {
+            let call = IAggregationRouterV6::unoswapCall::abi_decode(input).ok()?;
+            AggregatorSwap {
+                token_out: None,
+                amount_in: Some(call.amount),
+                amount_out: call.minReturn,
+                ..AggregatorSwap::new("1inch Unoswap", packed_address(call.token), Address::ZERO)
+            }
+        }
+        IAggregationRouterV6::unoswapToCall::SELECTOR => {
+            let call = IAggregationRouterV6::unoswapToCall::abi_decode(input).ok()?;
+            AggregatorSwap {
+                token_out: None,
+                amount_in: Some(call.amount),
+                amount_out: call.minReturn,
+                recipient: recipient(packed_address(call.to)),
+                ..AggregatorSwap::new("1inch Unoswap", packed_address(call.token), Address::ZERO)
+            }
+        }
+        IAggregationRouterV6::ethUnoswapCall::SELECTOR => {
+            let call = IAggregationRouterV6::ethUnoswapCall::abi_decode(input).ok()?;
+            AggregatorSwap {
+                token_out: None,
+                amount_out: call.minReturn,
+                ..AggregatorSwap::new("1inch Unoswap", Address::ZERO, Address::ZERO)
+            }
+        }
+        IAggregationRouterV6::ethUnoswapToCall::SELECTOR => {
+            let call = IAggregationRouterV6::ethUnoswapToCall::abi_decode(input).ok()?;
+            AggregatorSwap {
+                token_out: None,
+                amount_out: call.minReturn,
+                recipient: recipient(packed_address(call.to)),
+                ..AggregatorSwap::new("1inch Unoswap", Address::ZERO, Address::ZERO)
+            }
+        }
+        _ => return None,
+    };
+    Some(swap)
+}
+
+fn decode_zero_ex(input: &[u8]) -> Option<AggregatorSwap> {
+    let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+    let swap = match selector {
+        IZeroExProxy::transformERC20Call::SELECTOR => {
+            let call = IZeroExProxy::transformERC20Call::abi_decode(input).ok()?;
+            AggregatorSwap {
+                amount_in: Some(call.inputTokenAmount),
+                amount_out: call.minOutputTokenAmount,
+                extra: vec![text_field(
+                    "Transformations",
+                    &call.transformations.len().to_string(),
+                )],
+                ..AggregatorSwap::new("0x Swap", call.inputToken, call.outputToken)
+            }
+        }
+        IZeroExProxy::sellToUniswapCall::SELECTOR => {
+            let call = IZeroExProxy::sellToUniswapCall::abi_decode(input).ok()?;
+            let (first, last) = (call.tokens.first()?, call.tokens.last()?);
+            let pool = if call.isSushi {
+                "SushiSwap"
+            } else {
+                "Uniswap V2"
+            };
+            AggregatorSwap {
+                amount_in: Some(call.sellAmount),
+                amount_out: call.minBuyAmount,
+                extra: vec![text_field("Liquidity Source", pool)],
+                ..AggregatorSwap::new("0x Swap", *first, *last)
+            }
+        }
+        _ => return None,
+    };
+    Some(swap)
+}
+
+/// The name Settler calls are flagged under, as no deployment is registered.
+pub(crate) const SETTLER: &str = "0x Settler";
+
+/// Whether `input` is a Settler `execute` call.
+pub(crate) fn is_settler(input: &[u8]) -> bool {
+    decode_settler(input).is_some()
+}
+
+/// 0x redeploys Settler with each release, so it's recognized by selector
+/// rather than by address. The token sold is named by the Permit2 transfer
+/// that usually opens the action list.
+fn decode_settler(input: &[u8]) -> Option<AggregatorSwap> {
+    if input.get(..4)? != ISettler::executeCall::SELECTOR {
+        return None;
+    }
+    let call = ISettler::executeCall::abi_decode(input).ok()?;
+    let slippage = call.slippage;
+    let permit = call.actions.first().and_then(|action| {
+        (action.get(..4)? == ISettlerActions::TRANSFER_FROMCall::SELECTOR)
+            .then(|| ISettlerActions::TRANSFER_FROMCall::abi_decode(action).ok())
+            .flatten()
+    });
+    let mut extra = vec![text_field("Actions", &call.actions.len().to_string())];
+    let (token_in, amount_in) = match permit {
+        Some(transfer) => {
+            extra.push(deadline_field(transfer.permit.deadline));
+            (
+                Some(transfer.permit.permitted.token),
+                Some(transfer.permit.permitted.amount),
+            )
+        }
+        None => (None, None),
+    };
+    Some(AggregatorSwap {
+        token_in,
+        amount_in,
+        amount_out: slippage.minAmountOut,
+        recipient: recipient(slippage.recipient),
+        extra,
+        ..AggregatorSwap::new("0x Settler Swap", Address::ZERO, slippage.buyToken)
+    })
+}
+
+fn decode_paraswap(input: &[u8]) -> Option<AggregatorSwap> {
+    let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+    let swap = match selector {
+        IAugustusV5::simpleSwapCall::SELECTOR | IAugustusV5::simpleBuyCall::SELECTOR => {
+            let data = IAugustusV5::simpleSwapCall::abi_decode_raw(&input[4..])
+                .ok()?
+                .data;
+            AggregatorSwap {
+                amount_in: Some(data.fromAmount),
+                amount_out: data.toAmount,
+                exact_out: selector == IAugustusV5::simpleBuyCall::SELECTOR,
+                recipient: recipient(data.beneficiary),
+                extra: vec![deadline_field(data.deadline)],
+                ..AggregatorSwap::new("ParaSwap Swap", data.fromToken, data.toToken)
+            }
+        }
+        // The last leg of the path names the token bought
+        IAugustusV5::multiSwapCall::SELECTOR => {
+            let data = IAugustusV5::multiSwapCall::abi_decode(input).ok()?.data;
+            AggregatorSwap {
+                amount_in: Some(data.fromAmount),
+                amount_out: data.toAmount,
+                recipient: recipient(data.beneficiary),
+                extra: vec![deadline_field(data.deadline)],
+                ..AggregatorSwap::new("ParaSwap Swap", data.fromToken, data.path.last()?.to)
+            }
+        }
+        IAugustusV5::megaSwapCall::SELECTOR => {
+            let data = IAugustusV5::megaSwapCall::abi_decode(input).ok()?.data;
+            let token_out = data.path.first()?.path.last()?.to;
+            AggregatorSwap {
+                amount_in: Some(data.fromAmount),
+                amount_out: data.toAmount,
+                recipient: recipient(data.beneficiary),
+                extra: vec![deadline_field(data.deadline)],
+                ..AggregatorSwap::new("ParaSwap Swap", data.fromToken, token_out)
+            }
+        }
+        IAugustusV6::swapExactAmountInCall::SELECTOR
+        | IAugustusV6::swapExactAmountOutCall::SELECTOR => {
+            let data = IAugustusV6::swapExactAmountInCall::abi_decode_raw(&input[4..])
+                .ok()?
+                .swapData;
+            AggregatorSwap {
+                amount_in: Some(data.fromAmount),
+                amount_out: data.toAmount,
+                exact_out: selector == IAugustusV6::swapExactAmountOutCall::SELECTOR,
+                recipient: recipient(data.beneficiary),
+                ..AggregatorSwap::new("ParaSwap Swap", data.srcToken, data.destToken)
+            }
+        }
+        _ => return None,
+    };
+    Some(swap)
+}
+
+pub struct OneInchVisualizer {}
+
+impl OneInchVisualizer {
+    /// Decodes `input`, a call to a 1inch AggregationRouter on `chain_id`.
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: Option<u64>,
+        tokens: &TokenRegistry,
+    ) -> Option<SignablePayloadField> {
+        decode_one_inch(input).map(|swap| swap.layout(chain_id, tokens))
+    }
+}
+
+pub struct ZeroExVisualizer {}
+
+impl ZeroExVisualizer {
+    /// Decodes `input`, a call to the 0x Exchange Proxy on `chain_id`.
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: Option<u64>,
+        tokens: &TokenRegistry,
+    ) -> Option<SignablePayloadField> {
+        decode_zero_ex(input).map(|swap| swap.layout(chain_id, tokens))
+    }
+}
+
+pub struct SettlerVisualizer {}
+
+impl SettlerVisualizer {
+    /// Decodes `input`, a call to any 0x Settler deployment on `chain_id`.
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: Option<u64>,
+        tokens: &TokenRegistry,
+    ) -> Option<SignablePayloadField> {
+        decode_settler(input).map(|swap| swap.layout(chain_id, tokens))
+    }
+}
+
+pub struct ParaswapVisualizer {}
+
+impl ParaswapVisualizer {
+    /// Decodes `input`, a call to ParaSwap's Augustus on `chain_id`.
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: Option<u64>,
+        tokens: &TokenRegistry,
+    ) -> Option<SignablePayloadField> {
+        decode_paraswap(input).map(|swap| swap.layout(chain_id, tokens))
+    }
+}
+
+/// A warning when `input`, an aggregator swap sent by `sender` to `to`, pays
+/// its output to someone else. Without a known sender, any recipient the swap
+/// names is flagged for the signer to check.
+pub(crate) fn recipient_warning(
+    input: &[u8],
+    to: Address,
+    sender: Option<Address>,
+    chain_id: Option<u64>,
+    contracts: &ContractRegistry,
+    tokens: &TokenRegistry,
+) -> Option<SignablePayloadField> {
+    let swap = match contracts.lookup(chain_id, to).map(|info| info.kind) {
+        Some(ContractKind::OneInchRouter) => decode_one_inch(input),
+        Some(ContractKind::ZeroExExchangeProxy) => decode_zero_ex(input),
+        Some(ContractKind::ParaswapAugustus) => decode_paraswap(input),
+        Some(_) => None,
+        None => decode_settler(input),
+    }?;
+    let recipient = swap
+        .recipient
+        .filter(|recipient| sender != Some(*recipient))?;
+    let output = match swap.token_out {
+        Some(NATIVE) => token_name(tokens.currency(chain_id, Address::ZERO), Address::ZERO),
+        Some(token) => token_name(tokens.currency(chain_id, token), token),
+        None => "swap output".to_string(),
+    };
+    Some(plain_text_field(
+        "Warning",
+        &format!(
+            "The {output} bought by this swap is sent to {recipient:?}{}",
+            match sender {
+                Some(_) => ", not to you.",
+                None => "; confirm this is your address.",
+            }
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Bytes;
+
+    const USDC: Address = address!("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const WETH: Address = address!("0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    const ONE_INCH_V6: Address = address!("0x111111125421ca6dc452d289314280a0f8842a65");
+    const SIGNER: Address = Address::repeat_byte(0x11);
+    const STRANGER: Address = Address::repeat_byte(0x22);
+
+    fn summary(field: Option<SignablePayloadField>) -> String {
+        field.unwrap().fallback_text().clone()
+    }
+
+    fn one_inch_swap(receiver: Address) -> Vec<u8> {
+        IAggregationRouterV6::swapCall {
+            executor: Address::repeat_byte(0x33),
+            desc: IAggregationRouterV6::SwapDescription {
+                srcToken: USDC,
+                dstToken: NATIVE,
+                srcReceiver: Address::repeat_byte(0x33),
+                dstReceiver: receiver,
+                amount: U256::from(3_000_000_000u64),
+                minReturnAmount: U256::from(10u64).pow(U256::from(18)),
+                flags: U256::ZERO,
+            },
+            data: Bytes::new(),
+        }
+        .abi_encode()
+    }
+
+    #[test]
+    fn test_one_inch_swaps() {
+        let tokens = TokenRegistry::default();
+        let visualizer = OneInchVisualizer {};
+        assert_eq!(
+            summary(visualizer.visualize_tx_commands(
+                &one_inch_swap(Address::ZERO),
+                Some(1),
+                &tokens
+            )),
+            "Swap 3000 USDC for at least 1 ETH"
+        );
+
+        // The token bought is left to the pools
+        let mut token = [0u8; 32];
+        token[0] = 0x80;
+        token[12..].copy_from_slice(USDC.as_slice());
+        let input = IAggregationRouterV6::unoswapCall {
+            token: U256::from_be_bytes(token),
+            amount: U256::from(1_000_000u64),
+            minReturn: U256::from(500u64),
+            dex: U256::ZERO,
+        }
+        .abi_encode();
+        assert_eq!(
+            summary(visualizer.visualize_tx_commands(&input, Some(1), &tokens)),
+            "Swap 1 USDC for at least 500 of the route's output token"
+        );
+
+        let input = IAggregationRouterV6::ethUnoswapCall {
+            minReturn: U256::from(2_000_000u64),
+            dex: U256::ZERO,
+        }
+        .abi_encode();
+        assert_eq!(
+            summary(visualizer.visualize_tx_commands(&input, Some(1), &tokens)),
+            "Swap the ETH sent with this call for at least 2000000 of the route's output token"
+        );
+    }
+
+    #[test]
+    fn test_zero_ex_and_settler() {
+        let tokens = TokenRegistry::default();
+        let input = IZeroExProxy::transformERC20Call {
+            inputToken: NATIVE,
+            outputToken: USDC,
+            inputTokenAmount: U256::from(10u64).pow(U256::from(18)),
+            minOutputTokenAmount: U256::from(2_900_000_000u64),
+            transformations: vec![],
+        }
+        .abi_encode();
+        assert_eq!(
+            summary(ZeroExVisualizer {}.visualize_tx_commands(&input, Some(1), &tokens)),
+            "Swap 1 ETH for at least 2900 USDC"
+        );
+
+        let transfer = ISettlerActions::TRANSFER_FROMCall {
+            recipient: Address::repeat_byte(0x44),
+            permit: ISettlerActions::PermitTransferFrom {
+                permitted: ISettlerActions::TokenPermissions {
+                    token: USDC,
+                    amount: U256::from(5_000_000u64),
+                },
+                nonce: U256::from(1u64),
+                deadline: U256::from(1_700_000_000u64),
+            },
+            sig: Bytes::new(),
+        }
+        .abi_encode();
+        let input = ISettler::executeCall {
+            slippage: ISettler::AllowedSlippage {
+                recipient: STRANGER,
+                buyToken: WETH,
+                minAmountOut: U256::from(10u64).pow(U256::from(15)),
+            },
+            actions: vec![Bytes::from(transfer), Bytes::from(vec![0xaa, 0, 0, 0])],
+            zidAndAffiliate: Default::default(),
+        }
+        .abi_encode();
+        assert_eq!(
+            summary(SettlerVisualizer {}.visualize_tx_commands(&input, Some(1), &tokens)),
+            "Swap 5 USDC for at least 0.001 WETH"
+        );
+    }
+
+    #[test]
+    fn test_paraswap_exact_out() {
+        let input = IAugustusV6::swapExactAmountOutCall {
+            executor: Address::repeat_byte(0x33),
+            swapData: IAugustusV6::GenericData {
+                srcToken: USDC,
+                destToken: WETH,
+                fromAmount: U256::from(3_100_000_000u64),
+                toAmount: U256::from(10u64).pow(U256::from(18)),
+                quotedAmount: U256::from(3_000_000_000u64),
+                metadata: Default::default(),
+                beneficiary: Address::ZERO,
+            },
+            partnerAndFee: U256::ZERO,
+            permit: Bytes::new(),
+            executorData: Bytes::new(),
+        }
+        .abi_encode();
+        assert_eq!(
+            summary(ParaswapVisualizer {}.visualize_tx_commands(
+                &input,
+                Some(1),
+                &TokenRegistry::default()
+            )),
+            "Swap at most 3100 USDC for 1 WETH"
+        );
+    }
+
+    #[test]
+    fn test_recipient_warning() {
+        let warning = |input: &[u8]| {
+            recipient_warning(
+                input,
+                ONE_INCH_V6,
+                Some(SIGNER),
+                Some(1),
+                &ContractRegistry::default(),
+                &TokenRegistry::default(),
+            )
+            .map(|field| field.fallback_text().clone())
+        };
+        assert_eq!(warning(&one_inch_swap(Address::ZERO)), None);
+        assert_eq!(warning(&one_inch_swap(SIGNER)), None);
+        assert_eq!(
+            warning(&one_inch_swap(STRANGER)).unwrap(),
+            "The ETH bought by this swap is sent to 0x2222222222222222222222222222222222222222, not to you."
+        );
+
+        // Without a signer to compare to, any named recipient is flagged
+        let unsigned = recipient_warning(
+            &one_inch_swap(SIGNER),
+            ONE_INCH_V6,
+            None,
+            Some(1),
+            &ContractRegistry::default(),
+            &TokenRegistry::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            unsigned.fallback_text(),
+            "The ETH bought by this swap is sent to 0x1111111111111111111111111111111111111111; confirm this is your address."
+        );
+    }
+}
@@ -0,0 +1,418 @@
+//! Batches of calls: `multicall`, Multicall3's `aggregate` family and Safe's
+//! `multiSend`.
+//!
+//! Each sub-call is decoded by whichever visualizer would handle it if it were
+//! sent on its own, and listed in execution order.
+
+use alloy_primitives::{Address, Bytes, U256};
+use alloy_sol_types::{SolCall, sol};
+use visualsign::{AnnotatedPayloadField, SignablePayloadField};
+
+use crate::chains::native_currency;
+use crate::fields::{
+    addr, address_field, amount_field, annotated, plain_text_field, preview_layout, text_field,
+    with_annotation,
+};
+use crate::fmt::{format_timestamp, format_token_amount};
+
+sol! {
+    // Self-calls batched by Uniswap's periphery, OpenZeppelin's Multicall and others
+    interface IMulticall {
+        function multicall(bytes[] data) external payable returns (bytes[] results);
+        function multicall(uint256 deadline, bytes[] data) external payable returns (bytes[] results);
+    }
+
+    // From: https://github.com/mds1/multicall/blob/main/src/Multicall3.sol
+    interface IMulticall3 {
+        struct Call { address target; bytes callData; }
+        struct Call3 { address target; bool allowFailure; bytes callData; }
+        struct Call3Value { address target; bool allowFailure; uint256 value; bytes callData; }
+        struct Result { bool success; bytes returnData; }
+
+        function aggregate(Call[] calldata calls) external payable returns (uint256 blockNumber, bytes[] memory returnData);
+        function aggregate3(Call3[] calldata calls) external payable returns (Result[] memory returnData);
+        function aggregate3Value(Call3Value[] calldata calls) external payable returns (Result[] memory returnData);
+    }
+
+    // From: https://github.com/safe-global/safe-smart-account/blob/main/contracts/libraries/MultiSend.sol
+    interface IMultiSend {
+        function multiSend(bytes memory transactions) external payable;
+    }
+}
+
+/// One call of a batch.
+pub(crate) struct SubCall {
+    pub target: Address,
+    pub value: U256,
+    pub data: Bytes,
+    pub allow_failure: bool,
+    pub delegate_call: bool,
+}
+
+impl SubCall {
+    pub(crate) fn call(target: Address, data: Bytes) -> Self {
+        Self {
+            target,
+            value: U256::ZERO,
+            data,
+            allow_failure: false,
+            delegate_call: false,
+        }
+    }
+}
+
+/// Splits MultiSend's packed `transactions`: for each, a one-byte operation,
+/// a 20-byte target, a 32-byte value, a 32-byte data length and the data.
+fn decode_multi_send(mut packed: &[u8]) -> Option<Vec<SubCall>> {
+    const HEADER: usize = 1 + 20 + 32 + 32;
+    let mut calls = Vec::new();
+    while !packed.is_empty() {
+        if packed.len() < HEADER {
+            return None;
+        }
+        let operation = packed[0];
+        let target = Address::from_slice(&packed[1..21]);
+        let value = U256::from_be_slice(&packed[21..53]);
+        let length = usize::try_from(U256::from_be_slice(&packed[53..HEADER])).ok()?;
+        let data = packed.get(HEADER..HEADER.checked_add(length)?)?;
+        if operation > 1 {
+            return None;
+        }
+        calls.push(SubCall {
+            target,
+            value,
+            data: Bytes::copy_from_slice(data),
+            allow_failure: false,
+            delegate_call: operation == 1,
+        });
+        packed = &packed[HEADER + length..];
+    }
+    Some(calls)
+}
+
+/// Warnings for the top of the payload, one for each call of a `multiSend`
+/// batch that delegatecalls. A Safe delegatecalls MultiSend itself, so these
+/// run with the Safe's storage too.
+pub(crate) fn delegatecall_warnings(input: &[u8]) -> Vec<SignablePayloadField> {
+    let calls = (input.get(..4) == Some(IMultiSend::multiSendCall::SELECTOR.as_slice()))
+        .then(|| IMultiSend::multiSendCall::abi_decode(input).ok())
+        .flatten()
+        .and_then(|call| decode_multi_send(&call.transactions))
+        .unwrap_or_default();
+    calls
+        .iter()
+        .enumerate()
+        .filter(|(_, call)| call.delegate_call)
+        .map(|(i, call)| {
+            plain_text_field(
+                "Warning",
+                &format!(
+                    "Call {} of this MultiSend batch uses DELEGATECALL: {:?} runs with the Safe's own storage and balance, and can change its owners or move all of its assets.",
+                    i + 1,
+                    call.target
+                ),
+            )
+        })
+        .collect()
+}
+
+/// A short name for a decoded call: the title of its layout, its selector,
+/// or for a call without data its operation.
+fn call_name(call: &SubCall, decoded: &[AnnotatedPayloadField]) -> String {
+    let title = decoded
+        .first()
+        .and_then(|field| match &field.signable_payload_field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => preview_layout
+                .title
+                .as_ref()
+                .map(|title| title.text.clone()),
+            _ => None,
+        });
+    match (title, call.data.get(..4)) {
+        (Some(title), _) => title,
+        (None, Some(selector)) => format!("0x{}", hex::encode(selector)),
+        (None, None) if call.delegate_call => "DelegateCall".to_string(),
+        (None, None) => "Call".to_string(),
+    }
+}
+
+fn sub_call_field(
+    index: usize,
+    call: &SubCall,
+    chain_id: Option<u64>,
+    decode_call: &dyn Fn(Address, &[u8]) -> Vec<AnnotatedPayloadField>,
+) -> (String, AnnotatedPayloadField) {
+    let decoded = if call.data.is_empty() {
+        Vec::new()
+    } else {
+        decode_call(call.target, &call.data)
+    };
+    let name = call_name(call, &decoded);
+
+    let mut fields = vec![address_field("Target", &addr(call.target), None, None)];
+    if !call.value.is_zero() {
+        let (symbol, decimals) = native_currency(chain_id);
+        let value = format_token_amount(call.value, decimals);
+        fields.push(amount_field("Value", &value, Some(symbol)));
+    }
+    if call.delegate_call {
+        fields.push(with_annotation(
+            text_field("Operation", "DelegateCall"),
+            "The target runs with the caller's own storage and balance",
+        ));
+    }
+    if call.allow_failure {
+        fields.push(text_field("Allow Failure", "Yes"));
+    }
+    fields.extend(decoded);
+
+    let label = format!("Call {}", index + 1);
+    let subtitle = format!("{name} on {:?}", call.target);
+    let field = annotated(preview_layout(&label, &label, &subtitle, None, fields));
+    (name, field)
+}
+
+pub struct BatchVisualizer {}
+
+impl BatchVisualizer {
+    /// Decodes `input`, a batch sent to `to` on `chain_id`. `decode_call`
+    /// renders each sub-call, given its target and calldata.
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        to: Address,
+        chain_id: Option<u64>,
+        decode_call: &dyn Fn(Address, &[u8]) -> Vec<AnnotatedPayloadField>,
+    ) -> Option<SignablePayloadField> {
+        let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+        let (title, calls, deadline) = match selector {
+            IMulticall::multicall_0Call::SELECTOR => {
+                let call = IMulticall::multicall_0Call::abi_decode(input).ok()?;
+                let calls = call
+                    .data
+                    .into_iter()
+                    .map(|data| SubCall::call(to, data))
+                    .collect();
+                ("Multicall", calls, None)
+            }
+            IMulticall::multicall_1Call::SELECTOR => {
+                let call = IMulticall::multicall_1Call::abi_decode(input).ok()?;
+                let calls = call
+                    .data
+                    .into_iter()
+                    .map(|data| SubCall::call(to, data))
+                    .collect();
+                ("Multicall", calls, Some(call.deadline))
+            }
+            IMulticall3::aggregateCall::SELECTOR => {
+                let call = IMulticall3::aggregateCall::abi_decode(input).ok()?;
+                let calls = call
+                    .calls
+                    .into_iter()
+                    .map(|c| SubCall::call(c.target, c.callData))
+                    .collect();
+                ("Multicall3 Aggregate", calls, None)
+            }
+            IMulticall3::aggregate3Call::SELECTOR => {
+                let call = IMulticall3::aggregate3Call::abi_decode(input).ok()?;
+                let calls = call
+                    .calls
+                    .into_iter()
+                    .map(|c| SubCall {
+                        allow_failure: c.allowFailure,
+                        ..SubCall::call(c.target, c.callData)
+                    })
+                    .collect();
+                ("Multicall3 Aggregate3", calls, None)
+            }
+            IMulticall3::aggregate3ValueCall::SELECTOR => {
+                let call = IMulticall3::aggregate3ValueCall::abi_decode(input).ok()?;
+                let calls = call
+                    .calls
+                    .into_iter()
+                    .map(|c| SubCall {
+                        value: c.value,
+                        allow_failure: c.allowFailure,
+                        ..SubCall::call(c.target, c.callData)
+                    })
+                    .collect();
+                ("Multicall3 Aggregate3Value", calls, None)
+            }
+            IMultiSend::multiSendCall::SELECTOR => {
+                let call = IMultiSend::multiSendCall::abi_decode(input).ok()?;
+                (
+                    "Safe MultiSend",
+                    decode_multi_send(&call.transactions)?,
+                    None,
+                )
+            }
+            _ => return None,
+        };
+
+        let deadline = deadline.map(|deadline| text_field("Deadline", &format_timestamp(deadline)));
+        Some(calls_layout(
+            title,
+            &calls,
+            deadline.into_iter().collect(),
+            chain_id,
+            decode_call,
+        ))
+    }
+}
+
+/// A layout titled `title` listing `calls` in order, followed by `trailing`.
+pub(crate) fn calls_layout(
+    title: &str,
+    calls: &[SubCall],
+    trailing: Vec<AnnotatedPayloadField>,
+    chain_id: Option<u64>,
+    decode_call: &dyn Fn(Address, &[u8]) -> Vec<AnnotatedPayloadField>,
+) -> SignablePayloadField {
+    let (names, mut fields): (Vec<String>, Vec<AnnotatedPayloadField>) = calls
+        .iter()
+        .enumerate()
+        .map(|(i, call)| sub_call_field(i, call, chain_id, decode_call))
+        .unzip();
+    let summary = format!(
+        "{} {}: {}",
+        calls.len(),
+        if calls.len() == 1 { "call" } else { "calls" },
+        names.join(", ")
+    );
+    fields.extend(trailing);
+    preview_layout(
+        title,
+        title,
+        &summary,
+        Some(vec![text_field("Calls", &summary)]),
+        fields,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROUTER: Address = Address::repeat_byte(0x01);
+    const TOKEN: Address = Address::repeat_byte(0x02);
+
+    fn decode(to: Address, data: &[u8]) -> Vec<AnnotatedPayloadField> {
+        match data {
+            [0xaa, ..] => vec![annotated(preview_layout(
+                "Inner",
+                "approve",
+                "approve",
+                None,
+                vec![],
+            ))],
+            _ => vec![address_field("To", &addr(to), None, None)],
+        }
+    }
+
+    fn texts(field: SignablePayloadField) -> (String, Vec<String>) {
+        let SignablePayloadField::PreviewLayout {
+            common,
+            preview_layout,
+        } = field
+        else {
+            panic!("expected a preview layout");
+        };
+        let calls = preview_layout
+            .expanded
+            .unwrap()
+            .fields
+            .into_iter()
+            .map(|f| f.signable_payload_field.fallback_text().clone())
+            .collect();
+        (common.fallback_text, calls)
+    }
+
+    #[test]
+    fn test_multicall_self_calls() {
+        let input = IMulticall::multicall_0Call {
+            data: vec![
+                Bytes::from(vec![0xaa, 0, 0, 0]),
+                Bytes::from(vec![0xbb, 1, 2, 3]),
+            ],
+        }
+        .abi_encode();
+        let (summary, calls) = texts(
+            BatchVisualizer {}
+                .visualize_tx_commands(&input, ROUTER, Some(1), &decode)
+                .unwrap(),
+        );
+        assert_eq!(summary, "2 calls: approve, 0xbb010203");
+        assert_eq!(
+            calls,
+            vec![
+                format!("approve on {ROUTER:?}"),
+                format!("0xbb010203 on {ROUTER:?}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_aggregate3_targets() {
+        let input = IMulticall3::aggregate3Call {
+            calls: vec![IMulticall3::Call3 {
+                target: TOKEN,
+                allowFailure: true,
+                callData: Bytes::from(vec![0xaa]),
+            }],
+        }
+        .abi_encode();
+        let (summary, calls) = texts(
+            BatchVisualizer {}
+                .visualize_tx_commands(&input, ROUTER, Some(1), &decode)
+                .unwrap(),
+        );
+        assert_eq!(summary, "1 call: approve");
+        assert_eq!(calls, vec![format!("approve on {TOKEN:?}")]);
+    }
+
+    #[test]
+    fn test_multi_send_packing() {
+        let mut packed = Vec::new();
+        for (operation, target, value, data) in [
+            (0u8, TOKEN, U256::ZERO, vec![0xaa, 0xbb]),
+            (1u8, ROUTER, U256::from(5u64), vec![]),
+        ] {
+            packed.push(operation);
+            packed.extend_from_slice(target.as_slice());
+            packed.extend_from_slice(&value.to_be_bytes::<32>());
+            packed.extend_from_slice(&U256::from(data.len()).to_be_bytes::<32>());
+            packed.extend_from_slice(&data);
+        }
+        let calls = decode_multi_send(&packed).unwrap();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].target, TOKEN);
+        assert_eq!(calls[0].data.as_ref(), &[0xaa, 0xbb]);
+        assert!(calls[1].delegate_call);
+        assert_eq!(calls[1].value, U256::from(5u64));
+
+        let input = IMultiSend::multiSendCall {
+            transactions: Bytes::from(packed.clone()),
+        }
+        .abi_encode();
+        let (summary, _) = texts(
+            BatchVisualizer {}
+                .visualize_tx_commands(&input, ROUTER, Some(1), &decode)
+                .unwrap(),
+        );
+        assert_eq!(summary, "2 calls: approve, DelegateCall");
+
+        // The delegatecall is flagged ahead of the payload, not only in its call
+        let warnings = delegatecall_warnings(&input);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].fallback_text(),
+            &format!(
+                "Call 2 of this MultiSend batch uses DELEGATECALL: {ROUTER:?} runs with the Safe's own storage and balance, and can change its owners or move all of its assets."
+            )
+        );
+        assert!(delegatecall_warnings(&packed).is_empty());
+
+        // A truncated batch isn't decoded at all
+        assert!(decode_multi_send(&packed[..packed.len() - 1]).is_none());
+    }
+}
@@ -0,0 +1,412 @@
+//! Deposits through the canonical L1 bridges of OP Stack chains, Arbitrum
+//! and zkSync Era.
+//!
+//! A deposit is only claimable by its L2 recipient, which a bridge call can set
+//! to any address. [`recipient_warning`] flags deposits credited to an address
+//! other than the signer's.
+
+use alloy_primitives::{Address, Bytes, U256};
+use alloy_sol_types::{SolCall, sol};
+use visualsign::{AnnotatedPayloadField, SignablePayloadField};
+
+use crate::chains::{get_chain_name, native_currency};
+use crate::fields::{
+    addr, address_field, amount_field, plain_text_field, preview_layout, text_field,
+    with_annotation,
+};
+use crate::fmt::format_token_amount;
+use crate::registry::{ContractKind, ContractRegistry};
+use crate::tokens::{TokenRegistry, format_amount};
+
+sol! {
+    // From: https://github.com/ethereum-optimism/optimism/blob/develop/packages/contracts-bedrock/src/L1/L1StandardBridge.sol
+    interface IL1StandardBridge {
+        function depositETH(uint32 _minGasLimit, bytes calldata _extraData) external payable;
+        function depositETHTo(address _to, uint32 _minGasLimit, bytes calldata _extraData) external payable;
+        function depositERC20(address _l1Token, address _l2Token, uint256 _amount, uint32 _minGasLimit, bytes calldata _extraData) external;
+        function depositERC20To(address _l1Token, address _l2Token, address _to, uint256 _amount, uint32 _minGasLimit, bytes calldata _extraData) external;
+        // StandardBridge's chain-agnostic names for the same deposits
+        function bridgeETH(uint32 _minGasLimit, bytes calldata _extraData) external payable;
+        function bridgeETHTo(address _to, uint32 _minGasLimit, bytes calldata _extraData) external payable;
+        function bridgeERC20(address _localToken, address _remoteToken, uint256 _amount, uint32 _minGasLimit, bytes calldata _extraData) external;
+        function bridgeERC20To(address _localToken, address _remoteToken, address _to, uint256 _amount, uint32 _minGasLimit, bytes calldata _extraData) external;
+    }
+
+    // From: https://github.com/OffchainLabs/nitro-contracts/blob/main/src/bridge/Inbox.sol
+    interface IArbitrumInbox {
+        function depositEth() external payable returns (uint256);
+    }
+
+    // From: https://github.com/OffchainLabs/token-bridge-contracts/blob/main/contracts/tokenbridge/ethereum/gateway/L1GatewayRouter.sol
+    interface IL1GatewayRouter {
+        function outboundTransfer(address _token, address _to, uint256 _amount, uint256 _maxGas, uint256 _gasPriceBid, bytes calldata _data) external payable returns (bytes memory);
+        function outboundTransferCustomRefund(address _token, address _refundTo, address _to, uint256 _amount, uint256 _maxGas, uint256 _gasPriceBid, bytes calldata _data) external payable returns (bytes memory);
+    }
+
+    // From: https://github.com/matter-labs/era-contracts/blob/main/l1-contracts/contracts/zksync/interfaces/IMailbox.sol
+    interface IZkSyncMailbox {
+        function requestL2Transaction(address _contractL2, uint256 _l2Value, bytes calldata _calldata, uint256 _l2GasLimit, uint256 _l2GasPerPubdataByteLimit, bytes[] calldata _factoryDeps, address _refundRecipient) external payable returns (bytes32 canonicalTxHash);
+    }
+}
+
+/// A deposit to an L2, as far as the calldata names it.
+struct Deposit {
+    /// The L1 token, or `None` for ETH.
+    token: Option<Address>,
+    /// `None` when the deposit is the call's value.
+    amount: Option<U256>,
+    /// `None` when the sender is credited on L2.
+    recipient: Option<Address>,
+    extra: Vec<AnnotatedPayloadField>,
+}
+
+impl Deposit {
+    fn eth(recipient: Option<Address>) -> Self {
+        Deposit {
+            token: None,
+            amount: None,
+            recipient,
+            extra: Vec::new(),
+        }
+    }
+
+    fn erc20(token: Address, remote: Address, amount: U256, recipient: Option<Address>) -> Self {
+        Deposit {
+            token: Some(token),
+            amount: Some(amount),
+            recipient,
+            extra: vec![address_field("L2 Token", &addr(remote), None, None)],
+        }
+    }
+
+    fn layout(
+        self,
+        chain_id: Option<u64>,
+        destination: u64,
+        tokens: &TokenRegistry,
+    ) -> SignablePayloadField {
+        let network = get_chain_name(Some(destination));
+        let (symbol, decimals) = native_currency(chain_id);
+        let (amount_text, token_field, amount) = match (self.token, self.amount) {
+            (Some(token), Some(amount)) => {
+                let known = tokens.token(chain_id, token);
+                let amount_field = match known {
+                    Some(known) => amount_field(
+                        "Amount",
+                        &format_token_amount(amount, known.decimals),
+                        Some(known.symbol),
+                    ),
+                    None => amount_field("Amount", &amount.to_string(), None),
+                };
+                (
+                    format_amount(known, token, amount),
+                    address_field("Token", &addr(token), known.map(|known| known.symbol), None),
+                    amount_field,
+                )
+            }
+            (_, Some(amount)) => {
+                let amount = format_token_amount(amount, decimals);
+                (
+                    format!("{amount} {symbol}"),
+                    text_field("Token", symbol),
+                    amount_field("Amount", &amount, Some(symbol)),
+                )
+            }
+            (_, None) => (
+                format!("the {symbol} sent with this call"),
+                text_field("Token", symbol),
+                text_field("Amount", &format!("The {symbol} value sent with this call")),
+            ),
+        };
+        let (recipient_text, recipient) = match self.recipient {
+            Some(recipient) => (
+                addr(recipient),
+                address_field("L2 Recipient", &addr(recipient), None, None),
+            ),
+            None => (
+                "your address".to_string(),
+                text_field("L2 Recipient", "Sender"),
+            ),
+        };
+        let mut fields = vec![
+            text_field("Destination Chain", &network),
+            recipient,
+            token_field,
+            amount,
+        ];
+        fields.extend(self.extra);
+        let title = format!("Bridge to {network}");
+        preview_layout(
+            &title,
+            &title,
+            &format!("Deposit {amount_text} to {recipient_text} on {network}"),
+            None,
+            fields,
+        )
+    }
+}
+
+fn decode_op_stack(input: &[u8]) -> Option<Deposit> {
+    let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+    let args = &input[4..];
+    let deposit = match selector {
+        IL1StandardBridge::depositETHCall::SELECTOR
+        | IL1StandardBridge::bridgeETHCall::SELECTOR => {
+            IL1StandardBridge::depositETHCall::abi_decode_raw(args).ok()?;
+            Deposit::eth(None)
+        }
+        IL1StandardBridge::depositETHToCall::SELECTOR
+        | IL1StandardBridge::bridgeETHToCall::SELECTOR => {
+            let call = IL1StandardBridge::depositETHToCall::abi_decode_raw(args).ok()?;
+            Deposit::eth(Some(call._to))
+        }
+        IL1StandardBridge::depositERC20Call::SELECTOR
+        | IL1StandardBridge::bridgeERC20Call::SELECTOR => {
+            let call = IL1StandardBridge::depositERC20Call::abi_decode_raw(args).ok()?;
+            Deposit::erc20(call._l1Token, call._l2Token, call._amount, None)
+        }
+        IL1StandardBridge::depositERC20ToCall::SELECTOR
+        | IL1StandardBridge::bridgeERC20ToCall::SELECTOR => {
+            let call = IL1StandardBridge::depositERC20ToCall::abi_decode_raw(args).ok()?;
+            Deposit::erc20(call._l1Token, call._l2Token, call._amount, Some(call._to))
+        }
+        _ => return None,
+    };
+    Some(deposit)
+}
+
+fn decode_arbitrum(input: &[u8]) -> Option<Deposit> {
+    let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+    let deposit = match selector {
+        IArbitrumInbox::depositEthCall::SELECTOR => {
+            IArbitrumInbox::depositEthCall::abi_decode(input).ok()?;
+            Deposit::eth(None)
+        }
+        // The value sent with a token deposit pays for its L2 execution
+        IL1GatewayRouter::outboundTransferCall::SELECTOR => {
+            let call = IL1GatewayRouter::outboundTransferCall::abi_decode(input).ok()?;
+            Deposit {
+                token: Some(call._token),
+                amount: Some(call._amount),
+                recipient: Some(call._to),
+                extra: Vec::new(),
+            }
+        }
+        IL1GatewayRouter::outboundTransferCustomRefundCall::SELECTOR => {
+            let call =
+                IL1GatewayRouter::outboundTransferCustomRefundCall::abi_decode(input).ok()?;
+            Deposit {
+                token: Some(call._token),
+                amount: Some(call._amount),
+                recipient: Some(call._to),
+                extra: vec![address_field(
+                    "Refund Address",
+                    &addr(call._refundTo),
+                    None,
+                    None,
+                )],
+            }
+        }
+        _ => return None,
+    };
+    Some(deposit)
+}
+
+fn decode_zksync(input: &[u8]) -> Option<Deposit> {
+    if input.get(..4)? != IZkSyncMailbox::requestL2TransactionCall::SELECTOR {
+        return None;
+    }
+    let call = IZkSyncMailbox::requestL2TransactionCall::abi_decode(input).ok()?;
+    let mut extra = vec![address_field(
+        "Refund Address",
+        &addr(call._refundRecipient),
+        None,
+        None,
+    )];
+    // With calldata, the deposit is a call into an L2 contract
+    if !call._calldata.is_empty() {
+        extra.push(l2_call_field(&call._calldata));
+    }
+    Some(Deposit {
+        token: None,
+        amount: Some(call._l2Value),
+        recipient: Some(call._contractL2),
+        extra,
+    })
+}
+
+fn l2_call_field(calldata: &Bytes) -> AnnotatedPayloadField {
+    with_annotation(
+        text_field("L2 Call Data", &format!("0x{}", hex::encode(calldata))),
+        "Runs on L2 with the deposited value",
+    )
+}
+
+fn decode(kind: ContractKind, input: &[u8]) -> Option<Deposit> {
+    match kind {
+        ContractKind::OptimismBridge | ContractKind::BaseBridge => decode_op_stack(input),
+        ContractKind::ArbitrumBridge => decode_arbitrum(input),
+        ContractKind::ZkSyncBridge => decode_zksync(input),
+        _ => None,
+    }
+}
+
+pub struct BridgeVisualizer {}
+
+impl BridgeVisualizer {
+    /// Decodes `input`, a deposit on `chain_id` through a bridge of kind `kind`.
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        kind: ContractKind,
+        chain_id: Option<u64>,
+        tokens: &TokenRegistry,
+    ) -> Option<SignablePayloadField> {
+        let destination = kind.bridge_destination()?;
+        decode(kind, input).map(|deposit| deposit.layout(chain_id, destination, tokens))
+    }
+}
+
+/// A warning when `input`, sent by `sender` to a canonical bridge at `to`,
+/// credits the deposit to a different L2 address. Without a known sender, any
+/// L2 address the deposit names is flagged for the signer to check.
+pub(crate) fn recipient_warning(
+    input: &[u8],
+    to: Address,
+    sender: Option<Address>,
+    chain_id: Option<u64>,
+    contracts: &ContractRegistry,
+) -> Option<SignablePayloadField> {
+    let kind = contracts.lookup(chain_id, to)?.kind;
+    let destination = kind.bridge_destination()?;
+    let recipient = decode(kind, input)?
+        .recipient
+        .filter(|recipient| sender != Some(*recipient))?;
+    Some(plain_text_field(
+        "Warning",
+        &format!(
+            "This deposit is credited to {recipient:?} on {}{} Only that account can use the funds.",
+            get_chain_name(Some(destination)),
+            match sender {
+                Some(_) => ", not to your address.",
+                None => "; confirm this is your address.",
+            }
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    const USDC: Address = address!("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const BASE_BRIDGE: Address = address!("0x3154cf16ccdb4c6d922629664174b904d80f2c35");
+    const SIGNER: Address = Address::repeat_byte(0x11);
+    const STRANGER: Address = Address::repeat_byte(0x22);
+
+    fn summary(input: &[u8], kind: ContractKind) -> String {
+        BridgeVisualizer {}
+            .visualize_tx_commands(input, kind, Some(1), &TokenRegistry::default())
+            .unwrap()
+            .fallback_text()
+            .clone()
+    }
+
+    #[test]
+    fn test_deposits() {
+        let input = IL1StandardBridge::depositETHCall {
+            _minGasLimit: 200_000,
+            _extraData: Bytes::new(),
+        }
+        .abi_encode();
+        assert_eq!(
+            summary(&input, ContractKind::BaseBridge),
+            "Deposit the ETH sent with this call to your address on Base"
+        );
+
+        let input = IL1StandardBridge::bridgeERC20ToCall {
+            _localToken: USDC,
+            _remoteToken: Address::repeat_byte(0x33),
+            _to: STRANGER,
+            _amount: U256::from(250_000_000u64),
+            _minGasLimit: 200_000,
+            _extraData: Bytes::new(),
+        }
+        .abi_encode();
+        assert_eq!(
+            summary(&input, ContractKind::OptimismBridge),
+            "Deposit 250 USDC to 0x2222222222222222222222222222222222222222 on OP Mainnet"
+        );
+
+        let input = IL1GatewayRouter::outboundTransferCall {
+            _token: USDC,
+            _to: SIGNER,
+            _amount: U256::from(1_000_000u64),
+            _maxGas: U256::from(300_000u64),
+            _gasPriceBid: U256::from(100_000_000u64),
+            _data: Bytes::new(),
+        }
+        .abi_encode();
+        assert_eq!(
+            summary(&input, ContractKind::ArbitrumBridge),
+            "Deposit 1 USDC to 0x1111111111111111111111111111111111111111 on Arbitrum One"
+        );
+
+        let input = IZkSyncMailbox::requestL2TransactionCall {
+            _contractL2: SIGNER,
+            _l2Value: U256::from(10u64).pow(U256::from(17)),
+            _calldata: Bytes::new(),
+            _l2GasLimit: U256::from(700_000u64),
+            _l2GasPerPubdataByteLimit: U256::from(800u64),
+            _factoryDeps: vec![],
+            _refundRecipient: SIGNER,
+        }
+        .abi_encode();
+        assert_eq!(
+            summary(&input, ContractKind::ZkSyncBridge),
+            "Deposit 0.1 ETH to 0x1111111111111111111111111111111111111111 on zkSync Mainnet"
+        );
+    }
+
+    #[test]
+    fn test_recipient_warning() {
+        let deposit_to = |to| {
+            IL1StandardBridge::depositETHToCall {
+                _to: to,
+                _minGasLimit: 200_000,
+                _extraData: Bytes::new(),
+            }
+            .abi_encode()
+        };
+        let warning = |input: &[u8]| {
+            recipient_warning(
+                input,
+                BASE_BRIDGE,
+                Some(SIGNER),
+                Some(1),
+                &ContractRegistry::default(),
+            )
+            .map(|field| field.fallback_text().clone())
+        };
+        assert_eq!(warning(&deposit_to(SIGNER)), None);
+        assert_eq!(
+            warning(&deposit_to(STRANGER)).unwrap(),
+            "This deposit is credited to 0x2222222222222222222222222222222222222222 on Base, not to your address. Only that account can use the funds."
+        );
+
+        // Without a signer to compare to, any named L2 address is flagged
+        let unsigned = recipient_warning(
+            &deposit_to(SIGNER),
+            BASE_BRIDGE,
+            None,
+            Some(1),
+            &ContractRegistry::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            unsigned.fallback_text(),
+            "This deposit is credited to 0x1111111111111111111111111111111111111111 on Base; confirm this is your address. Only that account can use the funds."
+        );
+    }
+}
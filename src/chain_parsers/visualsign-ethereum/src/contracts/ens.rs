@@ -0,0 +1,479 @@
+//! ENS `.eth` registrations and the records a public resolver stores.
+//!
+//! Resolver calls name the record they change by namehash, which can't be
+//! reversed. Records set while registering a name are shown under that name;
+//! elsewhere the node itself is shown.
+
+use alloy_primitives::{Address, B256, Bytes, U256, keccak256};
+use alloy_sol_types::{SolCall, sol};
+use visualsign::{AnnotatedPayloadField, SignablePayloadField};
+
+use crate::chains::get_chain_name;
+use crate::fields::{addr, address_field, annotated, number_field, preview_layout, text_field};
+use crate::fmt::escape_non_ascii;
+
+sol! {
+    // From: https://github.com/ensdomains/ens-contracts/blob/staging/contracts/ethregistrar/ETHRegistrarController.sol
+    interface IETHRegistrarController {
+        function commit(bytes32 commitment) external;
+        function register(string name, address owner, uint256 duration, bytes32 secret, address resolver, bytes[] data, bool reverseRecord, uint16 ownerControlledFuses) external payable;
+        function renew(string name, uint256 duration) external payable;
+    }
+
+    // The controller in use before the name wrapper, which shares `commit` and `renew`
+    interface ILegacyETHRegistrarController {
+        function register(string name, address owner, uint256 duration, bytes32 secret) external payable;
+        function registerWithConfig(string name, address owner, uint256 duration, bytes32 secret, address resolver, address addr) external payable;
+    }
+
+    // From: https://github.com/ensdomains/ens-contracts/blob/staging/contracts/resolvers/PublicResolver.sol
+    interface IPublicResolver {
+        function setAddr(bytes32 node, address a) external;
+        function setAddr(bytes32 node, uint256 coinType, bytes a) external;
+        function setText(bytes32 node, string key, string value) external;
+        function setContenthash(bytes32 node, bytes hash) external;
+        function multicall(bytes[] data) external returns (bytes[] results);
+        function multicallWithNodeCheck(bytes32 nodehash, bytes[] data) external returns (bytes[] results);
+    }
+}
+
+/// ENS's coin type for ETH, from SLIP-44.
+const COIN_TYPE_ETH: u64 = 60;
+/// ENSIP-11 coin types for EVM chains set this bit over the chain id.
+const COIN_TYPE_EVM_FLAG: u64 = 0x8000_0000;
+const SECONDS_PER_DAY: u64 = 86_400;
+const SECONDS_PER_YEAR: u64 = 365 * SECONDS_PER_DAY;
+
+/// The ENSIP-1 namehash of `name`.
+fn namehash(name: &str) -> B256 {
+    name.rsplit('.').fold(B256::ZERO, |node, label| {
+        keccak256([node.as_slice(), keccak256(label).as_slice()].concat())
+    })
+}
+
+/// `label` as a `.eth` name. Labels can hold any Unicode, so anything outside
+/// printable ASCII is escaped rather than rendered as a lookalike.
+fn eth_name(label: &str) -> String {
+    format!("{}.eth", escape_non_ascii(label).0)
+}
+
+fn quoted(text: &str) -> String {
+    format!("\"{}\"", escape_non_ascii(text).0)
+}
+
+/// A registration period in whole years or days where it divides evenly.
+fn format_duration(seconds: U256) -> String {
+    let plural = |count: u64, unit: &str| match count {
+        1 => format!("1 {unit}"),
+        _ => format!("{count} {unit}s"),
+    };
+    match u64::try_from(seconds) {
+        Ok(seconds) if seconds > 0 && seconds % SECONDS_PER_YEAR == 0 => {
+            plural(seconds / SECONDS_PER_YEAR, "year")
+        }
+        Ok(seconds) if seconds > 0 && seconds % SECONDS_PER_DAY == 0 => {
+            plural(seconds / SECONDS_PER_DAY, "day")
+        }
+        _ => plural(seconds.saturating_to(), "second"),
+    }
+}
+
+/// What an address record of `coin_type` is for, and whether its value is an
+/// EVM address.
+fn coin_name(coin_type: U256) -> (String, bool) {
+    match u64::try_from(coin_type) {
+        Ok(COIN_TYPE_ETH) => ("ETH".to_string(), true),
+        Ok(0) => ("BTC".to_string(), false),
+        Ok(coin_type) if coin_type & COIN_TYPE_EVM_FLAG != 0 && coin_type >> 32 == 0 => {
+            (get_chain_name(Some(coin_type ^ COIN_TYPE_EVM_FLAG)), true)
+        }
+        _ => (format!("coin type {coin_type}"), false),
+    }
+}
+
+/// Shows `node` by `registering`, the unescaped name being registered, when
+/// it's that name's node.
+fn node_name(node: B256, registering: Option<&str>) -> String {
+    registering
+        .filter(|name| namehash(name) == node)
+        .map_or_else(|| format!("{node:?}"), |name| escape_non_ascii(name).0)
+}
+
+/// One record update sent to a resolver, on its own or batched.
+fn record_update(input: &[u8], registering: Option<&str>) -> Option<SignablePayloadField> {
+    let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+    let (title, summary, fields) = match selector {
+        IPublicResolver::setAddr_0Call::SELECTOR => {
+            let call = IPublicResolver::setAddr_0Call::abi_decode(input).ok()?;
+            let name = node_name(call.node, registering);
+            let summary = match call.a {
+                Address::ZERO => format!("Clear the ETH address of {name}"),
+                a => format!("Set the ETH address of {name} to {a:?}"),
+            };
+            let fields = vec![
+                text_field("Name", &name),
+                address_field("ETH Address", &addr(call.a), None, None),
+            ];
+            ("Set Address", summary, fields)
+        }
+        IPublicResolver::setAddr_1Call::SELECTOR => {
+            let call = IPublicResolver::setAddr_1Call::abi_decode(input).ok()?;
+            let name = node_name(call.node, registering);
+            let (coin, is_evm) = coin_name(call.coinType);
+            let value = match call.a.len() {
+                20 if is_evm => addr(Address::from_slice(&call.a)),
+                _ => format!("0x{}", hex::encode(&call.a)),
+            };
+            let summary = if call.a.is_empty() {
+                format!("Clear the {coin} address of {name}")
+            } else {
+                format!("Set the {coin} address of {name} to {value}")
+            };
+            let fields = vec![
+                text_field("Name", &name),
+                text_field("Coin", &coin),
+                text_field("Address", &value),
+            ];
+            ("Set Address", summary, fields)
+        }
+        IPublicResolver::setTextCall::SELECTOR => {
+            let call = IPublicResolver::setTextCall::abi_decode(input).ok()?;
+            let name = node_name(call.node, registering);
+            let key = quoted(&call.key);
+            let value = quoted(&call.value);
+            let summary = if call.value.is_empty() {
+                format!("Clear the {key} text record of {name}")
+            } else {
+                format!("Set the {key} text record of {name} to {value}")
+            };
+            let fields = vec![
+                text_field("Name", &name),
+                text_field("Key", &key),
+                text_field("Value", &value),
+            ];
+            ("Set Text Record", summary, fields)
+        }
+        IPublicResolver::setContenthashCall::SELECTOR => {
+            let call = IPublicResolver::setContenthashCall::abi_decode(input).ok()?;
+            let name = node_name(call.node, registering);
+            let hash = format!("0x{}", hex::encode(&call.hash));
+            let summary = if call.hash.is_empty() {
+                format!("Clear the content hash of {name}")
+            } else {
+                format!("Set the content hash of {name} to {hash}")
+            };
+            let fields = vec![text_field("Name", &name), text_field("Content Hash", &hash)];
+            ("Set Content Hash", summary, fields)
+        }
+        _ => return None,
+    };
+    Some(preview_layout(title, title, &summary, None, fields))
+}
+
+/// Each of `calls` as a record update, with raw calldata for anything else.
+fn record_updates(calls: &[Bytes], registering: Option<&str>) -> Vec<AnnotatedPayloadField> {
+    calls
+        .iter()
+        .map(|call| match record_update(call, registering) {
+            Some(field) => annotated(field),
+            None => text_field("Resolver Call", &format!("0x{}", hex::encode(call))),
+        })
+        .collect()
+}
+
+fn plural_records(count: usize) -> String {
+    match count {
+        1 => "1 record".to_string(),
+        _ => format!("{count} records"),
+    }
+}
+
+fn payment_field() -> AnnotatedPayloadField {
+    text_field("Payment", "The ETH value sent with this call")
+}
+
+/// A registration through the legacy controller, which can only point the
+/// name at a resolver and set its ETH address.
+fn legacy_registration(
+    label: &str,
+    owner: Address,
+    duration: U256,
+    resolver: Address,
+    eth_address: Address,
+) -> SignablePayloadField {
+    let name = eth_name(label);
+    let duration = format_duration(duration);
+    let mut fields = vec![
+        text_field("Name", &name),
+        address_field("Owner", &addr(owner), None, None),
+        text_field("Duration", &duration),
+    ];
+    if resolver != Address::ZERO {
+        fields.push(address_field("Resolver", &addr(resolver), None, None));
+    }
+    if eth_address != Address::ZERO {
+        fields.push(address_field("ETH Address", &addr(eth_address), None, None));
+    }
+    fields.push(payment_field());
+    preview_layout(
+        "ENS Registration",
+        "ENS Registration",
+        &format!("Register {name} for {duration}"),
+        None,
+        fields,
+    )
+}
+
+pub struct EnsControllerVisualizer {}
+
+impl EnsControllerVisualizer {
+    /// Decodes `input`, a call to an ENS `.eth` registrar controller.
+    pub fn visualize_tx_commands(&self, input: &[u8]) -> Option<SignablePayloadField> {
+        let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+        match selector {
+            IETHRegistrarController::commitCall::SELECTOR => {
+                let call = IETHRegistrarController::commitCall::abi_decode(input).ok()?;
+                Some(preview_layout(
+                    "ENS Commit",
+                    "ENS Commit",
+                    "Commit to registering an ENS name",
+                    None,
+                    vec![text_field("Commitment", &format!("{:?}", call.commitment))],
+                ))
+            }
+            IETHRegistrarController::registerCall::SELECTOR => {
+                let call = IETHRegistrarController::registerCall::abi_decode(input).ok()?;
+                let name = eth_name(&call.name);
+                let duration = format_duration(call.duration);
+                let mut summary = format!("Register {name} for {duration}");
+                if !call.data.is_empty() {
+                    summary.push_str(&format!(" and set {}", plural_records(call.data.len())));
+                }
+                let mut fields = vec![
+                    text_field("Name", &name),
+                    address_field("Owner", &addr(call.owner), None, None),
+                    text_field("Duration", &duration),
+                    address_field("Resolver", &addr(call.resolver), None, None),
+                    text_field(
+                        "Reverse Record",
+                        if call.reverseRecord { "Yes" } else { "No" },
+                    ),
+                ];
+                if call.ownerControlledFuses != 0 {
+                    fields.push(number_field(
+                        "Owner-Controlled Fuses",
+                        &call.ownerControlledFuses.to_string(),
+                    ));
+                }
+                fields.push(payment_field());
+                fields.extend(record_updates(
+                    &call.data,
+                    Some(&format!("{}.eth", call.name)),
+                ));
+                Some(preview_layout(
+                    "ENS Registration",
+                    "ENS Registration",
+                    &summary,
+                    None,
+                    fields,
+                ))
+            }
+            IETHRegistrarController::renewCall::SELECTOR => {
+                let call = IETHRegistrarController::renewCall::abi_decode(input).ok()?;
+                let name = eth_name(&call.name);
+                let duration = format_duration(call.duration);
+                Some(preview_layout(
+                    "ENS Renewal",
+                    "ENS Renewal",
+                    &format!("Renew {name} for {duration}"),
+                    None,
+                    vec![
+                        text_field("Name", &name),
+                        text_field("Duration", &duration),
+                        payment_field(),
+                    ],
+                ))
+            }
+            ILegacyETHRegistrarController::registerCall::SELECTOR => {
+                let call = ILegacyETHRegistrarController::registerCall::abi_decode(input).ok()?;
+                Some(legacy_registration(
+                    &call.name,
+                    call.owner,
+                    call.duration,
+                    Address::ZERO,
+                    Address::ZERO,
+                ))
+            }
+            ILegacyETHRegistrarController::registerWithConfigCall::SELECTOR => {
+                let call = ILegacyETHRegistrarController::registerWithConfigCall::abi_decode(input)
+                    .ok()?;
+                Some(legacy_registration(
+                    &call.name,
+                    call.owner,
+                    call.duration,
+                    call.resolver,
+                    call.addr,
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+pub struct EnsResolverVisualizer {}
+
+impl EnsResolverVisualizer {
+    /// Decodes `input`, a call to an ENS public resolver. A `multicall` is
+    /// listed as its individual record updates.
+    pub fn visualize_tx_commands(&self, input: &[u8]) -> Option<SignablePayloadField> {
+        let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+        let (calls, mut fields) = match selector {
+            IPublicResolver::multicallCall::SELECTOR => {
+                let call = IPublicResolver::multicallCall::abi_decode(input).ok()?;
+                (call.data, Vec::new())
+            }
+            IPublicResolver::multicallWithNodeCheckCall::SELECTOR => {
+                let call = IPublicResolver::multicallWithNodeCheckCall::abi_decode(input).ok()?;
+                let node = text_field("Name", &format!("{:?}", call.nodehash));
+                (call.data, vec![node])
+            }
+            _ => return record_update(input, None),
+        };
+        let summary = format!("Update {} in an ENS resolver", plural_records(calls.len()));
+        fields.extend(record_updates(&calls, None));
+        Some(preview_layout(
+            "ENS Records",
+            "ENS Records",
+            &summary,
+            None,
+            fields,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256};
+
+    const OWNER: Address = address!("0xd8da6bf26964af9d7eed9e10c48c7ba5bf0b6c9e");
+
+    fn summary(field: SignablePayloadField) -> String {
+        field.fallback_text().clone()
+    }
+
+    #[test]
+    fn test_namehash() {
+        assert_eq!(
+            namehash("eth"),
+            b256!("0x93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae")
+        );
+        assert_eq!(
+            namehash("foo.eth"),
+            b256!("0xde9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f")
+        );
+    }
+
+    #[test]
+    fn test_register_with_records() {
+        let node = namehash("vitalik.eth");
+        let input = IETHRegistrarController::registerCall {
+            name: "vitalik".to_string(),
+            owner: OWNER,
+            duration: U256::from(2 * SECONDS_PER_YEAR),
+            secret: B256::repeat_byte(0x01),
+            resolver: address!("0x231b0ee14048e9dccd1d247744d114a4eb5e8e63"),
+            data: vec![
+                IPublicResolver::setAddr_0Call { node, a: OWNER }
+                    .abi_encode()
+                    .into(),
+            ],
+            reverseRecord: true,
+            ownerControlledFuses: 0,
+        }
+        .abi_encode();
+        let field = EnsControllerVisualizer {}
+            .visualize_tx_commands(&input)
+            .unwrap();
+        assert_eq!(
+            field.fallback_text(),
+            "Register vitalik.eth for 2 years and set 1 record"
+        );
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } = field else {
+            panic!("expected a preview layout");
+        };
+        let record = preview_layout.expanded.unwrap().fields.pop().unwrap();
+        assert_eq!(
+            record.signable_payload_field.fallback_text(),
+            "Set the ETH address of vitalik.eth to 0xd8da6bf26964af9d7eed9e10c48c7ba5bf0b6c9e"
+        );
+
+        let input = IETHRegistrarController::renewCall {
+            name: "vitalik".to_string(),
+            duration: U256::from(30 * SECONDS_PER_DAY),
+        }
+        .abi_encode();
+        assert_eq!(
+            summary(
+                EnsControllerVisualizer {}
+                    .visualize_tx_commands(&input)
+                    .unwrap()
+            ),
+            "Renew vitalik.eth for 30 days"
+        );
+    }
+
+    #[test]
+    fn test_resolver_multicall() {
+        let node = namehash("vitalik.eth");
+        let input = IPublicResolver::multicallCall {
+            data: vec![
+                IPublicResolver::setTextCall {
+                    node,
+                    key: "url".to_string(),
+                    value: "https://vitalik.ca".to_string(),
+                }
+                .abi_encode()
+                .into(),
+                IPublicResolver::setAddr_1Call {
+                    node,
+                    coinType: U256::from(COIN_TYPE_EVM_FLAG | 8453),
+                    a: OWNER.to_vec().into(),
+                }
+                .abi_encode()
+                .into(),
+                IPublicResolver::setContenthashCall {
+                    node,
+                    hash: Bytes::new(),
+                }
+                .abi_encode()
+                .into(),
+            ],
+        }
+        .abi_encode();
+        let field = EnsResolverVisualizer {}
+            .visualize_tx_commands(&input)
+            .unwrap();
+        assert_eq!(field.fallback_text(), "Update 3 records in an ENS resolver");
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } = field else {
+            panic!("expected a preview layout");
+        };
+        let records: Vec<String> = preview_layout
+            .expanded
+            .unwrap()
+            .fields
+            .into_iter()
+            .map(|field| field.signable_payload_field.fallback_text().clone())
+            .collect();
+        assert_eq!(
+            records,
+            [
+                format!("Set the \"url\" text record of {node:?} to \"https://vitalik.ca\""),
+                format!(
+                    "Set the Base address of {node:?} to 0xd8da6bf26964af9d7eed9e10c48c7ba5bf0b6c9e"
+                ),
+                format!("Clear the content hash of {node:?}"),
+            ]
+        );
+    }
+}
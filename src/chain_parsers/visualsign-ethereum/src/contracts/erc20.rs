@@ -1,9 +1,10 @@
+use alloy_primitives::{Address, U256};
 use alloy_sol_types::{SolCall, sol};
-use visualsign::{
-    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldAddressV2,
-    SignablePayloadFieldAmountV2, SignablePayloadFieldCommon, SignablePayloadFieldListLayout,
-    SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
-};
+use visualsign::{AnnotatedPayloadField, SignablePayloadField};
+
+use crate::fields::{self, addr, address_field, preview_layout};
+use crate::fmt::format_token_amount;
+use crate::tokens::{Token, TokenInfo, format_amount};
 
 sol! {
     interface IERC20 {
@@ -16,15 +17,64 @@ sol! {
         function allowance(address owner, address spender) external view returns (uint256);
         function approve(address spender, uint256 amount) external returns (bool);
         function transferFrom(address from, address to, uint256 amount) external returns (bool);
+        function increaseAllowance(address spender, uint256 addedValue) external returns (bool);
 
         event Transfer(address indexed from, address indexed to, uint256 value);
         event Approval(address indexed owner, address indexed spender, uint256 value);
     }
 }
+
+/// The token contract a transfer or approval acts on, badged when the token
+/// registry doesn't know it.
+fn token_field(address: Address, token: Option<&TokenInfo>) -> AnnotatedPayloadField {
+    address_field(
+        "Token",
+        &addr(address),
+        token.map(|token| token.name.as_str()),
+        token.is_none().then_some("Unknown token"),
+    )
+}
+
+/// `1.5 USDC` for a known token; without decimals only the raw amount can be shown.
+fn amount_text(amount: U256, address: Address, token: Option<&TokenInfo>) -> String {
+    let token = token.map(|token| Token {
+        symbol: &token.symbol,
+        decimals: token.decimals,
+    });
+    format_amount(token, address, amount)
+}
+
+fn amount_field(amount: U256, token: Option<&TokenInfo>) -> AnnotatedPayloadField {
+    match token {
+        Some(token) => fields::amount_field(
+            "Amount",
+            &format_token_amount(amount, token.decimals),
+            Some(&token.symbol),
+        ),
+        None => fields::amount_field("Amount", &amount.to_string(), None),
+    }
+}
+
+/// A read-only call without arguments, which has nothing to expand.
+fn query_layout(label: &str, subtitle: &str) -> SignablePayloadField {
+    let mut layout = preview_layout(label, label, subtitle, None, Vec::new());
+    if let SignablePayloadField::PreviewLayout { preview_layout, .. } = &mut layout {
+        preview_layout.expanded = None;
+    }
+    layout
+}
+
 pub struct ERC20Visualizer {}
 
 impl ERC20Visualizer {
-    pub fn visualize_tx_commands(&self, input: &[u8]) -> Option<SignablePayloadField> {
+    /// Decodes `input`, a call to the token contract at `token_address`.
+    /// Amounts are shown in `token`'s units when the token is known.
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        token_address: Address,
+        token: Option<&TokenInfo>,
+    ) -> Option<SignablePayloadField> {
         if input.len() < 4 {
             return None;
         }
@@ -32,395 +82,142 @@ impl ERC20Visualizer {
         if selector == IERC20::transferCall::SELECTOR {
             // transfer(address,uint256)
             if let Ok(call) = IERC20::transferCall::abi_decode(input) {
-                let mut details = Vec::new();
-                // To Address
-                details.push(AnnotatedPayloadField {
-                    signable_payload_field: SignablePayloadField::AddressV2 {
-                        common: SignablePayloadFieldCommon {
-                            fallback_text: format!("{:?}", call.to),
-                            label: "Recipient".to_string(),
-                        },
-                        address_v2: SignablePayloadFieldAddressV2 {
-                            address: format!("{:?}", call.to),
-                            name: "".to_string(),
-                            memo: None,
-                            asset_label: "".to_string(),
-                            badge_text: None,
-                        },
-                    },
-                    static_annotation: None,
-                    dynamic_annotation: None,
-                });
-
-                // Amount
-                details.push(AnnotatedPayloadField {
-                    signable_payload_field: SignablePayloadField::AmountV2 {
-                        common: SignablePayloadFieldCommon {
-                            fallback_text: call.amount.to_string(),
-                            label: "Amount".to_string(),
-                        },
-                        amount_v2: SignablePayloadFieldAmountV2 {
-                            amount: call.amount.to_string(),
-                            abbreviation: None,
-                        },
-                    },
-                    static_annotation: None,
-                    dynamic_annotation: None,
-                });
-
-                return Some(SignablePayloadField::PreviewLayout {
-                    common: SignablePayloadFieldCommon {
-                        fallback_text: format!("Transfer {} tokens to {:?}", call.amount, call.to),
-                        label: "ERC20 Transfer".to_string(),
-                    },
-                    preview_layout: SignablePayloadFieldPreviewLayout {
-                        title: Some(SignablePayloadFieldTextV2 {
-                            text: "ERC20 Transfer".to_string(),
-                        }),
-                        subtitle: Some(SignablePayloadFieldTextV2 {
-                            text: format!("Transfer {} tokens to {:?}", call.amount, call.to),
-                        }),
-                        condensed: None,
-                        expanded: Some(SignablePayloadFieldListLayout { fields: details }),
-                    },
-                });
+                let subtitle = format!(
+                    "Transfer {} to {}",
+                    amount_text(call.amount, token_address, token),
+                    addr(call.to)
+                );
+                let details = vec![
+                    token_field(token_address, token),
+                    address_field("Recipient", &addr(call.to), None, None),
+                    amount_field(call.amount, token),
+                ];
+                return Some(preview_layout(
+                    "ERC20 Transfer",
+                    "ERC20 Transfer",
+                    &subtitle,
+                    None,
+                    details,
+                ));
             }
         } else if selector == IERC20::transferFromCall::SELECTOR {
             // transferFrom(address,address,uint256)
             if let Ok(call) = IERC20::transferFromCall::abi_decode(input) {
-                let mut details = Vec::new();
-
-                // From Address
-                details.push(AnnotatedPayloadField {
-                    signable_payload_field: SignablePayloadField::AddressV2 {
-                        common: SignablePayloadFieldCommon {
-                            fallback_text: format!("{:?}", call.from),
-                            label: "Sender".to_string(),
-                        },
-                        address_v2: SignablePayloadFieldAddressV2 {
-                            address: format!("{:?}", call.from),
-                            name: "".to_string(),
-                            memo: None,
-                            asset_label: "".to_string(),
-                            badge_text: None,
-                        },
-                    },
-                    static_annotation: None,
-                    dynamic_annotation: None,
-                });
-
-                // To Address
-                details.push(AnnotatedPayloadField {
-                    signable_payload_field: SignablePayloadField::AddressV2 {
-                        common: SignablePayloadFieldCommon {
-                            fallback_text: format!("{:?}", call.to),
-                            label: "Recipient".to_string(),
-                        },
-                        address_v2: SignablePayloadFieldAddressV2 {
-                            address: format!("{:?}", call.to),
-                            name: "".to_string(),
-                            memo: None,
-                            asset_label: "".to_string(),
-                            badge_text: None,
-                        },
-                    },
-                    static_annotation: None,
-                    dynamic_annotation: None,
-                });
-
-                // Amount
-                details.push(AnnotatedPayloadField {
-                    signable_payload_field: SignablePayloadField::AmountV2 {
-                        common: SignablePayloadFieldCommon {
-                            fallback_text: call.amount.to_string(),
-                            label: "Amount".to_string(),
-                        },
-                        amount_v2: SignablePayloadFieldAmountV2 {
-                            amount: call.amount.to_string(),
-                            abbreviation: None,
-                        },
-                    },
-                    static_annotation: None,
-                    dynamic_annotation: None,
-                });
-
-                let preview = SignablePayloadField::PreviewLayout {
-                    common: SignablePayloadFieldCommon {
-                        fallback_text: format!(
-                            "Transfer {} tokens from {:?} to {:?}",
-                            call.amount, call.from, call.to
-                        ),
-                        label: "ERC20 TransferFrom".to_string(),
-                    },
-                    preview_layout: SignablePayloadFieldPreviewLayout {
-                        title: Some(SignablePayloadFieldTextV2 {
-                            text: "ERC20 TransferFrom".to_string(),
-                        }),
-                        subtitle: Some(SignablePayloadFieldTextV2 {
-                            text: format!(
-                                "Transfer {} tokens from {:?} to {:?}",
-                                call.amount, call.from, call.to
-                            ),
-                        }),
-                        condensed: None,
-                        expanded: Some(SignablePayloadFieldListLayout { fields: details }),
-                    },
-                };
-                return Some(preview);
+                let subtitle = format!(
+                    "Transfer {} from {} to {}",
+                    amount_text(call.amount, token_address, token),
+                    addr(call.from),
+                    addr(call.to)
+                );
+                let details = vec![
+                    token_field(token_address, token),
+                    address_field("Sender", &addr(call.from), None, None),
+                    address_field("Recipient", &addr(call.to), None, None),
+                    amount_field(call.amount, token),
+                ];
+                return Some(preview_layout(
+                    "ERC20 TransferFrom",
+                    "ERC20 TransferFrom",
+                    &subtitle,
+                    None,
+                    details,
+                ));
             }
         } else if selector == IERC20::approveCall::SELECTOR {
             // approve(address,uint256)
             if let Ok(call) = IERC20::approveCall::abi_decode(input) {
-                let mut details = Vec::new();
-
-                // Spender Address
-                details.push(AnnotatedPayloadField {
-                    signable_payload_field: SignablePayloadField::AddressV2 {
-                        common: SignablePayloadFieldCommon {
-                            fallback_text: format!("{:?}", call.spender),
-                            label: "Spender".to_string(),
-                        },
-                        address_v2: SignablePayloadFieldAddressV2 {
-                            address: format!("{:?}", call.spender),
-                            name: "".to_string(),
-                            memo: None,
-                            asset_label: "".to_string(),
-                            badge_text: None,
-                        },
-                    },
-                    static_annotation: None,
-                    dynamic_annotation: None,
-                });
-
-                // Amount
-                details.push(AnnotatedPayloadField {
-                    signable_payload_field: SignablePayloadField::AmountV2 {
-                        common: SignablePayloadFieldCommon {
-                            fallback_text: call.amount.to_string(),
-                            label: "Amount".to_string(),
-                        },
-                        amount_v2: SignablePayloadFieldAmountV2 {
-                            amount: call.amount.to_string(),
-                            abbreviation: None,
-                        },
-                    },
-                    static_annotation: None,
-                    dynamic_annotation: None,
-                });
-
-                let preview = SignablePayloadField::PreviewLayout {
-                    common: SignablePayloadFieldCommon {
-                        fallback_text: format!(
-                            "Approve {:?} to spend {} tokens",
-                            call.spender, call.amount
-                        ),
-                        label: "ERC20 Approve".to_string(),
-                    },
-                    preview_layout: SignablePayloadFieldPreviewLayout {
-                        title: Some(SignablePayloadFieldTextV2 {
-                            text: "ERC20 Approve".to_string(),
-                        }),
-                        subtitle: Some(SignablePayloadFieldTextV2 {
-                            text: format!(
-                                "Approve {:?} to spend {} tokens",
-                                call.spender, call.amount
-                            ),
-                        }),
-                        condensed: None,
-                        expanded: Some(SignablePayloadFieldListLayout { fields: details }),
-                    },
-                };
-                return Some(preview);
+                let subtitle = format!(
+                    "Approve {} to spend {}",
+                    addr(call.spender),
+                    amount_text(call.amount, token_address, token)
+                );
+                let details = vec![
+                    token_field(token_address, token),
+                    address_field("Spender", &addr(call.spender), None, None),
+                    amount_field(call.amount, token),
+                ];
+                return Some(preview_layout(
+                    "ERC20 Approve",
+                    "ERC20 Approve",
+                    &subtitle,
+                    None,
+                    details,
+                ));
+            }
+        } else if selector == IERC20::increaseAllowanceCall::SELECTOR {
+            // increaseAllowance(address,uint256), OpenZeppelin's race-free approve
+            if let Ok(call) = IERC20::increaseAllowanceCall::abi_decode(input) {
+                let subtitle = format!(
+                    "Allow {} to spend {} more",
+                    addr(call.spender),
+                    amount_text(call.addedValue, token_address, token)
+                );
+                let details = vec![
+                    token_field(token_address, token),
+                    address_field("Spender", &addr(call.spender), None, None),
+                    amount_field(call.addedValue, token),
+                ];
+                return Some(preview_layout(
+                    "ERC20 IncreaseAllowance",
+                    "ERC20 IncreaseAllowance",
+                    &subtitle,
+                    None,
+                    details,
+                ));
             }
         } else if selector == IERC20::balanceOfCall::SELECTOR {
             // balanceOf(address)
             if let Ok(call) = IERC20::balanceOfCall::abi_decode(input) {
-                let mut details = Vec::new();
-
-                // Account Address
-                details.push(AnnotatedPayloadField {
-                    signable_payload_field: SignablePayloadField::AddressV2 {
-                        common: SignablePayloadFieldCommon {
-                            fallback_text: format!("{:?}", call.account),
-                            label: "Account".to_string(),
-                        },
-                        address_v2: SignablePayloadFieldAddressV2 {
-                            address: format!("{:?}", call.account),
-                            name: "".to_string(),
-                            memo: None,
-                            asset_label: "".to_string(),
-                            badge_text: None,
-                        },
-                    },
-                    static_annotation: None,
-                    dynamic_annotation: None,
-                });
-
-                let preview = SignablePayloadField::PreviewLayout {
-                    common: SignablePayloadFieldCommon {
-                        fallback_text: format!("Query balance of {:?}", call.account),
-                        label: "ERC20 BalanceOf".to_string(),
-                    },
-                    preview_layout: SignablePayloadFieldPreviewLayout {
-                        title: Some(SignablePayloadFieldTextV2 {
-                            text: "ERC20 BalanceOf".to_string(),
-                        }),
-                        subtitle: Some(SignablePayloadFieldTextV2 {
-                            text: format!("Query balance of {:?}", call.account),
-                        }),
-                        condensed: None,
-                        expanded: Some(SignablePayloadFieldListLayout { fields: details }),
-                    },
-                };
-                return Some(preview);
+                let details = vec![address_field("Account", &addr(call.account), None, None)];
+                return Some(preview_layout(
+                    "ERC20 BalanceOf",
+                    "ERC20 BalanceOf",
+                    &format!("Query balance of {}", addr(call.account)),
+                    None,
+                    details,
+                ));
             }
         } else if selector == IERC20::allowanceCall::SELECTOR {
             // allowance(address,address)
             if let Ok(call) = IERC20::allowanceCall::abi_decode(input) {
-                let mut details = Vec::new();
-                // Owner Address
-                details.push(AnnotatedPayloadField {
-                    signable_payload_field: SignablePayloadField::AddressV2 {
-                        common: SignablePayloadFieldCommon {
-                            fallback_text: format!("{:?}", call.owner),
-                            label: "Owner".to_string(),
-                        },
-                        address_v2: SignablePayloadFieldAddressV2 {
-                            address: format!("{:?}", call.owner),
-                            name: "".to_string(),
-                            memo: None,
-                            asset_label: "".to_string(),
-                            badge_text: None,
-                        },
-                    },
-                    static_annotation: None,
-                    dynamic_annotation: None,
-                });
-                // Spender Address
-                details.push(AnnotatedPayloadField {
-                    signable_payload_field: SignablePayloadField::AddressV2 {
-                        common: SignablePayloadFieldCommon {
-                            fallback_text: format!("{:?}", call.spender),
-                            label: "Spender".to_string(),
-                        },
-                        address_v2: SignablePayloadFieldAddressV2 {
-                            address: format!("{:?}", call.spender),
-                            name: "".to_string(),
-                            memo: None,
-                            asset_label: "".to_string(),
-                            badge_text: None,
-                        },
-                    },
-                    static_annotation: None,
-                    dynamic_annotation: None,
-                });
-
-                let preview = SignablePayloadField::PreviewLayout {
-                    common: SignablePayloadFieldCommon {
-                        fallback_text: format!(
-                            "Query allowance for {:?} by {:?}",
-                            call.spender, call.owner
-                        ),
-                        label: "ERC20 Allowance".to_string(),
-                    },
-                    preview_layout: SignablePayloadFieldPreviewLayout {
-                        title: Some(SignablePayloadFieldTextV2 {
-                            text: "ERC20 Allowance".to_string(),
-                        }),
-                        subtitle: Some(SignablePayloadFieldTextV2 {
-                            text: format!(
-                                "Query allowance for {:?} by {:?}",
-                                call.spender, call.owner
-                            ),
-                        }),
-                        condensed: None,
-                        expanded: Some(SignablePayloadFieldListLayout { fields: details }),
-                    },
-                };
-                return Some(preview);
+                let details = vec![
+                    address_field("Owner", &addr(call.owner), None, None),
+                    address_field("Spender", &addr(call.spender), None, None),
+                ];
+                return Some(preview_layout(
+                    "ERC20 Allowance",
+                    "ERC20 Allowance",
+                    &format!(
+                        "Query allowance for {} by {}",
+                        addr(call.spender),
+                        addr(call.owner)
+                    ),
+                    None,
+                    details,
+                ));
             }
         } else if selector == IERC20::nameCall::SELECTOR {
             // name()
-            if let Ok(_call) = IERC20::nameCall::abi_decode(input) {
-                let preview = SignablePayloadField::PreviewLayout {
-                    common: SignablePayloadFieldCommon {
-                        fallback_text: "Query token name".to_string(),
-                        label: "ERC20 Name".to_string(),
-                    },
-                    preview_layout: SignablePayloadFieldPreviewLayout {
-                        title: Some(SignablePayloadFieldTextV2 {
-                            text: "ERC20 Name".to_string(),
-                        }),
-                        subtitle: Some(SignablePayloadFieldTextV2 {
-                            text: "Query token name".to_string(),
-                        }),
-                        condensed: None,
-                        expanded: None,
-                    },
-                };
-                return Some(preview);
+            if IERC20::nameCall::abi_decode(input).is_ok() {
+                return Some(query_layout("ERC20 Name", "Query token name"));
             }
         } else if selector == IERC20::symbolCall::SELECTOR {
             // symbol()
-            if let Ok(_call) = IERC20::symbolCall::abi_decode(input) {
-                let preview = SignablePayloadField::PreviewLayout {
-                    common: SignablePayloadFieldCommon {
-                        fallback_text: "Query token symbol".to_string(),
-                        label: "ERC20 Symbol".to_string(),
-                    },
-                    preview_layout: SignablePayloadFieldPreviewLayout {
-                        title: Some(SignablePayloadFieldTextV2 {
-                            text: "ERC20 Symbol".to_string(),
-                        }),
-                        subtitle: Some(SignablePayloadFieldTextV2 {
-                            text: "Query token symbol".to_string(),
-                        }),
-                        condensed: None,
-                        expanded: None,
-                    },
-                };
-                return Some(preview);
+            if IERC20::symbolCall::abi_decode(input).is_ok() {
+                return Some(query_layout("ERC20 Symbol", "Query token symbol"));
             }
         } else if selector == IERC20::decimalsCall::SELECTOR {
             // decimals()
-            if let Ok(_call) = IERC20::decimalsCall::abi_decode(input) {
-                let preview = SignablePayloadField::PreviewLayout {
-                    common: SignablePayloadFieldCommon {
-                        fallback_text: "Query token decimals".to_string(),
-                        label: "ERC20 Decimals".to_string(),
-                    },
-                    preview_layout: SignablePayloadFieldPreviewLayout {
-                        title: Some(SignablePayloadFieldTextV2 {
-                            text: "ERC20 Decimals".to_string(),
-                        }),
-                        subtitle: Some(SignablePayloadFieldTextV2 {
-                            text: "Query token decimals".to_string(),
-                        }),
-                        condensed: None,
-                        expanded: None,
-                    },
-                };
-                return Some(preview);
+            if IERC20::decimalsCall::abi_decode(input).is_ok() {
+                return Some(query_layout("ERC20 Decimals", "Query token decimals"));
             }
         } else if selector == IERC20::totalSupplyCall::SELECTOR {
             // totalSupply()
-            if let Ok(_call) = IERC20::totalSupplyCall::abi_decode(input) {
-                return Some(SignablePayloadField::PreviewLayout {
-                    common: SignablePayloadFieldCommon {
-                        fallback_text: "Query total token supply".to_string(),
-                        label: "ERC20 TotalSupply".to_string(),
-                    },
-                    preview_layout: SignablePayloadFieldPreviewLayout {
-                        title: Some(SignablePayloadFieldTextV2 {
-                            text: "ERC20 TotalSupply".to_string(),
-                        }),
-                        subtitle: Some(SignablePayloadFieldTextV2 {
-                            text: "Query total token supply".to_string(),
-                        }),
-                        condensed: None,
-                        expanded: None,
-                    },
-                });
+            if IERC20::totalSupplyCall::abi_decode(input).is_ok() {
+                return Some(query_layout(
+                    "ERC20 TotalSupply",
+                    "Query total token supply",
+                ));
             }
         }
         None
@@ -431,6 +228,33 @@ impl ERC20Visualizer {
 mod tests {
     use super::*;
     use alloy_primitives::{U256, hex};
+    use visualsign::{
+        SignablePayloadFieldAddressV2, SignablePayloadFieldAmountV2, SignablePayloadFieldCommon,
+        SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout,
+        SignablePayloadFieldTextV2,
+    };
+
+    const TOKEN: Address = Address::repeat_byte(0x99);
+
+    fn unknown_token_field() -> AnnotatedPayloadField {
+        AnnotatedPayloadField {
+            signable_payload_field: SignablePayloadField::AddressV2 {
+                common: SignablePayloadFieldCommon {
+                    fallback_text: format!("{TOKEN:?}"),
+                    label: "Token".to_string(),
+                },
+                address_v2: SignablePayloadFieldAddressV2 {
+                    address: format!("{TOKEN:?}"),
+                    name: "".to_string(),
+                    memo: None,
+                    asset_label: "".to_string(),
+                    badge_text: Some("Unknown token".to_string()),
+                },
+            },
+            static_annotation: None,
+            dynamic_annotation: None,
+        }
+    }
 
     #[test]
     fn test_decode_transfer() {
@@ -441,7 +265,7 @@ mod tests {
         let input = IERC20::transferCall::abi_encode(&call);
 
         let expected = {
-            let mut details = Vec::new();
+            let mut details = vec![unknown_token_field()];
             details.push(AnnotatedPayloadField {
                 signable_payload_field: SignablePayloadField::AddressV2 {
                     common: SignablePayloadFieldCommon {
@@ -475,7 +299,10 @@ mod tests {
             });
             SignablePayloadField::PreviewLayout {
                 common: SignablePayloadFieldCommon {
-                    fallback_text: format!("Transfer {} tokens to {:?}", call.amount, call.to),
+                    fallback_text: format!(
+                        "Transfer {} of {TOKEN:?} to {:?}",
+                        call.amount, call.to
+                    ),
                     label: "ERC20 Transfer".to_string(),
                 },
                 preview_layout: SignablePayloadFieldPreviewLayout {
@@ -483,7 +310,7 @@ mod tests {
                         text: "ERC20 Transfer".to_string(),
                     }),
                     subtitle: Some(SignablePayloadFieldTextV2 {
-                        text: format!("Transfer {} tokens to {:?}", call.amount, call.to),
+                        text: format!("Transfer {} of {TOKEN:?} to {:?}", call.amount, call.to),
                     }),
                     condensed: None,
                     expanded: Some(SignablePayloadFieldListLayout { fields: details }),
@@ -492,7 +319,7 @@ mod tests {
         };
 
         let actual = ERC20Visualizer {}
-            .visualize_tx_commands(&input)
+            .visualize_tx_commands(&input, TOKEN, None)
             .expect("Expected PreviewLayout");
         assert_eq!(&actual, &expected);
     }
@@ -506,7 +333,7 @@ mod tests {
         };
         let input = IERC20::transferFromCall::abi_encode(&call);
 
-        let mut details = Vec::new();
+        let mut details = vec![unknown_token_field()];
         details.push(AnnotatedPayloadField {
             signable_payload_field: SignablePayloadField::AddressV2 {
                 common: SignablePayloadFieldCommon {
@@ -559,7 +386,7 @@ mod tests {
         let expected = SignablePayloadField::PreviewLayout {
             common: SignablePayloadFieldCommon {
                 fallback_text: format!(
-                    "Transfer {} tokens from {:?} to {:?}",
+                    "Transfer {} of {TOKEN:?} from {:?} to {:?}",
                     call.amount, call.from, call.to
                 ),
                 label: "ERC20 TransferFrom".to_string(),
@@ -570,7 +397,7 @@ mod tests {
                 }),
                 subtitle: Some(SignablePayloadFieldTextV2 {
                     text: format!(
-                        "Transfer {} tokens from {:?} to {:?}",
+                        "Transfer {} of {TOKEN:?} from {:?} to {:?}",
                         call.amount, call.from, call.to
                     ),
                 }),
@@ -580,7 +407,7 @@ mod tests {
         };
 
         let actual = ERC20Visualizer {}
-            .visualize_tx_commands(&input)
+            .visualize_tx_commands(&input, TOKEN, None)
             .expect("Expected PreviewLayout");
         assert_eq!(&actual, &expected);
     }
@@ -593,7 +420,7 @@ mod tests {
         };
         let input = IERC20::approveCall::abi_encode(&call);
 
-        let mut details = Vec::new();
+        let mut details = vec![unknown_token_field()];
         details.push(AnnotatedPayloadField {
             signable_payload_field: SignablePayloadField::AddressV2 {
                 common: SignablePayloadFieldCommon {
@@ -629,7 +456,7 @@ mod tests {
         let expected = SignablePayloadField::PreviewLayout {
             common: SignablePayloadFieldCommon {
                 fallback_text: format!(
-                    "Approve {:?} to spend {} tokens",
+                    "Approve {:?} to spend {} of {TOKEN:?}",
                     call.spender, call.amount
                 ),
                 label: "ERC20 Approve".to_string(),
@@ -639,7 +466,10 @@ mod tests {
                     text: "ERC20 Approve".to_string(),
                 }),
                 subtitle: Some(SignablePayloadFieldTextV2 {
-                    text: format!("Approve {:?} to spend {} tokens", call.spender, call.amount),
+                    text: format!(
+                        "Approve {:?} to spend {} of {TOKEN:?}",
+                        call.spender, call.amount
+                    ),
                 }),
                 condensed: None,
                 expanded: Some(SignablePayloadFieldListLayout { fields: details }),
@@ -647,11 +477,54 @@ mod tests {
         };
 
         let actual = ERC20Visualizer {}
-            .visualize_tx_commands(&input)
+            .visualize_tx_commands(&input, TOKEN, None)
             .expect("Expected PreviewLayout");
         assert_eq!(&actual, &expected);
     }
 
+    #[test]
+    fn test_decode_transfer_of_known_token() {
+        let usdc = TokenInfo {
+            symbol: "USDC".to_string(),
+            name: "USD Coin".to_string(),
+            decimals: 6,
+        };
+        let call = IERC20::transferCall {
+            to: [0x11u8; 20].into(),
+            amount: U256::from(1_000_000u64),
+        };
+        let input = IERC20::transferCall::abi_encode(&call);
+
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } = ERC20Visualizer {}
+            .visualize_tx_commands(&input, TOKEN, Some(&usdc))
+            .expect("Expected PreviewLayout")
+        else {
+            panic!("Expected PreviewLayout");
+        };
+        assert_eq!(
+            preview_layout.subtitle.unwrap().text,
+            format!("Transfer 1 USDC to {:?}", call.to)
+        );
+        let fields = preview_layout.expanded.unwrap().fields;
+        assert_eq!(
+            fields[0],
+            address_field("Token", &format!("{TOKEN:?}"), Some("USD Coin"), None)
+        );
+        assert_eq!(
+            fields[2].signable_payload_field,
+            SignablePayloadField::AmountV2 {
+                common: SignablePayloadFieldCommon {
+                    fallback_text: "1 USDC".to_string(),
+                    label: "Amount".to_string(),
+                },
+                amount_v2: SignablePayloadFieldAmountV2 {
+                    amount: "1".to_string(),
+                    abbreviation: Some("USDC".to_string()),
+                },
+            }
+        );
+    }
+
     #[test]
     fn test_decode_balance_of() {
         let call = IERC20::balanceOfCall {
@@ -696,7 +569,7 @@ mod tests {
         };
 
         let actual = ERC20Visualizer {}
-            .visualize_tx_commands(&input)
+            .visualize_tx_commands(&input, TOKEN, None)
             .expect("Expected PreviewLayout");
         assert_eq!(&actual, &expected);
     }
@@ -766,7 +639,7 @@ mod tests {
         };
 
         let actual = ERC20Visualizer {}
-            .visualize_tx_commands(&input)
+            .visualize_tx_commands(&input, TOKEN, None)
             .expect("Expected PreviewLayout");
         assert_eq!(&actual, &expected);
     }
@@ -791,7 +664,7 @@ mod tests {
             },
         };
         let actual = ERC20Visualizer {}
-            .visualize_tx_commands(&input)
+            .visualize_tx_commands(&input, TOKEN, None)
             .expect("Expected PreviewLayout");
         assert_eq!(&actual, &expected);
     }
@@ -816,7 +689,7 @@ mod tests {
             },
         };
         let actual = ERC20Visualizer {}
-            .visualize_tx_commands(&input)
+            .visualize_tx_commands(&input, TOKEN, None)
             .expect("Expected PreviewLayout");
         assert_eq!(&actual, &expected);
     }
@@ -826,7 +699,7 @@ mod tests {
         let input = IERC20::decimalsCall::abi_encode(&IERC20::decimalsCall {});
         assert_eq!(
             ERC20Visualizer {}
-                .visualize_tx_commands(&input)
+                .visualize_tx_commands(&input, TOKEN, None)
                 .expect("Expected PreviewLayout"),
             SignablePayloadField::PreviewLayout {
                 common: SignablePayloadFieldCommon {
@@ -852,7 +725,7 @@ mod tests {
         let input = IERC20::totalSupplyCall::abi_encode(&IERC20::totalSupplyCall {});
         assert_eq!(
             ERC20Visualizer {}
-                .visualize_tx_commands(&input)
+                .visualize_tx_commands(&input, TOKEN, None)
                 .expect("Expected PreviewLayout"),
             SignablePayloadField::PreviewLayout {
                 common: SignablePayloadFieldCommon {
@@ -876,14 +749,14 @@ mod tests {
     #[test]
     fn test_decode_invalid_selector() {
         let input = hex!("deadbeef01020304");
-        let actual = ERC20Visualizer {}.visualize_tx_commands(&input);
+        let actual = ERC20Visualizer {}.visualize_tx_commands(&input, TOKEN, None);
         assert!(actual.is_none());
     }
 
     #[test]
     fn test_decode_too_short_input() {
         let input = &[0x01, 0x02, 0x03];
-        let actual = ERC20Visualizer {}.visualize_tx_commands(input);
+        let actual = ERC20Visualizer {}.visualize_tx_commands(input, TOKEN, None);
         assert!(actual.is_none());
     }
 }
@@ -0,0 +1,790 @@
+//! Lending markets: the Aave v3 Pool and Compound v3's Comet.
+//!
+//! Both let a call credit or charge an account other than the caller's, so
+//! besides decoding each call, [`third_party_warning`] flags a supply,
+//! borrow, repayment or withdrawal made for someone other than the signer.
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{SolCall, sol};
+use visualsign::{AnnotatedPayloadField, SignablePayloadField};
+
+use crate::fields::{
+    addr, address_field, amount_field, plain_text_field, preview_layout, text_field,
+    with_annotation,
+};
+use crate::fmt::format_token_amount;
+use crate::registry::{ContractKind, ContractRegistry};
+use crate::tokens::{Token, TokenRegistry, format_amount, token_name};
+
+sol! {
+    // From: https://github.com/aave-dao/aave-v3-origin/blob/main/src/contracts/interfaces/IPool.sol
+    interface IAaveV3Pool {
+        function supply(address asset, uint256 amount, address onBehalfOf, uint16 referralCode) external;
+        // The v2 name, kept as an alias of `supply`
+        function deposit(address asset, uint256 amount, address onBehalfOf, uint16 referralCode) external;
+        function borrow(address asset, uint256 amount, uint256 interestRateMode, uint16 referralCode, address onBehalfOf) external;
+        function repay(address asset, uint256 amount, uint256 interestRateMode, address onBehalfOf) external returns (uint256);
+        function repayWithATokens(address asset, uint256 amount, uint256 interestRateMode) external returns (uint256);
+        function withdraw(address asset, uint256 amount, address to) external returns (uint256);
+        function setUserUseReserveAsCollateral(address asset, bool useAsCollateral) external;
+        function liquidationCall(address collateralAsset, address debtAsset, address user, uint256 debtToCover, bool receiveAToken) external;
+        function flashLoan(address receiverAddress, address[] calldata assets, uint256[] calldata amounts, uint256[] calldata interestRateModes, address onBehalfOf, bytes calldata params, uint16 referralCode) external;
+        function flashLoanSimple(address receiverAddress, address asset, uint256 amount, bytes calldata params, uint16 referralCode) external;
+    }
+
+    // From: https://github.com/compound-finance/comet/blob/main/contracts/CometMainInterface.sol
+    interface IComet {
+        function supply(address asset, uint256 amount) external;
+        function supplyTo(address dst, address asset, uint256 amount) external;
+        function supplyFrom(address from, address dst, address asset, uint256 amount) external;
+        function withdraw(address asset, uint256 amount) external;
+        function withdrawTo(address to, address asset, uint256 amount) external;
+        function withdrawFrom(address src, address to, address asset, uint256 amount) external;
+        function absorb(address absorber, address[] calldata accounts) external;
+        function allow(address manager, bool isAllowed) external;
+    }
+}
+
+/// Aave's interest rate modes. Stable-rate borrowing is disabled on current
+/// markets, but the mode is still accepted by repayments.
+fn interest_rate_mode(mode: U256) -> String {
+    match u64::try_from(mode) {
+        Ok(0) => "None".to_string(),
+        Ok(1) => "Stable".to_string(),
+        Ok(2) => "Variable".to_string(),
+        _ => mode.to_string(),
+    }
+}
+
+struct Asset<'a> {
+    address: Address,
+    token: Option<Token<'a>>,
+}
+
+impl<'a> Asset<'a> {
+    fn new(tokens: &'a TokenRegistry, chain_id: Option<u64>, address: Address) -> Self {
+        Asset {
+            address,
+            token: tokens.token(chain_id, address),
+        }
+    }
+
+    fn name(&self) -> String {
+        token_name(self.token, self.address)
+    }
+
+    fn amount_text(&self, amount: U256) -> String {
+        format_amount(self.token, self.address, amount)
+    }
+
+    fn field(&self, label: &str) -> AnnotatedPayloadField {
+        address_field(
+            label,
+            &addr(self.address),
+            self.token.map(|t| t.symbol),
+            None,
+        )
+    }
+
+    fn amount_field(&self, label: &str, amount: U256) -> AnnotatedPayloadField {
+        match self.token {
+            Some(token) => amount_field(
+                label,
+                &format_token_amount(amount, token.decimals),
+                Some(token.symbol),
+            ),
+            None => with_annotation(
+                amount_field(label, &amount.to_string(), None),
+                &format!("Raw amount of {:?}", self.address),
+            ),
+        }
+    }
+
+    /// Both protocols read `U256::MAX` as "everything": the whole balance on
+    /// withdrawals and the whole debt on repayments.
+    fn amount_or_all_field(&self, label: &str, amount: U256, all: &str) -> AnnotatedPayloadField {
+        if amount == U256::MAX {
+            text_field(label, all)
+        } else {
+            self.amount_field(label, amount)
+        }
+    }
+}
+
+fn account_field(label: &str, account: Address) -> AnnotatedPayloadField {
+    address_field(label, &addr(account), None, None)
+}
+
+fn layout(title: &str, subtitle: &str, fields: Vec<AnnotatedPayloadField>) -> SignablePayloadField {
+    preview_layout(title, title, subtitle, None, fields)
+}
+
+pub struct AaveV3Visualizer {}
+
+impl AaveV3Visualizer {
+    /// Decodes `input`, a call to an Aave v3 Pool on `chain_id`.
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: Option<u64>,
+        tokens: &TokenRegistry,
+    ) -> Option<SignablePayloadField> {
+        let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+        let asset = |address| Asset::new(tokens, chain_id, address);
+        match selector {
+            IAaveV3Pool::supplyCall::SELECTOR | IAaveV3Pool::depositCall::SELECTOR => {
+                let call = IAaveV3Pool::supplyCall::abi_decode_raw(&input[4..]).ok()?;
+                let supplied = asset(call.asset);
+                let mut fields = vec![
+                    supplied.field("Asset"),
+                    supplied.amount_field("Amount", call.amount),
+                    account_field("On Behalf Of", call.onBehalfOf),
+                ];
+                if call.referralCode != 0 {
+                    fields.push(text_field("Referral Code", &call.referralCode.to_string()));
+                }
+                Some(layout(
+                    "Aave Supply",
+                    &format!("Supply {}", supplied.amount_text(call.amount)),
+                    fields,
+                ))
+            }
+            IAaveV3Pool::borrowCall::SELECTOR => {
+                let call = IAaveV3Pool::borrowCall::abi_decode(input).ok()?;
+                let borrowed = asset(call.asset);
+                let mode = interest_rate_mode(call.interestRateMode);
+                Some(layout(
+                    "Aave Borrow",
+                    &format!(
+                        "Borrow {} at a {} rate",
+                        borrowed.amount_text(call.amount),
+                        mode.to_lowercase()
+                    ),
+                    vec![
+                        borrowed.field("Asset"),
+                        borrowed.amount_field("Amount", call.amount),
+                        text_field("Interest Rate Mode", &mode),
+                        account_field("On Behalf Of", call.onBehalfOf),
+                    ],
+                ))
+            }
+            IAaveV3Pool::repayCall::SELECTOR => {
+                let call = IAaveV3Pool::repayCall::abi_decode(input).ok()?;
+                let repaid = asset(call.asset);
+                let mode = interest_rate_mode(call.interestRateMode);
+                Some(layout(
+                    "Aave Repay",
+                    &repay_subtitle(&repaid, call.amount, &mode),
+                    vec![
+                        repaid.field("Asset"),
+                        repaid.amount_or_all_field("Amount", call.amount, "Entire debt"),
+                        text_field("Interest Rate Mode", &mode),
+                        account_field("On Behalf Of", call.onBehalfOf),
+                    ],
+                ))
+            }
+            IAaveV3Pool::repayWithATokensCall::SELECTOR => {
+                let call = IAaveV3Pool::repayWithATokensCall::abi_decode(input).ok()?;
+                let repaid = asset(call.asset);
+                let mode = interest_rate_mode(call.interestRateMode);
+                Some(layout(
+                    "Aave Repay With aTokens",
+                    &format!(
+                        "{} using supplied {}",
+                        repay_subtitle(&repaid, call.amount, &mode),
+                        repaid.name()
+                    ),
+                    vec![
+                        repaid.field("Asset"),
+                        repaid.amount_or_all_field("Amount", call.amount, "Entire debt"),
+                        text_field("Interest Rate Mode", &mode),
+                    ],
+                ))
+            }
+            IAaveV3Pool::withdrawCall::SELECTOR => {
+                let call = IAaveV3Pool::withdrawCall::abi_decode(input).ok()?;
+                let withdrawn = asset(call.asset);
+                Some(layout(
+                    "Aave Withdraw",
+                    &withdraw_subtitle(&withdrawn, call.amount),
+                    vec![
+                        withdrawn.field("Asset"),
+                        withdrawn.amount_or_all_field("Amount", call.amount, "Entire balance"),
+                        account_field("Recipient", call.to),
+                    ],
+                ))
+            }
+            IAaveV3Pool::setUserUseReserveAsCollateralCall::SELECTOR => {
+                let call =
+                    IAaveV3Pool::setUserUseReserveAsCollateralCall::abi_decode(input).ok()?;
+                let collateral = asset(call.asset);
+                let action = if call.useAsCollateral {
+                    "Enable"
+                } else {
+                    "Disable"
+                };
+                Some(layout(
+                    "Aave Collateral",
+                    &format!("{action} {} as collateral", collateral.name()),
+                    vec![
+                        collateral.field("Asset"),
+                        text_field(
+                            "Use As Collateral",
+                            if call.useAsCollateral { "Yes" } else { "No" },
+                        ),
+                    ],
+                ))
+            }
+            IAaveV3Pool::liquidationCallCall::SELECTOR => {
+                let call = IAaveV3Pool::liquidationCallCall::abi_decode(input).ok()?;
+                let debt = asset(call.debtAsset);
+                let collateral = asset(call.collateralAsset);
+                let debt_text = if call.debtToCover == U256::MAX {
+                    format!("as much {} debt as allowed", debt.name())
+                } else {
+                    debt.amount_text(call.debtToCover)
+                };
+                Some(layout(
+                    "Aave Liquidation",
+                    &format!(
+                        "Repay {debt_text} of {:?} for their {}",
+                        call.user,
+                        collateral.name()
+                    ),
+                    vec![
+                        account_field("Borrower", call.user),
+                        debt.field("Debt Asset"),
+                        debt.amount_or_all_field(
+                            "Debt To Cover",
+                            call.debtToCover,
+                            "Maximum allowed",
+                        ),
+                        collateral.field("Collateral Asset"),
+                        text_field(
+                            "Receive aTokens",
+                            if call.receiveAToken { "Yes" } else { "No" },
+                        ),
+                    ],
+                ))
+            }
+            IAaveV3Pool::flashLoanCall::SELECTOR => {
+                let call = IAaveV3Pool::flashLoanCall::abi_decode(input).ok()?;
+                if call.assets.len() != call.amounts.len()
+                    || call.assets.len() != call.interestRateModes.len()
+                {
+                    return None;
+                }
+                let mut fields = vec![account_field("Receiver", call.receiverAddress)];
+                let mut loans = Vec::new();
+                for (i, ((address, amount), mode)) in call
+                    .assets
+                    .iter()
+                    .zip(&call.amounts)
+                    .zip(&call.interestRateModes)
+                    .enumerate()
+                {
+                    let loaned = asset(*address);
+                    loans.push(loaned.amount_text(*amount));
+                    fields.push(loaned.amount_field(&format!("Loan {}", i + 1), *amount));
+                    // Any mode but 0 leaves the loan open as debt instead of repaying it
+                    if !mode.is_zero() {
+                        fields.push(text_field(
+                            &format!("Loan {} Debt Mode", i + 1),
+                            &interest_rate_mode(*mode),
+                        ));
+                    }
+                }
+                if call.interestRateModes.iter().any(|mode| !mode.is_zero()) {
+                    fields.push(account_field("On Behalf Of", call.onBehalfOf));
+                }
+                Some(layout(
+                    "Aave Flash Loan",
+                    &format!(
+                        "Flash loan {} to {:?}",
+                        loans.join(", "),
+                        call.receiverAddress
+                    ),
+                    fields,
+                ))
+            }
+            IAaveV3Pool::flashLoanSimpleCall::SELECTOR => {
+                let call = IAaveV3Pool::flashLoanSimpleCall::abi_decode(input).ok()?;
+                let loaned = asset(call.asset);
+                Some(layout(
+                    "Aave Flash Loan",
+                    &format!(
+                        "Flash loan {} to {:?}",
+                        loaned.amount_text(call.amount),
+                        call.receiverAddress
+                    ),
+                    vec![
+                        account_field("Receiver", call.receiverAddress),
+                        loaned.field("Asset"),
+                        loaned.amount_field("Amount", call.amount),
+                    ],
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn repay_subtitle(asset: &Asset<'_>, amount: U256, mode: &str) -> String {
+    let mode = mode.to_lowercase();
+    if amount == U256::MAX {
+        format!("Repay all {mode}-rate {} debt", asset.name())
+    } else {
+        format!("Repay {} of {mode}-rate debt", asset.amount_text(amount))
+    }
+}
+
+fn withdraw_subtitle(asset: &Asset<'_>, amount: U256) -> String {
+    if amount == U256::MAX {
+        format!("Withdraw all supplied {}", asset.name())
+    } else {
+        format!("Withdraw {}", asset.amount_text(amount))
+    }
+}
+
+pub struct CompoundV3Visualizer {}
+
+impl CompoundV3Visualizer {
+    /// Decodes `input`, a call to a Compound v3 Comet market on `chain_id`.
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: Option<u64>,
+        tokens: &TokenRegistry,
+    ) -> Option<SignablePayloadField> {
+        let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+        let asset = |address| Asset::new(tokens, chain_id, address);
+        // Supplying `U256::MAX` of the base asset repays the whole borrow
+        let supply = |address, amount, mut fields: Vec<AnnotatedPayloadField>| {
+            let supplied: Asset<'_> = asset(address);
+            let subtitle = if amount == U256::MAX {
+                format!("Repay the entire {} borrow", supplied.name())
+            } else {
+                format!("Supply {}", supplied.amount_text(amount))
+            };
+            fields.splice(
+                0..0,
+                [
+                    supplied.field("Asset"),
+                    supplied.amount_or_all_field("Amount", amount, "Entire borrow balance"),
+                ],
+            );
+            layout("Compound Supply", &subtitle, fields)
+        };
+        let withdraw = |address, amount, mut fields: Vec<AnnotatedPayloadField>| {
+            let withdrawn: Asset<'_> = asset(address);
+            fields.splice(
+                0..0,
+                [
+                    withdrawn.field("Asset"),
+                    withdrawn.amount_or_all_field("Amount", amount, "Entire balance"),
+                ],
+            );
+            layout(
+                "Compound Withdraw",
+                &withdraw_subtitle(&withdrawn, amount),
+                fields,
+            )
+        };
+        match selector {
+            IComet::supplyCall::SELECTOR => {
+                let call = IComet::supplyCall::abi_decode(input).ok()?;
+                Some(supply(call.asset, call.amount, Vec::new()))
+            }
+            IComet::supplyToCall::SELECTOR => {
+                let call = IComet::supplyToCall::abi_decode(input).ok()?;
+                Some(supply(
+                    call.asset,
+                    call.amount,
+                    vec![account_field("On Behalf Of", call.dst)],
+                ))
+            }
+            IComet::supplyFromCall::SELECTOR => {
+                let call = IComet::supplyFromCall::abi_decode(input).ok()?;
+                Some(supply(
+                    call.asset,
+                    call.amount,
+                    vec![
+                        account_field("From", call.from),
+                        account_field("On Behalf Of", call.dst),
+                    ],
+                ))
+            }
+            IComet::withdrawCall::SELECTOR => {
+                let call = IComet::withdrawCall::abi_decode(input).ok()?;
+                Some(withdraw(call.asset, call.amount, Vec::new()))
+            }
+            IComet::withdrawToCall::SELECTOR => {
+                let call = IComet::withdrawToCall::abi_decode(input).ok()?;
+                Some(withdraw(
+                    call.asset,
+                    call.amount,
+                    vec![account_field("Recipient", call.to)],
+                ))
+            }
+            IComet::withdrawFromCall::SELECTOR => {
+                let call = IComet::withdrawFromCall::abi_decode(input).ok()?;
+                Some(withdraw(
+                    call.asset,
+                    call.amount,
+                    vec![
+                        account_field("From", call.src),
+                        account_field("Recipient", call.to),
+                    ],
+                ))
+            }
+            IComet::absorbCall::SELECTOR => {
+                let call = IComet::absorbCall::abi_decode(input).ok()?;
+                let accounts = if call.accounts.len() == 1 {
+                    "account"
+                } else {
+                    "accounts"
+                };
+                let mut fields = vec![account_field("Absorber", call.absorber)];
+                fields.extend(
+                    call.accounts
+                        .iter()
+                        .enumerate()
+                        .map(|(i, account)| account_field(&format!("Account {}", i + 1), *account)),
+                );
+                Some(layout(
+                    "Compound Absorb",
+                    &format!("Liquidate {} underwater {accounts}", call.accounts.len()),
+                    fields,
+                ))
+            }
+            IComet::allowCall::SELECTOR => {
+                let call = IComet::allowCall::abi_decode(input).ok()?;
+                let subtitle = if call.isAllowed {
+                    format!(
+                        "Let {:?} supply, transfer and withdraw your assets",
+                        call.manager
+                    )
+                } else {
+                    format!("Revoke {:?} as a manager", call.manager)
+                };
+                Some(layout(
+                    "Compound Allow",
+                    &subtitle,
+                    vec![
+                        account_field("Manager", call.manager),
+                        text_field("Allowed", if call.isAllowed { "Yes" } else { "No" }),
+                    ],
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Who a lending call credits or charges, when the call names them.
+enum Beneficiary {
+    /// The account whose position receives the supplied asset.
+    Supply(Address, Address),
+    /// The account whose credit backs the borrow and who owes the debt.
+    Borrow(Address, Address),
+    /// The account whose debt the signer's tokens repay.
+    Repay(Address, Address),
+    /// The account that receives the withdrawn asset.
+    Withdraw(Address, Address),
+}
+
+fn beneficiary(kind: ContractKind, input: &[u8]) -> Option<Beneficiary> {
+    let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+    match (kind, selector) {
+        (
+            ContractKind::AaveV3Pool,
+            IAaveV3Pool::supplyCall::SELECTOR | IAaveV3Pool::depositCall::SELECTOR,
+        ) => {
+            let call = IAaveV3Pool::supplyCall::abi_decode_raw(&input[4..]).ok()?;
+            Some(Beneficiary::Supply(call.onBehalfOf, call.asset))
+        }
+        (ContractKind::AaveV3Pool, IAaveV3Pool::borrowCall::SELECTOR) => {
+            let call = IAaveV3Pool::borrowCall::abi_decode(input).ok()?;
+            Some(Beneficiary::Borrow(call.onBehalfOf, call.asset))
+        }
+        (ContractKind::AaveV3Pool, IAaveV3Pool::repayCall::SELECTOR) => {
+            let call = IAaveV3Pool::repayCall::abi_decode(input).ok()?;
+            Some(Beneficiary::Repay(call.onBehalfOf, call.asset))
+        }
+        (ContractKind::AaveV3Pool, IAaveV3Pool::withdrawCall::SELECTOR) => {
+            let call = IAaveV3Pool::withdrawCall::abi_decode(input).ok()?;
+            Some(Beneficiary::Withdraw(call.to, call.asset))
+        }
+        (ContractKind::CompoundV3Comet, IComet::supplyToCall::SELECTOR) => {
+            let call = IComet::supplyToCall::abi_decode(input).ok()?;
+            Some(Beneficiary::Supply(call.dst, call.asset))
+        }
+        (ContractKind::CompoundV3Comet, IComet::supplyFromCall::SELECTOR) => {
+            let call = IComet::supplyFromCall::abi_decode(input).ok()?;
+            Some(Beneficiary::Supply(call.dst, call.asset))
+        }
+        (ContractKind::CompoundV3Comet, IComet::withdrawToCall::SELECTOR) => {
+            let call = IComet::withdrawToCall::abi_decode(input).ok()?;
+            Some(Beneficiary::Withdraw(call.to, call.asset))
+        }
+        (ContractKind::CompoundV3Comet, IComet::withdrawFromCall::SELECTOR) => {
+            let call = IComet::withdrawFromCall::abi_decode(input).ok()?;
+            Some(Beneficiary::Withdraw(call.to, call.asset))
+        }
+        _ => None,
+    }
+}
+
+/// Ends a warning about an account when the signer isn't known to compare it to.
+const CONFIRM: &str = "; confirm this is your address.";
+
+/// A warning when `input`, sent by `sender` to a lending market at `to`,
+/// supplies, borrows, repays or withdraws for an account other than `sender`.
+/// Without a known sender, any account the call names is flagged for the
+/// signer to check.
+pub(crate) fn third_party_warning(
+    input: &[u8],
+    to: Address,
+    sender: Option<Address>,
+    chain_id: Option<u64>,
+    contracts: &ContractRegistry,
+    tokens: &TokenRegistry,
+) -> Option<SignablePayloadField> {
+    let info = contracts.lookup(chain_id, to)?;
+    let text = |account: Address, asset: Address| {
+        (sender != Some(account)).then(|| token_name(tokens.token(chain_id, asset), asset))
+    };
+    let (not_own, not_you, end) = match sender {
+        Some(_) => (", not your own.", ", not to you.", "."),
+        None => (CONFIRM, CONFIRM, CONFIRM),
+    };
+    let warning = match beneficiary(info.kind, input)? {
+        Beneficiary::Supply(account, asset) => {
+            let token = text(account, asset)?;
+            format!(
+                "This supplies your {token} to the {} position of {account:?}{not_own} Only that account can withdraw it.",
+                info.name
+            )
+        }
+        Beneficiary::Borrow(account, asset) => {
+            let token = text(account, asset)?;
+            format!(
+                "This borrows {token} against the collateral of {account:?}{not_own} The debt is charged to that account."
+            )
+        }
+        Beneficiary::Repay(account, asset) => {
+            let token = text(account, asset)?;
+            format!("This repays the {token} debt of {account:?} with your tokens{end}")
+        }
+        Beneficiary::Withdraw(account, asset) => {
+            let token = text(account, asset)?;
+            format!("The withdrawn {token} is sent to {account:?}{not_you}")
+        }
+    };
+    Some(plain_text_field("Warning", &warning))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    const USDC: Address = address!("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const WETH: Address = address!("0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    const AAVE_POOL: Address = address!("0x87870bca3f3fd6335c3f4ce8392d69350b4fa4e2");
+    const SIGNER: Address = Address::repeat_byte(0x11);
+    const STRANGER: Address = Address::repeat_byte(0x22);
+
+    fn aave(input: &[u8]) -> String {
+        AaveV3Visualizer {}
+            .visualize_tx_commands(input, Some(1), &TokenRegistry::default())
+            .unwrap()
+            .fallback_text()
+            .clone()
+    }
+
+    fn compound(input: &[u8]) -> String {
+        CompoundV3Visualizer {}
+            .visualize_tx_commands(input, Some(1), &TokenRegistry::default())
+            .unwrap()
+            .fallback_text()
+            .clone()
+    }
+
+    #[test]
+    fn test_aave_calls() {
+        let input = IAaveV3Pool::supplyCall {
+            asset: USDC,
+            amount: U256::from(250_000_000u64),
+            onBehalfOf: SIGNER,
+            referralCode: 0,
+        }
+        .abi_encode();
+        assert_eq!(aave(&input), "Supply 250 USDC");
+
+        let input = IAaveV3Pool::borrowCall {
+            asset: WETH,
+            amount: U256::from(1_500_000_000_000_000_000u128),
+            interestRateMode: U256::from(2u64),
+            referralCode: 0,
+            onBehalfOf: SIGNER,
+        }
+        .abi_encode();
+        assert_eq!(aave(&input), "Borrow 1.5 WETH at a variable rate");
+
+        let input = IAaveV3Pool::repayCall {
+            asset: WETH,
+            amount: U256::MAX,
+            interestRateMode: U256::from(2u64),
+            onBehalfOf: SIGNER,
+        }
+        .abi_encode();
+        assert_eq!(aave(&input), "Repay all variable-rate WETH debt");
+
+        let input = IAaveV3Pool::withdrawCall {
+            asset: USDC,
+            amount: U256::MAX,
+            to: SIGNER,
+        }
+        .abi_encode();
+        assert_eq!(aave(&input), "Withdraw all supplied USDC");
+
+        let input = IAaveV3Pool::setUserUseReserveAsCollateralCall {
+            asset: WETH,
+            useAsCollateral: false,
+        }
+        .abi_encode();
+        assert_eq!(aave(&input), "Disable WETH as collateral");
+
+        let input = IAaveV3Pool::liquidationCallCall {
+            collateralAsset: WETH,
+            debtAsset: USDC,
+            user: STRANGER,
+            debtToCover: U256::from(1_000_000_000u64),
+            receiveAToken: false,
+        }
+        .abi_encode();
+        assert_eq!(
+            aave(&input),
+            "Repay 1000 USDC of 0x2222222222222222222222222222222222222222 for their WETH"
+        );
+
+        let input = IAaveV3Pool::flashLoanCall {
+            receiverAddress: STRANGER,
+            assets: vec![USDC, WETH],
+            amounts: vec![
+                U256::from(5_000_000u64),
+                U256::from(10u64).pow(U256::from(18)),
+            ],
+            interestRateModes: vec![U256::ZERO, U256::from(2u64)],
+            onBehalfOf: SIGNER,
+            params: Default::default(),
+            referralCode: 0,
+        }
+        .abi_encode();
+        assert_eq!(
+            aave(&input),
+            "Flash loan 5 USDC, 1 WETH to 0x2222222222222222222222222222222222222222"
+        );
+    }
+
+    #[test]
+    fn test_compound_calls() {
+        let input = IComet::supplyCall {
+            asset: WETH,
+            amount: U256::from(2_000_000_000_000_000_000u128),
+        }
+        .abi_encode();
+        assert_eq!(compound(&input), "Supply 2 WETH");
+
+        let input = IComet::supplyCall {
+            asset: USDC,
+            amount: U256::MAX,
+        }
+        .abi_encode();
+        assert_eq!(compound(&input), "Repay the entire USDC borrow");
+
+        let input = IComet::withdrawToCall {
+            to: STRANGER,
+            asset: USDC,
+            amount: U256::from(1_000_000u64),
+        }
+        .abi_encode();
+        assert_eq!(compound(&input), "Withdraw 1 USDC");
+
+        let input = IComet::absorbCall {
+            absorber: SIGNER,
+            accounts: vec![STRANGER],
+        }
+        .abi_encode();
+        assert_eq!(compound(&input), "Liquidate 1 underwater account");
+
+        let input = IComet::allowCall {
+            manager: STRANGER,
+            isAllowed: true,
+        }
+        .abi_encode();
+        assert_eq!(
+            compound(&input),
+            "Let 0x2222222222222222222222222222222222222222 supply, transfer and withdraw your assets"
+        );
+    }
+
+    #[test]
+    fn test_third_party_warning() {
+        let warning = |input: &[u8], to: Address| {
+            third_party_warning(
+                input,
+                to,
+                Some(SIGNER),
+                Some(1),
+                &ContractRegistry::default(),
+                &TokenRegistry::default(),
+            )
+            .map(|field| field.fallback_text().clone())
+        };
+        let supply = |on_behalf_of| {
+            IAaveV3Pool::supplyCall {
+                asset: USDC,
+                amount: U256::from(1_000_000u64),
+                onBehalfOf: on_behalf_of,
+                referralCode: 0,
+            }
+            .abi_encode()
+        };
+        assert_eq!(warning(&supply(SIGNER), AAVE_POOL), None);
+        assert_eq!(
+            warning(&supply(STRANGER), AAVE_POOL).unwrap(),
+            "This supplies your USDC to the Aave v3 Pool position of 0x2222222222222222222222222222222222222222, not your own. Only that account can withdraw it."
+        );
+        // Only calls to a known market are checked
+        assert_eq!(warning(&supply(STRANGER), STRANGER), None);
+
+        let comet = address!("0xc3d688b66703497daa19211eedff47f25384cdc3");
+        let withdraw = IComet::withdrawToCall {
+            to: STRANGER,
+            asset: USDC,
+            amount: U256::from(1_000_000u64),
+        }
+        .abi_encode();
+        assert_eq!(
+            warning(&withdraw, comet).unwrap(),
+            "The withdrawn USDC is sent to 0x2222222222222222222222222222222222222222, not to you."
+        );
+
+        // Without a signer to compare to, the named account is always flagged
+        let unsigned = third_party_warning(
+            &supply(SIGNER),
+            AAVE_POOL,
+            None,
+            Some(1),
+            &ContractRegistry::default(),
+            &TokenRegistry::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            unsigned.fallback_text(),
+            "This supplies your USDC to the Aave v3 Pool position of 0x1111111111111111111111111111111111111111; confirm this is your address. Only that account can withdraw it."
+        );
+    }
+}
@@ -0,0 +1,308 @@
+//! Liquid staking entry points: Lido's stETH, withdrawal queue and wstETH,
+//! Rocket Pool's deposit pool and rETH, and Coinbase's cbETH.
+//!
+//! Staking deposits are payable functions without an amount argument; the ETH
+//! staked is the value sent with the call, shown alongside it.
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{SolCall, sol};
+use visualsign::{AnnotatedPayloadField, SignablePayloadField};
+
+use crate::fields::{addr, address_field, amount_field, preview_layout, text_field};
+use crate::fmt::format_ether;
+
+sol! {
+    // From: https://github.com/lidofinance/core/blob/master/contracts/0.4.24/Lido.sol
+    interface ILido {
+        function submit(address _referral) external payable returns (uint256);
+    }
+
+    // From: https://github.com/lidofinance/core/blob/master/contracts/0.8.9/WithdrawalQueue.sol
+    interface IWithdrawalQueue {
+        function requestWithdrawals(uint256[] calldata _amounts, address _owner) external returns (uint256[] memory requestIds);
+        function requestWithdrawalsWstETH(uint256[] calldata _amounts, address _owner) external returns (uint256[] memory requestIds);
+        function claimWithdrawals(uint256[] calldata _requestIds, uint256[] calldata _hints) external;
+        function claimWithdrawal(uint256 _requestId) external;
+    }
+
+    // From: https://github.com/lidofinance/core/blob/master/contracts/0.6.12/WstETH.sol
+    interface IWstETH {
+        function wrap(uint256 _stETHAmount) external returns (uint256);
+        function unwrap(uint256 _wstETHAmount) external returns (uint256);
+    }
+
+    // From: https://github.com/rocket-pool/rocketpool/blob/master/contracts/contract/deposit/RocketDepositPool.sol
+    interface IRocketDepositPool {
+        function deposit() external payable;
+    }
+
+    // From: https://github.com/rocket-pool/rocketpool/blob/master/contracts/contract/token/RocketTokenRETH.sol
+    interface IRocketTokenRETH {
+        function burn(uint256 _rethAmount) external;
+    }
+
+    // Coinbase's StakedTokenV1, a FiatToken whose minters are Coinbase
+    interface ICbETH {
+        function mint(address _to, uint256 _amount) external returns (bool);
+        function burn(uint256 _amount) external;
+    }
+}
+
+fn ether_amount(label: &str, amount: U256, symbol: &str) -> AnnotatedPayloadField {
+    amount_field(label, &format_ether(amount), Some(symbol))
+}
+
+/// The staked ETH isn't an argument; point at the call's value instead.
+fn staked_value_field() -> AnnotatedPayloadField {
+    text_field("Amount", "The ETH value sent with this call")
+}
+
+fn layout(title: &str, subtitle: &str, fields: Vec<AnnotatedPayloadField>) -> SignablePayloadField {
+    preview_layout(title, title, subtitle, None, fields)
+}
+
+/// Lido's withdrawal requests, which lock `symbol` in the queue until `owner`
+/// claims the ETH. A zero owner means the caller.
+fn withdrawal_request(amounts: &[U256], owner: Address, symbol: &str) -> SignablePayloadField {
+    let total = amounts
+        .iter()
+        .fold(U256::ZERO, |total, amount| total.saturating_add(*amount));
+    let mut fields: Vec<AnnotatedPayloadField> = amounts
+        .iter()
+        .enumerate()
+        .map(|(i, amount)| ether_amount(&format!("Request {}", i + 1), *amount, symbol))
+        .collect();
+    if owner != Address::ZERO {
+        fields.push(address_field("Owner", &addr(owner), None, None));
+    }
+    let requests = if amounts.len() == 1 {
+        "request"
+    } else {
+        "requests"
+    };
+    layout(
+        "Lido Withdrawal Request",
+        &format!(
+            "Request withdrawal of {} {symbol} in {} {requests}",
+            format_ether(total),
+            amounts.len()
+        ),
+        fields,
+    )
+}
+
+fn withdrawal_claim(request_ids: &[U256]) -> SignablePayloadField {
+    let ids: Vec<String> = request_ids.iter().map(|id| format!("#{id}")).collect();
+    let requests = if request_ids.len() == 1 {
+        "request"
+    } else {
+        "requests"
+    };
+    layout(
+        "Lido Withdrawal Claim",
+        &format!("Claim ETH for withdrawal {requests} {}", ids.join(", ")),
+        vec![text_field("Request IDs", &ids.join(", "))],
+    )
+}
+
+pub struct LidoVisualizer {}
+
+impl LidoVisualizer {
+    /// Decodes `input`, a call to stETH, the withdrawal queue or wstETH.
+    pub fn visualize_tx_commands(&self, input: &[u8]) -> Option<SignablePayloadField> {
+        let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+        match selector {
+            ILido::submitCall::SELECTOR => {
+                let call = ILido::submitCall::abi_decode(input).ok()?;
+                let mut fields = vec![staked_value_field()];
+                if call._referral != Address::ZERO {
+                    fields.push(address_field("Referral", &addr(call._referral), None, None));
+                }
+                Some(layout("Lido Stake", "Stake ETH for stETH", fields))
+            }
+            IWithdrawalQueue::requestWithdrawalsCall::SELECTOR => {
+                let call = IWithdrawalQueue::requestWithdrawalsCall::abi_decode(input).ok()?;
+                Some(withdrawal_request(&call._amounts, call._owner, "stETH"))
+            }
+            IWithdrawalQueue::requestWithdrawalsWstETHCall::SELECTOR => {
+                let call =
+                    IWithdrawalQueue::requestWithdrawalsWstETHCall::abi_decode(input).ok()?;
+                Some(withdrawal_request(&call._amounts, call._owner, "wstETH"))
+            }
+            IWithdrawalQueue::claimWithdrawalsCall::SELECTOR => {
+                let call = IWithdrawalQueue::claimWithdrawalsCall::abi_decode(input).ok()?;
+                Some(withdrawal_claim(&call._requestIds))
+            }
+            IWithdrawalQueue::claimWithdrawalCall::SELECTOR => {
+                let call = IWithdrawalQueue::claimWithdrawalCall::abi_decode(input).ok()?;
+                Some(withdrawal_claim(&[call._requestId]))
+            }
+            IWstETH::wrapCall::SELECTOR => {
+                let call = IWstETH::wrapCall::abi_decode(input).ok()?;
+                Some(layout(
+                    "Lido Wrap",
+                    &format!("Wrap {} stETH into wstETH", format_ether(call._stETHAmount)),
+                    vec![ether_amount("Amount", call._stETHAmount, "stETH")],
+                ))
+            }
+            IWstETH::unwrapCall::SELECTOR => {
+                let call = IWstETH::unwrapCall::abi_decode(input).ok()?;
+                Some(layout(
+                    "Lido Unwrap",
+                    &format!(
+                        "Unwrap {} wstETH into stETH",
+                        format_ether(call._wstETHAmount)
+                    ),
+                    vec![ether_amount("Amount", call._wstETHAmount, "wstETH")],
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+pub struct RocketPoolVisualizer {}
+
+impl RocketPoolVisualizer {
+    /// Decodes `input`, a call to the deposit pool or rETH.
+    pub fn visualize_tx_commands(&self, input: &[u8]) -> Option<SignablePayloadField> {
+        let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+        match selector {
+            IRocketDepositPool::depositCall::SELECTOR => {
+                IRocketDepositPool::depositCall::abi_decode(input).ok()?;
+                Some(layout(
+                    "Rocket Pool Deposit",
+                    "Stake ETH for rETH",
+                    vec![staked_value_field()],
+                ))
+            }
+            IRocketTokenRETH::burnCall::SELECTOR => {
+                let call = IRocketTokenRETH::burnCall::abi_decode(input).ok()?;
+                Some(layout(
+                    "Rocket Pool Burn",
+                    &format!("Burn {} rETH for ETH", format_ether(call._rethAmount)),
+                    vec![ether_amount("Amount", call._rethAmount, "rETH")],
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+pub struct CbEthVisualizer {}
+
+impl CbEthVisualizer {
+    /// Decodes `input`, a call to the cbETH token.
+    pub fn visualize_tx_commands(&self, input: &[u8]) -> Option<SignablePayloadField> {
+        let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+        match selector {
+            ICbETH::mintCall::SELECTOR => {
+                let call = ICbETH::mintCall::abi_decode(input).ok()?;
+                Some(layout(
+                    "cbETH Mint",
+                    &format!(
+                        "Mint {} cbETH to {:?}",
+                        format_ether(call._amount),
+                        call._to
+                    ),
+                    vec![
+                        address_field("Recipient", &addr(call._to), None, None),
+                        ether_amount("Amount", call._amount, "cbETH"),
+                    ],
+                ))
+            }
+            // Redemptions burn the caller's cbETH; Coinbase pays out the ETH
+            ICbETH::burnCall::SELECTOR => {
+                let call = ICbETH::burnCall::abi_decode(input).ok()?;
+                Some(layout(
+                    "cbETH Redeem",
+                    &format!("Burn {} cbETH", format_ether(call._amount)),
+                    vec![ether_amount("Amount", call._amount, "cbETH")],
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subtitle(field: Option<SignablePayloadField>) -> String {
+        field.unwrap().fallback_text().clone()
+    }
+
+    #[test]
+    fn test_lido_calls() {
+        let lido = LidoVisualizer {};
+        let input = ILido::submitCall {
+            _referral: Address::ZERO,
+        }
+        .abi_encode();
+        assert_eq!(
+            subtitle(lido.visualize_tx_commands(&input)),
+            "Stake ETH for stETH"
+        );
+
+        let input = IWithdrawalQueue::requestWithdrawalsCall {
+            _amounts: vec![
+                U256::from(1_000_000_000_000_000_000u128),
+                U256::from(500_000_000_000_000_000u128),
+            ],
+            _owner: Address::ZERO,
+        }
+        .abi_encode();
+        assert_eq!(
+            subtitle(lido.visualize_tx_commands(&input)),
+            "Request withdrawal of 1.5 stETH in 2 requests"
+        );
+
+        let input = IWithdrawalQueue::claimWithdrawalsCall {
+            _requestIds: vec![U256::from(7u64), U256::from(9u64)],
+            _hints: vec![U256::from(1u64), U256::from(1u64)],
+        }
+        .abi_encode();
+        assert_eq!(
+            subtitle(lido.visualize_tx_commands(&input)),
+            "Claim ETH for withdrawal requests #7, #9"
+        );
+
+        let input = IWstETH::unwrapCall {
+            _wstETHAmount: U256::from(2_000_000_000_000_000_000u128),
+        }
+        .abi_encode();
+        assert_eq!(
+            subtitle(lido.visualize_tx_commands(&input)),
+            "Unwrap 2 wstETH into stETH"
+        );
+    }
+
+    #[test]
+    fn test_rocket_pool_and_cbeth_calls() {
+        let input = IRocketDepositPool::depositCall {}.abi_encode();
+        assert_eq!(
+            subtitle(RocketPoolVisualizer {}.visualize_tx_commands(&input)),
+            "Stake ETH for rETH"
+        );
+        let input = IRocketTokenRETH::burnCall {
+            _rethAmount: U256::from(250_000_000_000_000_000u128),
+        }
+        .abi_encode();
+        assert_eq!(
+            subtitle(RocketPoolVisualizer {}.visualize_tx_commands(&input)),
+            "Burn 0.25 rETH for ETH"
+        );
+
+        // The same burn selector means a redemption on cbETH
+        assert_eq!(
+            subtitle(CbEthVisualizer {}.visualize_tx_commands(&input)),
+            "Burn 0.25 cbETH"
+        );
+        assert!(
+            CbEthVisualizer {}
+                .visualize_tx_commands(&[0xde, 0xad])
+                .is_none()
+        );
+    }
+}
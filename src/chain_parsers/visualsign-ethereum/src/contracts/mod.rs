@@ -1,3 +1,13 @@
+pub mod aggregators;
+pub mod batch;
+pub mod bridges;
 pub mod erc20;
 pub mod eigenlayer;
+pub mod ens;
+pub mod lending;
+pub mod liquid_staking;
+pub mod nft;
+pub mod safe;
+pub mod smart_account;
 pub mod uniswap;
+pub mod weth;
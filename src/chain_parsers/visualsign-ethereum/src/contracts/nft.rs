@@ -0,0 +1,412 @@
+//! ERC721 and ERC1155 transfers and approvals.
+//!
+//! ERC721's `transferFrom` and `approve` share their selectors with ERC20. The
+//! converter shows them as token calls when the destination is a known ERC20,
+//! and otherwise with [`NFTVisualizer::visualize_shared_selectors`], which
+//! labels the value as either an amount or a token ID. Callers that know the
+//! destination is a collection can use [`NFTVisualizer`] directly to show them
+//! as NFT calls.
+
+use alloy_primitives::{Address, Bytes, U256};
+use alloy_sol_types::{SolCall, sol};
+use visualsign::{AnnotatedPayloadField, SignablePayloadField};
+
+use crate::fields::{
+    addr, address_field, annotated, number_field, preview_layout, text_field, with_annotation,
+};
+
+sol! {
+    interface IERC721 {
+        function safeTransferFrom(address from, address to, uint256 tokenId, bytes data) external;
+        function safeTransferFrom(address from, address to, uint256 tokenId) external;
+        function transferFrom(address from, address to, uint256 tokenId) external;
+        function approve(address to, uint256 tokenId) external;
+        function setApprovalForAll(address operator, bool approved) external;
+    }
+
+    interface IERC1155 {
+        function safeTransferFrom(address from, address to, uint256 id, uint256 value, bytes data) external;
+        function safeBatchTransferFrom(address from, address to, uint256[] ids, uint256[] values, bytes data) external;
+    }
+}
+
+fn collection_field(collection: Address) -> AnnotatedPayloadField {
+    address_field("Collection", &addr(collection), None, None)
+}
+
+/// Bytes passed on to the recipient's `onERC721Received`/`onERC1155Received` hook.
+fn data_field(data: &Bytes) -> Option<AnnotatedPayloadField> {
+    (!data.is_empty()).then(|| text_field("Data", &format!("0x{}", hex::encode(data))))
+}
+
+fn transfer_fields(
+    collection: Address,
+    from: Address,
+    to: Address,
+    token: Vec<AnnotatedPayloadField>,
+    data: Option<&Bytes>,
+) -> Vec<AnnotatedPayloadField> {
+    let mut fields = vec![
+        collection_field(collection),
+        address_field("From", &addr(from), None, None),
+        address_field("Recipient", &addr(to), None, None),
+    ];
+    fields.extend(token);
+    fields.extend(data.and_then(data_field));
+    fields
+}
+
+fn erc721_transfer(
+    title: &str,
+    collection: Address,
+    from: Address,
+    to: Address,
+    token_id: U256,
+    data: Option<&Bytes>,
+) -> SignablePayloadField {
+    let subtitle = format!("Transfer token #{token_id} from {from:?} to {to:?}");
+    let token = vec![number_field("Token ID", &token_id.to_string())];
+    preview_layout(
+        title,
+        title,
+        &subtitle,
+        None,
+        transfer_fields(collection, from, to, token, data),
+    )
+}
+
+/// The value of a call whose selector ERC20 and ERC721 share.
+fn amount_or_token_id_field(value: U256) -> AnnotatedPayloadField {
+    with_annotation(
+        number_field("Amount Or Token ID", &value.to_string()),
+        "ERC20 amount or ERC721 token ID: the contract isn't a known token, so either standard may apply",
+    )
+}
+
+pub struct NFTVisualizer {}
+
+impl NFTVisualizer {
+    /// Decodes `transferFrom` or `approve` sent to `contract`, a contract that
+    /// may be either an ERC20 token or an ERC721 collection.
+    pub fn visualize_shared_selectors(
+        &self,
+        input: &[u8],
+        contract: Address,
+    ) -> Option<SignablePayloadField> {
+        let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+        let contract_field = address_field("Contract", &addr(contract), None, None);
+        match selector {
+            IERC721::transferFromCall::SELECTOR => {
+                let call = IERC721::transferFromCall::abi_decode(input).ok()?;
+                let subtitle = format!(
+                    "Transfer {} (ERC20 amount or ERC721 token ID) from {:?} to {:?}",
+                    call.tokenId, call.from, call.to
+                );
+                let fields = vec![
+                    contract_field,
+                    address_field("From", &addr(call.from), None, None),
+                    address_field("Recipient", &addr(call.to), None, None),
+                    amount_or_token_id_field(call.tokenId),
+                ];
+                Some(preview_layout(
+                    "ERC20 or ERC721 TransferFrom",
+                    "ERC20 or ERC721 TransferFrom",
+                    &subtitle,
+                    None,
+                    fields,
+                ))
+            }
+            IERC721::approveCall::SELECTOR => {
+                let call = IERC721::approveCall::abi_decode(input).ok()?;
+                let subtitle = format!(
+                    "Approve {:?} for {} (ERC20 amount or ERC721 token ID)",
+                    call.to, call.tokenId
+                );
+                let fields = vec![
+                    contract_field,
+                    address_field("Spender", &addr(call.to), None, None),
+                    amount_or_token_id_field(call.tokenId),
+                ];
+                Some(preview_layout(
+                    "ERC20 or ERC721 Approve",
+                    "ERC20 or ERC721 Approve",
+                    &subtitle,
+                    None,
+                    fields,
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Decodes `input`, a call to the ERC721 or ERC1155 collection at `collection`.
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        collection: Address,
+    ) -> Option<SignablePayloadField> {
+        let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+        match selector {
+            IERC721::safeTransferFrom_0Call::SELECTOR => {
+                let call = IERC721::safeTransferFrom_0Call::abi_decode(input).ok()?;
+                Some(erc721_transfer(
+                    "ERC721 SafeTransferFrom",
+                    collection,
+                    call.from,
+                    call.to,
+                    call.tokenId,
+                    Some(&call.data),
+                ))
+            }
+            IERC721::safeTransferFrom_1Call::SELECTOR => {
+                let call = IERC721::safeTransferFrom_1Call::abi_decode(input).ok()?;
+                Some(erc721_transfer(
+                    "ERC721 SafeTransferFrom",
+                    collection,
+                    call.from,
+                    call.to,
+                    call.tokenId,
+                    None,
+                ))
+            }
+            IERC721::transferFromCall::SELECTOR => {
+                let call = IERC721::transferFromCall::abi_decode(input).ok()?;
+                Some(erc721_transfer(
+                    "ERC721 TransferFrom",
+                    collection,
+                    call.from,
+                    call.to,
+                    call.tokenId,
+                    None,
+                ))
+            }
+            IERC721::approveCall::SELECTOR => {
+                let call = IERC721::approveCall::abi_decode(input).ok()?;
+                let subtitle = if call.to == Address::ZERO {
+                    format!("Clear the approval for token #{}", call.tokenId)
+                } else {
+                    format!("Approve {:?} to transfer token #{}", call.to, call.tokenId)
+                };
+                let fields = vec![
+                    collection_field(collection),
+                    address_field("Approved", &addr(call.to), None, None),
+                    number_field("Token ID", &call.tokenId.to_string()),
+                ];
+                Some(preview_layout(
+                    "ERC721 Approve",
+                    "ERC721 Approve",
+                    &subtitle,
+                    None,
+                    fields,
+                ))
+            }
+            IERC721::setApprovalForAllCall::SELECTOR => {
+                let call = IERC721::setApprovalForAllCall::abi_decode(input).ok()?;
+                let subtitle = if call.approved {
+                    format!("Allow {:?} to transfer all of your tokens", call.operator)
+                } else {
+                    format!(
+                        "Revoke {:?}'s approval for all of your tokens",
+                        call.operator
+                    )
+                };
+                let fields = vec![
+                    collection_field(collection),
+                    address_field("Operator", &addr(call.operator), None, None),
+                    text_field("Approved", if call.approved { "Yes" } else { "No" }),
+                ];
+                Some(preview_layout(
+                    "NFT SetApprovalForAll",
+                    "NFT SetApprovalForAll",
+                    &subtitle,
+                    None,
+                    fields,
+                ))
+            }
+            IERC1155::safeTransferFromCall::SELECTOR => {
+                let call = IERC1155::safeTransferFromCall::abi_decode(input).ok()?;
+                let subtitle = format!(
+                    "Transfer {} of token #{} from {:?} to {:?}",
+                    call.value, call.id, call.from, call.to
+                );
+                let token = vec![
+                    number_field("Token ID", &call.id.to_string()),
+                    number_field("Quantity", &call.value.to_string()),
+                ];
+                Some(preview_layout(
+                    "ERC1155 SafeTransferFrom",
+                    "ERC1155 SafeTransferFrom",
+                    &subtitle,
+                    None,
+                    transfer_fields(collection, call.from, call.to, token, Some(&call.data)),
+                ))
+            }
+            IERC1155::safeBatchTransferFromCall::SELECTOR => {
+                let call = IERC1155::safeBatchTransferFromCall::abi_decode(input).ok()?;
+                // The contract reverts on mismatched lengths, so don't guess at pairings
+                if call.ids.len() != call.values.len() {
+                    return None;
+                }
+                let subtitle = format!(
+                    "Transfer {} token types from {:?} to {:?}",
+                    call.ids.len(),
+                    call.from,
+                    call.to
+                );
+                let token = call
+                    .ids
+                    .iter()
+                    .zip(&call.values)
+                    .enumerate()
+                    .map(|(i, (id, value))| {
+                        let label = format!("Token {}", i + 1);
+                        let summary = format!("{value} of token #{id}");
+                        annotated(preview_layout(
+                            &label,
+                            &label,
+                            &summary,
+                            None,
+                            vec![
+                                number_field("Token ID", &id.to_string()),
+                                number_field("Quantity", &value.to_string()),
+                            ],
+                        ))
+                    })
+                    .collect();
+                Some(preview_layout(
+                    "ERC1155 SafeBatchTransferFrom",
+                    "ERC1155 SafeBatchTransferFrom",
+                    &subtitle,
+                    None,
+                    transfer_fields(collection, call.from, call.to, token, Some(&call.data)),
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLLECTION: Address = Address::repeat_byte(0xaa);
+    const FROM: Address = Address::repeat_byte(0x11);
+    const TO: Address = Address::repeat_byte(0x22);
+
+    #[test]
+    fn test_decode_erc721_safe_transfer_from() {
+        let input = IERC721::safeTransferFrom_1Call {
+            from: FROM,
+            to: TO,
+            tokenId: U256::from(42u64),
+        }
+        .abi_encode();
+
+        assert_eq!(
+            NFTVisualizer {}
+                .visualize_tx_commands(&input, COLLECTION)
+                .unwrap(),
+            preview_layout(
+                "ERC721 SafeTransferFrom",
+                "ERC721 SafeTransferFrom",
+                &format!("Transfer token #42 from {FROM:?} to {TO:?}"),
+                None,
+                vec![
+                    address_field("Collection", &addr(COLLECTION), None, None),
+                    address_field("From", &addr(FROM), None, None),
+                    address_field("Recipient", &addr(TO), None, None),
+                    number_field("Token ID", "42"),
+                ],
+            )
+        );
+
+        // The overload with data carries it through to the recipient
+        let input = IERC721::safeTransferFrom_0Call {
+            from: FROM,
+            to: TO,
+            tokenId: U256::from(42u64),
+            data: Bytes::from(vec![0xbe, 0xef]),
+        }
+        .abi_encode();
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } = NFTVisualizer {}
+            .visualize_tx_commands(&input, COLLECTION)
+            .unwrap()
+        else {
+            panic!("expected a preview layout");
+        };
+        let fields = preview_layout.expanded.unwrap().fields;
+        assert_eq!(fields.last().unwrap(), &text_field("Data", "0xbeef"));
+    }
+
+    #[test]
+    fn test_decode_approvals() {
+        let input = IERC721::setApprovalForAllCall {
+            operator: TO,
+            approved: true,
+        }
+        .abi_encode();
+        let field = NFTVisualizer {}
+            .visualize_tx_commands(&input, COLLECTION)
+            .unwrap();
+        assert_eq!(
+            field.fallback_text(),
+            &format!("Allow {TO:?} to transfer all of your tokens")
+        );
+
+        let input = IERC721::approveCall {
+            to: Address::ZERO,
+            tokenId: U256::from(7u64),
+        }
+        .abi_encode();
+        let field = NFTVisualizer {}
+            .visualize_tx_commands(&input, COLLECTION)
+            .unwrap();
+        assert_eq!(field.fallback_text(), "Clear the approval for token #7");
+    }
+
+    #[test]
+    fn test_decode_erc1155_batch_transfer() {
+        let input = IERC1155::safeBatchTransferFromCall {
+            from: FROM,
+            to: TO,
+            ids: vec![U256::from(1u64), U256::from(2u64)],
+            values: vec![U256::from(10u64), U256::from(1u64)],
+            data: Bytes::new(),
+        }
+        .abi_encode();
+        let SignablePayloadField::PreviewLayout {
+            common,
+            preview_layout,
+        } = NFTVisualizer {}
+            .visualize_tx_commands(&input, COLLECTION)
+            .unwrap()
+        else {
+            panic!("expected a preview layout");
+        };
+        assert_eq!(
+            common.fallback_text,
+            format!("Transfer 2 token types from {FROM:?} to {TO:?}")
+        );
+        let fields = preview_layout.expanded.unwrap().fields;
+        assert_eq!(fields.len(), 5);
+        assert_eq!(
+            fields[3].signable_payload_field.fallback_text(),
+            "10 of token #1"
+        );
+
+        // Mismatched ids and values revert on-chain and aren't decoded
+        let input = IERC1155::safeBatchTransferFromCall {
+            from: FROM,
+            to: TO,
+            ids: vec![U256::from(1u64)],
+            values: vec![],
+            data: Bytes::new(),
+        }
+        .abi_encode();
+        assert_eq!(
+            NFTVisualizer {}.visualize_tx_commands(&input, COLLECTION),
+            None
+        );
+    }
+}
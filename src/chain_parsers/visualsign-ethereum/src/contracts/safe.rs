@@ -0,0 +1,371 @@
+//! Safe (formerly Gnosis Safe) multisig transactions and owner management.
+//!
+//! Every Safe is its own proxy, so calls are recognized by selector rather than
+//! by address. The call inside `execTransaction` is decoded by whichever
+//! visualizer would handle it if it were sent directly.
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{SolCall, sol};
+use visualsign::{AnnotatedPayloadField, SignablePayloadField};
+
+use crate::chains::native_currency;
+use crate::fields::{
+    addr, address_field, amount_field, number_field, plain_text_field, preview_layout, text_field,
+    with_annotation,
+};
+use crate::fmt::format_token_amount;
+use crate::registry::{ContractKind, ContractRegistry};
+
+// From: https://github.com/safe-global/safe-smart-account/blob/main/contracts/Safe.sol
+sol! {
+    interface ISafe {
+        function execTransaction(
+            address to,
+            uint256 value,
+            bytes data,
+            uint8 operation,
+            uint256 safeTxGas,
+            uint256 baseGas,
+            uint256 gasPrice,
+            address gasToken,
+            address refundReceiver,
+            bytes signatures
+        ) external payable returns (bool success);
+
+        function addOwnerWithThreshold(address owner, uint256 _threshold) external;
+        function removeOwner(address prevOwner, address owner, uint256 _threshold) external;
+        function swapOwner(address prevOwner, address oldOwner, address newOwner) external;
+        function changeThreshold(uint256 _threshold) external;
+        function enableModule(address module) external;
+        function disableModule(address prevModule, address module) external;
+        function setGuard(address guard) external;
+        function setFallbackHandler(address handler) external;
+    }
+}
+
+/// `Enum.Operation.DelegateCall`: the target's code runs in the Safe's context.
+const DELEGATE_CALL: u8 = 1;
+
+/// Length of one ECDSA or approved-hash signature in `signatures`.
+const SIGNATURE_LENGTH: usize = 65;
+
+fn delegatecall_text(to: Address) -> String {
+    format!(
+        "This Safe transaction uses DELEGATECALL: {to:?} runs with the Safe's own storage and balance, and can change its owners or move all of its assets."
+    )
+}
+
+/// A warning for the top of the payload when `input` is an `execTransaction`
+/// that delegatecalls. Delegatecalls to a known MultiSend deployment on
+/// `chain_id` are how Safes batch calls, and aren't flagged; the batch's own
+/// delegatecalls are, by [`super::batch::delegatecall_warnings`].
+pub(crate) fn delegatecall_warning(
+    input: &[u8],
+    chain_id: Option<u64>,
+    contracts: &ContractRegistry,
+) -> Option<SignablePayloadField> {
+    if input.get(..4)? != ISafe::execTransactionCall::SELECTOR {
+        return None;
+    }
+    let call = ISafe::execTransactionCall::abi_decode(input).ok()?;
+    let multi_send = contracts
+        .lookup(chain_id, call.to)
+        .is_some_and(|info| info.kind == ContractKind::SafeMultiSend);
+    (call.operation == DELEGATE_CALL && !multi_send)
+        .then(|| plain_text_field("Warning", &delegatecall_text(call.to)))
+}
+
+fn owner_change(
+    title: &str,
+    subtitle: &str,
+    fields: Vec<AnnotatedPayloadField>,
+) -> Option<SignablePayloadField> {
+    Some(preview_layout(title, title, subtitle, None, fields))
+}
+
+fn threshold_field(threshold: U256) -> AnnotatedPayloadField {
+    number_field("Threshold", &threshold.to_string())
+}
+
+pub struct SafeVisualizer {}
+
+impl SafeVisualizer {
+    /// Decodes `input`, a call to a Safe on `chain_id`. `decode_call` renders
+    /// the call an `execTransaction` makes, given its target and calldata.
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: Option<u64>,
+        decode_call: &dyn Fn(Address, &[u8]) -> Vec<AnnotatedPayloadField>,
+    ) -> Option<SignablePayloadField> {
+        let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+        match selector {
+            ISafe::execTransactionCall::SELECTOR => {
+                let call = ISafe::execTransactionCall::abi_decode(input).ok()?;
+                Some(exec_transaction(&call, chain_id, decode_call))
+            }
+            ISafe::addOwnerWithThresholdCall::SELECTOR => {
+                let call = ISafe::addOwnerWithThresholdCall::abi_decode(input).ok()?;
+                owner_change(
+                    "Safe AddOwnerWithThreshold",
+                    &format!(
+                        "Add owner {:?} and require {} confirmations",
+                        call.owner, call._threshold
+                    ),
+                    vec![
+                        address_field("Owner", &addr(call.owner), None, None),
+                        threshold_field(call._threshold),
+                    ],
+                )
+            }
+            ISafe::removeOwnerCall::SELECTOR => {
+                let call = ISafe::removeOwnerCall::abi_decode(input).ok()?;
+                owner_change(
+                    "Safe RemoveOwner",
+                    &format!(
+                        "Remove owner {:?} and require {} confirmations",
+                        call.owner, call._threshold
+                    ),
+                    vec![
+                        address_field("Owner", &addr(call.owner), None, None),
+                        threshold_field(call._threshold),
+                    ],
+                )
+            }
+            ISafe::swapOwnerCall::SELECTOR => {
+                let call = ISafe::swapOwnerCall::abi_decode(input).ok()?;
+                owner_change(
+                    "Safe SwapOwner",
+                    &format!("Replace owner {:?} with {:?}", call.oldOwner, call.newOwner),
+                    vec![
+                        address_field("Old Owner", &addr(call.oldOwner), None, None),
+                        address_field("New Owner", &addr(call.newOwner), None, None),
+                    ],
+                )
+            }
+            ISafe::changeThresholdCall::SELECTOR => {
+                let call = ISafe::changeThresholdCall::abi_decode(input).ok()?;
+                owner_change(
+                    "Safe ChangeThreshold",
+                    &format!("Require {} confirmations", call._threshold),
+                    vec![threshold_field(call._threshold)],
+                )
+            }
+            ISafe::enableModuleCall::SELECTOR => {
+                let call = ISafe::enableModuleCall::abi_decode(input).ok()?;
+                owner_change(
+                    "Safe EnableModule",
+                    &format!("Enable module {:?}", call.module),
+                    vec![with_annotation(
+                        address_field("Module", &addr(call.module), None, None),
+                        "Modules can execute transactions from the Safe without owner signatures",
+                    )],
+                )
+            }
+            ISafe::disableModuleCall::SELECTOR => {
+                let call = ISafe::disableModuleCall::abi_decode(input).ok()?;
+                owner_change(
+                    "Safe DisableModule",
+                    &format!("Disable module {:?}", call.module),
+                    vec![address_field("Module", &addr(call.module), None, None)],
+                )
+            }
+            ISafe::setGuardCall::SELECTOR => {
+                let call = ISafe::setGuardCall::abi_decode(input).ok()?;
+                let subtitle = if call.guard == Address::ZERO {
+                    "Remove the transaction guard".to_string()
+                } else {
+                    format!("Set the transaction guard to {:?}", call.guard)
+                };
+                owner_change(
+                    "Safe SetGuard",
+                    &subtitle,
+                    vec![with_annotation(
+                        address_field("Guard", &addr(call.guard), None, None),
+                        "A guard can block every future transaction from the Safe",
+                    )],
+                )
+            }
+            ISafe::setFallbackHandlerCall::SELECTOR => {
+                let call = ISafe::setFallbackHandlerCall::abi_decode(input).ok()?;
+                owner_change(
+                    "Safe SetFallbackHandler",
+                    &format!("Set the fallback handler to {:?}", call.handler),
+                    vec![address_field("Handler", &addr(call.handler), None, None)],
+                )
+            }
+            _ => None,
+        }
+    }
+}
+
+fn exec_transaction(
+    call: &ISafe::execTransactionCall,
+    chain_id: Option<u64>,
+    decode_call: &dyn Fn(Address, &[u8]) -> Vec<AnnotatedPayloadField>,
+) -> SignablePayloadField {
+    let (symbol, decimals) = native_currency(chain_id);
+    let value = format_token_amount(call.value, decimals);
+    let operation = match call.operation {
+        0 => text_field("Operation", "Call"),
+        DELEGATE_CALL => with_annotation(
+            text_field("Operation", "DelegateCall"),
+            &delegatecall_text(call.to),
+        ),
+        other => text_field("Operation", &format!("Unknown ({other})")),
+    };
+    let subtitle = match call.operation {
+        DELEGATE_CALL => format!("Delegatecall {:?}", call.to),
+        _ if call.data.is_empty() => format!("Send {value} {symbol} to {:?}", call.to),
+        _ => format!("Call {:?}", call.to),
+    };
+
+    let mut fields = vec![
+        address_field("To", &addr(call.to), None, None),
+        amount_field("Value", &value, Some(symbol)),
+        operation,
+    ];
+    if !call.data.is_empty() {
+        fields.extend(decode_call(call.to, &call.data));
+    }
+
+    // Gas refunds are paid from the Safe, in `gasToken`, to `refundReceiver`
+    fields.push(number_field("Safe Tx Gas", &call.safeTxGas.to_string()));
+    if !call.gasPrice.is_zero() {
+        let gas_token = if call.gasToken == Address::ZERO {
+            symbol.to_string()
+        } else {
+            addr(call.gasToken)
+        };
+        let refund_receiver = if call.refundReceiver == Address::ZERO {
+            "Transaction sender".to_string()
+        } else {
+            addr(call.refundReceiver)
+        };
+        fields.extend([
+            number_field("Base Gas", &call.baseGas.to_string()),
+            number_field("Gas Price", &call.gasPrice.to_string()),
+            text_field("Gas Token", &gas_token),
+            text_field("Refund Receiver", &refund_receiver),
+        ]);
+    }
+    let signatures = if call.signatures.len().is_multiple_of(SIGNATURE_LENGTH) {
+        format!("{}", call.signatures.len() / SIGNATURE_LENGTH)
+    } else {
+        format!("{} bytes", call.signatures.len())
+    };
+    fields.push(text_field("Signatures", &signatures));
+
+    preview_layout(
+        "Safe Transaction",
+        "Safe execTransaction",
+        &subtitle,
+        None,
+        fields,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Bytes;
+
+    const TARGET: Address = Address::repeat_byte(0x42);
+
+    fn exec(to: Address, data: Vec<u8>, operation: u8) -> Vec<u8> {
+        ISafe::execTransactionCall {
+            to,
+            value: U256::ZERO,
+            data: Bytes::from(data),
+            operation,
+            safeTxGas: U256::ZERO,
+            baseGas: U256::ZERO,
+            gasPrice: U256::ZERO,
+            gasToken: Address::ZERO,
+            refundReceiver: Address::ZERO,
+            signatures: Bytes::from(vec![0u8; 130]),
+        }
+        .abi_encode()
+    }
+
+    fn inner(to: Address, data: &[u8]) -> Vec<AnnotatedPayloadField> {
+        vec![text_field(
+            "Inner",
+            &format!("{to:?} 0x{}", hex::encode(data)),
+        )]
+    }
+
+    #[test]
+    fn test_exec_transaction_decodes_inner_call() {
+        let input = exec(TARGET, vec![0xde, 0xad], 0);
+        assert_eq!(
+            SafeVisualizer {}
+                .visualize_tx_commands(&input, Some(1), &inner)
+                .unwrap(),
+            preview_layout(
+                "Safe Transaction",
+                "Safe execTransaction",
+                &format!("Call {TARGET:?}"),
+                None,
+                vec![
+                    address_field("To", &addr(TARGET), None, None),
+                    amount_field("Value", "0", Some("ETH")),
+                    text_field("Operation", "Call"),
+                    text_field("Inner", &format!("{TARGET:?} 0xdead")),
+                    number_field("Safe Tx Gas", "0"),
+                    text_field("Signatures", "2"),
+                ],
+            )
+        );
+        assert_eq!(
+            delegatecall_warning(&input, Some(1), &ContractRegistry::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_delegatecall_is_flagged() {
+        let input = exec(TARGET, vec![0xde, 0xad], DELEGATE_CALL);
+        let contracts = ContractRegistry::default();
+        let warning = delegatecall_warning(&input, Some(1), &contracts).unwrap();
+        assert_eq!(warning.label(), "Warning");
+        assert!(warning.fallback_text().contains("DELEGATECALL"));
+
+        let field = SafeVisualizer {}
+            .visualize_tx_commands(&input, Some(1), &inner)
+            .unwrap();
+        assert_eq!(field.fallback_text(), &format!("Delegatecall {TARGET:?}"));
+
+        // Batching through Safe's own MultiSend is the expected delegatecall
+        let multi_send = alloy_primitives::address!("0x40a2accbd92bca938b02010e17a5b8929b49130d");
+        let input = exec(multi_send, vec![0xde, 0xad], DELEGATE_CALL);
+        assert_eq!(delegatecall_warning(&input, Some(1), &contracts), None);
+        assert!(delegatecall_warning(&input, Some(56), &contracts).is_some());
+    }
+
+    #[test]
+    fn test_owner_management() {
+        let input = ISafe::addOwnerWithThresholdCall {
+            owner: TARGET,
+            _threshold: U256::from(2u64),
+        }
+        .abi_encode();
+        let field = SafeVisualizer {}
+            .visualize_tx_commands(&input, Some(1), &inner)
+            .unwrap();
+        assert_eq!(
+            field.fallback_text(),
+            &format!("Add owner {TARGET:?} and require 2 confirmations")
+        );
+
+        let input = ISafe::changeThresholdCall {
+            _threshold: U256::from(3u64),
+        }
+        .abi_encode();
+        let field = SafeVisualizer {}
+            .visualize_tx_commands(&input, Some(1), &inner)
+            .unwrap();
+        assert_eq!(field.label(), "Safe ChangeThreshold");
+        assert_eq!(field.fallback_text(), "Require 3 confirmations");
+    }
+}
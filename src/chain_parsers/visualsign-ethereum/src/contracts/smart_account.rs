@@ -0,0 +1,339 @@
+//! The `execute` entry points smart accounts expose to their EntryPoint.
+//!
+//! A UserOperation's `callData` is a call from the EntryPoint to the account
+//! itself, which then makes the calls the owner actually asked for. Each
+//! account family encodes those differently; this unpacks them into the same
+//! call list used for batches.
+
+use alloy_primitives::{Address, B256, Bytes, U256};
+use alloy_sol_types::{SolCall, SolValue, sol};
+use visualsign::{AnnotatedPayloadField, SignablePayloadField};
+
+use crate::contracts::batch::{SubCall, calls_layout};
+
+sol! {
+    // From: https://github.com/eth-infinitism/account-abstraction/blob/develop/contracts/samples/SimpleAccount.sol
+    interface ISimpleAccount {
+        function execute(address dest, uint256 value, bytes calldata func) external;
+        // v0.6 batches can't carry value
+        function executeBatch(address[] calldata dest, bytes[] calldata func) external;
+        function executeBatch(address[] calldata dest, uint256[] calldata value, bytes[] calldata func) external;
+    }
+
+    // From: https://github.com/safe-global/safe-modules/blob/main/modules/4337/contracts/Safe4337Module.sol
+    interface ISafe4337Module {
+        function executeUserOp(address to, uint256 value, bytes memory data, uint8 operation) external;
+        function executeUserOpWithErrorString(address to, uint256 value, bytes memory data, uint8 operation) external;
+    }
+
+    // From: https://github.com/zerodevapp/kernel/blob/v2.4/src/Kernel.sol
+    interface IKernelV2 {
+        struct Call { address to; uint256 value; bytes data; }
+
+        function execute(address to, uint256 value, bytes calldata data, uint8 operation) external payable;
+        function executeBatch(Call[] memory calls) external payable;
+    }
+
+    // ERC-7579 modular accounts, including Kernel v3
+    interface IERC7579Execution {
+        struct Execution { address target; uint256 value; bytes callData; }
+
+        function execute(bytes32 mode, bytes calldata executionCalldata) external payable;
+    }
+}
+
+/// ERC-7579 call types, the first byte of the execution mode.
+const CALL_TYPE_SINGLE: u8 = 0x00;
+const CALL_TYPE_BATCH: u8 = 0x01;
+const CALL_TYPE_DELEGATE: u8 = 0xff;
+/// The execution type, in the second byte: revert on failure, or carry on.
+const EXEC_TYPE_TRY: u8 = 0x01;
+
+/// Unpacks an ERC-7579 `executionCalldata` according to `mode`.
+fn decode_erc7579(mode: B256, execution: &[u8]) -> Option<Vec<SubCall>> {
+    let allow_failure = mode[1] == EXEC_TYPE_TRY;
+    let calls = match mode[0] {
+        // abi.encodePacked(target, value, callData)
+        CALL_TYPE_SINGLE => {
+            let target = Address::from_slice(execution.get(..20)?);
+            let value = U256::from_be_slice(execution.get(20..52)?);
+            vec![SubCall {
+                value,
+                ..SubCall::call(target, Bytes::copy_from_slice(&execution[52..]))
+            }]
+        }
+        CALL_TYPE_BATCH => Vec::<IERC7579Execution::Execution>::abi_decode(execution)
+            .ok()?
+            .into_iter()
+            .map(|call| SubCall {
+                value: call.value,
+                ..SubCall::call(call.target, call.callData)
+            })
+            .collect(),
+        // abi.encodePacked(target, callData)
+        CALL_TYPE_DELEGATE => {
+            let target = Address::from_slice(execution.get(..20)?);
+            vec![SubCall {
+                delegate_call: true,
+                ..SubCall::call(target, Bytes::copy_from_slice(&execution[20..]))
+            }]
+        }
+        _ => return None,
+    };
+    Some(
+        calls
+            .into_iter()
+            .map(|call| SubCall {
+                allow_failure,
+                ..call
+            })
+            .collect(),
+    )
+}
+
+/// A call with Safe's `operation` argument, where 1 means delegatecall.
+fn operation_call(to: Address, value: U256, data: Bytes, operation: u8) -> Option<SubCall> {
+    (operation <= 1).then(|| SubCall {
+        value,
+        delegate_call: operation == 1,
+        ..SubCall::call(to, data)
+    })
+}
+
+pub struct SmartAccountVisualizer {}
+
+impl SmartAccountVisualizer {
+    /// Decodes `input`, a call from the EntryPoint into a smart account on
+    /// `chain_id`. `decode_call` renders each call the account makes.
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: Option<u64>,
+        decode_call: &dyn Fn(Address, &[u8]) -> Vec<AnnotatedPayloadField>,
+    ) -> Option<SignablePayloadField> {
+        let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+        let (title, calls) = match selector {
+            ISimpleAccount::executeCall::SELECTOR => {
+                let call = ISimpleAccount::executeCall::abi_decode(input).ok()?;
+                let calls = vec![SubCall {
+                    value: call.value,
+                    ..SubCall::call(call.dest, call.func)
+                }];
+                ("SimpleAccount Execute", calls)
+            }
+            ISimpleAccount::executeBatch_0Call::SELECTOR => {
+                let call = ISimpleAccount::executeBatch_0Call::abi_decode(input).ok()?;
+                if call.dest.len() != call.func.len() {
+                    return None;
+                }
+                let calls = call
+                    .dest
+                    .into_iter()
+                    .zip(call.func)
+                    .map(|(dest, func)| SubCall::call(dest, func))
+                    .collect();
+                ("SimpleAccount Execute Batch", calls)
+            }
+            ISimpleAccount::executeBatch_1Call::SELECTOR => {
+                let call = ISimpleAccount::executeBatch_1Call::abi_decode(input).ok()?;
+                // An empty value array means no call sends value
+                if call.dest.len() != call.func.len()
+                    || !(call.value.is_empty() || call.value.len() == call.dest.len())
+                {
+                    return None;
+                }
+                let values = call.value.into_iter().chain(std::iter::repeat(U256::ZERO));
+                let calls = call
+                    .dest
+                    .into_iter()
+                    .zip(call.func)
+                    .zip(values)
+                    .map(|((dest, func), value)| SubCall {
+                        value,
+                        ..SubCall::call(dest, func)
+                    })
+                    .collect();
+                ("SimpleAccount Execute Batch", calls)
+            }
+            ISafe4337Module::executeUserOpCall::SELECTOR => {
+                let call = ISafe4337Module::executeUserOpCall::abi_decode(input).ok()?;
+                let calls = vec![operation_call(
+                    call.to,
+                    call.value,
+                    call.data,
+                    call.operation,
+                )?];
+                ("Safe 4337 Execute", calls)
+            }
+            ISafe4337Module::executeUserOpWithErrorStringCall::SELECTOR => {
+                let call =
+                    ISafe4337Module::executeUserOpWithErrorStringCall::abi_decode(input).ok()?;
+                let calls = vec![operation_call(
+                    call.to,
+                    call.value,
+                    call.data,
+                    call.operation,
+                )?];
+                ("Safe 4337 Execute", calls)
+            }
+            IKernelV2::executeCall::SELECTOR => {
+                let call = IKernelV2::executeCall::abi_decode(input).ok()?;
+                let calls = vec![operation_call(
+                    call.to,
+                    call.value,
+                    call.data,
+                    call.operation,
+                )?];
+                ("Kernel Execute", calls)
+            }
+            // Coinbase Smart Wallet's executeBatch has the same signature
+            IKernelV2::executeBatchCall::SELECTOR => {
+                let call = IKernelV2::executeBatchCall::abi_decode(input).ok()?;
+                let calls = call
+                    .calls
+                    .into_iter()
+                    .map(|c| SubCall {
+                        value: c.value,
+                        ..SubCall::call(c.to, c.data)
+                    })
+                    .collect();
+                ("Account Execute Batch", calls)
+            }
+            IERC7579Execution::executeCall::SELECTOR => {
+                let call = IERC7579Execution::executeCall::abi_decode(input).ok()?;
+                (
+                    "ERC-7579 Execute",
+                    decode_erc7579(call.mode, &call.executionCalldata)?,
+                )
+            }
+            _ => return None,
+        };
+        Some(calls_layout(
+            title,
+            &calls,
+            Vec::new(),
+            chain_id,
+            decode_call,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fields::text_field;
+
+    const TOKEN: Address = Address::repeat_byte(0x02);
+    const VAULT: Address = Address::repeat_byte(0x03);
+
+    fn decode(to: Address, data: &[u8]) -> Vec<AnnotatedPayloadField> {
+        vec![text_field(
+            "Input Data",
+            &format!("{to:?} 0x{}", hex::encode(data)),
+        )]
+    }
+
+    fn summary(input: &[u8]) -> Option<String> {
+        SmartAccountVisualizer {}
+            .visualize_tx_commands(input, Some(1), &decode)
+            .map(|field| field.fallback_text().clone())
+    }
+
+    #[test]
+    fn test_simple_account_batches() {
+        let input = ISimpleAccount::executeBatch_1Call {
+            dest: vec![TOKEN, VAULT],
+            value: vec![],
+            func: vec![
+                Bytes::from(vec![0xaa, 0, 0, 0]),
+                Bytes::from(vec![0xbb, 0, 0, 0]),
+            ],
+        }
+        .abi_encode();
+        assert_eq!(summary(&input).unwrap(), "2 calls: 0xaa000000, 0xbb000000");
+
+        // Every destination needs its calldata
+        let input = ISimpleAccount::executeBatch_0Call {
+            dest: vec![TOKEN, VAULT],
+            func: vec![Bytes::from(vec![0xaa, 0, 0, 0])],
+        }
+        .abi_encode();
+        assert_eq!(summary(&input), None);
+    }
+
+    #[test]
+    fn test_safe_and_kernel_operations() {
+        let input = ISafe4337Module::executeUserOpCall {
+            to: VAULT,
+            value: U256::ZERO,
+            data: Bytes::from(vec![0xaa, 0, 0, 0]),
+            operation: 1,
+        }
+        .abi_encode();
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } = SmartAccountVisualizer {}
+            .visualize_tx_commands(&input, Some(1), &decode)
+            .unwrap()
+        else {
+            panic!("expected a preview layout");
+        };
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } =
+            &preview_layout.expanded.unwrap().fields[0].signable_payload_field
+        else {
+            panic!("expected a preview layout");
+        };
+        let labels: Vec<String> = preview_layout
+            .expanded
+            .as_ref()
+            .unwrap()
+            .fields
+            .iter()
+            .map(|f| f.signable_payload_field.label().clone())
+            .collect();
+        assert_eq!(labels, vec!["Target", "Operation", "Input Data"]);
+
+        let input = IKernelV2::executeCall {
+            to: VAULT,
+            value: U256::ZERO,
+            data: Bytes::new(),
+            operation: 2,
+        }
+        .abi_encode();
+        assert_eq!(summary(&input), None);
+    }
+
+    #[test]
+    fn test_erc7579_modes() {
+        let mut single = TOKEN.to_vec();
+        single.extend_from_slice(&U256::from(1u64).to_be_bytes::<32>());
+        single.extend_from_slice(&[0xaa, 0xbb, 0xcc, 0xdd]);
+        let input = IERC7579Execution::executeCall {
+            mode: B256::ZERO,
+            executionCalldata: Bytes::from(single),
+        }
+        .abi_encode();
+        assert_eq!(summary(&input).unwrap(), "1 call: 0xaabbccdd");
+
+        let executions = vec![
+            IERC7579Execution::Execution {
+                target: TOKEN,
+                value: U256::ZERO,
+                callData: Bytes::from(vec![0xaa, 0, 0, 0]),
+            },
+            IERC7579Execution::Execution {
+                target: VAULT,
+                value: U256::ZERO,
+                callData: Bytes::new(),
+            },
+        ];
+        let mut mode = B256::ZERO;
+        mode[0] = CALL_TYPE_BATCH;
+        mode[1] = EXEC_TYPE_TRY;
+        let calls = decode_erc7579(mode, &executions.abi_encode()).unwrap();
+        assert_eq!(calls.len(), 2);
+        assert!(calls.iter().all(|call| call.allow_failure));
+        assert_eq!(calls[1].target, VAULT);
+
+        mode[0] = 0x02;
+        assert!(decode_erc7579(mode, &executions.abi_encode()).is_none());
+    }
+}
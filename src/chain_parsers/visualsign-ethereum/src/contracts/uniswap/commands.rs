@@ -0,0 +1,554 @@
+//! Decodes the input of each Universal Router command into readable fields.
+//!
+//! Input layouts follow `Dispatcher.sol` in Uniswap's universal-router. Each
+//! command decodes to a one-line summary plus the fields behind it; inputs that
+//! don't match their command's layout are left for the caller to show raw.
+
+use alloy_primitives::{Address, U160, U256, Uint};
+use alloy_sol_types::{SolValue, sol};
+use visualsign::AnnotatedPayloadField;
+
+use super::{Command, v4};
+use crate::fields::{
+    addr, address_field, amount_field, annotated, preview_layout, text_field, with_annotation,
+};
+use crate::fmt::{format_timestamp, format_token_amount};
+use crate::tokens::{Token, TokenRegistry, format_amount, token_name};
+
+sol! {
+    struct V3SwapParams { address recipient; uint256 amount; uint256 limit; bytes path; bool payerIsUser; }
+    struct V2SwapParams { address recipient; uint256 amount; uint256 limit; address[] path; bool payerIsUser; }
+    struct TokenRecipientAmount { address token; address recipient; uint256 amount; }
+    struct RecipientAmount { address recipient; uint256 amount; }
+    struct BalanceCheckParams { address owner; address token; uint256 minBalance; }
+    struct SubPlanParams { bytes commands; bytes[] inputs; }
+
+    struct PermitDetails { address token; uint160 amount; uint48 expiration; uint48 nonce; }
+    struct PermitSingle { PermitDetails details; address spender; uint256 sigDeadline; }
+    struct PermitBatch { PermitDetails[] details; address spender; uint256 sigDeadline; }
+    struct PermitParams { PermitSingle permitSingle; bytes signature; }
+    struct PermitBatchParams { PermitBatch permitBatch; bytes signature; }
+    struct AllowanceTransferDetails { address from; address to; uint160 amount; address token; }
+    struct TransferFromBatchParams { AllowanceTransferDetails[] batchDetails; }
+
+    struct PoolKey { address currency0; address currency1; uint24 fee; int24 tickSpacing; address hooks; }
+    struct InitializePoolParams { PoolKey poolKey; uint160 sqrtPriceX96; }
+}
+
+/// Recipient meaning "whoever called the router" (`ActionConstants.MSG_SENDER`).
+const MSG_SENDER: Address = Address::with_last_byte(1);
+/// Recipient meaning "the router itself" (`ActionConstants.ADDRESS_THIS`).
+const ADDRESS_THIS: Address = Address::with_last_byte(2);
+/// Amount meaning "the router's whole balance" (`ActionConstants.CONTRACT_BALANCE`).
+const CONTRACT_BALANCE: U256 = U256::from_limbs([0, 0, 0, 1 << 63]);
+
+/// Sub-plans can nest; deeper plans are shown raw rather than decoded.
+const MAX_SUB_PLAN_DEPTH: usize = 4;
+
+/// What a decoded command or action looks like in the payload.
+pub(super) struct CommandView {
+    pub summary: String,
+    pub fields: Vec<AnnotatedPayloadField>,
+}
+
+/// Tokens are resolved against the chain the transaction is for.
+#[derive(Clone, Copy)]
+pub(super) struct Context<'a> {
+    pub tokens: &'a TokenRegistry,
+    pub chain_id: Option<u64>,
+    pub depth: usize,
+}
+
+impl<'a> Context<'a> {
+    pub(super) fn token(&self, address: Address) -> Option<Token<'a>> {
+        self.tokens.token(self.chain_id, address)
+    }
+
+    pub(super) fn currency(&self, address: Address) -> Option<Token<'a>> {
+        self.tokens.currency(self.chain_id, address)
+    }
+
+    /// Text for `amount` of `token`, spelling out the router's sentinel amounts.
+    pub(super) fn amount_text(
+        &self,
+        token: Option<Token<'_>>,
+        address: Address,
+        amount: U256,
+    ) -> String {
+        if amount == CONTRACT_BALANCE {
+            format!("the router's entire {} balance", token_name(token, address))
+        } else {
+            format_amount(token, address, amount)
+        }
+    }
+
+    pub(super) fn amount_field(
+        &self,
+        label: &str,
+        token: Option<Token<'_>>,
+        address: Address,
+        amount: U256,
+    ) -> AnnotatedPayloadField {
+        match token {
+            _ if amount == CONTRACT_BALANCE => {
+                text_field(label, &self.amount_text(token, address, amount))
+            }
+            Some(token) => amount_field(
+                label,
+                &format_token_amount(amount, token.decimals),
+                Some(token.symbol),
+            ),
+            None => with_annotation(
+                amount_field(label, &amount.to_string(), None),
+                &format!("Raw amount of {address:?}"),
+            ),
+        }
+    }
+}
+
+pub(super) fn token_field(
+    label: &str,
+    token: Option<Token<'_>>,
+    address: Address,
+) -> AnnotatedPayloadField {
+    address_field(label, &addr(address), token.map(|t| t.symbol), None)
+}
+
+pub(super) fn recipient_name(recipient: Address) -> String {
+    match recipient {
+        MSG_SENDER => "Sender".to_string(),
+        ADDRESS_THIS => "Router".to_string(),
+        other => addr(other),
+    }
+}
+
+pub(super) fn recipient_field(label: &str, recipient: Address) -> AnnotatedPayloadField {
+    let name = match recipient {
+        MSG_SENDER => Some("Sender"),
+        ADDRESS_THIS => Some("Router"),
+        _ => None,
+    };
+    address_field(label, &addr(recipient), name, None)
+}
+
+/// Fee tiers are in hundredths of a basis point: 3000 is 0.3%.
+pub(super) fn fee_text(fee: u32) -> String {
+    format!("{}%", format_token_amount(U256::from(fee), 4))
+}
+
+/// Decodes one command's input; `None` if the input doesn't fit the command.
+pub(super) fn decode_command(
+    command: Command,
+    input: &[u8],
+    ctx: Context<'_>,
+) -> Option<CommandView> {
+    match command {
+        Command::V3SwapExactIn | Command::V3SwapExactOut => {
+            let params = V3SwapParams::abi_decode_params(input).ok()?;
+            let (swap, route) = v3_swap(
+                ctx,
+                command == Command::V3SwapExactIn,
+                &params.path,
+                params.amount,
+                params.limit,
+            )?;
+            Some(swap.view(ctx, params.recipient, route))
+        }
+        Command::V2SwapExactIn | Command::V2SwapExactOut => {
+            let params = V2SwapParams::abi_decode_params(input).ok()?;
+            let (swap, route) = v2_swap(
+                ctx,
+                command == Command::V2SwapExactIn,
+                &params.path,
+                params.amount,
+                params.limit,
+            )?;
+            Some(swap.view(ctx, params.recipient, route))
+        }
+        Command::WrapEth | Command::UnwrapWeth => {
+            let params = RecipientAmount::abi_decode_params(input).ok()?;
+            let native = ctx.currency(Address::ZERO);
+            let summary = if command == Command::WrapEth {
+                format!(
+                    "Wrap {}",
+                    ctx.amount_text(native, Address::ZERO, params.amount)
+                )
+            } else {
+                format!(
+                    "Unwrap at least {} to {}",
+                    ctx.amount_text(native, Address::ZERO, params.amount),
+                    recipient_name(params.recipient)
+                )
+            };
+            let label = if command == Command::WrapEth {
+                "Amount"
+            } else {
+                "Minimum Amount"
+            };
+            Some(CommandView {
+                summary,
+                fields: vec![
+                    ctx.amount_field(label, native, Address::ZERO, params.amount),
+                    recipient_field("Recipient", params.recipient),
+                ],
+            })
+        }
+        Command::Sweep | Command::Transfer | Command::Permit2TransferFrom => {
+            let params = TokenRecipientAmount::abi_decode_params(input).ok()?;
+            let token = ctx.token(params.token);
+            let amount = ctx.amount_text(token, params.token, params.amount);
+            let recipient = recipient_name(params.recipient);
+            let (summary, amount_label) = match command {
+                Command::Sweep => (
+                    format!("Sweep at least {amount} to {recipient}"),
+                    "Minimum Amount",
+                ),
+                Command::Transfer => (format!("Send {amount} to {recipient}"), "Amount"),
+                _ => (
+                    format!("Pull {amount} from the sender via Permit2 to {recipient}"),
+                    "Amount",
+                ),
+            };
+            Some(CommandView {
+                summary,
+                fields: vec![
+                    token_field("Token", token, params.token),
+                    ctx.amount_field(amount_label, token, params.token, params.amount),
+                    recipient_field("Recipient", params.recipient),
+                ],
+            })
+        }
+        Command::PayPortion => {
+            let params = TokenRecipientAmount::abi_decode_params(input).ok()?;
+            let token = ctx.token(params.token);
+            // The amount is a share of the router's balance, in basis points
+            let portion = format!("{}%", format_token_amount(params.amount, 2));
+            Some(CommandView {
+                summary: format!(
+                    "Pay {portion} of the router's {} to {}",
+                    token_name(token, params.token),
+                    recipient_name(params.recipient)
+                ),
+                fields: vec![
+                    token_field("Token", token, params.token),
+                    text_field("Portion", &portion),
+                    recipient_field("Recipient", params.recipient),
+                ],
+            })
+        }
+        Command::BalanceCheckErc20 => {
+            let params = BalanceCheckParams::abi_decode_params(input).ok()?;
+            let token = ctx.token(params.token);
+            Some(CommandView {
+                summary: format!(
+                    "Require {} to hold at least {}",
+                    recipient_name(params.owner),
+                    ctx.amount_text(token, params.token, params.minBalance)
+                ),
+                fields: vec![
+                    recipient_field("Owner", params.owner),
+                    token_field("Token", token, params.token),
+                    ctx.amount_field("Minimum Balance", token, params.token, params.minBalance),
+                ],
+            })
+        }
+        Command::Permit2Permit => {
+            let params = PermitParams::abi_decode_params(input).ok()?;
+            let permit = params.permitSingle;
+            let (summary, mut fields) = permit_details_view(ctx, &permit.details);
+            fields.push(address_field("Spender", &addr(permit.spender), None, None));
+            fields.push(text_field(
+                "Signature Deadline",
+                &format_timestamp(permit.sigDeadline),
+            ));
+            Some(CommandView {
+                summary: format!("Permit2: allow {:?} to spend {summary}", permit.spender),
+                fields,
+            })
+        }
+        Command::Permit2PermitBatch => {
+            let params = PermitBatchParams::abi_decode_params(input).ok()?;
+            let permit = params.permitBatch;
+            let mut fields = Vec::new();
+            for (i, details) in permit.details.iter().enumerate() {
+                let (summary, expanded) = permit_details_view(ctx, details);
+                let label = format!("Permit {}", i + 1);
+                fields.push(annotated(preview_layout(
+                    &label, &label, &summary, None, expanded,
+                )));
+            }
+            fields.push(address_field("Spender", &addr(permit.spender), None, None));
+            fields.push(text_field(
+                "Signature Deadline",
+                &format_timestamp(permit.sigDeadline),
+            ));
+            Some(CommandView {
+                summary: format!(
+                    "Permit2: allow {:?} to spend {} tokens",
+                    permit.spender,
+                    permit.details.len()
+                ),
+                fields,
+            })
+        }
+        Command::Permit2TransferFromBatch => {
+            let params = TransferFromBatchParams::abi_decode_params(input).ok()?;
+            let fields = params
+                .batchDetails
+                .iter()
+                .enumerate()
+                .map(|(i, transfer)| {
+                    let token = ctx.token(transfer.token);
+                    let amount =
+                        ctx.amount_text(token, transfer.token, U256::from(transfer.amount));
+                    let label = format!("Transfer {}", i + 1);
+                    let subtitle = format!(
+                        "{amount} from {:?} to {}",
+                        transfer.from,
+                        recipient_name(transfer.to)
+                    );
+                    let expanded = vec![
+                        token_field("Token", token, transfer.token),
+                        ctx.amount_field(
+                            "Amount",
+                            token,
+                            transfer.token,
+                            U256::from(transfer.amount),
+                        ),
+                        address_field("From", &addr(transfer.from), None, None),
+                        recipient_field("To", transfer.to),
+                    ];
+                    annotated(preview_layout(&label, &label, &subtitle, None, expanded))
+                })
+                .collect();
+            Some(CommandView {
+                summary: format!(
+                    "Pull {} token transfers via Permit2",
+                    params.batchDetails.len()
+                ),
+                fields,
+            })
+        }
+        Command::V4Swap => v4::decode_v4_swap(input, ctx),
+        Command::V4InitializePool => {
+            let params = InitializePoolParams::abi_decode_params(input).ok()?;
+            let (summary, fields) = pool_key_view(ctx, &params.poolKey);
+            Some(CommandView {
+                summary: format!("Create the {summary} pool"),
+                fields,
+            })
+        }
+        Command::ExecuteSubPlan => {
+            if ctx.depth >= MAX_SUB_PLAN_DEPTH {
+                return None;
+            }
+            let params = SubPlanParams::abi_decode_params(input).ok()?;
+            let nested = Context {
+                depth: ctx.depth + 1,
+                ..ctx
+            };
+            let fields = super::command_fields(&params.commands, &params.inputs, nested);
+            Some(CommandView {
+                summary: format!("Run a sub-plan of {} commands", fields.len()),
+                fields,
+            })
+        }
+        // Position manager calls carry raw calldata for another contract
+        Command::V3PositionManagerPermit
+        | Command::V3PositionManagerCall
+        | Command::V4PositionManagerCall => None,
+    }
+}
+
+/// A swap of one token for another. `limit` is the minimum output of an
+/// exact-input swap, or the maximum input of an exact-output one.
+pub(super) struct Swap {
+    pub exact_in: bool,
+    pub token_in: Address,
+    pub token_out: Address,
+    pub amount: U256,
+    pub limit: U256,
+}
+
+impl Swap {
+    pub(super) fn summary(&self, ctx: Context<'_>) -> String {
+        let (known_in, known_out) = (ctx.currency(self.token_in), ctx.currency(self.token_out));
+        if self.exact_in {
+            format!(
+                "Swap {} for at least {}",
+                ctx.amount_text(known_in, self.token_in, self.amount),
+                ctx.amount_text(known_out, self.token_out, self.limit)
+            )
+        } else {
+            format!(
+                "Swap at most {} for {}",
+                ctx.amount_text(known_in, self.token_in, self.limit),
+                ctx.amount_text(known_out, self.token_out, self.amount)
+            )
+        }
+    }
+
+    pub(super) fn fields(&self, ctx: Context<'_>) -> Vec<AnnotatedPayloadField> {
+        let (known_in, known_out) = (ctx.currency(self.token_in), ctx.currency(self.token_out));
+        let amounts = if self.exact_in {
+            [
+                ctx.amount_field("Amount In", known_in, self.token_in, self.amount),
+                ctx.amount_field("Minimum Amount Out", known_out, self.token_out, self.limit),
+            ]
+        } else {
+            [
+                ctx.amount_field("Maximum Amount In", known_in, self.token_in, self.limit),
+                ctx.amount_field("Amount Out", known_out, self.token_out, self.amount),
+            ]
+        };
+        let mut fields = vec![
+            token_field("Token In", known_in, self.token_in),
+            token_field("Token Out", known_out, self.token_out),
+        ];
+        fields.extend(amounts);
+        fields
+    }
+
+    fn view(&self, ctx: Context<'_>, recipient: Address, route: String) -> CommandView {
+        let mut fields = self.fields(ctx);
+        fields.push(text_field("Route", &route));
+        fields.push(recipient_field("Recipient", recipient));
+        CommandView {
+            summary: self.summary(ctx),
+            fields,
+        }
+    }
+}
+
+/// A V3 swap along an encoded `path`, and its route with each hop's fee tier.
+pub(super) fn v3_swap(
+    ctx: Context<'_>,
+    exact_in: bool,
+    path: &[u8],
+    amount: U256,
+    limit: U256,
+) -> Option<(Swap, String)> {
+    let (mut tokens, mut fees) = decode_v3_path(path)?;
+    // Exact-output paths run backwards, from the output token
+    if !exact_in {
+        tokens.reverse();
+        fees.reverse();
+    }
+    let mut route = token_name(ctx.token(tokens[0]), tokens[0]);
+    for (fee, token) in fees.iter().zip(&tokens[1..]) {
+        route.push_str(&format!(
+            " > {} > {}",
+            fee_text(*fee),
+            token_name(ctx.token(*token), *token)
+        ));
+    }
+    let swap = Swap {
+        exact_in,
+        token_in: tokens[0],
+        token_out: tokens[tokens.len() - 1],
+        amount,
+        limit,
+    };
+    Some((swap, route))
+}
+
+/// A V2 swap along `path`, a list of tokens, and its route.
+pub(super) fn v2_swap(
+    ctx: Context<'_>,
+    exact_in: bool,
+    path: &[Address],
+    amount: U256,
+    limit: U256,
+) -> Option<(Swap, String)> {
+    if path.len() < 2 {
+        return None;
+    }
+    let route = path
+        .iter()
+        .map(|token| token_name(ctx.token(*token), *token))
+        .collect::<Vec<_>>()
+        .join(" > ");
+    let swap = Swap {
+        exact_in,
+        token_in: path[0],
+        token_out: path[path.len() - 1],
+        amount,
+        limit,
+    };
+    Some((swap, route))
+}
+
+/// Splits a V3 path, `token (fee token)*`, into its tokens and fee tiers.
+fn decode_v3_path(path: &[u8]) -> Option<(Vec<Address>, Vec<u32>)> {
+    const HOP: usize = 3 + 20;
+    if path.len() < 20 + HOP || !(path.len() - 20).is_multiple_of(HOP) {
+        return None;
+    }
+    let mut tokens = vec![Address::from_slice(&path[..20])];
+    let mut fees = Vec::new();
+    for hop in path[20..].chunks(HOP) {
+        fees.push(u32::from_be_bytes([0, hop[0], hop[1], hop[2]]));
+        tokens.push(Address::from_slice(&hop[3..]));
+    }
+    Some((tokens, fees))
+}
+
+fn permit_details_view(
+    ctx: Context<'_>,
+    details: &PermitDetails,
+) -> (String, Vec<AnnotatedPayloadField>) {
+    let token = ctx.token(details.token);
+    let (amount_text, amount) = if details.amount == U160::MAX {
+        (
+            format!("unlimited {}", token_name(token, details.token)),
+            with_annotation(
+                text_field("Amount", "Unlimited"),
+                "Permit2 allowance with no cap",
+            ),
+        )
+    } else {
+        let amount = U256::from(details.amount);
+        (
+            ctx.amount_text(token, details.token, amount),
+            ctx.amount_field("Amount", token, details.token, amount),
+        )
+    };
+    let expiration = if details.expiration == Uint::<48, 1>::MAX {
+        "Never".to_string()
+    } else {
+        format_timestamp(U256::from(details.expiration))
+    };
+    let fields = vec![
+        token_field("Token", token, details.token),
+        amount,
+        text_field("Expires", &expiration),
+        text_field("Nonce", &details.nonce.to_string()),
+    ];
+    (format!("{amount_text} until {expiration}"), fields)
+}
+
+pub(super) fn pool_key_view(
+    ctx: Context<'_>,
+    key: &PoolKey,
+) -> (String, Vec<AnnotatedPayloadField>) {
+    let (currency0, currency1) = (ctx.currency(key.currency0), ctx.currency(key.currency1));
+    let fee = fee_text(key.fee.to::<u32>());
+    let mut fields = vec![
+        token_field("Currency 0", currency0, key.currency0),
+        token_field("Currency 1", currency1, key.currency1),
+        text_field("Fee", &fee),
+        text_field("Tick Spacing", &key.tickSpacing.to_string()),
+    ];
+    if key.hooks != Address::ZERO {
+        fields.push(with_annotation(
+            address_field("Hooks", &addr(key.hooks), None, None),
+            "The pool runs this contract's code on every swap",
+        ));
+    }
+    let summary = format!(
+        "{}/{} {fee}",
+        token_name(currency0, key.currency0),
+        token_name(currency1, key.currency1)
+    );
+    (summary, fields)
+}
@@ -1,7 +1,22 @@
+use alloy_primitives::Bytes;
 use alloy_sol_types::{SolCall as _, sol};
 use chrono::{TimeZone, Utc};
 use num_enum::TryFromPrimitive;
-use visualsign::{SignablePayloadField, SignablePayloadFieldCommon, SignablePayloadFieldTextV2};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon,
+    SignablePayloadFieldTextV2,
+};
+
+use crate::fields::{annotated, with_annotation};
+use crate::tokens::TokenRegistry;
+
+mod commands;
+mod routers;
+mod v4;
+
+pub use routers::{
+    NonfungiblePositionManagerVisualizer, UniswapV2RouterVisualizer, UniswapV3RouterVisualizer,
+};
 
 // From: https://github.com/Uniswap/universal-router/blob/main/contracts/interfaces/IUniversalRouter.sol
 sol! {
@@ -43,20 +58,100 @@ pub enum Command {
     ExecuteSubPlan = 0x21,
 }
 
+/// Command bytes carry flags above the command type.
+const COMMAND_TYPE_MASK: u8 = 0x3f;
+/// A failing command with this flag set doesn't revert the whole transaction.
+const FLAG_ALLOW_REVERT: u8 = 0x80;
+
+impl Command {
+    fn from_byte(byte: u8) -> Option<Self> {
+        Self::try_from(byte & COMMAND_TYPE_MASK).ok()
+    }
+}
+
 fn map_commands(raw: &[u8]) -> Vec<Command> {
-    let mut out = Vec::with_capacity(raw.len());
-    for &b in raw {
-        if let Ok(cmd) = Command::try_from(b) {
-            out.push(cmd);
-        }
+    raw.iter().filter_map(|&b| Command::from_byte(b)).collect()
+}
+
+/// One field per recognized command, each paired with the input at the same
+/// position in `inputs`.
+fn command_fields(
+    commands: &[u8],
+    inputs: &[Bytes],
+    ctx: commands::Context<'_>,
+) -> Vec<AnnotatedPayloadField> {
+    let mut fields = Vec::new();
+    for (position, &byte) in commands.iter().enumerate() {
+        let Some(cmd) = Command::from_byte(byte) else {
+            continue;
+        };
+        let input = inputs.get(position);
+        let label = format!("Command {}", fields.len() + 1);
+        let decoded = input.and_then(|input| commands::decode_command(cmd, input, ctx));
+        let field = match decoded {
+            Some(view) => SignablePayloadField::PreviewLayout {
+                common: SignablePayloadFieldCommon {
+                    fallback_text: format!("{cmd:?}: {}", view.summary),
+                    label,
+                },
+                preview_layout: visualsign::SignablePayloadFieldPreviewLayout {
+                    title: Some(visualsign::SignablePayloadFieldTextV2 {
+                        text: format!("{cmd:?}"),
+                    }),
+                    subtitle: Some(visualsign::SignablePayloadFieldTextV2 { text: view.summary }),
+                    condensed: None,
+                    expanded: Some(visualsign::SignablePayloadFieldListLayout {
+                        fields: view.fields,
+                    }),
+                },
+            },
+            None => {
+                let input_hex = input
+                    .map(|b| format!("0x{}", hex::encode(&b.0)))
+                    .unwrap_or_else(|| "None".to_string());
+                SignablePayloadField::PreviewLayout {
+                    common: SignablePayloadFieldCommon {
+                        fallback_text: format!("{cmd:?} input: {input_hex}"),
+                        label,
+                    },
+                    preview_layout: visualsign::SignablePayloadFieldPreviewLayout {
+                        title: Some(visualsign::SignablePayloadFieldTextV2 {
+                            text: format!("{cmd:?}"),
+                        }),
+                        subtitle: Some(visualsign::SignablePayloadFieldTextV2 {
+                            text: format!("Input: {input_hex}"),
+                        }),
+                        condensed: None,
+                        expanded: None,
+                    },
+                }
+            }
+        };
+        let field = annotated(field);
+        fields.push(if byte & FLAG_ALLOW_REVERT != 0 {
+            with_annotation(field, "Allowed to fail without reverting the transaction")
+        } else {
+            field
+        });
     }
-    out
+    fields
 }
 
 pub struct UniswapV4Visualizer {}
 
 impl UniswapV4Visualizer {
     pub fn visualize_tx_commands(&self, input: &[u8]) -> Option<SignablePayloadField> {
+        self.visualize_tx_commands_on_chain(input, None, &TokenRegistry::empty())
+    }
+
+    /// Like [`Self::visualize_tx_commands`], resolving token symbols and
+    /// decimals on `chain_id` through `tokens`.
+    pub fn visualize_tx_commands_on_chain(
+        &self,
+        input: &[u8],
+        chain_id: Option<u64>,
+        tokens: &TokenRegistry,
+    ) -> Option<SignablePayloadField> {
         if input.len() < 4 {
             return None;
         }
@@ -73,42 +168,22 @@ impl UniswapV4Visualizer {
                 None
             };
             let mapped = map_commands(&call.commands.0);
-            let mut detail_fields = Vec::new();
-
-            for (i, cmd) in mapped.iter().enumerate() {
-                let input_hex = call
-                    .inputs
-                    .get(i)
-                    .map(|b| format!("0x{}", hex::encode(&b.0)))
-                    .unwrap_or_else(|| "None".to_string()); // TODO: decode into readable values
-
-                detail_fields.push(SignablePayloadField::PreviewLayout {
-                    common: SignablePayloadFieldCommon {
-                        fallback_text: format!("{cmd:?} input: {input_hex}"),
-                        label: format!("Command {}", i + 1),
-                    },
-                    preview_layout: visualsign::SignablePayloadFieldPreviewLayout {
-                        title: Some(visualsign::SignablePayloadFieldTextV2 {
-                            text: format!("{cmd:?}"),
-                        }),
-                        subtitle: Some(visualsign::SignablePayloadFieldTextV2 {
-                            text: format!("Input: {input_hex}"),
-                        }),
-                        condensed: None,
-                        expanded: None,
-                    },
-                });
-            }
+            let ctx = commands::Context {
+                tokens,
+                chain_id,
+                depth: 0,
+            };
+            let mut detail_fields = command_fields(&call.commands.0, &call.inputs, ctx);
 
             // Deadline field (optional)
             if let Some(dl) = &deadline {
-                detail_fields.push(SignablePayloadField::TextV2 {
+                detail_fields.push(annotated(SignablePayloadField::TextV2 {
                     common: SignablePayloadFieldCommon {
                         fallback_text: dl.clone(),
                         label: "Deadline".to_string(),
                     },
                     text_v2: SignablePayloadFieldTextV2 { text: dl.clone() },
-                });
+                }));
             }
 
             return Some(SignablePayloadField::PreviewLayout {
@@ -144,14 +219,7 @@ impl UniswapV4Visualizer {
                     },
                     condensed: None,
                     expanded: Some(visualsign::SignablePayloadFieldListLayout {
-                        fields: detail_fields
-                            .into_iter()
-                            .map(|f| visualsign::AnnotatedPayloadField {
-                                signable_payload_field: f,
-                                static_annotation: None,
-                                dynamic_annotation: None,
-                            })
-                            .collect(),
+                        fields: detail_fields,
                     }),
                 },
             });
@@ -163,7 +231,8 @@ impl UniswapV4Visualizer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::{Bytes, U256};
+    use alloy_primitives::{Address, Bytes, U256, address};
+    use alloy_sol_types::SolValue;
     use visualsign::{
         AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon,
         SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout,
@@ -442,7 +511,8 @@ mod tests {
 
     #[test]
     fn test_visualize_tx_commands_unrecognized_command() {
-        // 0xff is not a valid Command, so it should be skipped
+        // 0xff is not a valid Command, so it should be skipped, but its input
+        // still belongs to it rather than the next command
         let commands = vec![0xff, Command::Transfer as u8];
         let inputs = vec![vec![0x01], vec![0x02]];
         let deadline = 0u64;
@@ -469,7 +539,7 @@ mod tests {
                         fields: vec![AnnotatedPayloadField {
                             signable_payload_field: SignablePayloadField::PreviewLayout {
                                 common: SignablePayloadFieldCommon {
-                                    fallback_text: "Transfer input: 0x01".to_string(),
+                                    fallback_text: "Transfer input: 0x02".to_string(),
                                     label: "Command 1".to_string(),
                                 },
                                 preview_layout: SignablePayloadFieldPreviewLayout {
@@ -477,7 +547,7 @@ mod tests {
                                         text: "Transfer".to_string(),
                                     }),
                                     subtitle: Some(SignablePayloadFieldTextV2 {
-                                        text: "Input: 0x01".to_string(),
+                                        text: "Input: 0x02".to_string(),
                                     }),
                                     condensed: None,
                                     expanded: None,
@@ -491,4 +561,184 @@ mod tests {
             }
         );
     }
+
+    const WETH: &str = "c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2";
+    const USDC: &str = "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+
+    /// Fallback text of each command in a decoded `execute` call, and of the
+    /// fields inside each.
+    fn command_texts(field: SignablePayloadField) -> Vec<(String, Vec<String>)> {
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } = field else {
+            panic!("expected a preview layout");
+        };
+        preview_layout
+            .expanded
+            .unwrap()
+            .fields
+            .into_iter()
+            .map(|f| match f.signable_payload_field {
+                SignablePayloadField::PreviewLayout {
+                    common,
+                    preview_layout,
+                } => (
+                    common.fallback_text,
+                    preview_layout
+                        .expanded
+                        .map(|l| {
+                            l.fields
+                                .into_iter()
+                                .map(|f| f.signable_payload_field.fallback_text().clone())
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                ),
+                other => (other.fallback_text().clone(), Vec::new()),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_decode_v3_swap_and_sweep() {
+        let path = hex::decode(format!("{WETH}000bb8{USDC}")).unwrap();
+        let swap = commands::V3SwapParams {
+            recipient: Address::with_last_byte(1),
+            amount: U256::from(10u64).pow(U256::from(18u64)),
+            limit: U256::from(2_500_000_000u64),
+            path: Bytes::from(path),
+            payerIsUser: true,
+        }
+        .abi_encode_params();
+        let sweep = commands::TokenRecipientAmount {
+            token: USDC.parse().unwrap(),
+            recipient: address!("0x1111111111111111111111111111111111111111"),
+            amount: U256::ZERO,
+        }
+        .abi_encode_params();
+        let commands = [
+            Command::V3SwapExactIn as u8,
+            Command::Sweep as u8 | FLAG_ALLOW_REVERT,
+        ];
+        let input = encode_execute_call(&commands, vec![swap, sweep], 0);
+
+        let texts = command_texts(
+            UniswapV4Visualizer {}
+                .visualize_tx_commands_on_chain(&input, Some(1), &TokenRegistry::default())
+                .unwrap(),
+        );
+        assert_eq!(
+            texts[0],
+            (
+                "V3SwapExactIn: Swap 1 WETH for at least 2500 USDC".to_string(),
+                vec![
+                    format!("0x{WETH}"),
+                    format!("0x{USDC}"),
+                    "1 WETH".to_string(),
+                    "2500 USDC".to_string(),
+                    "WETH > 0.3% > USDC".to_string(),
+                    "0x0000000000000000000000000000000000000001".to_string(),
+                ]
+            )
+        );
+        assert_eq!(
+            texts[1].0,
+            "Sweep: Sweep at least 0 USDC to 0x1111111111111111111111111111111111111111"
+        );
+
+        // Without a chain id the same tokens are only known by address
+        let texts = command_texts(
+            UniswapV4Visualizer {}
+                .visualize_tx_commands(&input)
+                .unwrap(),
+        );
+        assert_eq!(
+            texts[0].0,
+            format!(
+                "V3SwapExactIn: Swap 1000000000000000000 of 0x{WETH} for at least 2500000000 of 0x{USDC}"
+            )
+        );
+    }
+
+    #[test]
+    fn test_decode_v4_swap_plan() {
+        let key = commands::PoolKey {
+            currency0: Address::ZERO,
+            currency1: USDC.parse().unwrap(),
+            fee: 500u32.try_into().unwrap(),
+            tickSpacing: 10i32.try_into().unwrap(),
+            hooks: Address::ZERO,
+        };
+        let swap = v4::ExactInputSingleParams {
+            poolKey: key,
+            zeroForOne: true,
+            amountIn: 10u128.pow(17),
+            amountOutMinimum: 250_000_000,
+            hookData: Bytes::new(),
+        }
+        .abi_encode();
+        let settle = v4::CurrencyAmount {
+            currency: Address::ZERO,
+            amount: U256::from(10u64).pow(U256::from(17u64)),
+        }
+        .abi_encode_params();
+        let take = v4::CurrencyAmount {
+            currency: USDC.parse().unwrap(),
+            amount: U256::from(250_000_000u64),
+        }
+        .abi_encode_params();
+        let plan = v4::ActionPlan {
+            actions: Bytes::from(vec![
+                v4::Action::SwapExactInSingle as u8,
+                v4::Action::SettleAll as u8,
+                v4::Action::TakeAll as u8,
+            ]),
+            params: vec![swap.into(), settle.into(), take.into()],
+        }
+        .abi_encode_params();
+        let input = encode_execute_call(&[Command::V4Swap as u8], vec![plan], 0);
+
+        let texts = command_texts(
+            UniswapV4Visualizer {}
+                .visualize_tx_commands_on_chain(&input, Some(1), &TokenRegistry::default())
+                .unwrap(),
+        );
+        assert_eq!(
+            texts[0],
+            (
+                "V4Swap: Swap 0.1 ETH for at least 250 USDC".to_string(),
+                vec![
+                    "Swap 0.1 ETH for at least 250 USDC".to_string(),
+                    "Pay what is owed, up to 0.1 ETH".to_string(),
+                    "Receive everything owed, at least 250 USDC".to_string(),
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_decode_sub_plan() {
+        let wrap = commands::RecipientAmount {
+            recipient: Address::with_last_byte(2),
+            amount: U256::from(5u64) * U256::from(10u64).pow(U256::from(15u64)),
+        }
+        .abi_encode_params();
+        let sub_plan = commands::SubPlanParams {
+            commands: Bytes::from(vec![Command::WrapEth as u8]),
+            inputs: vec![wrap.into()],
+        }
+        .abi_encode_params();
+        let input = encode_execute_call(&[Command::ExecuteSubPlan as u8], vec![sub_plan], 0);
+
+        let texts = command_texts(
+            UniswapV4Visualizer {}
+                .visualize_tx_commands_on_chain(&input, Some(1), &TokenRegistry::default())
+                .unwrap(),
+        );
+        assert_eq!(
+            texts[0],
+            (
+                "ExecuteSubPlan: Run a sub-plan of 1 commands".to_string(),
+                vec!["WrapEth: Wrap 0.005 ETH".to_string()]
+            )
+        );
+    }
 }
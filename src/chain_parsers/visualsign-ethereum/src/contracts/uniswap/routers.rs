@@ -0,0 +1,894 @@
+//! Uniswap's standalone periphery contracts: the V2 router, the V3
+//! `SwapRouter` and `SwapRouter02`, and the V3 `NonfungiblePositionManager`.
+//!
+//! Swaps reuse the Universal Router's [`Swap`] view, so a route reads the same
+//! whichever router it goes through. The routers and the position manager are
+//! usually called through `multicall`, which the dispatcher unpacks into the
+//! calls decoded here.
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{SolCall, sol};
+use visualsign::{AnnotatedPayloadField, SignablePayloadField};
+
+use super::commands::{
+    Context, Swap, fee_text, recipient_field, recipient_name, token_field, v2_swap, v3_swap,
+};
+use crate::fields::{addr, address_field, number_field, preview_layout, text_field};
+use crate::fmt::format_timestamp;
+use crate::tokens::{TokenRegistry, token_name};
+
+sol! {
+    // From: https://github.com/Uniswap/v2-periphery/blob/master/contracts/interfaces/IUniswapV2Router01.sol
+    interface IUniswapV2Router02 {
+        function swapExactTokensForTokens(uint256 amountIn, uint256 amountOutMin, address[] calldata path, address to, uint256 deadline) external returns (uint256[] memory amounts);
+        function swapTokensForExactTokens(uint256 amountOut, uint256 amountInMax, address[] calldata path, address to, uint256 deadline) external returns (uint256[] memory amounts);
+        function swapExactETHForTokens(uint256 amountOutMin, address[] calldata path, address to, uint256 deadline) external payable returns (uint256[] memory amounts);
+        function swapTokensForExactETH(uint256 amountOut, uint256 amountInMax, address[] calldata path, address to, uint256 deadline) external returns (uint256[] memory amounts);
+        function swapExactTokensForETH(uint256 amountIn, uint256 amountOutMin, address[] calldata path, address to, uint256 deadline) external returns (uint256[] memory amounts);
+        function swapETHForExactTokens(uint256 amountOut, address[] calldata path, address to, uint256 deadline) external payable returns (uint256[] memory amounts);
+        function swapExactTokensForTokensSupportingFeeOnTransferTokens(uint256 amountIn, uint256 amountOutMin, address[] calldata path, address to, uint256 deadline) external;
+        function swapExactETHForTokensSupportingFeeOnTransferTokens(uint256 amountOutMin, address[] calldata path, address to, uint256 deadline) external payable;
+        function swapExactTokensForETHSupportingFeeOnTransferTokens(uint256 amountIn, uint256 amountOutMin, address[] calldata path, address to, uint256 deadline) external;
+    }
+
+    // From: https://github.com/Uniswap/v3-periphery/blob/main/contracts/interfaces/ISwapRouter.sol
+    interface ISwapRouter {
+        struct ExactInputSingleParams { address tokenIn; address tokenOut; uint24 fee; address recipient; uint256 deadline; uint256 amountIn; uint256 amountOutMinimum; uint160 sqrtPriceLimitX96; }
+        struct ExactInputParams { bytes path; address recipient; uint256 deadline; uint256 amountIn; uint256 amountOutMinimum; }
+        struct ExactOutputSingleParams { address tokenIn; address tokenOut; uint24 fee; address recipient; uint256 deadline; uint256 amountOut; uint256 amountInMaximum; uint160 sqrtPriceLimitX96; }
+        struct ExactOutputParams { bytes path; address recipient; uint256 deadline; uint256 amountOut; uint256 amountInMaximum; }
+
+        function exactInputSingle(ExactInputSingleParams calldata params) external payable returns (uint256 amountOut);
+        function exactInput(ExactInputParams calldata params) external payable returns (uint256 amountOut);
+        function exactOutputSingle(ExactOutputSingleParams calldata params) external payable returns (uint256 amountIn);
+        function exactOutput(ExactOutputParams calldata params) external payable returns (uint256 amountIn);
+    }
+
+    // From: https://github.com/Uniswap/swap-router-contracts/blob/main/contracts/interfaces/IV3SwapRouter.sol
+    // SwapRouter02 moved the deadline out to `multicall`, which changes every selector
+    interface ISwapRouter02 {
+        struct ExactInputSingleParams { address tokenIn; address tokenOut; uint24 fee; address recipient; uint256 amountIn; uint256 amountOutMinimum; uint160 sqrtPriceLimitX96; }
+        struct ExactInputParams { bytes path; address recipient; uint256 amountIn; uint256 amountOutMinimum; }
+        struct ExactOutputSingleParams { address tokenIn; address tokenOut; uint24 fee; address recipient; uint256 amountOut; uint256 amountInMaximum; uint160 sqrtPriceLimitX96; }
+        struct ExactOutputParams { bytes path; address recipient; uint256 amountOut; uint256 amountInMaximum; }
+
+        function exactInputSingle(ExactInputSingleParams calldata params) external payable returns (uint256 amountOut);
+        function exactInput(ExactInputParams calldata params) external payable returns (uint256 amountOut);
+        function exactOutputSingle(ExactOutputSingleParams calldata params) external payable returns (uint256 amountIn);
+        function exactOutput(ExactOutputParams calldata params) external payable returns (uint256 amountIn);
+        // V2 pools, through IV2SwapRouter
+        function swapExactTokensForTokens(uint256 amountIn, uint256 amountOutMin, address[] calldata path, address to) external payable returns (uint256 amountOut);
+        function swapTokensForExactTokens(uint256 amountOut, uint256 amountInMax, address[] calldata path, address to) external payable returns (uint256 amountIn);
+    }
+
+    // From: https://github.com/Uniswap/v3-periphery/blob/main/contracts/interfaces/INonfungiblePositionManager.sol
+    interface INonfungiblePositionManager {
+        struct MintParams { address token0; address token1; uint24 fee; int24 tickLower; int24 tickUpper; uint256 amount0Desired; uint256 amount1Desired; uint256 amount0Min; uint256 amount1Min; address recipient; uint256 deadline; }
+        struct IncreaseLiquidityParams { uint256 tokenId; uint256 amount0Desired; uint256 amount1Desired; uint256 amount0Min; uint256 amount1Min; uint256 deadline; }
+        struct DecreaseLiquidityParams { uint256 tokenId; uint128 liquidity; uint256 amount0Min; uint256 amount1Min; uint256 deadline; }
+        struct CollectParams { uint256 tokenId; address recipient; uint128 amount0Max; uint128 amount1Max; }
+
+        function mint(MintParams calldata params) external payable returns (uint256 tokenId, uint128 liquidity, uint256 amount0, uint256 amount1);
+        function increaseLiquidity(IncreaseLiquidityParams calldata params) external payable returns (uint128 liquidity, uint256 amount0, uint256 amount1);
+        function decreaseLiquidity(DecreaseLiquidityParams calldata params) external payable returns (uint256 amount0, uint256 amount1);
+        function collect(CollectParams calldata params) external payable returns (uint256 amount0, uint256 amount1);
+        function burn(uint256 tokenId) external payable;
+    }
+
+    // From: https://github.com/Uniswap/v3-periphery/blob/main/contracts/interfaces/IPeripheryPayments.sol
+    interface IPeripheryPayments {
+        function unwrapWETH9(uint256 amountMinimum, address recipient) external payable;
+        function refundETH() external payable;
+        function sweepToken(address token, uint256 amountMinimum, address recipient) external payable;
+    }
+}
+
+/// How a periphery contract reads the recipient it's given. Every other
+/// address is shown as is.
+#[derive(Clone, Copy)]
+enum Recipients {
+    /// The V2 router and the payment functions pay out to exactly the address given.
+    Literal,
+    /// The V3 `SwapRouter` and position manager read the zero address as the
+    /// contract itself, so that a later call in the same `multicall` can
+    /// unwrap or sweep the output.
+    ZeroIsSelf(&'static str),
+    /// `SwapRouter02` shares the Universal Router's placeholders: 1 for the
+    /// caller and 2 for the router.
+    Placeholders,
+}
+
+impl Recipients {
+    fn name(self, recipient: Address) -> String {
+        match self {
+            Recipients::ZeroIsSelf(contract) if recipient == Address::ZERO => {
+                format!("the {}", contract.to_lowercase())
+            }
+            Recipients::Placeholders => recipient_name(recipient),
+            _ => addr(recipient),
+        }
+    }
+
+    fn field(self, recipient: Address) -> AnnotatedPayloadField {
+        match self {
+            Recipients::ZeroIsSelf(contract) if recipient == Address::ZERO => {
+                address_field("Recipient", &addr(recipient), Some(contract), None)
+            }
+            Recipients::Placeholders => recipient_field("Recipient", recipient),
+            _ => address_field("Recipient", &addr(recipient), None, None),
+        }
+    }
+}
+
+fn deadline_field(deadline: U256) -> AnnotatedPayloadField {
+    text_field("Deadline", &format_timestamp(deadline))
+}
+
+fn position_text(token_id: U256) -> String {
+    format!("position #{token_id}")
+}
+
+/// A swap's layout: its amounts, route and recipient, then the deadline when
+/// the call carries one.
+fn swap_layout(
+    title: &str,
+    ctx: Context<'_>,
+    swap: &Swap,
+    route: String,
+    recipient: AnnotatedPayloadField,
+    deadline: Option<U256>,
+) -> SignablePayloadField {
+    let mut fields = swap.fields(ctx);
+    fields.push(text_field("Route", &route));
+    fields.push(recipient);
+    fields.extend(deadline.map(deadline_field));
+    preview_layout(title, title, &swap.summary(ctx), None, fields)
+}
+
+/// `unwrapWETH9`, `refundETH` and `sweepToken`, which the V3 router and the
+/// position manager share to pay out what a `multicall` left behind.
+fn payment_layout(input: &[u8], ctx: Context<'_>, contract: &str) -> Option<SignablePayloadField> {
+    let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+    let native = ctx.currency(Address::ZERO);
+    let (title, summary, fields) = match selector {
+        IPeripheryPayments::unwrapWETH9Call::SELECTOR => {
+            let call = IPeripheryPayments::unwrapWETH9Call::abi_decode(input).ok()?;
+            (
+                "Uniswap Unwrap",
+                format!(
+                    "Unwrap at least {} to {}",
+                    ctx.amount_text(native, Address::ZERO, call.amountMinimum),
+                    Recipients::Literal.name(call.recipient)
+                ),
+                vec![
+                    ctx.amount_field("Minimum Amount", native, Address::ZERO, call.amountMinimum),
+                    Recipients::Literal.field(call.recipient),
+                ],
+            )
+        }
+        IPeripheryPayments::refundETHCall::SELECTOR => {
+            IPeripheryPayments::refundETHCall::abi_decode(input).ok()?;
+            (
+                "Uniswap Refund",
+                format!(
+                    "Refund {} left in the {contract} to the sender",
+                    token_name(native, Address::ZERO)
+                ),
+                Vec::new(),
+            )
+        }
+        IPeripheryPayments::sweepTokenCall::SELECTOR => {
+            let call = IPeripheryPayments::sweepTokenCall::abi_decode(input).ok()?;
+            let token = ctx.token(call.token);
+            (
+                "Uniswap Sweep",
+                format!(
+                    "Sweep at least {} to {}",
+                    ctx.amount_text(token, call.token, call.amountMinimum),
+                    Recipients::Literal.name(call.recipient)
+                ),
+                vec![
+                    token_field("Token", token, call.token),
+                    ctx.amount_field("Minimum Amount", token, call.token, call.amountMinimum),
+                    Recipients::Literal.field(call.recipient),
+                ],
+            )
+        }
+        _ => return None,
+    };
+    Some(preview_layout(title, title, &summary, None, fields))
+}
+
+pub struct UniswapV2RouterVisualizer {}
+
+impl UniswapV2RouterVisualizer {
+    /// Decodes `input`, a call to `UniswapV2Router02` on `chain_id`.
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: Option<u64>,
+        tokens: &TokenRegistry,
+    ) -> Option<SignablePayloadField> {
+        use IUniswapV2Router02 as V2;
+
+        let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+        let ctx = Context {
+            tokens,
+            chain_id,
+            depth: 0,
+        };
+        let title = "Uniswap V2 Swap";
+        // The router wraps and unwraps ETH at either end of a WETH path
+        let to_native = |(swap, route): (Swap, String)| {
+            let swap = Swap {
+                token_out: Address::ZERO,
+                ..swap
+            };
+            (swap, route)
+        };
+        let (swap, route, to, deadline) = match selector {
+            // The fee-on-transfer variants take the same arguments
+            V2::swapExactTokensForTokensCall::SELECTOR
+            | V2::swapExactTokensForTokensSupportingFeeOnTransferTokensCall::SELECTOR => {
+                let call = V2::swapExactTokensForTokensCall::abi_decode_raw(&input[4..]).ok()?;
+                let (swap, route) =
+                    v2_swap(ctx, true, &call.path, call.amountIn, call.amountOutMin)?;
+                (swap, route, call.to, call.deadline)
+            }
+            V2::swapTokensForExactTokensCall::SELECTOR => {
+                let call = V2::swapTokensForExactTokensCall::abi_decode(input).ok()?;
+                let (swap, route) =
+                    v2_swap(ctx, false, &call.path, call.amountOut, call.amountInMax)?;
+                (swap, route, call.to, call.deadline)
+            }
+            V2::swapExactTokensForETHCall::SELECTOR
+            | V2::swapExactTokensForETHSupportingFeeOnTransferTokensCall::SELECTOR => {
+                let call = V2::swapExactTokensForETHCall::abi_decode_raw(&input[4..]).ok()?;
+                let (swap, route) = to_native(v2_swap(
+                    ctx,
+                    true,
+                    &call.path,
+                    call.amountIn,
+                    call.amountOutMin,
+                )?);
+                (swap, route, call.to, call.deadline)
+            }
+            V2::swapTokensForExactETHCall::SELECTOR => {
+                let call = V2::swapTokensForExactETHCall::abi_decode(input).ok()?;
+                let (swap, route) = to_native(v2_swap(
+                    ctx,
+                    false,
+                    &call.path,
+                    call.amountOut,
+                    call.amountInMax,
+                )?);
+                (swap, route, call.to, call.deadline)
+            }
+            V2::swapExactETHForTokensCall::SELECTOR
+            | V2::swapExactETHForTokensSupportingFeeOnTransferTokensCall::SELECTOR => {
+                let call = V2::swapExactETHForTokensCall::abi_decode_raw(&input[4..]).ok()?;
+                return eth_in_swap_layout(
+                    ctx,
+                    true,
+                    call.amountOutMin,
+                    &call.path,
+                    call.to,
+                    call.deadline,
+                );
+            }
+            V2::swapETHForExactTokensCall::SELECTOR => {
+                let call = V2::swapETHForExactTokensCall::abi_decode(input).ok()?;
+                return eth_in_swap_layout(
+                    ctx,
+                    false,
+                    call.amountOut,
+                    &call.path,
+                    call.to,
+                    call.deadline,
+                );
+            }
+            _ => return None,
+        };
+        Some(swap_layout(
+            title,
+            ctx,
+            &swap,
+            route,
+            Recipients::Literal.field(to),
+            Some(deadline),
+        ))
+    }
+}
+
+/// Swaps paid for with the call's ETH value, which isn't an argument: the
+/// whole value for exact-input swaps, or a maximum with the rest refunded.
+fn eth_in_swap_layout(
+    ctx: Context<'_>,
+    exact_in: bool,
+    amount_out: U256,
+    path: &[Address],
+    to: Address,
+    deadline: U256,
+) -> Option<SignablePayloadField> {
+    let (swap, route) = v2_swap(ctx, exact_in, path, U256::ZERO, U256::ZERO)?;
+    let native = ctx.currency(Address::ZERO);
+    let token_out = ctx.token(swap.token_out);
+    let native_name = token_name(native, Address::ZERO);
+    let out_text = ctx.amount_text(token_out, swap.token_out, amount_out);
+    let (summary, amount_in, out_label) = if exact_in {
+        (
+            format!("Swap the {native_name} sent with this call for at least {out_text}"),
+            format!("The {native_name} value sent with this call"),
+            "Minimum Amount Out",
+        )
+    } else {
+        (
+            format!("Swap at most the {native_name} sent with this call for {out_text}"),
+            format!("At most the {native_name} value sent with this call; the rest is refunded"),
+            "Amount Out",
+        )
+    };
+    let fields = vec![
+        token_field("Token In", native, Address::ZERO),
+        token_field("Token Out", token_out, swap.token_out),
+        text_field("Amount In", &amount_in),
+        ctx.amount_field(out_label, token_out, swap.token_out, amount_out),
+        text_field("Route", &route),
+        Recipients::Literal.field(to),
+        deadline_field(deadline),
+    ];
+    let title = "Uniswap V2 Swap";
+    Some(preview_layout(title, title, &summary, None, fields))
+}
+
+pub struct UniswapV3RouterVisualizer {}
+
+impl UniswapV3RouterVisualizer {
+    /// Decodes `input`, a call to the V3 `SwapRouter` or `SwapRouter02` on
+    /// `chain_id`.
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: Option<u64>,
+        tokens: &TokenRegistry,
+    ) -> Option<SignablePayloadField> {
+        let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+        let ctx = Context {
+            tokens,
+            chain_id,
+            depth: 0,
+        };
+        let single = |token_in, token_out, fee: u32, exact_in, amount, limit| {
+            let swap = Swap {
+                exact_in,
+                token_in,
+                token_out,
+                amount,
+                limit,
+            };
+            let route = format!(
+                "{} > {} > {}",
+                token_name(ctx.token(token_in), token_in),
+                fee_text(fee),
+                token_name(ctx.token(token_out), token_out)
+            );
+            (swap, route)
+        };
+        let (title, (swap, route), recipient, deadline) = match selector {
+            ISwapRouter::exactInputSingleCall::SELECTOR => {
+                let p = ISwapRouter::exactInputSingleCall::abi_decode(input)
+                    .ok()?
+                    .params;
+                let swap = single(
+                    p.tokenIn,
+                    p.tokenOut,
+                    p.fee.to(),
+                    true,
+                    p.amountIn,
+                    p.amountOutMinimum,
+                );
+                ("Uniswap V3 Swap", swap, p.recipient, Some(p.deadline))
+            }
+            ISwapRouter::exactInputCall::SELECTOR => {
+                let p = ISwapRouter::exactInputCall::abi_decode(input).ok()?.params;
+                let swap = v3_swap(ctx, true, &p.path, p.amountIn, p.amountOutMinimum)?;
+                ("Uniswap V3 Swap", swap, p.recipient, Some(p.deadline))
+            }
+            ISwapRouter::exactOutputSingleCall::SELECTOR => {
+                let p = ISwapRouter::exactOutputSingleCall::abi_decode(input)
+                    .ok()?
+                    .params;
+                let swap = single(
+                    p.tokenIn,
+                    p.tokenOut,
+                    p.fee.to(),
+                    false,
+                    p.amountOut,
+                    p.amountInMaximum,
+                );
+                ("Uniswap V3 Swap", swap, p.recipient, Some(p.deadline))
+            }
+            ISwapRouter::exactOutputCall::SELECTOR => {
+                let p = ISwapRouter::exactOutputCall::abi_decode(input).ok()?.params;
+                let swap = v3_swap(ctx, false, &p.path, p.amountOut, p.amountInMaximum)?;
+                ("Uniswap V3 Swap", swap, p.recipient, Some(p.deadline))
+            }
+            ISwapRouter02::exactInputSingleCall::SELECTOR => {
+                let p = ISwapRouter02::exactInputSingleCall::abi_decode(input)
+                    .ok()?
+                    .params;
+                let swap = single(
+                    p.tokenIn,
+                    p.tokenOut,
+                    p.fee.to(),
+                    true,
+                    p.amountIn,
+                    p.amountOutMinimum,
+                );
+                ("Uniswap V3 Swap", swap, p.recipient, None)
+            }
+            ISwapRouter02::exactInputCall::SELECTOR => {
+                let p = ISwapRouter02::exactInputCall::abi_decode(input)
+                    .ok()?
+                    .params;
+                let swap = v3_swap(ctx, true, &p.path, p.amountIn, p.amountOutMinimum)?;
+                ("Uniswap V3 Swap", swap, p.recipient, None)
+            }
+            ISwapRouter02::exactOutputSingleCall::SELECTOR => {
+                let p = ISwapRouter02::exactOutputSingleCall::abi_decode(input)
+                    .ok()?
+                    .params;
+                let swap = single(
+                    p.tokenIn,
+                    p.tokenOut,
+                    p.fee.to(),
+                    false,
+                    p.amountOut,
+                    p.amountInMaximum,
+                );
+                ("Uniswap V3 Swap", swap, p.recipient, None)
+            }
+            ISwapRouter02::exactOutputCall::SELECTOR => {
+                let p = ISwapRouter02::exactOutputCall::abi_decode(input)
+                    .ok()?
+                    .params;
+                let swap = v3_swap(ctx, false, &p.path, p.amountOut, p.amountInMaximum)?;
+                ("Uniswap V3 Swap", swap, p.recipient, None)
+            }
+            ISwapRouter02::swapExactTokensForTokensCall::SELECTOR => {
+                let call = ISwapRouter02::swapExactTokensForTokensCall::abi_decode(input).ok()?;
+                let swap = v2_swap(ctx, true, &call.path, call.amountIn, call.amountOutMin)?;
+                ("Uniswap V2 Swap", swap, call.to, None)
+            }
+            ISwapRouter02::swapTokensForExactTokensCall::SELECTOR => {
+                let call = ISwapRouter02::swapTokensForExactTokensCall::abi_decode(input).ok()?;
+                let swap = v2_swap(ctx, false, &call.path, call.amountOut, call.amountInMax)?;
+                ("Uniswap V2 Swap", swap, call.to, None)
+            }
+            _ => return payment_layout(input, ctx, "router"),
+        };
+        // Only the original SwapRouter takes a deadline with each swap;
+        // SwapRouter02 moved it to `multicall`
+        let recipients = if deadline.is_some() {
+            Recipients::ZeroIsSelf("Router")
+        } else {
+            Recipients::Placeholders
+        };
+        Some(swap_layout(
+            title,
+            ctx,
+            &swap,
+            route,
+            recipients.field(recipient),
+            deadline,
+        ))
+    }
+}
+
+pub struct NonfungiblePositionManagerVisualizer {}
+
+impl NonfungiblePositionManagerVisualizer {
+    /// Decodes `input`, a call to the V3 `NonfungiblePositionManager` on
+    /// `chain_id`.
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: Option<u64>,
+        tokens: &TokenRegistry,
+    ) -> Option<SignablePayloadField> {
+        let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+        let ctx = Context {
+            tokens,
+            chain_id,
+            depth: 0,
+        };
+        let (title, summary, fields) = match selector {
+            INonfungiblePositionManager::mintCall::SELECTOR => {
+                let p = INonfungiblePositionManager::mintCall::abi_decode(input)
+                    .ok()?
+                    .params;
+                let (token0, token1) = (ctx.token(p.token0), ctx.token(p.token1));
+                let fee = fee_text(p.fee.to());
+                let pool = format!(
+                    "{}/{} {fee}",
+                    token_name(token0, p.token0),
+                    token_name(token1, p.token1)
+                );
+                let fields = vec![
+                    token_field("Token 0", token0, p.token0),
+                    token_field("Token 1", token1, p.token1),
+                    text_field("Fee", &fee),
+                    text_field("Tick Lower", &p.tickLower.to_string()),
+                    text_field("Tick Upper", &p.tickUpper.to_string()),
+                    ctx.amount_field("Amount 0 Desired", token0, p.token0, p.amount0Desired),
+                    ctx.amount_field("Amount 1 Desired", token1, p.token1, p.amount1Desired),
+                    ctx.amount_field("Amount 0 Minimum", token0, p.token0, p.amount0Min),
+                    ctx.amount_field("Amount 1 Minimum", token1, p.token1, p.amount1Min),
+                    Recipients::Literal.field(p.recipient),
+                    deadline_field(p.deadline),
+                ];
+                (
+                    "Uniswap V3 Mint Position",
+                    format!(
+                        "Provide up to {} and {} to the {pool} pool",
+                        ctx.amount_text(token0, p.token0, p.amount0Desired),
+                        ctx.amount_text(token1, p.token1, p.amount1Desired)
+                    ),
+                    fields,
+                )
+            }
+            // The position's tokens aren't in the call, so amounts stay raw
+            INonfungiblePositionManager::increaseLiquidityCall::SELECTOR => {
+                let p = INonfungiblePositionManager::increaseLiquidityCall::abi_decode(input)
+                    .ok()?
+                    .params;
+                let fields = vec![
+                    number_field("Token ID", &p.tokenId.to_string()),
+                    number_field("Amount 0 Desired", &p.amount0Desired.to_string()),
+                    number_field("Amount 1 Desired", &p.amount1Desired.to_string()),
+                    number_field("Amount 0 Minimum", &p.amount0Min.to_string()),
+                    number_field("Amount 1 Minimum", &p.amount1Min.to_string()),
+                    deadline_field(p.deadline),
+                ];
+                (
+                    "Uniswap V3 Increase Liquidity",
+                    format!("Add liquidity to {}", position_text(p.tokenId)),
+                    fields,
+                )
+            }
+            INonfungiblePositionManager::decreaseLiquidityCall::SELECTOR => {
+                let p = INonfungiblePositionManager::decreaseLiquidityCall::abi_decode(input)
+                    .ok()?
+                    .params;
+                let fields = vec![
+                    number_field("Token ID", &p.tokenId.to_string()),
+                    number_field("Liquidity", &p.liquidity.to_string()),
+                    number_field("Amount 0 Minimum", &p.amount0Min.to_string()),
+                    number_field("Amount 1 Minimum", &p.amount1Min.to_string()),
+                    deadline_field(p.deadline),
+                ];
+                (
+                    "Uniswap V3 Decrease Liquidity",
+                    format!(
+                        "Remove {} liquidity from {}",
+                        p.liquidity,
+                        position_text(p.tokenId)
+                    ),
+                    fields,
+                )
+            }
+            INonfungiblePositionManager::collectCall::SELECTOR => {
+                let p = INonfungiblePositionManager::collectCall::abi_decode(input)
+                    .ok()?
+                    .params;
+                let max_text = |max: u128| {
+                    if max == u128::MAX {
+                        "All".to_string()
+                    } else {
+                        max.to_string()
+                    }
+                };
+                let recipients = Recipients::ZeroIsSelf("Position Manager");
+                let fields = vec![
+                    number_field("Token ID", &p.tokenId.to_string()),
+                    recipients.field(p.recipient),
+                    text_field("Amount 0 Maximum", &max_text(p.amount0Max)),
+                    text_field("Amount 1 Maximum", &max_text(p.amount1Max)),
+                ];
+                (
+                    "Uniswap V3 Collect",
+                    format!(
+                        "Collect tokens owed by {} to {}",
+                        position_text(p.tokenId),
+                        recipients.name(p.recipient)
+                    ),
+                    fields,
+                )
+            }
+            INonfungiblePositionManager::burnCall::SELECTOR => {
+                let call = INonfungiblePositionManager::burnCall::abi_decode(input).ok()?;
+                (
+                    "Uniswap V3 Burn Position",
+                    format!("Burn the NFT of {}", position_text(call.tokenId)),
+                    vec![number_field("Token ID", &call.tokenId.to_string())],
+                )
+            }
+            _ => return payment_layout(input, ctx, "position manager"),
+        };
+        Some(preview_layout(title, title, &summary, None, fields))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Bytes, Uint, address};
+
+    const USDC: Address = address!("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const WETH: Address = address!("0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    const DAI: Address = address!("0x6b175474e89094c44da98b954eedeac495271d0f");
+    const RECIPIENT: Address = Address::repeat_byte(0x11);
+
+    fn texts(field: Option<SignablePayloadField>) -> (String, Vec<String>) {
+        let SignablePayloadField::PreviewLayout {
+            common,
+            preview_layout,
+        } = field.unwrap()
+        else {
+            panic!("expected a preview layout");
+        };
+        let fields = preview_layout
+            .expanded
+            .unwrap()
+            .fields
+            .iter()
+            .map(|f| {
+                format!(
+                    "{}: {}",
+                    f.signable_payload_field.label(),
+                    f.signable_payload_field.fallback_text()
+                )
+            })
+            .collect();
+        (common.fallback_text, fields)
+    }
+
+    fn v3_path(hops: &[(Address, u32)], last: Address) -> Bytes {
+        let mut path = Vec::new();
+        for (token, fee) in hops {
+            path.extend_from_slice(token.as_slice());
+            path.extend_from_slice(&fee.to_be_bytes()[1..]);
+        }
+        path.extend_from_slice(last.as_slice());
+        Bytes::from(path)
+    }
+
+    #[test]
+    fn test_v3_multi_hop_route() {
+        let tokens = TokenRegistry::default();
+        let input = ISwapRouter02::exactInputCall {
+            params: ISwapRouter02::ExactInputParams {
+                path: v3_path(&[(USDC, 500), (WETH, 3000)], DAI),
+                recipient: RECIPIENT,
+                amountIn: U256::from(1_000_000_000u64),
+                amountOutMinimum: U256::from(990u64) * U256::from(10u64).pow(U256::from(18)),
+            },
+        }
+        .abi_encode();
+        let (summary, fields) =
+            texts(UniswapV3RouterVisualizer {}.visualize_tx_commands(&input, Some(1), &tokens));
+        assert_eq!(summary, "Swap 1000 USDC for at least 990 DAI");
+        assert!(fields.contains(&"Route: USDC > 0.05% > WETH > 0.3% > DAI".to_string()));
+
+        // Exact-output paths are encoded from the output token back
+        let input = ISwapRouter::exactOutputCall {
+            params: ISwapRouter::ExactOutputParams {
+                path: v3_path(&[(DAI, 100), (WETH, 3000)], USDC),
+                recipient: Address::ZERO,
+                deadline: U256::from(1_700_000_000u64),
+                amountOut: U256::from(5u64) * U256::from(10u64).pow(U256::from(18)),
+                amountInMaximum: U256::from(5_100_000u64),
+            },
+        }
+        .abi_encode();
+        let (summary, fields) =
+            texts(UniswapV3RouterVisualizer {}.visualize_tx_commands(&input, Some(1), &tokens));
+        assert_eq!(summary, "Swap at most 5.1 USDC for 5 DAI");
+        assert!(fields.contains(&"Route: USDC > 0.3% > WETH > 0.01% > DAI".to_string()));
+        assert!(
+            fields.contains(&"Recipient: 0x0000000000000000000000000000000000000000".to_string())
+        );
+        assert_eq!(fields.last().unwrap(), "Deadline: 2023-11-14 22:13:20 UTC");
+
+        let input = ISwapRouter02::exactInputCall {
+            params: ISwapRouter02::ExactInputParams {
+                path: Bytes::from(USDC.to_vec()),
+                recipient: RECIPIENT,
+                amountIn: U256::from(1u64),
+                amountOutMinimum: U256::ZERO,
+            },
+        }
+        .abi_encode();
+        assert!(
+            UniswapV3RouterVisualizer {}
+                .visualize_tx_commands(&input, Some(1), &tokens)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_v2_eth_swaps() {
+        let tokens = TokenRegistry::default();
+        let input = IUniswapV2Router02::swapExactETHForTokensCall {
+            amountOutMin: U256::from(2_500_000_000u64),
+            path: vec![WETH, USDC],
+            to: RECIPIENT,
+            deadline: U256::from(1_700_000_000u64),
+        }
+        .abi_encode();
+        let (summary, _) =
+            texts(UniswapV2RouterVisualizer {}.visualize_tx_commands(&input, Some(1), &tokens));
+        assert_eq!(
+            summary,
+            "Swap the ETH sent with this call for at least 2500 USDC"
+        );
+
+        let input = IUniswapV2Router02::swapExactTokensForETHCall {
+            amountIn: U256::from(2_500_000_000u64),
+            amountOutMin: U256::from(10u64).pow(U256::from(18)),
+            path: vec![USDC, WETH],
+            to: RECIPIENT,
+            deadline: U256::from(1_700_000_000u64),
+        }
+        .abi_encode();
+        let (summary, fields) =
+            texts(UniswapV2RouterVisualizer {}.visualize_tx_commands(&input, Some(1), &tokens));
+        assert_eq!(summary, "Swap 2500 USDC for at least 1 ETH");
+        assert!(fields.contains(&"Route: USDC > WETH".to_string()));
+    }
+
+    /// The `Recipient` field of a swap layout.
+    fn recipient(field: Option<SignablePayloadField>) -> AnnotatedPayloadField {
+        let Some(SignablePayloadField::PreviewLayout { preview_layout, .. }) = field else {
+            panic!("expected a preview layout");
+        };
+        preview_layout
+            .expanded
+            .unwrap()
+            .fields
+            .into_iter()
+            .find(|f| f.signable_payload_field.label() == "Recipient")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_recipient_conventions() {
+        let tokens = TokenRegistry::default();
+        let one = Address::with_last_byte(1);
+        let named = |address: Address, name| address_field("Recipient", &addr(address), name, None);
+
+        // The V2 router pays out to exactly the address given
+        let input = IUniswapV2Router02::swapExactTokensForTokensCall {
+            amountIn: U256::from(1_000_000u64),
+            amountOutMin: U256::ZERO,
+            path: vec![USDC, WETH],
+            to: one,
+            deadline: U256::from(1_700_000_000u64),
+        }
+        .abi_encode();
+        let field = UniswapV2RouterVisualizer {}.visualize_tx_commands(&input, Some(1), &tokens);
+        assert_eq!(recipient(field), named(one, None));
+
+        // SwapRouter only reads the zero address as itself
+        let v3 = |recipient| {
+            ISwapRouter::exactInputSingleCall {
+                params: ISwapRouter::ExactInputSingleParams {
+                    tokenIn: USDC,
+                    tokenOut: WETH,
+                    fee: Uint::from(500u32),
+                    recipient,
+                    deadline: U256::from(1_700_000_000u64),
+                    amountIn: U256::from(1_000_000u64),
+                    amountOutMinimum: U256::ZERO,
+                    sqrtPriceLimitX96: Uint::ZERO,
+                },
+            }
+            .abi_encode()
+        };
+        let visualize = |input: &[u8]| {
+            UniswapV3RouterVisualizer {}.visualize_tx_commands(input, Some(1), &tokens)
+        };
+        assert_eq!(
+            recipient(visualize(&v3(Address::ZERO))),
+            named(Address::ZERO, Some("Router"))
+        );
+        assert_eq!(recipient(visualize(&v3(one))), named(one, None));
+
+        // SwapRouter02 only has the placeholders 1 and 2
+        let v3_02 = |recipient| {
+            ISwapRouter02::exactInputSingleCall {
+                params: ISwapRouter02::ExactInputSingleParams {
+                    tokenIn: USDC,
+                    tokenOut: WETH,
+                    fee: Uint::from(500u32),
+                    recipient,
+                    amountIn: U256::from(1_000_000u64),
+                    amountOutMinimum: U256::ZERO,
+                    sqrtPriceLimitX96: Uint::ZERO,
+                },
+            }
+            .abi_encode()
+        };
+        assert_eq!(
+            recipient(visualize(&v3_02(one))),
+            named(one, Some("Sender"))
+        );
+        assert_eq!(
+            recipient(visualize(&v3_02(Address::ZERO))),
+            named(Address::ZERO, None)
+        );
+    }
+
+    #[test]
+    fn test_position_manager_calls() {
+        let tokens = TokenRegistry::default();
+        let visualize = |input: &[u8]| {
+            texts(
+                NonfungiblePositionManagerVisualizer {}.visualize_tx_commands(
+                    input,
+                    Some(1),
+                    &tokens,
+                ),
+            )
+        };
+        let input = INonfungiblePositionManager::mintCall {
+            params: INonfungiblePositionManager::MintParams {
+                token0: USDC,
+                token1: WETH,
+                fee: Uint::from(500u32),
+                tickLower: "-200000".parse().unwrap(),
+                tickUpper: "-190000".parse().unwrap(),
+                amount0Desired: U256::from(3_000_000_000u64),
+                amount1Desired: U256::from(10u64).pow(U256::from(18)),
+                amount0Min: U256::ZERO,
+                amount1Min: U256::ZERO,
+                recipient: RECIPIENT,
+                deadline: U256::from(1_700_000_000u64),
+            },
+        }
+        .abi_encode();
+        let (summary, fields) = visualize(&input);
+        assert_eq!(
+            summary,
+            "Provide up to 3000 USDC and 1 WETH to the USDC/WETH 0.05% pool"
+        );
+        assert!(fields.contains(&"Tick Lower: -200000".to_string()));
+
+        let input = INonfungiblePositionManager::collectCall {
+            params: INonfungiblePositionManager::CollectParams {
+                tokenId: U256::from(42u64),
+                recipient: Address::ZERO,
+                amount0Max: u128::MAX,
+                amount1Max: u128::MAX,
+            },
+        }
+        .abi_encode();
+        let (summary, _) = visualize(&input);
+        assert_eq!(
+            summary,
+            "Collect tokens owed by position #42 to the position manager"
+        );
+
+        let input = IPeripheryPayments::sweepTokenCall {
+            token: USDC,
+            amountMinimum: U256::from(1_000_000u64),
+            recipient: RECIPIENT,
+        }
+        .abi_encode();
+        let (summary, _) = visualize(&input);
+        assert_eq!(
+            summary,
+            "Sweep at least 1 USDC to 0x1111111111111111111111111111111111111111"
+        );
+    }
+}
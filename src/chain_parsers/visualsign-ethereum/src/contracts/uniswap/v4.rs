@@ -0,0 +1,407 @@
+//! Decodes the action plan carried by a `V4_SWAP` command.
+//!
+//! Action ids and parameter layouts follow `Actions.sol` and `V4Router.sol` in
+//! Uniswap's v4-periphery. The zero address is the chain's native currency.
+
+use alloy_primitives::{Address, Bytes, U256};
+use alloy_sol_types::{SolValue, sol};
+use num_enum::TryFromPrimitive;
+use visualsign::AnnotatedPayloadField;
+
+use super::commands::{
+    CommandView, Context, PoolKey, Swap, fee_text, pool_key_view, recipient_field, recipient_name,
+    token_field,
+};
+use crate::fields::{annotated, preview_layout, text_field};
+use crate::fmt::format_token_amount;
+use crate::tokens::token_name;
+
+sol! {
+    struct PathKey { address intermediateCurrency; uint24 fee; int24 tickSpacing; address hooks; bytes hookData; }
+    struct ExactInputSingleParams { PoolKey poolKey; bool zeroForOne; uint128 amountIn; uint128 amountOutMinimum; bytes hookData; }
+    struct ExactInputParams { address currencyIn; PathKey[] path; uint128 amountIn; uint128 amountOutMinimum; }
+    struct ExactOutputSingleParams { PoolKey poolKey; bool zeroForOne; uint128 amountOut; uint128 amountInMaximum; bytes hookData; }
+    struct ExactOutputParams { address currencyOut; PathKey[] path; uint128 amountOut; uint128 amountInMaximum; }
+
+    struct ActionPlan { bytes actions; bytes[] params; }
+    struct CurrencyAmountPayer { address currency; uint256 amount; bool payerIsUser; }
+    struct CurrencyAmount { address currency; uint256 amount; }
+    struct CurrencyPair { address currency0; address currency1; }
+    struct CurrencyRecipientAmount { address currency; address recipient; uint256 amount; }
+    struct CurrencyPairRecipient { address currency0; address currency1; address recipient; }
+    struct CurrencyOnly { address currency; }
+    struct CurrencyRecipient { address currency; address recipient; }
+    struct AmountOnly { uint256 amount; }
+}
+
+/// Settle and take amounts of zero mean "whatever is owed" (`ActionConstants.OPEN_DELTA`).
+const OPEN_DELTA: U256 = U256::ZERO;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, TryFromPrimitive)]
+#[repr(u8)]
+pub enum Action {
+    IncreaseLiquidity = 0x00,
+    DecreaseLiquidity = 0x01,
+    MintPosition = 0x02,
+    BurnPosition = 0x03,
+    IncreaseLiquidityFromDeltas = 0x04,
+    MintPositionFromDeltas = 0x05,
+
+    SwapExactInSingle = 0x06,
+    SwapExactIn = 0x07,
+    SwapExactOutSingle = 0x08,
+    SwapExactOut = 0x09,
+
+    Donate = 0x0a,
+
+    Settle = 0x0b,
+    SettleAll = 0x0c,
+    SettlePair = 0x0d,
+
+    Take = 0x0e,
+    TakeAll = 0x0f,
+    TakePortion = 0x10,
+    TakePair = 0x11,
+
+    CloseCurrency = 0x12,
+    ClearOrTake = 0x13,
+    Sweep = 0x14,
+
+    Wrap = 0x15,
+    Unwrap = 0x16,
+
+    Mint6909 = 0x17,
+    Burn6909 = 0x18,
+}
+
+pub(super) fn decode_v4_swap(input: &[u8], ctx: Context<'_>) -> Option<CommandView> {
+    let plan = ActionPlan::abi_decode_params(input).ok()?;
+    let mut fields = Vec::new();
+    let mut swaps = Vec::new();
+    for (i, &byte) in plan.actions.iter().enumerate() {
+        let label = format!("Action {}", i + 1);
+        let params = plan.params.get(i).map(Bytes::as_ref).unwrap_or_default();
+        let decoded = Action::try_from(byte)
+            .ok()
+            .map(|action| (action, decode_action(action, params, ctx)));
+        let field = match decoded {
+            Some((action, Some(view))) => {
+                if matches!(
+                    action,
+                    Action::SwapExactInSingle
+                        | Action::SwapExactIn
+                        | Action::SwapExactOutSingle
+                        | Action::SwapExactOut
+                ) {
+                    swaps.push(view.summary.clone());
+                }
+                preview_layout(
+                    &label,
+                    &format!("{action:?}"),
+                    &view.summary,
+                    None,
+                    view.fields,
+                )
+            }
+            Some((action, None)) => {
+                let raw = format!("Params: 0x{}", hex::encode(params));
+                preview_layout(&label, &format!("{action:?}"), &raw, None, Vec::new())
+            }
+            None => {
+                let raw = format!("Unknown action 0x{byte:02x}");
+                preview_layout(&label, &raw, &raw, None, Vec::new())
+            }
+        };
+        fields.push(annotated(field));
+    }
+    let summary = if swaps.is_empty() {
+        format!("Run {} Uniswap v4 actions", plan.actions.len())
+    } else {
+        swaps.join("; ")
+    };
+    Some(CommandView { summary, fields })
+}
+
+fn decode_action(action: Action, params: &[u8], ctx: Context<'_>) -> Option<CommandView> {
+    match action {
+        Action::SwapExactInSingle => {
+            let params = ExactInputSingleParams::abi_decode(params).ok()?;
+            let (token_in, token_out) = single_pool_direction(&params.poolKey, params.zeroForOne);
+            let swap = Swap {
+                exact_in: true,
+                token_in,
+                token_out,
+                amount: U256::from(params.amountIn),
+                limit: U256::from(params.amountOutMinimum),
+            };
+            Some(single_pool_swap_view(ctx, &swap, &params.poolKey))
+        }
+        Action::SwapExactOutSingle => {
+            let params = ExactOutputSingleParams::abi_decode(params).ok()?;
+            let (token_in, token_out) = single_pool_direction(&params.poolKey, params.zeroForOne);
+            let swap = Swap {
+                exact_in: false,
+                token_in,
+                token_out,
+                amount: U256::from(params.amountOut),
+                limit: U256::from(params.amountInMaximum),
+            };
+            Some(single_pool_swap_view(ctx, &swap, &params.poolKey))
+        }
+        Action::SwapExactIn => {
+            let params = ExactInputParams::abi_decode(params).ok()?;
+            let token_out = params.path.last()?.intermediateCurrency;
+            let swap = Swap {
+                exact_in: true,
+                token_in: params.currencyIn,
+                token_out,
+                amount: U256::from(params.amountIn),
+                limit: U256::from(params.amountOutMinimum),
+            };
+            let route = path_route(ctx, params.currencyIn, &params.path);
+            Some(multi_pool_swap_view(ctx, &swap, route))
+        }
+        Action::SwapExactOut => {
+            let params = ExactOutputParams::abi_decode(params).ok()?;
+            // Exact-output paths are listed from the input side, ending before the output
+            let token_in = params.path.first()?.intermediateCurrency;
+            let swap = Swap {
+                exact_in: false,
+                token_in,
+                token_out: params.currencyOut,
+                amount: U256::from(params.amountOut),
+                limit: U256::from(params.amountInMaximum),
+            };
+            let mut route = token_name(ctx.currency(token_in), token_in);
+            for (hop, next) in params.path.iter().zip(
+                params
+                    .path
+                    .iter()
+                    .skip(1)
+                    .map(|key| key.intermediateCurrency)
+                    .chain([params.currencyOut]),
+            ) {
+                route.push_str(&format!(
+                    " > {} > {}",
+                    fee_text(hop.fee.to::<u32>()),
+                    token_name(ctx.currency(next), next)
+                ));
+            }
+            Some(multi_pool_swap_view(ctx, &swap, route))
+        }
+        Action::Settle => {
+            let params = CurrencyAmountPayer::abi_decode_params(params).ok()?;
+            let payer = if params.payerIsUser {
+                "the sender"
+            } else {
+                "the router"
+            };
+            let amount = delta_amount(ctx, params.currency, params.amount, "the full amount owed");
+            Some(currency_view(
+                ctx,
+                format!("Pay {amount} from {payer}"),
+                params.currency,
+                (params.amount != OPEN_DELTA).then_some(("Amount", params.amount)),
+                None,
+            ))
+        }
+        Action::SettleAll => {
+            let params = CurrencyAmount::abi_decode_params(params).ok()?;
+            let amount = ctx.amount_text(
+                ctx.currency(params.currency),
+                params.currency,
+                params.amount,
+            );
+            Some(currency_view(
+                ctx,
+                format!("Pay what is owed, up to {amount}"),
+                params.currency,
+                Some(("Maximum Amount", params.amount)),
+                None,
+            ))
+        }
+        Action::TakeAll => {
+            let params = CurrencyAmount::abi_decode_params(params).ok()?;
+            let amount = ctx.amount_text(
+                ctx.currency(params.currency),
+                params.currency,
+                params.amount,
+            );
+            Some(currency_view(
+                ctx,
+                format!("Receive everything owed, at least {amount}"),
+                params.currency,
+                Some(("Minimum Amount", params.amount)),
+                None,
+            ))
+        }
+        Action::Take => {
+            let params = CurrencyRecipientAmount::abi_decode_params(params).ok()?;
+            let amount = delta_amount(ctx, params.currency, params.amount, "everything owed");
+            Some(currency_view(
+                ctx,
+                format!("Send {amount} to {}", recipient_name(params.recipient)),
+                params.currency,
+                (params.amount != OPEN_DELTA).then_some(("Amount", params.amount)),
+                Some(params.recipient),
+            ))
+        }
+        Action::TakePortion => {
+            let params = CurrencyRecipientAmount::abi_decode_params(params).ok()?;
+            let portion = format!("{}%", format_token_amount(params.amount, 2));
+            let mut view = currency_view(
+                ctx,
+                format!(
+                    "Send {portion} of the {} owed to {}",
+                    token_name(ctx.currency(params.currency), params.currency),
+                    recipient_name(params.recipient)
+                ),
+                params.currency,
+                None,
+                Some(params.recipient),
+            );
+            view.fields.push(text_field("Portion", &portion));
+            Some(view)
+        }
+        Action::SettlePair | Action::TakePair => {
+            let (currency0, currency1, recipient) = if action == Action::SettlePair {
+                let params = CurrencyPair::abi_decode_params(params).ok()?;
+                (params.currency0, params.currency1, None)
+            } else {
+                let params = CurrencyPairRecipient::abi_decode_params(params).ok()?;
+                (params.currency0, params.currency1, Some(params.recipient))
+            };
+            let pair = format!(
+                "{} and {}",
+                token_name(ctx.currency(currency0), currency0),
+                token_name(ctx.currency(currency1), currency1)
+            );
+            let summary = match recipient {
+                None => format!("Pay what is owed in {pair}"),
+                Some(recipient) => format!(
+                    "Send what is owed in {pair} to {}",
+                    recipient_name(recipient)
+                ),
+            };
+            let mut fields = vec![
+                token_field("Currency 0", ctx.currency(currency0), currency0),
+                token_field("Currency 1", ctx.currency(currency1), currency1),
+            ];
+            fields.extend(recipient.map(|recipient| recipient_field("Recipient", recipient)));
+            Some(CommandView { summary, fields })
+        }
+        Action::CloseCurrency => {
+            let params = CurrencyOnly::abi_decode_params(params).ok()?;
+            let name = token_name(ctx.currency(params.currency), params.currency);
+            Some(currency_view(
+                ctx,
+                format!("Settle or take the {name} balance"),
+                params.currency,
+                None,
+                None,
+            ))
+        }
+        Action::Sweep => {
+            let params = CurrencyRecipient::abi_decode_params(params).ok()?;
+            let name = token_name(ctx.currency(params.currency), params.currency);
+            Some(currency_view(
+                ctx,
+                format!(
+                    "Sweep the router's {name} to {}",
+                    recipient_name(params.recipient)
+                ),
+                params.currency,
+                None,
+                Some(params.recipient),
+            ))
+        }
+        Action::Wrap | Action::Unwrap => {
+            let params = AmountOnly::abi_decode_params(params).ok()?;
+            let native = ctx.currency(Address::ZERO);
+            let amount = delta_amount(ctx, Address::ZERO, params.amount, "the full amount owed");
+            let verb = if action == Action::Wrap {
+                "Wrap"
+            } else {
+                "Unwrap"
+            };
+            Some(CommandView {
+                summary: format!("{verb} {amount}"),
+                fields: vec![ctx.amount_field("Amount", native, Address::ZERO, params.amount)],
+            })
+        }
+        // Liquidity, donation and ERC-6909 actions are shown raw
+        _ => None,
+    }
+}
+
+/// The input and output currencies of a swap through a single pool.
+fn single_pool_direction(key: &PoolKey, zero_for_one: bool) -> (Address, Address) {
+    if zero_for_one {
+        (key.currency0, key.currency1)
+    } else {
+        (key.currency1, key.currency0)
+    }
+}
+
+fn single_pool_swap_view(ctx: Context<'_>, swap: &Swap, key: &PoolKey) -> CommandView {
+    let (pool, pool_fields) = pool_key_view(ctx, key);
+    let mut fields = swap.fields(ctx);
+    fields.push(annotated(preview_layout(
+        "Pool",
+        "Pool",
+        &pool,
+        None,
+        pool_fields,
+    )));
+    CommandView {
+        summary: swap.summary(ctx),
+        fields,
+    }
+}
+
+fn multi_pool_swap_view(ctx: Context<'_>, swap: &Swap, route: String) -> CommandView {
+    let mut fields = swap.fields(ctx);
+    fields.push(text_field("Route", &route));
+    CommandView {
+        summary: swap.summary(ctx),
+        fields,
+    }
+}
+
+fn path_route(ctx: Context<'_>, start: Address, path: &[PathKey]) -> String {
+    let mut route = token_name(ctx.currency(start), start);
+    for hop in path {
+        route.push_str(&format!(
+            " > {} > {}",
+            fee_text(hop.fee.to::<u32>()),
+            token_name(
+                ctx.currency(hop.intermediateCurrency),
+                hop.intermediateCurrency
+            )
+        ));
+    }
+    route
+}
+
+fn delta_amount(ctx: Context<'_>, currency: Address, amount: U256, open_delta: &str) -> String {
+    let known = ctx.currency(currency);
+    if amount == OPEN_DELTA {
+        format!("{open_delta} in {}", token_name(known, currency))
+    } else {
+        ctx.amount_text(known, currency, amount)
+    }
+}
+
+fn currency_view(
+    ctx: Context<'_>,
+    summary: String,
+    currency: Address,
+    amount: Option<(&str, U256)>,
+    recipient: Option<Address>,
+) -> CommandView {
+    let known = ctx.currency(currency);
+    let mut fields: Vec<AnnotatedPayloadField> = vec![token_field("Currency", known, currency)];
+    fields.extend(amount.map(|(label, amount)| ctx.amount_field(label, known, currency, amount)));
+    fields.extend(recipient.map(|recipient| recipient_field("Recipient", recipient)));
+    CommandView { summary, fields }
+}
@@ -0,0 +1,87 @@
+//! WETH9 and the wrapped native tokens cloned from it, such as WPOL.
+
+use alloy_sol_types::{SolCall, sol};
+use visualsign::SignablePayloadField;
+
+use crate::chains::native_currency;
+use crate::fields::{amount_field, preview_layout, text_field};
+use crate::fmt::format_token_amount;
+
+sol! {
+    // From: https://etherscan.io/address/0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2#code
+    interface IWETH9 {
+        function deposit() external payable;
+        function withdraw(uint256 wad) external;
+    }
+}
+
+pub struct WethVisualizer {}
+
+impl WethVisualizer {
+    /// Decodes `input`, a call to the wrapped native token on `chain_id`.
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: Option<u64>,
+    ) -> Option<SignablePayloadField> {
+        let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+        let (symbol, decimals) = native_currency(chain_id);
+        let wrapped = format!("W{symbol}");
+        match selector {
+            IWETH9::depositCall::SELECTOR => {
+                IWETH9::depositCall::abi_decode(input).ok()?;
+                Some(preview_layout(
+                    "Wrap",
+                    "Wrap",
+                    &format!("Wrap {symbol} into {wrapped}"),
+                    None,
+                    vec![text_field(
+                        "Amount",
+                        &format!("The {symbol} value sent with this call"),
+                    )],
+                ))
+            }
+            IWETH9::withdrawCall::SELECTOR => {
+                let call = IWETH9::withdrawCall::abi_decode(input).ok()?;
+                let amount = format_token_amount(call.wad, decimals);
+                Some(preview_layout(
+                    "Unwrap",
+                    "Unwrap",
+                    &format!("Unwrap {amount} {wrapped} into {symbol}"),
+                    None,
+                    vec![amount_field("Amount", &amount, Some(&wrapped))],
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::U256;
+
+    #[test]
+    fn test_wrap_and_unwrap() {
+        let input = IWETH9::depositCall {}.abi_encode();
+        assert_eq!(
+            WethVisualizer {}
+                .visualize_tx_commands(&input, Some(1))
+                .unwrap()
+                .fallback_text(),
+            "Wrap ETH into WETH"
+        );
+        let input = IWETH9::withdrawCall {
+            wad: U256::from(25u64) * U256::from(10u64).pow(U256::from(17)),
+        }
+        .abi_encode();
+        assert_eq!(
+            WethVisualizer {}
+                .visualize_tx_commands(&input, Some(137))
+                .unwrap()
+                .fallback_text(),
+            "Unwrap 2.5 WPOL into POL"
+        );
+    }
+}
@@ -0,0 +1,360 @@
+//! Contract creation, both direct (a transaction without `to`) and through the
+//! deterministic CREATE2 deployers.
+//!
+//! Init code is shown by size and hash rather than as hex, together with the
+//! address the contract will land at when the deployer is known. Proxies
+//! built from the common OpenZeppelin and EIP-1167 templates are recognized so
+//! the implementation they point at, and any initializer call, can be shown.
+
+use alloy_primitives::{Address, B256, Bytes, address, b256, keccak256};
+use alloy_sol_types::{SolType, abi::TokenSeq, sol_data};
+use visualsign::{AnnotatedPayloadField, SignablePayloadField};
+
+use crate::fields::{addr, address_field, preview_layout, text_field, with_annotation};
+
+/// `bytes32(uint256(keccak256("eip1967.proxy.implementation")) - 1)`, which
+/// ERC-1967 proxies push when storing their implementation.
+const IMPLEMENTATION_SLOT: B256 =
+    b256!("0x360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc");
+
+/// EIP-1167 minimal proxy creation code, around the 20-byte implementation.
+const MINIMAL_PROXY_PREFIX: &[u8] = &[
+    0x3d, 0x60, 0x2d, 0x80, 0x60, 0x0a, 0x3d, 0x39, 0x81, 0xf3, 0x36, 0x3d, 0x3d, 0x37, 0x3d, 0x3d,
+    0x3d, 0x36, 0x3d, 0x73,
+];
+const MINIMAL_PROXY_SUFFIX: &[u8] = &[
+    0x5a, 0xf4, 0x3d, 0x82, 0x80, 0x3e, 0x90, 0x3d, 0x91, 0x60, 0x2b, 0x57, 0xfd, 0x5b, 0xf3,
+];
+
+/// Deployers that take `salt ++ initCode` as raw calldata and CREATE2 it.
+/// Both are deployed keylessly, at the same address on every chain.
+const CREATE2_FACTORIES: [(Address, &str); 2] = [
+    (
+        address!("0x4e59b44847b379578588920ca78fbf26c0b4956c"),
+        "Deterministic Deployment Proxy",
+    ),
+    (
+        address!("0x914d7fec6aac8cd542e72bca78b30650d45643d7"),
+        "Safe Singleton Factory",
+    ),
+];
+
+/// `ERC1967Proxy(address implementation, bytes data)`.
+type Erc1967ProxyArgs = (sol_data::Address, sol_data::Bytes);
+/// `TransparentUpgradeableProxy(address logic, address admin, bytes data)`.
+type TransparentProxyArgs = (sol_data::Address, sol_data::Address, sol_data::Bytes);
+
+/// A proxy template recognized in init code.
+#[derive(Debug, PartialEq)]
+enum Proxy {
+    Erc1967 {
+        implementation: Address,
+        data: Bytes,
+    },
+    Transparent {
+        implementation: Address,
+        admin: Address,
+        data: Bytes,
+    },
+    Minimal {
+        implementation: Address,
+    },
+}
+
+/// Constructor arguments are appended to the creation code with nothing to
+/// mark where they start, so try each word-aligned suffix, shortest first, and
+/// keep the first that is exactly the encoding of `T`.
+fn trailing_args<T>(init_code: &[u8]) -> Option<T::RustType>
+where
+    T: SolType,
+    for<'a> T::Token<'a>: TokenSeq<'a>,
+{
+    (1..init_code.len() / 32).find_map(|words| {
+        let args = &init_code[init_code.len() - words * 32..];
+        T::abi_decode_params(args)
+            .ok()
+            .filter(|value| T::abi_encode_params(value) == args)
+    })
+}
+
+fn detect_proxy(init_code: &[u8]) -> Option<Proxy> {
+    if init_code.len() == MINIMAL_PROXY_PREFIX.len() + 20 + MINIMAL_PROXY_SUFFIX.len()
+        && init_code.starts_with(MINIMAL_PROXY_PREFIX)
+        && init_code.ends_with(MINIMAL_PROXY_SUFFIX)
+    {
+        let start = MINIMAL_PROXY_PREFIX.len();
+        return Some(Proxy::Minimal {
+            implementation: Address::from_slice(&init_code[start..start + 20]),
+        });
+    }
+
+    let pushes_slot = init_code
+        .windows(33)
+        .any(|window| window[0] == 0x7f && window[1..] == IMPLEMENTATION_SLOT[..]);
+    if !pushes_slot {
+        return None;
+    }
+    if let Some((implementation, admin, data)) = trailing_args::<TransparentProxyArgs>(init_code) {
+        return Some(Proxy::Transparent {
+            implementation,
+            admin,
+            data,
+        });
+    }
+    trailing_args::<Erc1967ProxyArgs>(init_code).map(|(implementation, data)| Proxy::Erc1967 {
+        implementation,
+        data,
+    })
+}
+
+/// Size, hash and any recognized proxy template of `init_code`, with the
+/// proxy's initializer call rendered by `decode_call`.
+fn init_code_fields(
+    init_code: &[u8],
+    proxy: Option<&Proxy>,
+    decode_call: &dyn Fn(Address, &[u8]) -> Vec<AnnotatedPayloadField>,
+) -> Vec<AnnotatedPayloadField> {
+    let mut fields = vec![
+        text_field("Init Code Size", &format!("{} bytes", init_code.len())),
+        text_field("Init Code Hash", &format!("{:?}", keccak256(init_code))),
+    ];
+    let (pattern, implementation, initializer) = match proxy {
+        None => return fields,
+        Some(Proxy::Erc1967 {
+            implementation,
+            data,
+        }) => ("ERC-1967 proxy", *implementation, Some(data)),
+        Some(Proxy::Transparent {
+            implementation,
+            admin,
+            data,
+        }) => {
+            fields.push(address_field("Proxy Admin", &addr(*admin), None, None));
+            ("Transparent upgradeable proxy", *implementation, Some(data))
+        }
+        Some(Proxy::Minimal { implementation }) => {
+            ("EIP-1167 minimal proxy", *implementation, None)
+        }
+    };
+    fields.push(text_field("Pattern", pattern));
+    fields.push(with_annotation(
+        address_field("Implementation", &addr(implementation), None, None),
+        "Every call to the new contract runs this implementation's code",
+    ));
+    if let Some(data) = initializer.filter(|data| !data.is_empty()) {
+        fields.extend(decode_call(implementation, data));
+    }
+    fields
+}
+
+fn deployment_subtitle(proxy: Option<&Proxy>, address: Option<Address>) -> String {
+    let what = match proxy {
+        Some(Proxy::Erc1967 { .. }) => "an ERC-1967 proxy",
+        Some(Proxy::Transparent { .. }) => "a transparent upgradeable proxy",
+        Some(Proxy::Minimal { .. }) => "a minimal proxy",
+        None => "a contract",
+    };
+    match address {
+        Some(address) => format!("Deploy {what} at {address:?}"),
+        None => format!("Deploy {what}"),
+    }
+}
+
+/// A layout for a transaction without a destination, which deploys
+/// `init_code`. The contract's address is only known when `sender` is.
+pub(crate) fn deployment_layout(
+    init_code: &[u8],
+    sender: Option<Address>,
+    nonce: u64,
+    decode_call: &dyn Fn(Address, &[u8]) -> Vec<AnnotatedPayloadField>,
+) -> SignablePayloadField {
+    let proxy = detect_proxy(init_code);
+    let address = sender.map(|sender| sender.create(nonce));
+
+    let mut fields = Vec::new();
+    if let (Some(sender), Some(address)) = (sender, address) {
+        fields.push(address_field("Deployer", &addr(sender), None, None));
+        fields.push(address_field(
+            "Contract Address",
+            &addr(address),
+            None,
+            None,
+        ));
+    }
+    fields.extend(init_code_fields(init_code, proxy.as_ref(), decode_call));
+
+    preview_layout(
+        "Contract Deployment",
+        "Contract Deployment",
+        &deployment_subtitle(proxy.as_ref(), address),
+        None,
+        fields,
+    )
+}
+
+/// A layout for `input` sent to `to` if `to` is one of the CREATE2 deployers.
+pub(crate) fn create2_deployment(
+    to: Address,
+    input: &[u8],
+    decode_call: &dyn Fn(Address, &[u8]) -> Vec<AnnotatedPayloadField>,
+) -> Option<SignablePayloadField> {
+    let (_, factory_name) = CREATE2_FACTORIES
+        .iter()
+        .find(|(factory, _)| *factory == to)?;
+    if input.len() <= 32 {
+        return None;
+    }
+    let (salt, init_code) = input.split_at(32);
+    let salt = B256::from_slice(salt);
+    let address = to.create2(salt, keccak256(init_code));
+    let proxy = detect_proxy(init_code);
+
+    let mut fields = vec![
+        address_field("Factory", &addr(to), Some(factory_name), None),
+        text_field("Salt", &format!("{salt:?}")),
+        address_field("Contract Address", &addr(address), None, None),
+    ];
+    fields.extend(init_code_fields(init_code, proxy.as_ref(), decode_call));
+
+    Some(preview_layout(
+        "Contract Deployment",
+        "CREATE2 Deployment",
+        &deployment_subtitle(proxy.as_ref(), Some(address)),
+        None,
+        fields,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IMPLEMENTATION: Address = Address::repeat_byte(0x11);
+    const DEPLOYER: Address = Address::repeat_byte(0x22);
+
+    fn decode_initializer(_: Address, _: &[u8]) -> Vec<AnnotatedPayloadField> {
+        vec![text_field("Initializer", "decoded")]
+    }
+
+    /// Creation code that stores to the ERC-1967 slot, followed by `args`.
+    fn proxy_init_code(args: Vec<u8>) -> Vec<u8> {
+        let mut code = vec![0x60, 0x80, 0x60, 0x40, 0x52, 0x7f];
+        code.extend_from_slice(IMPLEMENTATION_SLOT.as_slice());
+        code.extend_from_slice(&[0x55, 0x00]);
+        code.extend(args);
+        code
+    }
+
+    fn expanded_labels(field: SignablePayloadField) -> Vec<String> {
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } = field else {
+            panic!("expected a preview layout");
+        };
+        preview_layout
+            .expanded
+            .unwrap()
+            .fields
+            .iter()
+            .map(|field| field.signable_payload_field.label().clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_detect_proxies() {
+        let initializer = Bytes::from(vec![0x81, 0x29, 0xfc, 0x1c]);
+        let erc1967 = proxy_init_code(Erc1967ProxyArgs::abi_encode_params(&(
+            IMPLEMENTATION,
+            initializer.clone(),
+        )));
+        assert_eq!(
+            detect_proxy(&erc1967),
+            Some(Proxy::Erc1967 {
+                implementation: IMPLEMENTATION,
+                data: initializer.clone(),
+            })
+        );
+
+        let transparent = proxy_init_code(TransparentProxyArgs::abi_encode_params(&(
+            IMPLEMENTATION,
+            DEPLOYER,
+            Bytes::new(),
+        )));
+        assert_eq!(
+            detect_proxy(&transparent),
+            Some(Proxy::Transparent {
+                implementation: IMPLEMENTATION,
+                admin: DEPLOYER,
+                data: Bytes::new(),
+            })
+        );
+
+        let mut minimal = MINIMAL_PROXY_PREFIX.to_vec();
+        minimal.extend_from_slice(IMPLEMENTATION.as_slice());
+        minimal.extend_from_slice(MINIMAL_PROXY_SUFFIX);
+        assert_eq!(
+            detect_proxy(&minimal),
+            Some(Proxy::Minimal {
+                implementation: IMPLEMENTATION
+            })
+        );
+
+        // Using the slot without proxy constructor arguments isn't a proxy
+        assert_eq!(detect_proxy(&proxy_init_code(vec![])), None);
+    }
+
+    #[test]
+    fn test_deployment_layout() {
+        let init_code = proxy_init_code(Erc1967ProxyArgs::abi_encode_params(&(
+            IMPLEMENTATION,
+            Bytes::from(vec![0x81, 0x29, 0xfc, 0x1c]),
+        )));
+        let field = deployment_layout(&init_code, Some(DEPLOYER), 7, &decode_initializer);
+        assert_eq!(
+            field.fallback_text(),
+            &format!("Deploy an ERC-1967 proxy at {:?}", DEPLOYER.create(7))
+        );
+        assert_eq!(
+            expanded_labels(field),
+            vec![
+                "Deployer",
+                "Contract Address",
+                "Init Code Size",
+                "Init Code Hash",
+                "Pattern",
+                "Implementation",
+                "Initializer",
+            ]
+        );
+
+        // Without the sender there's no address to predict
+        let field = deployment_layout(&[0x60, 0x00], None, 7, &decode_initializer);
+        assert_eq!(field.fallback_text(), "Deploy a contract");
+        assert_eq!(
+            expanded_labels(field),
+            vec!["Init Code Size", "Init Code Hash"]
+        );
+    }
+
+    #[test]
+    fn test_create2_factory_call() {
+        let factory = CREATE2_FACTORIES[0].0;
+        let salt = B256::repeat_byte(0x01);
+        let init_code = [0x60, 0x00, 0x60, 0x00, 0xf3];
+        let input = [salt.as_slice(), &init_code].concat();
+
+        let field = create2_deployment(factory, &input, &decode_initializer).unwrap();
+        let expected = factory.create2(salt, keccak256(init_code));
+        assert_eq!(
+            field.fallback_text(),
+            &format!("Deploy a contract at {expected:?}")
+        );
+
+        assert_eq!(
+            create2_deployment(DEPLOYER, &input, &decode_initializer),
+            None
+        );
+        assert_eq!(
+            create2_deployment(factory, salt.as_slice(), &decode_initializer),
+            None
+        );
+    }
+}
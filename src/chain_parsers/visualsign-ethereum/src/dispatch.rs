@@ -0,0 +1,176 @@
+//! Picks the visualizers for a call from its destination and selector.
+//!
+//! Calls that wrap other calls, such as a Safe's `execTransaction` or a
+//! `multicall`, send each inner call back through
+//! [`CallDispatcher::call_fields`] one level deeper. Their warnings are
+//! collected as they're decoded, for the payload to lead with.
+
+use std::cell::RefCell;
+
+use alloy_primitives::Address;
+use visualsign::{AnnotatedPayloadField, SignablePayloadField, vsptrait::VisualSignOptions};
+
+use crate::abi;
+use crate::call_warnings;
+use crate::chains;
+use crate::contracts;
+use crate::deployment;
+use crate::fields::{annotated, plain_text_field};
+use crate::registry::ContractRegistry;
+use crate::selectors;
+use crate::tokens::TokenRegistry;
+
+/// Wrapped calls nested deeper than this are shown as raw calldata.
+pub(crate) const MAX_CALL_DEPTH: usize = 4;
+
+/// Everything needed to decode a call besides the call itself.
+#[derive(Clone, Copy)]
+pub(crate) struct CallDispatcher<'a> {
+    pub contracts: &'a ContractRegistry,
+    pub tokens: &'a TokenRegistry,
+    pub chain_id: Option<u64>,
+    pub options: &'a VisualSignOptions,
+    pub depth: usize,
+    /// The account inner calls are made on behalf of, when known, which
+    /// their recipients are checked against.
+    pub account: Option<Address>,
+    /// Warnings raised by the inner calls decoded so far.
+    pub warnings: &'a RefCell<Vec<SignablePayloadField>>,
+}
+
+pub(crate) fn raw_input_field(input: &[u8]) -> SignablePayloadField {
+    plain_text_field("Input Data", &format!("0x{}", hex::encode(input)))
+}
+
+impl CallDispatcher<'_> {
+    /// Fields describing `input` sent to `to`; raw calldata when nothing decodes it.
+    pub(crate) fn call_fields(
+        &self,
+        to: Option<Address>,
+        input: &[u8],
+    ) -> Vec<SignablePayloadField> {
+        if self.depth > MAX_CALL_DEPTH {
+            return vec![raw_input_field(input)];
+        }
+        let mut fields: Vec<SignablePayloadField> = Vec::new();
+
+        // Protocol visualizers only run for the deployments in the registry
+        let destination = to.and_then(|to| self.contracts.lookup(self.chain_id, to));
+        fields.extend(
+            destination.and_then(|info| info.kind.visualize(input, self.chain_id, self.tokens)),
+        );
+
+        // The CREATE2 deployers sit at the same address on every chain
+        if fields.is_empty() {
+            fields.extend(to.and_then(|to| {
+                deployment::create2_deployment(to, input, &|to, data| {
+                    self.nested_call_fields(to, data)
+                })
+            }));
+        }
+
+        // Safes and batching contracts are deployed per owner or per app, so
+        // they're recognized by selector
+        if fields.is_empty() {
+            let decode_call = |to, data: &[u8]| self.nested_call_fields(to, data);
+            fields.extend(
+                (contracts::safe::SafeVisualizer {})
+                    .visualize_tx_commands(input, self.chain_id, &decode_call)
+                    .or_else(|| {
+                        to.and_then(|to| {
+                            (contracts::batch::BatchVisualizer {}).visualize_tx_commands(
+                                input,
+                                to,
+                                self.chain_id,
+                                &decode_call,
+                            )
+                        })
+                    }),
+            );
+        }
+
+        // So is 0x Settler, which is redeployed with each release. Nothing
+        // vouches for the address, so inner calls carry the same warning the
+        // transaction's own destination gets
+        let settler = fields
+            .is_empty()
+            .then(|| {
+                (contracts::aggregators::SettlerVisualizer {}).visualize_tx_commands(
+                    input,
+                    self.chain_id,
+                    self.tokens,
+                )
+            })
+            .flatten();
+        if let Some(swap) = settler {
+            if let Some(to) = to.filter(|_| self.depth > 0) {
+                fields.push(crate::lookalike_warning(
+                    contracts::aggregators::SETTLER,
+                    to,
+                    &chains::get_chain_name(self.chain_id),
+                ));
+            }
+            fields.push(swap);
+        }
+
+        if self.options.decode_transfers {
+            // ERC721's transferFrom and approve share ERC20's selectors, so unless
+            // the destination is a known token they could be either
+            fields.extend(to.and_then(|to| {
+                let token = self.tokens.lookup(self.chain_id, to);
+                let nft = contracts::nft::NFTVisualizer {};
+                token
+                    .is_none()
+                    .then(|| nft.visualize_shared_selectors(input, to))
+                    .flatten()
+                    .or_else(|| {
+                        (contracts::erc20::ERC20Visualizer {})
+                            .visualize_tx_commands(input, to, token)
+                    })
+                    .or_else(|| nft.visualize_tx_commands(input, to))
+            }));
+        }
+        // The caller-supplied ABI describes the transaction's own destination
+        if fields.is_empty() && self.depth == 0 {
+            fields.extend(
+                self.options
+                    .metadata
+                    .as_ref()
+                    .and_then(abi::AbiVisualizer::from_chain_metadata)
+                    .and_then(|visualizer| visualizer.visualize_tx_commands(input)),
+            );
+        }
+        // Last resort: guess the function from its selector, next to the raw input
+        if fields.is_empty() {
+            fields.extend(selectors::signature_guess(input));
+            fields.push(raw_input_field(input));
+        }
+        fields
+    }
+
+    /// [`Self::call_fields`] for a call made by the call being decoded, whose
+    /// warnings are added to [`Self::warnings`].
+    pub(crate) fn nested_call_fields(
+        &self,
+        to: Address,
+        input: &[u8],
+    ) -> Vec<AnnotatedPayloadField> {
+        self.warnings.borrow_mut().extend(call_warnings(
+            to,
+            input,
+            self.account,
+            self.chain_id,
+            self.contracts,
+            self.tokens,
+        ));
+        let nested = CallDispatcher {
+            depth: self.depth + 1,
+            ..*self
+        };
+        nested
+            .call_fields(Some(to), input)
+            .into_iter()
+            .map(annotated)
+            .collect()
+    }
+}
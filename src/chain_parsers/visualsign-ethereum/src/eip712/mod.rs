@@ -0,0 +1,367 @@
+//! EIP-712 typed data, as requested by `eth_signTypedData_v4`.
+//!
+//! The input is the JSON typed data object (or that object as a JSON string).
+//! The payload shows the signing domain, the primary type's message as a tree
+//! of nested structs, and the hashes that end up being signed.
+
+use alloy_dyn_abi::TypedData;
+use alloy_sol_types::Eip712Domain;
+use visualsign::{
+    AnnotatedPayloadField, SignablePayload, SignablePayloadField,
+    vsptrait::{
+        Transaction, TransactionParseError, VisualSignConverter, VisualSignConverterFromString,
+        VisualSignError, VisualSignOptions,
+    },
+};
+
+mod protocols;
+
+use crate::EthereumVisualSignConverter;
+use crate::abi::value_to_field;
+use crate::chains;
+use crate::fields::{
+    addr, address_field, number_field, plain_text_field, preview_layout, text_field,
+};
+use crate::tokens::TokenRegistry;
+
+/// Wrapper around Alloy's `TypedData` that implements the Transaction trait
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Eip712TypedDataWrapper {
+    typed_data: TypedData,
+}
+
+impl Transaction for Eip712TypedDataWrapper {
+    fn from_string(data: &str) -> Result<Self, TransactionParseError> {
+        let typed_data: TypedData = serde_json::from_str(data).map_err(|e| {
+            TransactionParseError::InvalidFormat(format!("Invalid typed data: {e}"))
+        })?;
+        // Make sure the message matches its declared types before we display it
+        typed_data
+            .eip712_signing_hash()
+            .map_err(|e| TransactionParseError::DecodeError(e.to_string()))?;
+        Ok(Self { typed_data })
+    }
+
+    fn transaction_type(&self) -> String {
+        "EthereumTypedData".to_string()
+    }
+}
+
+impl Eip712TypedDataWrapper {
+    pub fn new(typed_data: TypedData) -> Self {
+        Self { typed_data }
+    }
+
+    pub fn inner(&self) -> &TypedData {
+        &self.typed_data
+    }
+}
+
+/// Returns true if `data` looks like typed data JSON rather than an RLP transaction.
+pub fn is_typed_data(data: &str) -> bool {
+    let data = data.trim_start();
+    data.starts_with('{') || data.starts_with('"')
+}
+
+/// Converter that knows how to format EIP-712 typed data for VisualSign
+pub struct Eip712VisualSignConverter;
+
+impl VisualSignConverter<Eip712TypedDataWrapper> for Eip712VisualSignConverter {
+    fn to_visual_sign_payload(
+        &self,
+        wrapper: Eip712TypedDataWrapper,
+        options: VisualSignOptions,
+    ) -> Result<SignablePayload, VisualSignError> {
+        convert_to_visual_sign_payload(wrapper.inner(), TokenRegistry::builtin(), options)
+    }
+}
+
+impl VisualSignConverterFromString<Eip712TypedDataWrapper> for Eip712VisualSignConverter {}
+
+/// The Ethereum converter renders typed data with its own token registry.
+impl VisualSignConverter<Eip712TypedDataWrapper> for EthereumVisualSignConverter {
+    fn to_visual_sign_payload(
+        &self,
+        wrapper: Eip712TypedDataWrapper,
+        options: VisualSignOptions,
+    ) -> Result<SignablePayload, VisualSignError> {
+        convert_to_visual_sign_payload(wrapper.inner(), &self.tokens, options)
+    }
+}
+
+fn convert_to_visual_sign_payload(
+    typed_data: &TypedData,
+    tokens: &TokenRegistry,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let message = typed_data
+        .coerce()
+        .map_err(|e| VisualSignError::DecodeError(format!("Invalid typed data message: {e}")))?;
+    let signing_hash = typed_data
+        .eip712_signing_hash()
+        .map_err(|e| VisualSignError::DecodeError(e.to_string()))?;
+
+    let domain = &typed_data.domain;
+    // A known protocol's name on another contract leads the payload
+    let lookalike = protocols::lookalike_warning(typed_data);
+    let unverified = lookalike.is_some();
+    let mut fields: Vec<SignablePayloadField> = lookalike.into_iter().collect();
+    if let Some(chain_id) = domain.chain_id {
+        let network = chains::get_chain_name(u64::try_from(chain_id).ok());
+        fields.push(plain_text_field("Network", &network));
+    }
+    fields.push(domain_field(domain, unverified));
+    fields.push(plain_text_field("Primary Type", &typed_data.primary_type));
+    // Permits and orders from known protocols get a summary ahead of the raw message
+    fields.extend(protocols::visualize_known_message(
+        typed_data, &message, tokens,
+    ));
+    // The domain itself can be signed as the primary type; it has no separate message
+    if typed_data.primary_type != "EIP712Domain" {
+        let message_field = value_to_field(&typed_data.primary_type, &[], &message);
+        fields.push(message_field.signable_payload_field);
+    }
+    fields.push(plain_text_field(
+        "Domain Separator",
+        &domain.separator().to_string(),
+    ));
+    if let Ok(struct_hash) = typed_data.hash_struct() {
+        fields.push(plain_text_field("Struct Hash", &struct_hash.to_string()));
+    }
+    fields.push(plain_text_field("Signing Hash", &signing_hash.to_string()));
+
+    let title = options
+        .transaction_name
+        .unwrap_or_else(|| format!("Sign {}", typed_data.primary_type));
+    let subtitle = domain.name.as_ref().map(|name| name.to_string());
+    Ok(SignablePayload::new(
+        0,
+        title,
+        subtitle,
+        fields,
+        "EthereumTypedData".to_string(),
+    ))
+}
+
+/// The signing domain; `unverified` badges a verifying contract that claims
+/// a protocol's name without being its deployment.
+fn domain_field(domain: &Eip712Domain, unverified: bool) -> SignablePayloadField {
+    let mut expanded: Vec<AnnotatedPayloadField> = Vec::new();
+    expanded.extend(domain.name.as_deref().map(|name| text_field("Name", name)));
+    expanded.extend(
+        domain
+            .version
+            .as_deref()
+            .map(|version| text_field("Version", version)),
+    );
+    expanded.extend(
+        domain
+            .chain_id
+            .map(|chain_id| number_field("Chain ID", &chain_id.to_string())),
+    );
+    expanded.extend(domain.verifying_contract.map(|contract| {
+        address_field(
+            "Verifying Contract",
+            &addr(contract),
+            None,
+            unverified.then_some("Unverified contract"),
+        )
+    }));
+    expanded.extend(
+        domain
+            .salt
+            .map(|salt| text_field("Salt", &salt.to_string())),
+    );
+
+    let title = domain.name.as_deref().unwrap_or("Unnamed domain");
+    let subtitle = match (&domain.version, domain.verifying_contract) {
+        (Some(version), Some(contract)) => format!("v{version} at {}", addr(contract)),
+        (Some(version), None) => format!("v{version}"),
+        (None, Some(contract)) => addr(contract),
+        (None, None) => "EIP-712 domain".to_string(),
+    };
+    let condensed = vec![text_field("Name", title)];
+    preview_layout("Domain", title, &subtitle, Some(condensed), expanded)
+}
+
+// Public API functions for ease of use
+pub fn typed_data_to_visual_sign(
+    typed_data: TypedData,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    Eip712VisualSignConverter
+        .to_visual_sign_payload(Eip712TypedDataWrapper::new(typed_data), options)
+}
+
+pub fn typed_data_string_to_visual_sign(
+    typed_data: &str,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    Eip712VisualSignConverter.to_visual_sign_payload_from_string(typed_data, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example from the EIP-712 specification
+    const MAIL: &str = r#"{
+        "types": {
+            "EIP712Domain": [
+                {"name": "name", "type": "string"},
+                {"name": "version", "type": "string"},
+                {"name": "chainId", "type": "uint256"},
+                {"name": "verifyingContract", "type": "address"}
+            ],
+            "Person": [
+                {"name": "name", "type": "string"},
+                {"name": "wallet", "type": "address"}
+            ],
+            "Mail": [
+                {"name": "from", "type": "Person"},
+                {"name": "to", "type": "Person"},
+                {"name": "contents", "type": "string"}
+            ]
+        },
+        "primaryType": "Mail",
+        "domain": {
+            "name": "Ether Mail",
+            "version": "1",
+            "chainId": 1,
+            "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+        },
+        "message": {
+            "from": {"name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"},
+            "to": {"name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"},
+            "contents": "Hello, Bob!"
+        }
+    }"#;
+
+    fn expanded_fields(field: &SignablePayloadField) -> &[AnnotatedPayloadField] {
+        match field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => {
+                &preview_layout.expanded.as_ref().unwrap().fields
+            }
+            other => panic!("expected preview layout, got {other:?}"),
+        }
+    }
+
+    fn find<'a>(payload: &'a SignablePayload, label: &str) -> &'a SignablePayloadField {
+        payload.fields.iter().find(|f| f.label() == label).unwrap()
+    }
+
+    #[test]
+    fn test_mail_example() {
+        let payload = typed_data_string_to_visual_sign(MAIL, VisualSignOptions::default()).unwrap();
+
+        assert_eq!(payload.title, "Sign Mail");
+        assert_eq!(payload.subtitle.as_deref(), Some("Ether Mail"));
+        assert_eq!(payload.payload_type, "EthereumTypedData");
+        assert_eq!(
+            find(&payload, "Network").fallback_text(),
+            "Ethereum Mainnet"
+        );
+
+        let domain = expanded_fields(find(&payload, "Domain"));
+        assert_eq!(domain[0], text_field("Name", "Ether Mail"));
+        assert_eq!(domain[1], text_field("Version", "1"));
+        assert_eq!(domain[2], number_field("Chain ID", "1"));
+        assert_eq!(
+            domain[3],
+            address_field(
+                "Verifying Contract",
+                "0xcccccccccccccccccccccccccccccccccccccccc",
+                None,
+                None
+            )
+        );
+
+        let mail = expanded_fields(find(&payload, "Mail"));
+        let from = &mail[0].signable_payload_field;
+        assert_eq!(from.label(), "from");
+        assert_eq!(from.fallback_text(), "2 fields");
+        assert_eq!(
+            expanded_fields(from),
+            &[
+                text_field("name", "Cow"),
+                address_field(
+                    "wallet",
+                    "0xcd2a3d9f938e13cd947ec05abc7fe734df8dd826",
+                    None,
+                    None
+                ),
+            ]
+        );
+        assert_eq!(mail[2], text_field("contents", "Hello, Bob!"));
+
+        assert_eq!(
+            find(&payload, "Domain Separator").fallback_text(),
+            "0xf2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+        );
+        assert_eq!(
+            find(&payload, "Struct Hash").fallback_text(),
+            "0xc52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e"
+        );
+        assert_eq!(
+            find(&payload, "Signing Hash").fallback_text(),
+            "0xbe609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+        );
+    }
+
+    #[test]
+    fn test_protocol_name_on_another_contract() {
+        let lookalike = MAIL.replace("\"Ether Mail\"", "\"Permit2\"");
+        let payload =
+            typed_data_string_to_visual_sign(&lookalike, VisualSignOptions::default()).unwrap();
+        assert_eq!(payload.fields[0].label(), "Warning");
+        assert!(payload.fields[0].fallback_text().contains(
+            "0xcccccccccccccccccccccccccccccccccccccccc is not a known Permit2 deployment"
+        ));
+        let domain = expanded_fields(find(&payload, "Domain"));
+        assert_eq!(
+            domain[3],
+            address_field(
+                "Verifying Contract",
+                "0xcccccccccccccccccccccccccccccccccccccccc",
+                None,
+                Some("Unverified contract")
+            )
+        );
+
+        // At the canonical deployment the name is trusted
+        let canonical = lookalike.replace(
+            "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC",
+            "0x000000000022D473030F116dDEE9F6B43aC78BA3",
+        );
+        let payload =
+            typed_data_string_to_visual_sign(&canonical, VisualSignOptions::default()).unwrap();
+        assert_eq!(payload.fields[0].label(), "Network");
+    }
+
+    #[test]
+    fn test_ethereum_converter_routes_typed_data() {
+        let payload = EthereumVisualSignConverter::default()
+            .to_visual_sign_payload_from_string(MAIL, VisualSignOptions::default())
+            .unwrap();
+        assert_eq!(payload.payload_type, "EthereumTypedData");
+
+        // Stringified typed data, as some wallets send it
+        let stringified = serde_json::to_string(MAIL).unwrap();
+        assert!(is_typed_data(&stringified));
+        assert!(Eip712TypedDataWrapper::from_string(&stringified).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_typed_data() {
+        assert!(matches!(
+            Eip712TypedDataWrapper::from_string("{\"types\": 1}"),
+            Err(TransactionParseError::InvalidFormat(_))
+        ));
+        // Message doesn't match the declared types
+        let mismatched = MAIL.replace("\"Hello, Bob!\"", "{\"not\": \"a string\"}");
+        assert!(matches!(
+            Eip712TypedDataWrapper::from_string(&mismatched),
+            Err(TransactionParseError::DecodeError(_))
+        ));
+    }
+}
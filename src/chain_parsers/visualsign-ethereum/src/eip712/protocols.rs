@@ -0,0 +1,984 @@
+//! Renderers for the EIP-712 messages of well-known protocols.
+//!
+//! Token permits and off-chain orders are the typed messages most likely to
+//! hand over funds, so rather than leaving the signer to read the raw struct
+//! tree we summarise who can spend how much of which token, and until when.
+
+use alloy_dyn_abi::{DynSolValue, TypedData};
+use alloy_primitives::{Address, U256, address};
+use visualsign::{AnnotatedPayloadField, SignablePayloadField};
+
+use crate::chains::native_currency;
+use crate::fields::{
+    addr, address_field, amount_field, annotated, number_field, plain_text_field, preview_layout,
+    text_field, with_annotation,
+};
+use crate::fmt::{format_timestamp, format_token_amount};
+use crate::tokens::{Token, TokenRegistry, format_amount, token_name};
+
+const PERMIT2: Address = address!("0x000000000022D473030F116dDEE9F6B43aC78BA3");
+const GPV2_SETTLEMENT: Address = address!("0x9008D19f58AAbD9eD0D60971565AA8510560ab41");
+const SEAPORT_1_5: Address = address!("0x00000000000000ADc04C56Bf30aC9d3c0aAF14dC");
+const SEAPORT_1_6: Address = address!("0x0000000000000068F116a894984e2DB1123eB395");
+
+/// Protocols whose messages are recognised by the domain name they sign under.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Protocol {
+    Permit2,
+    CoW,
+    Seaport,
+}
+
+impl Protocol {
+    fn from_domain_name(name: &str) -> Option<Self> {
+        match name {
+            "Permit2" => Some(Self::Permit2),
+            "Gnosis Protocol" => Some(Self::CoW),
+            "Seaport" => Some(Self::Seaport),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Permit2 => "Permit2",
+            Self::CoW => "CoW Protocol",
+            Self::Seaport => "Seaport",
+        }
+    }
+
+    /// The canonical deployments, which sit at the same address on every chain.
+    fn deployments(self) -> &'static [Address] {
+        match self {
+            Self::Permit2 => &[PERMIT2],
+            Self::CoW => &[GPV2_SETTLEMENT],
+            Self::Seaport => &[SEAPORT_1_5, SEAPORT_1_6],
+        }
+    }
+}
+
+/// A warning when the domain names a known protocol but its verifying
+/// contract isn't one of that protocol's deployments, since any contract can
+/// sign under any name.
+pub(crate) fn lookalike_warning(typed_data: &TypedData) -> Option<SignablePayloadField> {
+    let domain = &typed_data.domain;
+    let protocol = Protocol::from_domain_name(domain.name.as_deref()?)?;
+    let warning = match domain.verifying_contract {
+        Some(contract) if protocol.deployments().contains(&contract) => return None,
+        Some(contract) => format!(
+            "This message is signed under the {} domain, but {} is not a known {} deployment. Treat it as an unverified contract.",
+            protocol.name(),
+            addr(contract),
+            protocol.name(),
+        ),
+        None => format!(
+            "This message is signed under the {} domain, but names no verifying contract. Treat it as unverified.",
+            protocol.name(),
+        ),
+    };
+    Some(plain_text_field("Warning", &warning))
+}
+
+/// Renders `message` if the domain and primary type belong to a known protocol.
+/// Token amounts are looked up in `tokens` on the domain's chain.
+pub(crate) fn visualize_known_message(
+    typed_data: &TypedData,
+    message: &DynSolValue,
+    tokens: &TokenRegistry,
+) -> Option<SignablePayloadField> {
+    let message = Struct::new(message)?;
+    let chain_id = typed_data
+        .domain
+        .chain_id
+        .and_then(|chain_id| u64::try_from(chain_id).ok());
+    let tokens = Tokens { tokens, chain_id };
+    let protocol = typed_data
+        .domain
+        .name
+        .as_deref()
+        .and_then(Protocol::from_domain_name);
+    match (protocol, typed_data.primary_type.as_str()) {
+        (Some(Protocol::Permit2), "PermitSingle") => permit2_single(&message, &tokens),
+        (Some(Protocol::Permit2), "PermitBatch") => permit2_batch(&message, &tokens),
+        (Some(Protocol::Permit2), "PermitTransferFrom" | "PermitWitnessTransferFrom") => {
+            permit2_transfer(&message, &tokens)
+        }
+        (Some(Protocol::Permit2), "PermitBatchTransferFrom" | "PermitBatchWitnessTransferFrom") => {
+            permit2_batch_transfer(&message, &tokens)
+        }
+        (Some(Protocol::CoW), "Order") => cow_order(&message, &tokens),
+        (Some(Protocol::Seaport), "OrderComponents") => seaport_order(&message, &tokens),
+        (_, "Permit") => erc2612_permit(typed_data, &message, &tokens),
+        _ => None,
+    }
+}
+
+/// The token registry, narrowed to the chain the message is signed for.
+struct Tokens<'a> {
+    tokens: &'a TokenRegistry,
+    chain_id: Option<u64>,
+}
+
+impl Tokens<'_> {
+    fn token(&self, address: Address) -> Option<Token<'_>> {
+        self.tokens.token(self.chain_id, address)
+    }
+
+    fn amount_text(&self, address: Address, amount: U256) -> String {
+        format_amount(self.token(address), address, amount)
+    }
+
+    fn amount_field(&self, label: &str, address: Address, amount: U256) -> AnnotatedPayloadField {
+        match self.token(address) {
+            Some(token) => amount_field(
+                label,
+                &format_token_amount(amount, token.decimals),
+                Some(token.symbol),
+            ),
+            None => amount_field(label, &amount.to_string(), None),
+        }
+    }
+
+    fn token_field(&self, label: &str, address: Address) -> AnnotatedPayloadField {
+        let symbol = self.token(address).map(|token| token.symbol);
+        address_field(label, &addr(address), symbol, None)
+    }
+
+    /// An allowance of `amount`, where the type's maximum reads as unlimited.
+    fn allowance_text(&self, address: Address, amount: U256, unlimited: bool) -> String {
+        if unlimited {
+            format!("unlimited {}", token_name(self.token(address), address))
+        } else {
+            self.amount_text(address, amount)
+        }
+    }
+
+    fn allowance_field(
+        &self,
+        label: &str,
+        address: Address,
+        amount: U256,
+        unlimited: bool,
+    ) -> AnnotatedPayloadField {
+        if unlimited {
+            with_annotation(
+                text_field(label, "Unlimited"),
+                "The spender can move your entire balance of this token",
+            )
+        } else {
+            self.amount_field(label, address, amount)
+        }
+    }
+}
+
+/// Named access to the members of a coerced EIP-712 struct.
+struct Struct<'a> {
+    names: &'a [String],
+    values: &'a [DynSolValue],
+}
+
+impl<'a> Struct<'a> {
+    fn new(value: &'a DynSolValue) -> Option<Self> {
+        let (_, names, values) = value.as_custom_struct()?;
+        Some(Self { names, values })
+    }
+
+    fn get(&self, name: &str) -> Option<&'a DynSolValue> {
+        let index = self.names.iter().position(|n| n == name)?;
+        self.values.get(index)
+    }
+
+    fn address(&self, name: &str) -> Option<Address> {
+        self.get(name)?.as_address()
+    }
+
+    fn uint(&self, name: &str) -> Option<U256> {
+        self.get(name)?.as_uint().map(|(value, _)| value)
+    }
+
+    /// The value of a `uintN` member and whether it is `type(uintN).max`.
+    fn uint_with_max(&self, name: &str) -> Option<(U256, bool)> {
+        let (value, bits) = self.get(name)?.as_uint()?;
+        Some((value, value == U256::MAX >> (256 - bits)))
+    }
+
+    fn string(&self, name: &str) -> Option<&'a str> {
+        self.get(name)?.as_str()
+    }
+
+    fn member(&self, name: &str) -> Option<Struct<'a>> {
+        Struct::new(self.get(name)?)
+    }
+
+    fn members(&self, name: &str) -> Option<Vec<Struct<'a>>> {
+        self.get(name)?
+            .as_array()?
+            .iter()
+            .map(Struct::new)
+            .collect()
+    }
+}
+
+fn expiry_text(timestamp: U256, never: bool) -> String {
+    if never {
+        "Never".to_string()
+    } else {
+        format_timestamp(timestamp)
+    }
+}
+
+fn expiry_field(label: &str, timestamp: U256, never: bool) -> AnnotatedPayloadField {
+    text_field(label, &expiry_text(timestamp, never))
+}
+
+/// EIP-2612 `Permit`: the verifying contract is the token itself. The domain
+/// name is whatever the contract chose to call itself, so the token is named
+/// from the registry and the domain name is only shown as its claim.
+fn erc2612_permit(
+    typed_data: &TypedData,
+    message: &Struct,
+    tokens: &Tokens,
+) -> Option<SignablePayloadField> {
+    let token = typed_data.domain.verifying_contract?;
+    let claimed_name = typed_data
+        .domain
+        .name
+        .as_deref()
+        .filter(|_| tokens.token(token).is_none());
+    let owner = message.address("owner")?;
+    let spender = message.address("spender")?;
+    let (value, unlimited) = message.uint_with_max("value")?;
+    let nonce = message.uint("nonce")?;
+    let (deadline, never) = message.uint_with_max("deadline")?;
+
+    let token_field = match tokens.token(token) {
+        Some(_) => tokens.token_field("Token", token),
+        None => address_field("Token", &addr(token), None, Some("Unknown token")),
+    };
+    let spender_field = address_field("Spender", &addr(spender), None, None);
+    let amount = tokens.allowance_field("Amount", token, value, unlimited);
+    let condensed = vec![
+        token_field.clone(),
+        spender_field.clone(),
+        amount.clone(),
+        expiry_field("Expires", deadline, never),
+    ];
+    let mut expanded = vec![token_field];
+    expanded.extend(claimed_name.map(|name| {
+        with_annotation(
+            text_field("Claimed Name", name),
+            "Set by the token contract itself and not verified",
+        )
+    }));
+    expanded.extend([
+        address_field("Owner", &addr(owner), None, None),
+        spender_field,
+        amount,
+        number_field("Nonce", &nonce.to_string()),
+        expiry_field("Deadline", deadline, never),
+    ]);
+    let subtitle = format!(
+        "{} can spend {} until {}",
+        addr(spender),
+        tokens.allowance_text(token, value, unlimited),
+        expiry_text(deadline, never)
+    );
+    Some(preview_layout(
+        "Token Permit",
+        "Token Permit",
+        &subtitle,
+        Some(condensed),
+        expanded,
+    ))
+}
+
+/// A `PermitDetails` entry of a Permit2 allowance.
+struct Permit2Details {
+    token: Address,
+    amount: U256,
+    unlimited: bool,
+    expiration: U256,
+    never_expires: bool,
+    nonce: U256,
+}
+
+impl Permit2Details {
+    fn parse(details: &Struct) -> Option<Self> {
+        let (amount, unlimited) = details.uint_with_max("amount")?;
+        let (expiration, never_expires) = details.uint_with_max("expiration")?;
+        Some(Self {
+            token: details.address("token")?,
+            amount,
+            unlimited,
+            expiration,
+            never_expires,
+            nonce: details.uint("nonce")?,
+        })
+    }
+
+    fn summary(&self, tokens: &Tokens) -> String {
+        format!(
+            "{} until {}",
+            tokens.allowance_text(self.token, self.amount, self.unlimited),
+            expiry_text(self.expiration, self.never_expires)
+        )
+    }
+
+    fn fields(&self, tokens: &Tokens) -> Vec<AnnotatedPayloadField> {
+        vec![
+            tokens.token_field("Token", self.token),
+            tokens.allowance_field("Amount", self.token, self.amount, self.unlimited),
+            expiry_field("Expires", self.expiration, self.never_expires),
+            number_field("Nonce", &self.nonce.to_string()),
+        ]
+    }
+}
+
+fn permit2_single(message: &Struct, tokens: &Tokens) -> Option<SignablePayloadField> {
+    let details = Permit2Details::parse(&message.member("details")?)?;
+    let spender = message.address("spender")?;
+    let sig_deadline = message.uint("sigDeadline")?;
+
+    let spender_field = address_field("Spender", &addr(spender), None, None);
+    let mut condensed = vec![spender_field.clone()];
+    condensed.extend(details.fields(tokens).into_iter().take(3));
+    let mut expanded = vec![spender_field];
+    expanded.extend(details.fields(tokens));
+    expanded.push(text_field(
+        "Signature Deadline",
+        &format_timestamp(sig_deadline),
+    ));
+    let subtitle = format!("{} can spend {}", addr(spender), details.summary(tokens));
+    Some(preview_layout(
+        "Permit2 Allowance",
+        "Permit2 Allowance",
+        &subtitle,
+        Some(condensed),
+        expanded,
+    ))
+}
+
+fn permit2_batch(message: &Struct, tokens: &Tokens) -> Option<SignablePayloadField> {
+    let details = message
+        .members("details")?
+        .iter()
+        .map(Permit2Details::parse)
+        .collect::<Option<Vec<_>>>()?;
+    let spender = message.address("spender")?;
+    let sig_deadline = message.uint("sigDeadline")?;
+
+    let spender_field = address_field("Spender", &addr(spender), None, None);
+    let mut condensed = vec![spender_field.clone()];
+    let mut expanded = vec![spender_field];
+    for (i, entry) in details.iter().enumerate() {
+        let label = format!("Token {}", i + 1);
+        condensed.push(text_field(&label, &entry.summary(tokens)));
+        expanded.push(annotated(preview_layout(
+            &label,
+            &token_name(tokens.token(entry.token), entry.token),
+            &entry.summary(tokens),
+            None,
+            entry.fields(tokens),
+        )));
+    }
+    expanded.push(text_field(
+        "Signature Deadline",
+        &format_timestamp(sig_deadline),
+    ));
+    let subtitle = format!("{} can spend {} tokens", addr(spender), details.len());
+    Some(preview_layout(
+        "Permit2 Allowance",
+        "Permit2 Batch Allowance",
+        &subtitle,
+        Some(condensed),
+        expanded,
+    ))
+}
+
+/// A `TokenPermissions` entry of a Permit2 signature transfer.
+fn token_permissions(permitted: &Struct) -> Option<(Address, U256)> {
+    Some((permitted.address("token")?, permitted.uint("amount")?))
+}
+
+fn permit2_transfer(message: &Struct, tokens: &Tokens) -> Option<SignablePayloadField> {
+    let (token, amount) = token_permissions(&message.member("permitted")?)?;
+    permit2_transfer_layout(message, &[(token, amount)], tokens)
+}
+
+fn permit2_batch_transfer(message: &Struct, tokens: &Tokens) -> Option<SignablePayloadField> {
+    let permitted = message
+        .members("permitted")?
+        .iter()
+        .map(token_permissions)
+        .collect::<Option<Vec<_>>>()?;
+    permit2_transfer_layout(message, &permitted, tokens)
+}
+
+fn permit2_transfer_layout(
+    message: &Struct,
+    permitted: &[(Address, U256)],
+    tokens: &Tokens,
+) -> Option<SignablePayloadField> {
+    let spender = message.address("spender")?;
+    let nonce = message.uint("nonce")?;
+    let deadline = message.uint("deadline")?;
+
+    let spender_field = address_field("Spender", &addr(spender), None, None);
+    let deadline_field = text_field("Deadline", &format_timestamp(deadline));
+    let mut token_fields = Vec::new();
+    for (i, (token, amount)) in permitted.iter().enumerate() {
+        let suffix = if permitted.len() == 1 {
+            String::new()
+        } else {
+            format!(" {}", i + 1)
+        };
+        token_fields.push(tokens.token_field(&format!("Token{suffix}"), *token));
+        token_fields.push(tokens.amount_field(&format!("Amount{suffix}"), *token, *amount));
+    }
+
+    let mut condensed = vec![spender_field.clone()];
+    condensed.extend(token_fields.iter().cloned());
+    condensed.push(deadline_field.clone());
+    let mut expanded = vec![spender_field];
+    expanded.extend(token_fields);
+    expanded.push(number_field("Nonce", &nonce.to_string()));
+    expanded.push(deadline_field);
+
+    let subtitle = match permitted {
+        [(token, amount)] => format!(
+            "{} can transfer {} once, until {}",
+            addr(spender),
+            tokens.amount_text(*token, *amount),
+            format_timestamp(deadline)
+        ),
+        _ => format!(
+            "{} can transfer {} tokens once, until {}",
+            addr(spender),
+            permitted.len(),
+            format_timestamp(deadline)
+        ),
+    };
+    Some(preview_layout(
+        "Permit2 Transfer",
+        "Permit2 Transfer",
+        &subtitle,
+        Some(condensed),
+        expanded,
+    ))
+}
+
+/// CoW Protocol (GPv2) `Order`.
+fn cow_order(message: &Struct, tokens: &Tokens) -> Option<SignablePayloadField> {
+    let sell_token = message.address("sellToken")?;
+    let buy_token = message.address("buyToken")?;
+    let receiver = message.address("receiver")?;
+    let sell_amount = message.uint("sellAmount")?;
+    let buy_amount = message.uint("buyAmount")?;
+    let valid_to = message.uint("validTo")?;
+    let fee_amount = message.uint("feeAmount")?;
+    let kind = message.string("kind")?;
+    let partially_fillable = message.get("partiallyFillable")?.as_bool()?;
+
+    // For sell orders the buy amount is a floor, for buy orders the sell amount is a ceiling
+    let (sell_label, buy_label) = if kind == "buy" {
+        ("Max Sell Amount", "Buy Amount")
+    } else {
+        ("Sell Amount", "Min Buy Amount")
+    };
+    let receiver_field = if receiver == Address::ZERO {
+        address_field("Receiver", &addr(receiver), Some("Signer"), None)
+    } else {
+        with_annotation(
+            address_field("Receiver", &addr(receiver), None, None),
+            "Bought tokens are sent to this address, not the signer",
+        )
+    };
+    let condensed = vec![
+        tokens.token_field("Sell Token", sell_token),
+        tokens.amount_field(sell_label, sell_token, sell_amount),
+        tokens.token_field("Buy Token", buy_token),
+        tokens.amount_field(buy_label, buy_token, buy_amount),
+        receiver_field,
+        text_field("Valid Until", &format_timestamp(valid_to)),
+    ];
+    let mut expanded = condensed.clone();
+    expanded.push(tokens.amount_field("Fee Amount", sell_token, fee_amount));
+    expanded.push(text_field("Kind", kind));
+    expanded.push(text_field(
+        "Partially Fillable",
+        &partially_fillable.to_string(),
+    ));
+    expanded.extend(
+        message
+            .get("appData")
+            .and_then(|app_data| app_data.as_fixed_bytes())
+            .map(|(bytes, _)| text_field("App Data", &format!("0x{}", hex::encode(bytes)))),
+    );
+
+    let subtitle = format!(
+        "Sell {} for {}{}",
+        tokens.amount_text(sell_token, sell_amount),
+        if kind == "buy" { "" } else { "at least " },
+        tokens.amount_text(buy_token, buy_amount)
+    );
+    Some(preview_layout(
+        "CoW Order",
+        "CoW Swap Order",
+        &subtitle,
+        Some(condensed),
+        expanded,
+    ))
+}
+
+fn seaport_item_type(item_type: U256) -> String {
+    match u64::try_from(item_type) {
+        Ok(0) => "Native".to_string(),
+        Ok(1) => "ERC20".to_string(),
+        Ok(2) => "ERC721".to_string(),
+        Ok(3) => "ERC1155".to_string(),
+        Ok(4) => "ERC721 (criteria)".to_string(),
+        Ok(5) => "ERC1155 (criteria)".to_string(),
+        _ => format!("Unknown ({item_type})"),
+    }
+}
+
+/// A Seaport `OfferItem` or `ConsiderationItem`.
+fn seaport_item(
+    label: &str,
+    item: &Struct,
+    tokens: &Tokens,
+) -> Option<(String, AnnotatedPayloadField)> {
+    let item_type = seaport_item_type(item.uint("itemType")?);
+    let token = item.address("token")?;
+    let identifier = item.uint("identifierOrCriteria")?;
+    let start_amount = item.uint("startAmount")?;
+    let end_amount = item.uint("endAmount")?;
+    let recipient = item.address("recipient");
+
+    // Fungible amounts are in the currency's units, NFT amounts are counts
+    let (native_symbol, native_decimals) = native_currency(tokens.chain_id);
+    let currency = match item_type.as_str() {
+        "Native" => Some(Token {
+            symbol: native_symbol,
+            decimals: native_decimals,
+        }),
+        "ERC20" => tokens.token(token),
+        _ => None,
+    };
+    let units = |amount: U256| match currency {
+        Some(currency) => format_token_amount(amount, currency.decimals),
+        None => amount.to_string(),
+    };
+    let amount = if start_amount == end_amount {
+        units(start_amount)
+    } else {
+        format!("{} to {}", units(start_amount), units(end_amount))
+    };
+    let asset = match item_type.as_str() {
+        "Native" | "ERC20" => token_name(currency, token),
+        _ => format!("{item_type} {} #{identifier}", addr(token)),
+    };
+    let mut summary = format!("{amount} {asset}");
+    if let Some(recipient) = recipient {
+        summary.push_str(&format!(" to {}", addr(recipient)));
+    }
+
+    let mut fields = vec![
+        text_field("Item Type", &item_type),
+        tokens.token_field("Token", token),
+        number_field("Identifier Or Criteria", &identifier.to_string()),
+        amount_field("Amount", &amount, currency.map(|currency| currency.symbol)),
+    ];
+    fields.extend(recipient.map(|r| address_field("Recipient", &addr(r), None, None)));
+    let field = annotated(preview_layout(label, &item_type, &summary, None, fields));
+    Some((summary, field))
+}
+
+fn seaport_items(
+    message: &Struct,
+    name: &str,
+    label: &str,
+    tokens: &Tokens,
+) -> Option<Vec<(String, AnnotatedPayloadField)>> {
+    message
+        .members(name)?
+        .iter()
+        .enumerate()
+        .map(|(i, item)| seaport_item(&format!("{label} {}", i + 1), item, tokens))
+        .collect()
+}
+
+/// Seaport `OrderComponents`: the offerer gives the offer items in exchange
+/// for the consideration items.
+fn seaport_order(message: &Struct, tokens: &Tokens) -> Option<SignablePayloadField> {
+    let offerer = message.address("offerer")?;
+    let zone = message.address("zone")?;
+    let offer = seaport_items(message, "offer", "Offer", tokens)?;
+    let consideration = seaport_items(message, "consideration", "Consideration", tokens)?;
+    let order_type = message.uint("orderType")?;
+    let start_time = message.uint("startTime")?;
+    let end_time = message.uint("endTime")?;
+    let counter = message.uint("counter")?;
+
+    let offerer_field = address_field("Offerer", &addr(offerer), None, None);
+    let end_field = text_field("Valid Until", &format_timestamp(end_time));
+    let mut condensed = vec![offerer_field.clone()];
+    for (i, (summary, _)) in offer.iter().enumerate() {
+        condensed.push(text_field(&format!("Offer {}", i + 1), summary));
+    }
+    for (i, (summary, _)) in consideration.iter().enumerate() {
+        condensed.push(text_field(&format!("Consideration {}", i + 1), summary));
+    }
+    condensed.push(end_field.clone());
+
+    let mut expanded = vec![
+        offerer_field,
+        address_field("Zone", &addr(zone), None, None),
+    ];
+    expanded.extend(offer.iter().map(|(_, field)| field.clone()));
+    expanded.extend(consideration.iter().map(|(_, field)| field.clone()));
+    expanded.extend([
+        number_field("Order Type", &order_type.to_string()),
+        text_field("Valid From", &format_timestamp(start_time)),
+        end_field,
+        number_field("Counter", &counter.to_string()),
+    ]);
+
+    let subtitle = format!(
+        "Offer {} item(s) for {} item(s), until {}",
+        offer.len(),
+        consideration.len(),
+        format_timestamp(end_time)
+    );
+    Some(preview_layout(
+        "Seaport Order",
+        "Seaport Order",
+        &subtitle,
+        Some(condensed),
+        expanded,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(json: &str) -> Option<SignablePayloadField> {
+        let typed_data: TypedData = serde_json::from_str(json).unwrap();
+        visualize_known_message(
+            &typed_data,
+            &typed_data.coerce().unwrap(),
+            &TokenRegistry::default(),
+        )
+    }
+
+    fn condensed_fields(field: &SignablePayloadField) -> &[AnnotatedPayloadField] {
+        match field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => {
+                &preview_layout.condensed.as_ref().unwrap().fields
+            }
+            other => panic!("expected preview layout, got {other:?}"),
+        }
+    }
+
+    fn expanded_fields(field: &SignablePayloadField) -> &[AnnotatedPayloadField] {
+        match field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => {
+                &preview_layout.expanded.as_ref().unwrap().fields
+            }
+            other => panic!("expected preview layout, got {other:?}"),
+        }
+    }
+
+    const USDC: &str = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+    const WETH: &str = "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2";
+    const SPENDER: &str = "0x3fc91a3afd70395cd496c647d5a6cc9d4b2b7fad";
+
+    #[test]
+    fn test_erc2612_permit() {
+        let json = format!(
+            r#"{{
+            "types": {{
+                "EIP712Domain": [
+                    {{"name": "name", "type": "string"}},
+                    {{"name": "version", "type": "string"}},
+                    {{"name": "chainId", "type": "uint256"}},
+                    {{"name": "verifyingContract", "type": "address"}}
+                ],
+                "Permit": [
+                    {{"name": "owner", "type": "address"}},
+                    {{"name": "spender", "type": "address"}},
+                    {{"name": "value", "type": "uint256"}},
+                    {{"name": "nonce", "type": "uint256"}},
+                    {{"name": "deadline", "type": "uint256"}}
+                ]
+            }},
+            "primaryType": "Permit",
+            "domain": {{"name": "USD Coin", "version": "2", "chainId": 1, "verifyingContract": "{USDC}"}},
+            "message": {{
+                "owner": "0x1111111111111111111111111111111111111111",
+                "spender": "{SPENDER}",
+                "value": "1000000",
+                "nonce": 0,
+                "deadline": 1700000000
+            }}
+        }}"#
+        );
+        let field = render(&json).unwrap();
+        assert_eq!(field.label(), "Token Permit");
+        assert_eq!(
+            field.fallback_text(),
+            &format!("{SPENDER} can spend 1 USDC until 2023-11-14 22:13:20 UTC")
+        );
+        let condensed = condensed_fields(&field);
+        // The token is named by the registry, not by the domain it signs under
+        assert_eq!(
+            condensed[0],
+            address_field("Token", USDC, Some("USDC"), None)
+        );
+        assert_eq!(condensed[2], amount_field("Amount", "1", Some("USDC")));
+        assert!(
+            expanded_fields(&field)
+                .iter()
+                .all(|f| f.signable_payload_field.label() != "Claimed Name")
+        );
+
+        // The registry is keyed by the domain's chain, so elsewhere the token is
+        // unknown and the domain name is only shown as its claim
+        let field = render(&json.replace("\"chainId\": 1,", "\"chainId\": 10,")).unwrap();
+        assert_eq!(
+            field.fallback_text(),
+            &format!("{SPENDER} can spend 1000000 of {USDC} until 2023-11-14 22:13:20 UTC")
+        );
+        let condensed = condensed_fields(&field);
+        assert_eq!(
+            condensed[0],
+            address_field("Token", USDC, None, Some("Unknown token"))
+        );
+        assert_eq!(condensed[2], amount_field("Amount", "1000000", None));
+        assert_eq!(
+            expanded_fields(&field)[1],
+            with_annotation(
+                text_field("Claimed Name", "USD Coin"),
+                "Set by the token contract itself and not verified",
+            )
+        );
+
+        // Max value and deadline read as unlimited and never expiring
+        let unlimited = json
+            .replace("\"1000000\"", &format!("\"{}\"", U256::MAX))
+            .replace("1700000000", &format!("\"{}\"", U256::MAX));
+        let field = render(&unlimited).unwrap();
+        assert_eq!(
+            field.fallback_text(),
+            &format!("{SPENDER} can spend unlimited USDC until Never")
+        );
+        assert!(condensed_fields(&field)[2].static_annotation.is_some());
+    }
+
+    #[test]
+    fn test_permit2_single() {
+        let json = format!(
+            r#"{{
+            "types": {{
+                "EIP712Domain": [
+                    {{"name": "name", "type": "string"}},
+                    {{"name": "chainId", "type": "uint256"}},
+                    {{"name": "verifyingContract", "type": "address"}}
+                ],
+                "PermitDetails": [
+                    {{"name": "token", "type": "address"}},
+                    {{"name": "amount", "type": "uint160"}},
+                    {{"name": "expiration", "type": "uint48"}},
+                    {{"name": "nonce", "type": "uint48"}}
+                ],
+                "PermitSingle": [
+                    {{"name": "details", "type": "PermitDetails"}},
+                    {{"name": "spender", "type": "address"}},
+                    {{"name": "sigDeadline", "type": "uint256"}}
+                ]
+            }},
+            "primaryType": "PermitSingle",
+            "domain": {{"name": "Permit2", "chainId": 1, "verifyingContract": "0x000000000022d473030f116ddee9f6b43ac78ba3"}},
+            "message": {{
+                "details": {{
+                    "token": "{WETH}",
+                    "amount": "1461501637330902918203684832716283019655932542975",
+                    "expiration": 1700000000,
+                    "nonce": 0
+                }},
+                "spender": "{SPENDER}",
+                "sigDeadline": 1700000000
+            }}
+        }}"#
+        );
+        let field = render(&json).unwrap();
+        assert_eq!(field.label(), "Permit2 Allowance");
+        assert_eq!(
+            field.fallback_text(),
+            &format!("{SPENDER} can spend unlimited WETH until 2023-11-14 22:13:20 UTC")
+        );
+        let condensed = condensed_fields(&field);
+        assert_eq!(condensed.len(), 4);
+        assert_eq!(
+            condensed[1],
+            address_field("Token", WETH, Some("WETH"), None)
+        );
+        assert_eq!(
+            condensed[3],
+            text_field("Expires", "2023-11-14 22:13:20 UTC")
+        );
+
+        // Same primary type under another domain isn't Permit2
+        assert!(render(&json.replace("\"Permit2\"", "\"Other\"")).is_none());
+
+        // The Permit2 name only counts at the canonical deployment
+        let typed_data: TypedData = serde_json::from_str(&json).unwrap();
+        assert_eq!(lookalike_warning(&typed_data), None);
+        let lookalike = json.replace(
+            "0x000000000022d473030f116ddee9f6b43ac78ba3",
+            "0x1111111111111111111111111111111111111111",
+        );
+        let typed_data: TypedData = serde_json::from_str(&lookalike).unwrap();
+        assert_eq!(
+            lookalike_warning(&typed_data).unwrap().fallback_text(),
+            "This message is signed under the Permit2 domain, but 0x1111111111111111111111111111111111111111 is not a known Permit2 deployment. Treat it as an unverified contract."
+        );
+        // It's still summarized, behind the warning the payload leads with
+        assert_eq!(render(&lookalike).unwrap().label(), "Permit2 Allowance");
+    }
+
+    #[test]
+    fn test_cow_order() {
+        let json = format!(
+            r#"{{
+            "types": {{
+                "EIP712Domain": [
+                    {{"name": "name", "type": "string"}},
+                    {{"name": "version", "type": "string"}},
+                    {{"name": "chainId", "type": "uint256"}},
+                    {{"name": "verifyingContract", "type": "address"}}
+                ],
+                "Order": [
+                    {{"name": "sellToken", "type": "address"}},
+                    {{"name": "buyToken", "type": "address"}},
+                    {{"name": "receiver", "type": "address"}},
+                    {{"name": "sellAmount", "type": "uint256"}},
+                    {{"name": "buyAmount", "type": "uint256"}},
+                    {{"name": "validTo", "type": "uint32"}},
+                    {{"name": "appData", "type": "bytes32"}},
+                    {{"name": "feeAmount", "type": "uint256"}},
+                    {{"name": "kind", "type": "string"}},
+                    {{"name": "partiallyFillable", "type": "bool"}},
+                    {{"name": "sellTokenBalance", "type": "string"}},
+                    {{"name": "buyTokenBalance", "type": "string"}}
+                ]
+            }},
+            "primaryType": "Order",
+            "domain": {{"name": "Gnosis Protocol", "version": "v2", "chainId": 1, "verifyingContract": "0x9008d19f58aabd9ed0d60971565aa8510560ab41"}},
+            "message": {{
+                "sellToken": "{WETH}",
+                "buyToken": "{USDC}",
+                "receiver": "0x0000000000000000000000000000000000000000",
+                "sellAmount": "1000000000000000000",
+                "buyAmount": "2000000000",
+                "validTo": 1700000000,
+                "appData": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                "feeAmount": "0",
+                "kind": "sell",
+                "partiallyFillable": false,
+                "sellTokenBalance": "erc20",
+                "buyTokenBalance": "erc20"
+            }}
+        }}"#
+        );
+        let field = render(&json).unwrap();
+        assert_eq!(field.fallback_text(), "Sell 1 WETH for at least 2000 USDC");
+        let condensed = condensed_fields(&field);
+        assert_eq!(
+            condensed[3],
+            amount_field("Min Buy Amount", "2000", Some("USDC"))
+        );
+        assert_eq!(
+            condensed[4],
+            address_field(
+                "Receiver",
+                "0x0000000000000000000000000000000000000000",
+                Some("Signer"),
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_seaport_order() {
+        let item = r#"[
+            {"name": "itemType", "type": "uint8"},
+            {"name": "token", "type": "address"},
+            {"name": "identifierOrCriteria", "type": "uint256"},
+            {"name": "startAmount", "type": "uint256"},
+            {"name": "endAmount", "type": "uint256"}"#;
+        let json = format!(
+            r#"{{
+            "types": {{
+                "EIP712Domain": [
+                    {{"name": "name", "type": "string"}},
+                    {{"name": "version", "type": "string"}},
+                    {{"name": "chainId", "type": "uint256"}},
+                    {{"name": "verifyingContract", "type": "address"}}
+                ],
+                "OrderComponents": [
+                    {{"name": "offerer", "type": "address"}},
+                    {{"name": "zone", "type": "address"}},
+                    {{"name": "offer", "type": "OfferItem[]"}},
+                    {{"name": "consideration", "type": "ConsiderationItem[]"}},
+                    {{"name": "orderType", "type": "uint8"}},
+                    {{"name": "startTime", "type": "uint256"}},
+                    {{"name": "endTime", "type": "uint256"}},
+                    {{"name": "zoneHash", "type": "bytes32"}},
+                    {{"name": "salt", "type": "uint256"}},
+                    {{"name": "conduitKey", "type": "bytes32"}},
+                    {{"name": "counter", "type": "uint256"}}
+                ],
+                "OfferItem": {item}],
+                "ConsiderationItem": {item}, {{"name": "recipient", "type": "address"}}]
+            }},
+            "primaryType": "OrderComponents",
+            "domain": {{"name": "Seaport", "version": "1.5", "chainId": 1, "verifyingContract": "0x00000000000000adc04c56bf30ac9d3c0aaf14dc"}},
+            "message": {{
+                "offerer": "0x1111111111111111111111111111111111111111",
+                "zone": "0x0000000000000000000000000000000000000000",
+                "offer": [{{"itemType": 2, "token": "0x2222222222222222222222222222222222222222", "identifierOrCriteria": "42", "startAmount": "1", "endAmount": "1"}}],
+                "consideration": [{{"itemType": 0, "token": "0x0000000000000000000000000000000000000000", "identifierOrCriteria": "0", "startAmount": "1500000000000000000", "endAmount": "1500000000000000000", "recipient": "0x1111111111111111111111111111111111111111"}}],
+                "orderType": 0,
+                "startTime": 0,
+                "endTime": 1700000000,
+                "zoneHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                "salt": "0",
+                "conduitKey": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                "counter": "0"
+            }}
+        }}"#
+        );
+        let field = render(&json).unwrap();
+        assert_eq!(
+            field.fallback_text(),
+            "Offer 1 item(s) for 1 item(s), until 2023-11-14 22:13:20 UTC"
+        );
+        let condensed = condensed_fields(&field);
+        assert_eq!(
+            condensed[1],
+            text_field(
+                "Offer 1",
+                "1 ERC721 0x2222222222222222222222222222222222222222 #42"
+            )
+        );
+        assert_eq!(
+            condensed[2],
+            text_field(
+                "Consideration 1",
+                "1.5 ETH to 0x1111111111111111111111111111111111111111"
+            )
+        );
+    }
+}
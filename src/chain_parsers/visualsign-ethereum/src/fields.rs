@@ -0,0 +1,131 @@
+//! Small constructors for the payload fields shared by the Ethereum visualizers.
+//!
+//! These mirror the private helpers in `contracts::eigenlayer` so that newer
+//! visualizers don't have to spell out every `AnnotatedPayloadField` by hand.
+
+use alloy_primitives::Address;
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldAddressV2,
+    SignablePayloadFieldAmountV2, SignablePayloadFieldCommon, SignablePayloadFieldListLayout,
+    SignablePayloadFieldNumber, SignablePayloadFieldPreviewLayout,
+    SignablePayloadFieldStaticAnnotation, SignablePayloadFieldTextV2,
+};
+
+/// `address` as the payloads show it: lowercase hex with a `0x` prefix.
+pub(crate) fn addr(address: Address) -> String {
+    format!("{address:?}")
+}
+
+pub(crate) fn annotated(field: SignablePayloadField) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: field,
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+pub(crate) fn with_annotation(
+    mut field: AnnotatedPayloadField,
+    annotation: &str,
+) -> AnnotatedPayloadField {
+    field.static_annotation = Some(SignablePayloadFieldStaticAnnotation {
+        text: annotation.to_string(),
+    });
+    field
+}
+
+pub(crate) fn plain_text_field(label: &str, text: &str) -> SignablePayloadField {
+    SignablePayloadField::TextV2 {
+        common: SignablePayloadFieldCommon {
+            fallback_text: text.to_string(),
+            label: label.to_string(),
+        },
+        text_v2: SignablePayloadFieldTextV2 {
+            text: text.to_string(),
+        },
+    }
+}
+
+pub(crate) fn text_field(label: &str, text: &str) -> AnnotatedPayloadField {
+    annotated(plain_text_field(label, text))
+}
+
+pub(crate) fn number_field(label: &str, number: &str) -> AnnotatedPayloadField {
+    annotated(SignablePayloadField::Number {
+        common: SignablePayloadFieldCommon {
+            fallback_text: number.to_string(),
+            label: label.to_string(),
+        },
+        number: SignablePayloadFieldNumber {
+            number: number.to_string(),
+        },
+    })
+}
+
+pub(crate) fn amount_field(
+    label: &str,
+    amount: &str,
+    abbreviation: Option<&str>,
+) -> AnnotatedPayloadField {
+    let fallback_text = match abbreviation {
+        Some(abbreviation) => format!("{amount} {abbreviation}"),
+        None => amount.to_string(),
+    };
+    annotated(SignablePayloadField::AmountV2 {
+        common: SignablePayloadFieldCommon {
+            fallback_text,
+            label: label.to_string(),
+        },
+        amount_v2: SignablePayloadFieldAmountV2 {
+            amount: amount.to_string(),
+            abbreviation: abbreviation.map(|s| s.to_string()),
+        },
+    })
+}
+
+pub(crate) fn address_field(
+    label: &str,
+    address: &str,
+    name: Option<&str>,
+    badge_text: Option<&str>,
+) -> AnnotatedPayloadField {
+    annotated(SignablePayloadField::AddressV2 {
+        common: SignablePayloadFieldCommon {
+            fallback_text: address.to_string(),
+            label: label.to_string(),
+        },
+        address_v2: SignablePayloadFieldAddressV2 {
+            address: address.to_string(),
+            name: name.unwrap_or("").to_string(),
+            asset_label: "".to_string(),
+            memo: None,
+            badge_text: badge_text.map(|s| s.to_string()),
+        },
+    })
+}
+
+/// Builds a `PreviewLayout` whose fallback text is the subtitle.
+pub(crate) fn preview_layout(
+    label: &str,
+    title: &str,
+    subtitle: &str,
+    condensed: Option<Vec<AnnotatedPayloadField>>,
+    expanded: Vec<AnnotatedPayloadField>,
+) -> SignablePayloadField {
+    SignablePayloadField::PreviewLayout {
+        common: SignablePayloadFieldCommon {
+            fallback_text: subtitle.to_string(),
+            label: label.to_string(),
+        },
+        preview_layout: SignablePayloadFieldPreviewLayout {
+            title: Some(SignablePayloadFieldTextV2 {
+                text: title.to_string(),
+            }),
+            subtitle: Some(SignablePayloadFieldTextV2 {
+                text: subtitle.to_string(),
+            }),
+            condensed: condensed.map(|fields| SignablePayloadFieldListLayout { fields }),
+            expanded: Some(SignablePayloadFieldListLayout { fields: expanded }),
+        },
+    }
+}
@@ -1,4 +1,6 @@
+use alloy_primitives::U256;
 use alloy_primitives::utils::{ParseUnits, format_units};
+use chrono::{TimeZone, Utc};
 fn trim_trailing_zeros(s: String) -> String {
     if s.contains('.') {
         s.trim_end_matches('0').trim_end_matches('.').to_string()
@@ -15,6 +17,36 @@ pub fn format_ether<T: Into<ParseUnits> + ToString + Copy>(wei: T) -> String {
 pub fn format_gwei<T: Into<ParseUnits> + ToString + Copy>(wei: T) -> String {
     trim_trailing_zeros(format_units(wei, "gwei").unwrap_or_else(|_| wei.to_string()))
 }
+// Helper function to format a token amount in its display units
+pub fn format_token_amount(amount: U256, decimals: u8) -> String {
+    trim_trailing_zeros(format_units(amount, decimals).unwrap_or_else(|_| amount.to_string()))
+}
+// Helper function to format a unix timestamp as a UTC date; out of range values are shown raw
+pub fn format_timestamp(seconds: U256) -> String {
+    i64::try_from(seconds)
+        .ok()
+        .and_then(|seconds| Utc.timestamp_opt(seconds, 0).single())
+        .map_or_else(|| seconds.to_string(), |date| date.to_string())
+}
+// Helper function to make untrusted text safe for the payload's ASCII-only charset.
+// Anything that would not survive `validate_charset` is written as `<U+XXXX>`;
+// the flag is true when something had to be escaped.
+pub fn escape_non_ascii(text: &str) -> (String, bool) {
+    let mut escaped = String::with_capacity(text.len());
+    let mut changed = false;
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        // A literal backslash before `u` would read as a JSON unicode escape
+        let unsafe_backslash = ch == '\\' && chars.peek() == Some(&'u');
+        if (ch.is_ascii_graphic() || matches!(ch, ' ' | '\n' | '\t')) && !unsafe_backslash {
+            escaped.push(ch);
+        } else {
+            escaped.push_str(&format!("<U+{:04X}>", ch as u32));
+            changed = true;
+        }
+    }
+    (escaped, changed)
+}
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -80,4 +112,44 @@ mod tests {
         let wei = 123_456_789_000u128;
         assert_eq!("123.456789", format_gwei(wei));
     }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(
+            "2023-11-14 22:13:20 UTC",
+            format_timestamp(U256::from(1_700_000_000u64))
+        );
+        assert_eq!(
+            "281474976710655",
+            format_timestamp(U256::from(281_474_976_710_655u64))
+        );
+        assert_eq!(U256::MAX.to_string(), format_timestamp(U256::MAX));
+    }
+
+    #[test]
+    fn test_format_token_amount() {
+        assert_eq!(format_token_amount(U256::from(1_500_000u64), 6), "1.5");
+        assert_eq!(format_token_amount(U256::from(100_000_000u64), 8), "1");
+        assert_eq!(format_token_amount(U256::from(42u64), 0), "42");
+    }
+
+    #[test]
+    fn test_escape_non_ascii() {
+        assert_eq!(
+            escape_non_ascii("Hello\n\tworld"),
+            ("Hello\n\tworld".to_string(), false)
+        );
+        assert_eq!(
+            escape_non_ascii("caf\u{e9} \u{1F600}"),
+            ("caf<U+00E9> <U+1F600>".to_string(), true)
+        );
+        assert_eq!(
+            escape_non_ascii("a\r\u{0}b"),
+            ("a<U+000D><U+0000>b".to_string(), true)
+        );
+        assert_eq!(
+            escape_non_ascii("C:\\users \\x"),
+            ("C:<U+005C>users \\x".to_string(), true)
+        );
+    }
 }
@@ -1,5 +1,13 @@
-use crate::fmt::{format_ether, format_gwei};
-use alloy_consensus::{Transaction as _, TxType, TypedTransaction};
+use std::cell::RefCell;
+
+use crate::fmt::{format_gwei, format_token_amount};
+use crate::registry::{ContractKind, ContractRegistry};
+use crate::tokens::TokenRegistry;
+use alloy_consensus::{
+    SignableTransaction, Transaction as _, TxEnvelope, TxType, TypedTransaction,
+};
+use alloy_eips::eip2718::Decodable2718;
+use alloy_primitives::{Address, B256, Signature, U256};
 use alloy_rlp::{Buf, Decodable};
 use base64::{Engine as _, engine::general_purpose::STANDARD as b64};
 use visualsign::{
@@ -8,13 +16,25 @@ use visualsign::{
     encodings::SupportedEncodings,
     vsptrait::{
         Transaction, TransactionParseError, VisualSignConverter, VisualSignConverterFromString,
-        VisualSignError, VisualSignOptions,
+        VisualSignError, VisualSignOptions, chain_metadata,
     },
 };
 
+pub mod abi;
+mod approvals;
 pub mod chains;
 pub mod contracts;
+mod deployment;
+mod dispatch;
+pub mod eip712;
+mod fields;
 pub mod fmt;
+pub mod personal_sign;
+pub mod registry;
+pub mod selectors;
+pub mod tokens;
+mod typed_tx;
+pub mod user_operation;
 
 #[derive(Debug, Eq, PartialEq, thiserror::Error)]
 pub enum EthereumParserError {
@@ -26,6 +46,8 @@ pub enum EthereumParserError {
     UnsupportedTransactionType(String),
     #[error("Failed to decode transaction: {0}")]
     FailedToDecodeTransaction(String),
+    #[error("Failed to recover sender: {0}")]
+    FailedToRecoverSender(String),
 }
 
 // Helper function to extract gas price from different transaction types
@@ -61,9 +83,25 @@ fn extract_priority_fee(transaction: &TypedTransaction) -> Option<u128> {
     }
 }
 
+// Helper function to format a per-gas price: in gwei where the native currency has
+// 18 decimals like ether, otherwise in the currency itself
+fn format_gas_price(price: u128, (symbol, decimals): (&str, u8)) -> String {
+    if decimals == 18 {
+        format!("{} gwei", format_gwei(price))
+    } else {
+        format!(
+            "{} {symbol}",
+            format_token_amount(U256::from(price), decimals)
+        )
+    }
+}
+
 // Helper function to create priority fee field
-fn create_priority_fee_field(max_priority_fee_per_gas: u128) -> SignablePayloadField {
-    let priority_fee_text = format!("{} gwei", format_gwei(max_priority_fee_per_gas));
+fn create_priority_fee_field(
+    max_priority_fee_per_gas: u128,
+    currency: (&str, u8),
+) -> SignablePayloadField {
+    let priority_fee_text = format_gas_price(max_priority_fee_per_gas, currency);
     SignablePayloadField::TextV2 {
         common: SignablePayloadFieldCommon {
             fallback_text: priority_fee_text.clone(),
@@ -79,6 +117,8 @@ fn create_priority_fee_field(max_priority_fee_per_gas: u128) -> SignablePayloadF
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct EthereumTransactionWrapper {
     transaction: TypedTransaction,
+    /// Present when the input was a signed envelope rather than a signing payload
+    signature: Option<Signature>,
 }
 
 impl Transaction for EthereumTransactionWrapper {
@@ -88,9 +128,18 @@ impl Transaction for EthereumTransactionWrapper {
         } else {
             visualsign::encodings::SupportedEncodings::detect(data)
         };
-        let transaction = decode_transaction(data, format)
+        let (transaction, signature) = decode_transaction(data, format)
             .map_err(|e| TransactionParseError::DecodeError(e.to_string()))?;
-        Ok(Self { transaction })
+        let wrapper = Self {
+            transaction,
+            signature,
+        };
+        if wrapper.signature.is_some() {
+            wrapper
+                .recover_sender()
+                .map_err(|e| TransactionParseError::DecodeError(e.to_string()))?;
+        }
+        Ok(wrapper)
     }
     fn transaction_type(&self) -> String {
         "Ethereum".to_string()
@@ -99,15 +148,68 @@ impl Transaction for EthereumTransactionWrapper {
 
 impl EthereumTransactionWrapper {
     pub fn new(transaction: TypedTransaction) -> Self {
-        Self { transaction }
+        Self {
+            transaction,
+            signature: None,
+        }
+    }
+    /// This transaction, signed with `signature`.
+    pub fn with_signature(self, signature: Signature) -> Self {
+        Self {
+            signature: Some(signature),
+            ..self
+        }
     }
     pub fn inner(&self) -> &TypedTransaction {
         &self.transaction
     }
+    pub fn signature(&self) -> Option<&Signature> {
+        self.signature.as_ref()
+    }
+    /// Hash of the signed transaction, as it will appear on chain.
+    pub fn tx_hash(&self) -> Option<B256> {
+        self.signature
+            .map(|signature| self.transaction.tx_hash(&signature))
+    }
+    /// The account that signed the transaction.
+    pub fn sender(&self) -> Option<Address> {
+        self.recover_sender().ok().flatten()
+    }
+
+    fn recover_sender(&self) -> Result<Option<Address>, EthereumParserError> {
+        let Some(signature) = self.signature else {
+            return Ok(None);
+        };
+        signature
+            .recover_address_from_prehash(&self.transaction.signature_hash())
+            .map(Some)
+            .map_err(|e| EthereumParserError::FailedToRecoverSender(e.to_string()))
+    }
 }
 
 /// Converter that knows how to format Ethereum transactions for VisualSign
-pub struct EthereumVisualSignConverter;
+#[derive(Debug, Clone, Default)]
+pub struct EthereumVisualSignConverter {
+    contracts: ContractRegistry,
+    tokens: TokenRegistry,
+}
+
+impl EthereumVisualSignConverter {
+    /// A converter that routes calldata using `contracts` instead of the
+    /// built-in deployments.
+    pub fn with_contract_registry(contracts: ContractRegistry) -> Self {
+        Self {
+            contracts,
+            ..Self::default()
+        }
+    }
+
+    /// This converter, naming token amounts with `tokens` instead of the
+    /// built-in token list.
+    pub fn with_token_registry(self, tokens: TokenRegistry) -> Self {
+        Self { tokens, ..self }
+    }
+}
 
 impl VisualSignConverter<EthereumTransactionWrapper> for EthereumVisualSignConverter {
     fn to_visual_sign_payload(
@@ -115,22 +217,52 @@ impl VisualSignConverter<EthereumTransactionWrapper> for EthereumVisualSignConve
         transaction_wrapper: EthereumTransactionWrapper,
         options: VisualSignOptions,
     ) -> Result<SignablePayload, VisualSignError> {
+        let signed = transaction_wrapper
+            .sender()
+            .zip(transaction_wrapper.tx_hash());
         let transaction = transaction_wrapper.inner().clone();
-        let is_supported = match transaction.tx_type() {
-            TxType::Eip2930 | TxType::Eip4844 | TxType::Eip7702 => false,
-            TxType::Legacy | TxType::Eip1559 => true,
-        };
-        if is_supported {
-            return Ok(convert_to_visual_sign_payload(transaction, options));
+        Ok(convert_to_visual_sign_payload(
+            transaction,
+            signed,
+            &self.contracts,
+            &self.tokens,
+            options,
+        ))
+    }
+}
+
+impl VisualSignConverterFromString<EthereumTransactionWrapper> for EthereumVisualSignConverter {
+    fn to_visual_sign_payload_from_string(
+        &self,
+        transaction_data: &str,
+        options: VisualSignOptions,
+    ) -> Result<SignablePayload, VisualSignError> {
+        // UserOperations are JSON as well, but never typed data
+        if user_operation::is_user_operation(transaction_data) {
+            let user_operation =
+                user_operation::UserOperationWrapper::from_string(transaction_data)
+                    .map_err(VisualSignError::ParseError)?;
+            return self.to_validated_visual_sign_payload(user_operation, options);
         }
-        Err(VisualSignError::DecodeError(format!(
-            "Unsupported transaction type: {}",
-            transaction.tx_type()
-        )))
+        // Typed data (eth_signTypedData_v4) arrives as JSON rather than RLP
+        if eip712::is_typed_data(transaction_data) {
+            let typed_data = eip712::Eip712TypedDataWrapper::from_string(transaction_data)
+                .map_err(VisualSignError::ParseError)?;
+            return self.to_validated_visual_sign_payload(typed_data, options);
+        }
+        // personal_sign preimages start with 0x19, which is never a transaction type
+        if personal_sign::is_personal_message(transaction_data) {
+            return personal_sign::personal_message_string_to_visual_sign(
+                transaction_data,
+                options,
+            );
+        }
+        let transaction = EthereumTransactionWrapper::from_string(transaction_data)
+            .map_err(VisualSignError::ParseError)?;
+        self.to_validated_visual_sign_payload(transaction, options)
     }
 }
 
-impl VisualSignConverterFromString<EthereumTransactionWrapper> for EthereumVisualSignConverter {}
 fn decode_transaction_bytes(mut buf: &[u8]) -> Result<TypedTransaction, EthereumParserError> {
     let tx = if buf.is_empty() {
         Err(EthereumParserError::FailedToDecodeTransaction(
@@ -156,14 +288,21 @@ fn decode_transaction_bytes(mut buf: &[u8]) -> Result<TypedTransaction, Ethereum
                 alloy_consensus::TxEip1559::decode(&mut buf)
                     .map_err(|e| EthereumParserError::FailedToDecodeTransaction(e.to_string()))?,
             )),
-            TxType::Eip2930 => Err(EthereumParserError::UnsupportedTransactionType(
-                "eip-2930".to_string(),
+            TxType::Eip2930 => Ok(TypedTransaction::Eip2930(
+                alloy_consensus::TxEip2930::decode(&mut buf)
+                    .map_err(|e| EthereumParserError::FailedToDecodeTransaction(e.to_string()))?,
             )),
-            TxType::Eip4844 => Err(EthereumParserError::UnsupportedTransactionType(
-                "eip-4844".to_string(),
+            // Signing payloads never include the blob sidecar
+            TxType::Eip4844 => Ok(TypedTransaction::Eip4844(
+                alloy_consensus::TxEip4844Variant::TxEip4844(
+                    alloy_consensus::TxEip4844::decode(&mut buf).map_err(|e| {
+                        EthereumParserError::FailedToDecodeTransaction(e.to_string())
+                    })?,
+                ),
             )),
-            TxType::Eip7702 => Err(EthereumParserError::UnsupportedTransactionType(
-                "eip-7702".to_string(),
+            TxType::Eip7702 => Ok(TypedTransaction::Eip7702(
+                alloy_consensus::TxEip7702::decode(&mut buf)
+                    .map_err(|e| EthereumParserError::FailedToDecodeTransaction(e.to_string()))?,
             )),
             TxType::Legacy => Err(EthereumParserError::UnexpectedTransactionType(
                 "legacy".to_string(), // This shouldn't happen
@@ -183,10 +322,23 @@ fn decode_transaction_bytes(mut buf: &[u8]) -> Result<TypedTransaction, Ethereum
     tx
 }
 
+/// A broadcast-ready transaction: an EIP-2718 envelope or a legacy transaction
+/// with `v`, `r` and `s`.
+fn decode_signed_transaction_bytes(buf: &[u8]) -> Option<(TypedTransaction, Signature)> {
+    let envelope = TxEnvelope::decode_2718_exact(buf).ok()?;
+    let signature = *envelope.signature();
+    // An EIP-155 signing payload ends in `chain_id, 0, 0`, which also reads as
+    // a legacy signature, but never a valid one
+    if signature.r().is_zero() || signature.s().is_zero() {
+        return None;
+    }
+    Some((envelope.into(), signature))
+}
+
 fn decode_transaction(
     raw_transaction: &str,
     encodings: SupportedEncodings,
-) -> Result<TypedTransaction, EthereumParserError> {
+) -> Result<(TypedTransaction, Option<Signature>), EthereumParserError> {
     let bytes = match encodings {
         SupportedEncodings::Hex => {
             let clean_hex = raw_transaction
@@ -200,25 +352,137 @@ fn decode_transaction(
             EthereumParserError::FailedToDecodeTransaction(format!("Failed to decode base64: {e}"))
         })?,
     };
-    decode_transaction_bytes(&bytes)
+    if let Some((transaction, signature)) = decode_signed_transaction_bytes(&bytes) {
+        return Ok((transaction, Some(signature)));
+    }
+    decode_transaction_bytes(&bytes).map(|transaction| (transaction, None))
+}
+
+/// The signing account the caller claims in the Ethereum metadata. Nothing
+/// vouches for it, so it only predicts a deployment's address and never
+/// decides whether a call needs a warning.
+fn metadata_sender(options: &VisualSignOptions) -> Option<Address> {
+    match options.metadata.as_ref()?.metadata.as_ref()? {
+        chain_metadata::Metadata::Ethereum(ethereum) => ethereum.sender.as_ref()?.parse().ok(),
+        chain_metadata::Metadata::Solana(_) => None,
+    }
+}
+
+/// Flags a call to `to` that matches `protocol`'s interface but isn't one of
+/// its known deployments on `chain_name`.
+pub(crate) fn lookalike_warning(
+    protocol: &str,
+    to: Address,
+    chain_name: &str,
+) -> SignablePayloadField {
+    fields::plain_text_field(
+        "Warning",
+        &format!(
+            "This call matches the {protocol} interface, but {to} is not a known {protocol} deployment on {chain_name}. Treat it as an unverified contract."
+        ),
+    )
+}
+
+/// Warnings for a call of `input` to `to` made by `sender`, which lead the
+/// payload: open-ended allowances, a Safe running someone else's code in its
+/// own context, and lending, swapping or bridging for an account that isn't
+/// the sender's. When the sender is unknown, every account such a call names
+/// is flagged for the signer to confirm.
+pub(crate) fn call_warnings(
+    to: Address,
+    input: &[u8],
+    sender: Option<Address>,
+    chain_id: Option<u64>,
+    contracts: &ContractRegistry,
+    tokens: &TokenRegistry,
+) -> Vec<SignablePayloadField> {
+    let mut warnings: Vec<SignablePayloadField> =
+        approvals::approval_warning(input, to, chain_id, contracts, tokens)
+            .into_iter()
+            .collect();
+    warnings.extend(contracts::safe::delegatecall_warning(
+        input, chain_id, contracts,
+    ));
+    warnings.extend(contracts::batch::delegatecall_warnings(input));
+    warnings.extend(contracts::lending::third_party_warning(
+        input, to, sender, chain_id, contracts, tokens,
+    ));
+    warnings.extend(contracts::aggregators::recipient_warning(
+        input, to, sender, chain_id, contracts, tokens,
+    ));
+    warnings.extend(contracts::bridges::recipient_warning(
+        input, to, sender, chain_id, contracts,
+    ));
+    warnings
 }
 
 fn convert_to_visual_sign_payload(
     transaction: TypedTransaction,
+    signed: Option<(Address, B256)>,
+    contracts: &ContractRegistry,
+    tokens: &TokenRegistry,
     options: VisualSignOptions,
 ) -> SignablePayload {
     // Extract chain ID to determine the network
     let chain_id = transaction.chain_id();
 
     let chain_name = chains::get_chain_name(chain_id);
+    let currency = chains::native_currency(chain_id);
+
+    let input = transaction.input();
+    let destination = transaction
+        .to()
+        .and_then(|to| contracts.lookup(chain_id, to));
+    // Calldata shaped like a known protocol's, sent somewhere we don't know.
+    // Settler has no registry entries, so it's always decoded this way
+    let lookalike = match (transaction.to(), destination) {
+        (Some(_), None) => ContractKind::matching_selector(input)
+            .map(ContractKind::protocol_name)
+            .or_else(|| {
+                contracts::aggregators::is_settler(input).then_some(contracts::aggregators::SETTLER)
+            }),
+        _ => None,
+    };
 
-    let mut fields = vec![SignablePayloadField::TextV2 {
+    // Delegating the account is dangerous enough to lead the payload
+    let mut fields: Vec<SignablePayloadField> = typed_tx::delegation_warning(&transaction)
+        .into_iter()
+        .collect();
+    // So is what the call lets others do with the signer's assets. Only a
+    // recovered signer is trusted to say which assets are the signer's
+    let signer = signed.map(|(sender, _)| sender);
+    if let Some(to) = transaction.to() {
+        fields.extend(call_warnings(
+            to, input, signer, chain_id, contracts, tokens,
+        ));
+    }
+    if let (Some(protocol), Some(to)) = (lookalike, transaction.to()) {
+        fields.push(lookalike_warning(protocol, to, &chain_name));
+    }
+    // Inner calls' warnings are lifted to here, ahead of the network
+    let network_index = fields.len();
+    fields.push(SignablePayloadField::TextV2 {
         common: SignablePayloadFieldCommon {
             fallback_text: chain_name.clone(),
             label: "Network".to_string(),
         },
         text_v2: SignablePayloadFieldTextV2 { text: chain_name },
-    }];
+    });
+    if let Some((sender, _)) = signed {
+        fields.push(SignablePayloadField::AddressV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: sender.to_string(),
+                label: "From".to_string(),
+            },
+            address_v2: SignablePayloadFieldAddressV2 {
+                address: sender.to_string(),
+                name: "From".to_string(),
+                asset_label: "Test Asset".to_string(),
+                memo: None,
+                badge_text: None,
+            },
+        });
+    }
     if let Some(to) = transaction.to() {
         fields.push(SignablePayloadField::AddressV2 {
             common: SignablePayloadFieldCommon {
@@ -227,22 +491,29 @@ fn convert_to_visual_sign_payload(
             },
             address_v2: SignablePayloadFieldAddressV2 {
                 address: to.to_string(),
-                name: "To".to_string(),
+                name: destination
+                    .map_or("To", |info| info.name.as_str())
+                    .to_string(),
                 asset_label: "Test Asset".to_string(),
                 memo: None,
-                badge_text: None,
+                badge_text: match lookalike {
+                    Some(_) => Some("Unverified contract".to_string()),
+                    None => chains::is_testnet(chain_id).then(|| "Testnet".to_string()),
+                },
             },
         });
     }
+    let (symbol, decimals) = currency;
+    let value = format_token_amount(transaction.value(), decimals);
     fields.extend([
         SignablePayloadField::AmountV2 {
             common: SignablePayloadFieldCommon {
-                fallback_text: format!("{} ETH", format_ether(transaction.value())),
+                fallback_text: format!("{value} {symbol}"),
                 label: "Value".to_string(),
             },
             amount_v2: SignablePayloadFieldAmountV2 {
-                amount: format_ether(transaction.value()),
-                abbreviation: Some("ETH".to_string()),
+                amount: value,
+                abbreviation: Some(symbol.to_string()),
             },
         },
         SignablePayloadField::TextV2 {
@@ -257,7 +528,7 @@ fn convert_to_visual_sign_payload(
     ]);
 
     // Handle gas pricing based on transaction type
-    let gas_price_text = format!("{} gwei", format_gwei(extract_gas_price(&transaction)));
+    let gas_price_text = format_gas_price(extract_gas_price(&transaction), currency);
 
     fields.push(SignablePayloadField::TextV2 {
         common: SignablePayloadFieldCommon {
@@ -271,7 +542,7 @@ fn convert_to_visual_sign_payload(
 
     // Add priority fee for EIP-1559, EIP-4844, and EIP-7702 transactions
     if let Some(priority_fee) = extract_priority_fee(&transaction) {
-        fields.push(create_priority_fee_field(priority_fee));
+        fields.push(create_priority_fee_field(priority_fee, currency));
     }
 
     fields.push(SignablePayloadField::TextV2 {
@@ -283,47 +554,52 @@ fn convert_to_visual_sign_payload(
             text: format!("{}", transaction.nonce()),
         },
     });
+    if let Some((_, tx_hash)) = signed {
+        fields.push(SignablePayloadField::TextV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: format!("{tx_hash:?}"),
+                label: "Transaction Hash".to_string(),
+            },
+            text_v2: SignablePayloadFieldTextV2 {
+                text: format!("{tx_hash:?}"),
+            },
+        });
+    }
 
-    // Add contract call data if present
-    let input = transaction.input();
-    if !input.is_empty() {
-        let mut input_fields: Vec<SignablePayloadField> = Vec::new();
-
-        // EigenLayer check (prioritized first for restaking operations)
-        if let Some(field) =
-            (contracts::eigenlayer::EigenLayerVisualizer {}).visualize_tx_commands(input)
-        {
-            input_fields.push(field);
-        }
+    // Access lists, blobs and authorizations of typed transactions
+    fields.extend(typed_tx::typed_transaction_fields(&transaction));
 
-        if options.decode_transfers {
-            if let Some(field) = (contracts::erc20::ERC20Visualizer {}).visualize_tx_commands(input)
-            {
-                input_fields.push(field);
-            }
-        }
-        if let Some(field) =
-            (contracts::uniswap::UniswapV4Visualizer {}).visualize_tx_commands(input)
-        {
-            input_fields.push(field);
-        }
-        if input_fields.is_empty() {
-            input_fields.push(SignablePayloadField::TextV2 {
-                common: SignablePayloadFieldCommon {
-                    fallback_text: format!("0x{}", hex::encode(input)),
-                    label: "Input Data".to_string(),
-                },
-                text_v2: SignablePayloadFieldTextV2 {
-                    text: format!("0x{}", hex::encode(input)),
-                },
-            });
-        }
-        fields.append(&mut input_fields);
+    let warnings = RefCell::new(Vec::new());
+    let dispatcher = dispatch::CallDispatcher {
+        contracts,
+        tokens,
+        chain_id,
+        options: &options,
+        depth: 0,
+        account: None,
+        warnings: &warnings,
+    };
+    let is_deployment = transaction.to().is_none();
+    if is_deployment {
+        fields.push(deployment::deployment_layout(
+            input,
+            signer.or_else(|| metadata_sender(&options)),
+            transaction.nonce(),
+            &|to, data| dispatcher.nested_call_fields(to, data),
+        ));
+    } else if !input.is_empty() {
+        // Add contract call data if present
+        fields.extend(dispatcher.call_fields(transaction.to(), input));
     }
+    fields.splice(network_index..network_index, warnings.into_inner());
 
-    let title = options
-        .transaction_name
-        .unwrap_or_else(|| "Ethereum Transaction".to_string());
+    let title = options.transaction_name.clone().unwrap_or_else(|| {
+        if is_deployment {
+            "Contract Deployment".to_string()
+        } else {
+            "Ethereum Transaction".to_string()
+        }
+    });
     SignablePayload::new(0, title, None, fields, "EthereumTx".to_string())
 }
 
@@ -333,7 +609,7 @@ pub fn transaction_to_visual_sign(
     options: VisualSignOptions,
 ) -> Result<SignablePayload, VisualSignError> {
     let wrapper = EthereumTransactionWrapper::new(transaction);
-    let converter = EthereumVisualSignConverter;
+    let converter = EthereumVisualSignConverter::default();
     converter.to_visual_sign_payload(wrapper, options)
 }
 
@@ -341,7 +617,7 @@ pub fn transaction_string_to_visual_sign(
     transaction_data: &str,
     options: VisualSignOptions,
 ) -> Result<SignablePayload, VisualSignError> {
-    let converter = EthereumVisualSignConverter;
+    let converter = EthereumVisualSignConverter::default();
     converter.to_visual_sign_payload_from_string(transaction_data, options)
 }
 
@@ -350,6 +626,7 @@ mod tests {
     use super::*;
     use alloy_consensus::{SignableTransaction, TxLegacy, TypedTransaction};
     use alloy_primitives::{Address, Bytes, ChainId, U256};
+    use alloy_sol_types::SolCall;
     use visualsign::SignablePayloadFieldAddressV2;
 
     fn unsigned_to_hex(tx: &TypedTransaction) -> String {
@@ -495,6 +772,732 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_transaction_with_abi_metadata() {
+        use visualsign::vsptrait::{Abi, ChainMetadata, EthereumMetadata, chain_metadata};
+
+        let abi = r#"[{"type":"function","name":"setFee","inputs":[{"name":"fee","type":"uint16"}],"outputs":[],"stateMutability":"nonpayable"}]"#;
+        // setFee(uint16) with fee = 30
+        let mut input = alloy_primitives::keccak256("setFee(uint16)")[..4].to_vec();
+        input.extend([0u8; 31]);
+        input.push(30);
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 1,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 50000,
+            to: alloy_primitives::TxKind::Call(Address::ZERO),
+            value: U256::ZERO,
+            input: Bytes::from(input),
+        });
+        let options = VisualSignOptions {
+            metadata: Some(ChainMetadata {
+                metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
+                    abi: Some(Abi {
+                        value: abi.to_string(),
+                        signature: None,
+                    }),
+                    sender: None,
+                })),
+            }),
+            ..VisualSignOptions::default()
+        };
+        let payload = transaction_to_visual_sign(tx.clone(), options).unwrap();
+
+        assert!(payload.fields.iter().all(|f| f.label() != "Input Data"));
+        let call = payload
+            .fields
+            .iter()
+            .find(|f| f.label() == "setFee")
+            .unwrap();
+        assert_eq!(call.fallback_text(), "setFee(uint16)");
+
+        // Without metadata the calldata is shown raw
+        let payload = transaction_to_visual_sign(tx, VisualSignOptions::default()).unwrap();
+        assert!(payload.fields.iter().any(|f| f.label() == "Input Data"));
+    }
+
+    #[test]
+    fn test_protocol_calls_routed_by_destination() {
+        use crate::registry::{ContractInfo, ContractKind, ContractRegistry};
+
+        // EigenLayer depositIntoStrategy, sent to an address that isn't the StrategyManager
+        let input = hex::decode("e7a050aa00000000000000000000000093c4b944d05dfe6df7645a86cd2206016c51564d000000000000000000000000ae7ab96520de3a18e5e111b5eaab095312d7fe8400000000000000000000000000000000000000000000000000028b30699cdc00").unwrap();
+        let lookalike = Address::repeat_byte(0x42);
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 0,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 100_000,
+            to: alloy_primitives::TxKind::Call(lookalike),
+            value: U256::ZERO,
+            input: Bytes::from(input),
+        });
+        let payload = transaction_to_visual_sign(tx.clone(), VisualSignOptions::default()).unwrap();
+
+        assert_eq!(payload.fields[0].label(), "Warning");
+        assert!(payload.fields[0].fallback_text().contains("EigenLayer"));
+        match &payload.fields[2] {
+            SignablePayloadField::AddressV2 { address_v2, .. } => {
+                assert_eq!(
+                    address_v2.badge_text.as_deref(),
+                    Some("Unverified contract")
+                );
+            }
+            other => panic!("expected the To address, got {other:?}"),
+        }
+        assert!(payload.fields.iter().any(|f| f.label() == "Input Data"));
+
+        // Registering the deployment routes the call to the EigenLayer visualizer
+        let mut contracts = ContractRegistry::empty();
+        contracts.register(
+            1,
+            lookalike,
+            ContractInfo {
+                name: "Staging StrategyManager".to_string(),
+                kind: ContractKind::EigenLayer,
+            },
+        );
+        let payload = EthereumVisualSignConverter::with_contract_registry(contracts)
+            .to_visual_sign_payload(
+                EthereumTransactionWrapper::new(tx),
+                VisualSignOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(payload.fields[0].label(), "Network");
+        match &payload.fields[1] {
+            SignablePayloadField::AddressV2 { address_v2, .. } => {
+                assert_eq!(address_v2.name, "Staging StrategyManager");
+                assert_eq!(address_v2.badge_text, None);
+            }
+            other => panic!("expected the To address, got {other:?}"),
+        }
+        assert!(payload.fields.iter().all(|f| f.label() != "Input Data"));
+    }
+
+    #[test]
+    fn test_erc20_amounts_use_token_registry() {
+        let usdc = alloy_primitives::address!("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+        let input = contracts::erc20::IERC20::transferCall {
+            to: Address::repeat_byte(0x11),
+            amount: U256::from(1_000_000u64),
+        }
+        .abi_encode();
+        let transfer_on = |chain_id: u64| {
+            let tx = TypedTransaction::Legacy(TxLegacy {
+                chain_id: Some(ChainId::from(chain_id)),
+                nonce: 0,
+                gas_price: 1_000_000_000u128,
+                gas_limit: 60_000,
+                to: alloy_primitives::TxKind::Call(usdc),
+                value: U256::ZERO,
+                input: Bytes::from(input.clone()),
+            });
+            let options = VisualSignOptions {
+                decode_transfers: true,
+                transaction_name: None,
+                metadata: None,
+            };
+            let payload = transaction_to_visual_sign(tx, options).unwrap();
+            payload.fields.last().unwrap().fallback_text().clone()
+        };
+
+        assert_eq!(
+            transfer_on(1),
+            "Transfer 1 USDC to 0x1111111111111111111111111111111111111111"
+        );
+        // USDC's mainnet address is not a known token on BNB Smart Chain
+        assert_eq!(
+            transfer_on(56),
+            "Transfer 1000000 of 0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48 to 0x1111111111111111111111111111111111111111"
+        );
+    }
+
+    #[test]
+    fn test_nft_transfers_decoded_with_transfers() {
+        let input = contracts::nft::IERC721::safeTransferFrom_1Call {
+            from: Address::repeat_byte(0x11),
+            to: Address::repeat_byte(0x22),
+            tokenId: U256::from(42u64),
+        }
+        .abi_encode();
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 0,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 90_000,
+            to: alloy_primitives::TxKind::Call(Address::repeat_byte(0xaa)),
+            value: U256::ZERO,
+            input: Bytes::from(input),
+        });
+        let options = VisualSignOptions {
+            decode_transfers: true,
+            transaction_name: None,
+            metadata: None,
+        };
+        let payload = transaction_to_visual_sign(tx, options).unwrap();
+
+        let field = payload.fields.last().unwrap();
+        assert_eq!(field.label(), "ERC721 SafeTransferFrom");
+        assert_eq!(
+            field.fallback_text(),
+            "Transfer token #42 from 0x1111111111111111111111111111111111111111 to 0x2222222222222222222222222222222222222222"
+        );
+    }
+
+    #[test]
+    fn test_shared_selectors_ambiguous_unless_known_token() {
+        let input = contracts::nft::IERC721::transferFromCall {
+            from: Address::repeat_byte(0x11),
+            to: Address::repeat_byte(0x22),
+            tokenId: U256::from(42u64),
+        }
+        .abi_encode();
+        let transfer_from = |to: Address| {
+            let tx = TypedTransaction::Legacy(TxLegacy {
+                chain_id: Some(ChainId::from(1u64)),
+                nonce: 0,
+                gas_price: 1_000_000_000u128,
+                gas_limit: 90_000,
+                to: alloy_primitives::TxKind::Call(to),
+                value: U256::ZERO,
+                input: Bytes::from(input.clone()),
+            });
+            let options = VisualSignOptions {
+                decode_transfers: true,
+                transaction_name: None,
+                metadata: None,
+            };
+            let payload = transaction_to_visual_sign(tx, options).unwrap();
+            payload.fields.last().unwrap().clone()
+        };
+
+        // An unknown contract may be a collection, so the value could be a token ID
+        let field = transfer_from(Address::repeat_byte(0xaa));
+        assert_eq!(field.label(), "ERC20 or ERC721 TransferFrom");
+        assert_eq!(
+            field.fallback_text(),
+            "Transfer 42 (ERC20 amount or ERC721 token ID) from 0x1111111111111111111111111111111111111111 to 0x2222222222222222222222222222222222222222"
+        );
+
+        let field = transfer_from(alloy_primitives::address!(
+            "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+        ));
+        assert_eq!(field.label(), "ERC20 TransferFrom");
+    }
+
+    #[test]
+    fn test_unlimited_approval_warning_leads_payload() {
+        let input = contracts::erc20::IERC20::approveCall {
+            spender: Address::repeat_byte(0x42),
+            amount: U256::MAX,
+        }
+        .abi_encode();
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 0,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 60_000,
+            to: alloy_primitives::TxKind::Call(alloy_primitives::address!(
+                "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+            )),
+            value: U256::ZERO,
+            input: Bytes::from(input),
+        });
+        let payload = transaction_to_visual_sign(tx, VisualSignOptions::default()).unwrap();
+
+        assert_eq!(payload.fields[0].label(), "Approval Warning");
+        assert!(
+            payload.fields[0]
+                .fallback_text()
+                .starts_with("Unlimited approval: 0x4242424242424242424242424242424242424242")
+        );
+        assert_eq!(payload.fields[1].label(), "Network");
+    }
+
+    #[test]
+    fn test_supply_on_behalf_of_another_account_warns() {
+        let signer = Address::repeat_byte(0x11);
+        let pool = alloy_primitives::address!("0x87870bca3f3fd6335c3f4ce8392d69350b4fa4e2");
+        let supply = |on_behalf_of: Address| {
+            contracts::lending::IAaveV3Pool::supplyCall {
+                asset: alloy_primitives::address!("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
+                amount: U256::from(100_000_000u64),
+                onBehalfOf: on_behalf_of,
+                referralCode: 0,
+            }
+            .abi_encode()
+        };
+        let warnings_for = |on_behalf_of: Address| {
+            call_warnings(
+                pool,
+                &supply(on_behalf_of),
+                Some(signer),
+                Some(1),
+                &ContractRegistry::default(),
+                &TokenRegistry::default(),
+            )
+        };
+
+        assert!(warnings_for(signer).is_empty());
+        let warnings = warnings_for(Address::repeat_byte(0x22));
+        assert_eq!(warnings.len(), 1);
+        assert!(
+            warnings[0]
+                .fallback_text()
+                .starts_with("This supplies your USDC to the Aave v3 Pool position of")
+        );
+    }
+
+    #[test]
+    fn test_metadata_sender_doesnt_vouch_for_recipients() {
+        use visualsign::vsptrait::{ChainMetadata, EthereumMetadata, chain_metadata};
+
+        // The claimed sender matches onBehalfOf, but only a signature could show
+        // it's the signer's own position, so the signer is asked to check
+        let claimed = Address::repeat_byte(0x11);
+        let input = contracts::lending::IAaveV3Pool::supplyCall {
+            asset: alloy_primitives::address!("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
+            amount: U256::from(100_000_000u64),
+            onBehalfOf: claimed,
+            referralCode: 0,
+        }
+        .abi_encode();
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 0,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 300_000,
+            to: alloy_primitives::TxKind::Call(alloy_primitives::address!(
+                "0x87870bca3f3fd6335c3f4ce8392d69350b4fa4e2"
+            )),
+            value: U256::ZERO,
+            input: Bytes::from(input),
+        });
+        let options = VisualSignOptions {
+            metadata: Some(ChainMetadata {
+                metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
+                    abi: None,
+                    sender: Some(claimed.to_string()),
+                })),
+            }),
+            ..VisualSignOptions::default()
+        };
+        let payload = transaction_to_visual_sign(tx, options).unwrap();
+        assert_eq!(
+            payload.fields.last().unwrap().fallback_text(),
+            "Supply 100 USDC"
+        );
+        assert_eq!(payload.fields[0].label(), "Warning");
+        assert_eq!(
+            payload.fields[0].fallback_text(),
+            "This supplies your USDC to the Aave v3 Pool position of 0x1111111111111111111111111111111111111111; confirm this is your address. Only that account can withdraw it."
+        );
+        assert_eq!(payload.fields[1].label(), "Network");
+    }
+
+    #[test]
+    fn test_unsigned_swap_to_named_recipient_warns() {
+        use contracts::aggregators::IAggregationRouterV6;
+
+        let input = IAggregationRouterV6::swapCall {
+            executor: Address::repeat_byte(0x33),
+            desc: IAggregationRouterV6::SwapDescription {
+                srcToken: alloy_primitives::address!("0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"),
+                dstToken: alloy_primitives::address!("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
+                srcReceiver: Address::repeat_byte(0x33),
+                dstReceiver: Address::repeat_byte(0x22),
+                amount: U256::from(10u64).pow(U256::from(18)),
+                minReturnAmount: U256::from(3_000_000_000u64),
+                flags: U256::ZERO,
+            },
+            data: Bytes::new(),
+        }
+        .abi_encode();
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 0,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 300_000,
+            to: alloy_primitives::TxKind::Call(alloy_primitives::address!(
+                "0x111111125421ca6dc452d289314280a0f8842a65"
+            )),
+            value: U256::ZERO,
+            input: Bytes::from(input),
+        });
+        let payload = transaction_to_visual_sign(tx, VisualSignOptions::default()).unwrap();
+
+        assert_eq!(payload.fields[0].label(), "Warning");
+        assert_eq!(
+            payload.fields[0].fallback_text(),
+            "The USDC bought by this swap is sent to 0x2222222222222222222222222222222222222222; confirm this is your address."
+        );
+        assert_eq!(payload.fields[1].label(), "Network");
+    }
+
+    #[test]
+    fn test_unsigned_deposit_to_named_recipient_warns() {
+        let input = contracts::bridges::IL1StandardBridge::depositETHToCall {
+            _to: Address::repeat_byte(0x22),
+            _minGasLimit: 200_000,
+            _extraData: Bytes::new(),
+        }
+        .abi_encode();
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 0,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 200_000,
+            to: alloy_primitives::TxKind::Call(alloy_primitives::address!(
+                "0x3154cf16ccdb4c6d922629664174b904d80f2c35"
+            )),
+            value: U256::from(10u64).pow(U256::from(18)),
+            input: Bytes::from(input),
+        });
+        let payload = transaction_to_visual_sign(tx, VisualSignOptions::default()).unwrap();
+
+        assert_eq!(payload.fields[0].label(), "Warning");
+        assert_eq!(
+            payload.fields[0].fallback_text(),
+            "This deposit is credited to 0x2222222222222222222222222222222222222222 on Base; confirm this is your address. Only that account can use the funds."
+        );
+        assert_eq!(payload.fields[1].label(), "Network");
+    }
+
+    #[test]
+    fn test_safe_transaction_decodes_inner_call() {
+        let usdc = alloy_primitives::address!("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+        let transfer = contracts::erc20::IERC20::transferCall {
+            to: Address::repeat_byte(0x11),
+            amount: U256::from(1_000_000u64),
+        }
+        .abi_encode();
+        let exec = |operation: u8| {
+            contracts::safe::ISafe::execTransactionCall {
+                to: usdc,
+                value: U256::ZERO,
+                data: Bytes::from(transfer.clone()),
+                operation,
+                safeTxGas: U256::ZERO,
+                baseGas: U256::ZERO,
+                gasPrice: U256::ZERO,
+                gasToken: Address::ZERO,
+                refundReceiver: Address::ZERO,
+                signatures: Bytes::new(),
+            }
+            .abi_encode()
+        };
+        let payload_for = |input: Vec<u8>| {
+            let tx = TypedTransaction::Legacy(TxLegacy {
+                chain_id: Some(ChainId::from(1u64)),
+                nonce: 0,
+                gas_price: 1_000_000_000u128,
+                gas_limit: 150_000,
+                to: alloy_primitives::TxKind::Call(Address::repeat_byte(0x5a)),
+                value: U256::ZERO,
+                input: Bytes::from(input),
+            });
+            let options = VisualSignOptions {
+                decode_transfers: true,
+                transaction_name: None,
+                metadata: None,
+            };
+            transaction_to_visual_sign(tx, options).unwrap()
+        };
+
+        let payload = payload_for(exec(0));
+        assert_eq!(payload.fields[0].label(), "Network");
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } =
+            payload.fields.last().unwrap()
+        else {
+            panic!("expected the Safe transaction layout");
+        };
+        let inner = &preview_layout.expanded.as_ref().unwrap().fields[3];
+        assert_eq!(inner.signable_payload_field.label(), "ERC20 Transfer");
+        assert_eq!(
+            inner.signable_payload_field.fallback_text(),
+            "Transfer 1 USDC to 0x1111111111111111111111111111111111111111"
+        );
+
+        // Delegatecalls are called out before anything else
+        let payload = payload_for(exec(1));
+        assert_eq!(payload.fields[0].label(), "Warning");
+        assert!(payload.fields[0].fallback_text().contains("DELEGATECALL"));
+    }
+
+    #[test]
+    fn test_inner_call_warnings_lead_payload() {
+        let approve = contracts::erc20::IERC20::approveCall {
+            spender: Address::repeat_byte(0x42),
+            amount: U256::MAX,
+        }
+        .abi_encode();
+        let input = contracts::safe::ISafe::execTransactionCall {
+            to: alloy_primitives::address!("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
+            value: U256::ZERO,
+            data: Bytes::from(approve),
+            operation: 0,
+            safeTxGas: U256::ZERO,
+            baseGas: U256::ZERO,
+            gasPrice: U256::ZERO,
+            gasToken: Address::ZERO,
+            refundReceiver: Address::ZERO,
+            signatures: Bytes::new(),
+        }
+        .abi_encode();
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 0,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 150_000,
+            to: alloy_primitives::TxKind::Call(Address::repeat_byte(0x5a)),
+            value: U256::ZERO,
+            input: Bytes::from(input),
+        });
+        let payload = transaction_to_visual_sign(tx, VisualSignOptions::default()).unwrap();
+
+        // The Safe's own call warns about nothing; the approval it makes does
+        assert_eq!(payload.fields[0].label(), "Approval Warning");
+        assert!(
+            payload.fields[0]
+                .fallback_text()
+                .starts_with("Unlimited approval: 0x4242424242424242424242424242424242424242")
+        );
+        assert_eq!(payload.fields[1].label(), "Network");
+    }
+
+    #[test]
+    fn test_batch_lists_each_call() {
+        use contracts::batch::IMulticall3;
+        use contracts::erc20::IERC20;
+
+        let usdc = alloy_primitives::address!("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+        let recipient = Address::repeat_byte(0x11);
+        let call = |data: Vec<u8>| IMulticall3::Call3 {
+            target: usdc,
+            allowFailure: false,
+            callData: Bytes::from(data),
+        };
+        let input = IMulticall3::aggregate3Call {
+            calls: vec![
+                call(
+                    IERC20::approveCall {
+                        spender: recipient,
+                        amount: U256::from(2_000_000u64),
+                    }
+                    .abi_encode(),
+                ),
+                call(
+                    IERC20::transferCall {
+                        to: recipient,
+                        amount: U256::from(1_000_000u64),
+                    }
+                    .abi_encode(),
+                ),
+            ],
+        }
+        .abi_encode();
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 0,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 150_000,
+            to: alloy_primitives::TxKind::Call(Address::repeat_byte(0xca)),
+            value: U256::ZERO,
+            input: Bytes::from(input),
+        });
+        let options = VisualSignOptions {
+            decode_transfers: true,
+            transaction_name: None,
+            metadata: None,
+        };
+        let payload = transaction_to_visual_sign(tx, options).unwrap();
+
+        let SignablePayloadField::PreviewLayout {
+            common,
+            preview_layout,
+        } = payload.fields.last().unwrap()
+        else {
+            panic!("expected the batch layout");
+        };
+        assert_eq!(
+            common.fallback_text,
+            "2 calls: ERC20 Approve, ERC20 Transfer"
+        );
+        let calls = &preview_layout.expanded.as_ref().unwrap().fields;
+        assert_eq!(calls[1].signable_payload_field.label(), "Call 2");
+    }
+
+    #[test]
+    fn test_settler_calls_are_unverified() {
+        use contracts::aggregators::ISettler;
+        use contracts::batch::IMulticall3;
+
+        let settler = Address::repeat_byte(0x5e);
+        let swap = ISettler::executeCall {
+            slippage: ISettler::AllowedSlippage {
+                recipient: Address::ZERO,
+                buyToken: alloy_primitives::address!("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
+                minAmountOut: U256::from(1_000_000u64),
+            },
+            actions: vec![],
+            zidAndAffiliate: Default::default(),
+        }
+        .abi_encode();
+        let payload_for = |to, input| {
+            let tx = TypedTransaction::Legacy(TxLegacy {
+                chain_id: Some(ChainId::from(1u64)),
+                nonce: 0,
+                gas_price: 1_000_000_000u128,
+                gas_limit: 300_000,
+                to: alloy_primitives::TxKind::Call(to),
+                value: U256::ZERO,
+                input: Bytes::from(input),
+            });
+            transaction_to_visual_sign(tx, VisualSignOptions::default()).unwrap()
+        };
+
+        // Sent directly, the warning leads and the destination is badged
+        let payload = payload_for(settler, swap.clone());
+        assert_eq!(payload.fields[0].label(), "Warning");
+        assert!(
+            payload.fields[0]
+                .fallback_text()
+                .contains("matches the 0x Settler interface")
+        );
+        match &payload.fields[2] {
+            SignablePayloadField::AddressV2 { address_v2, .. } => {
+                assert_eq!(
+                    address_v2.badge_text.as_deref(),
+                    Some("Unverified contract")
+                );
+            }
+            other => panic!("expected the To address, got {other:?}"),
+        }
+        assert_eq!(
+            payload.fields.last().unwrap().fallback_text(),
+            "Swap the route's input for at least 1 USDC"
+        );
+
+        // Batched, the warning heads the inner call
+        let input = IMulticall3::aggregate3Call {
+            calls: vec![IMulticall3::Call3 {
+                target: settler,
+                allowFailure: false,
+                callData: Bytes::from(swap),
+            }],
+        }
+        .abi_encode();
+        let payload = payload_for(Address::repeat_byte(0xca), input);
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } =
+            payload.fields.last().unwrap()
+        else {
+            panic!("expected the batch layout");
+        };
+        let calls = &preview_layout.expanded.as_ref().unwrap().fields;
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } =
+            &calls[0].signable_payload_field
+        else {
+            panic!("expected the inner call");
+        };
+        let inner = &preview_layout.expanded.as_ref().unwrap().fields;
+        assert_eq!(inner[1].signable_payload_field.label(), "Warning");
+        assert!(
+            inner[1]
+                .signable_payload_field
+                .fallback_text()
+                .contains(&format!("{settler} is not a known 0x Settler deployment"))
+        );
+    }
+
+    #[test]
+    fn test_contract_deployment() {
+        use visualsign::vsptrait::{ChainMetadata, EthereumMetadata, chain_metadata};
+
+        let sender = Address::repeat_byte(0x22);
+        let payload_for = |sender: Option<Address>| {
+            let tx = TypedTransaction::Legacy(TxLegacy {
+                chain_id: Some(ChainId::from(1u64)),
+                nonce: 3,
+                gas_price: 1_000_000_000u128,
+                gas_limit: 500_000,
+                to: alloy_primitives::TxKind::Create,
+                value: U256::ZERO,
+                input: Bytes::from(vec![0x60, 0x80, 0x60, 0x40, 0x52]),
+            });
+            let options = VisualSignOptions {
+                decode_transfers: true,
+                transaction_name: None,
+                metadata: Some(ChainMetadata {
+                    metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
+                        abi: None,
+                        sender: sender.map(|sender| sender.to_string()),
+                    })),
+                }),
+            };
+            transaction_to_visual_sign(tx, options).unwrap()
+        };
+
+        let payload = payload_for(Some(sender));
+        assert_eq!(payload.title, "Contract Deployment");
+        assert!(payload.fields.iter().all(|field| field.label() != "To"));
+        let deployment = payload.fields.last().unwrap();
+        assert_eq!(deployment.label(), "Contract Deployment");
+        assert_eq!(
+            deployment.fallback_text(),
+            &format!("Deploy a contract at {:?}", sender.create(3))
+        );
+
+        let payload = payload_for(None);
+        assert_eq!(
+            payload.fields.last().unwrap().fallback_text(),
+            "Deploy a contract"
+        );
+    }
+
+    #[test]
+    fn test_value_in_native_currency() {
+        let payload_for = |chain_id: u64| {
+            let tx = TypedTransaction::Legacy(TxLegacy {
+                chain_id: Some(ChainId::from(chain_id)),
+                nonce: 0,
+                gas_price: 30_000_000_000u128,
+                gas_limit: 21000,
+                to: alloy_primitives::TxKind::Call(Address::repeat_byte(0x11)),
+                value: U256::from(2_500_000_000_000_000_000u128),
+                input: Bytes::new(),
+            });
+            transaction_to_visual_sign(tx, VisualSignOptions::default()).unwrap()
+        };
+        let field = |payload: &SignablePayload, label: &str| {
+            payload
+                .fields
+                .iter()
+                .find(|field| field.label() == label)
+                .unwrap()
+                .clone()
+        };
+
+        let polygon = payload_for(137);
+        assert_eq!(
+            field(&polygon, "Network").fallback_text(),
+            "Polygon Mainnet"
+        );
+        assert_eq!(field(&polygon, "Value").fallback_text(), "2.5 POL");
+        assert_eq!(field(&polygon, "Gas Price").fallback_text(), "30 gwei");
+
+        let SignablePayloadField::AddressV2 { address_v2, .. } =
+            field(&payload_for(11155111), "To")
+        else {
+            panic!("expected an address field");
+        };
+        assert_eq!(address_v2.badge_text.as_deref(), Some("Testnet"));
+        let SignablePayloadField::AddressV2 { address_v2, .. } = field(&polygon, "To") else {
+            panic!("expected an address field");
+        };
+        assert_eq!(address_v2.badge_text, None);
+    }
+
     #[test]
     fn test_transaction_with_custom_title() {
         let tx = TypedTransaction::Legacy(TxLegacy {
@@ -615,7 +1618,7 @@ mod tests {
             EthereumTransactionWrapper::from_string(&unsigned_to_hex(&eip1559_tx)),
             Ok(EthereumTransactionWrapper::new(eip1559_tx.clone())),
         );
-        // Test with EIP-2930 transaction (unsupported)
+        // Test with EIP-2930 transaction
         let eip2930_tx = TypedTransaction::Eip2930(alloy_consensus::TxEip2930 {
             chain_id: ChainId::from(1u64),
             nonce: 1,
@@ -628,11 +1631,9 @@ mod tests {
         });
         assert_eq!(
             EthereumTransactionWrapper::from_string(&unsigned_to_hex(&eip2930_tx)),
-            Err(TransactionParseError::DecodeError(
-                "Unsupported transaction type: eip-2930".to_string()
-            ))
+            Ok(EthereumTransactionWrapper::new(eip2930_tx)),
         );
-        // Test with EIP-4844 transaction (unsupported)
+        // Test with EIP-4844 transaction
         let eip4844_tx = TypedTransaction::Eip4844(alloy_consensus::TxEip4844Variant::TxEip4844(
             alloy_consensus::TxEip4844 {
                 chain_id: ChainId::from(1u64),
@@ -650,11 +1651,9 @@ mod tests {
         ));
         assert_eq!(
             EthereumTransactionWrapper::from_string(&unsigned_to_hex(&eip4844_tx)),
-            Err(TransactionParseError::DecodeError(
-                "Unsupported transaction type: eip-4844".to_string()
-            ))
+            Ok(EthereumTransactionWrapper::new(eip4844_tx)),
         );
-        // Test with EIP-7702 transaction (unsupported)
+        // Test with EIP-7702 transaction
         let eip7702_tx = TypedTransaction::Eip7702(alloy_consensus::TxEip7702 {
             chain_id: ChainId::from(1u64),
             nonce: 1,
@@ -669,9 +1668,99 @@ mod tests {
         });
         assert_eq!(
             EthereumTransactionWrapper::from_string(&unsigned_to_hex(&eip7702_tx)),
-            Err(TransactionParseError::DecodeError(
-                "Unsupported transaction type: eip-7702".to_string()
-            ))
+            Ok(EthereumTransactionWrapper::new(eip7702_tx)),
+        );
+    }
+
+    #[test]
+    fn test_signed_legacy_transaction() {
+        // The example from EIP-155, signed with the private key 0x4646...46
+        let signed = "0xf86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+        let sender: Address = "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F"
+            .parse()
+            .unwrap();
+        let tx_hash = "0x33469b22e9f636356c4160a87eb19df52b7412e8eac32a4a55ffe88ea8350788";
+
+        let wrapper = EthereumTransactionWrapper::from_string(signed).unwrap();
+        assert_eq!(wrapper.inner().chain_id(), Some(1));
+        assert_eq!(wrapper.inner().nonce(), 9);
+        assert_eq!(wrapper.sender(), Some(sender));
+        assert_eq!(format!("{:?}", wrapper.tx_hash().unwrap()), tx_hash);
+
+        let payload =
+            transaction_string_to_visual_sign(signed, VisualSignOptions::default()).unwrap();
+        let labels: Vec<&str> = payload.fields.iter().map(|f| f.label().as_str()).collect();
+        assert_eq!(labels[..3], ["Network", "From", "To"]);
+        let field = |label: &str| {
+            payload
+                .fields
+                .iter()
+                .find(|field| field.label() == label)
+                .unwrap()
+                .fallback_text()
+                .clone()
+        };
+        assert_eq!(field("From"), sender.to_string());
+        assert_eq!(field("Transaction Hash"), tx_hash);
+
+        // The same transaction as a signing payload has neither
+        let unsigned = EthereumTransactionWrapper::new(wrapper.inner().clone());
+        assert_eq!(
+            EthereumTransactionWrapper::from_string(&unsigned_to_hex(unsigned.inner())),
+            Ok(unsigned.clone())
+        );
+        assert_eq!((unsigned.sender(), unsigned.tx_hash()), (None, None));
+    }
+
+    #[test]
+    fn test_signed_typed_transaction() {
+        use alloy_eips::eip2718::Encodable2718;
+
+        let tx = TypedTransaction::Eip1559(alloy_consensus::TxEip1559 {
+            chain_id: ChainId::from(1u64),
+            nonce: 7,
+            gas_limit: 500_000,
+            max_fee_per_gas: 30_000_000_000u128,
+            max_priority_fee_per_gas: 2_000_000_000u128,
+            to: alloy_primitives::TxKind::Create,
+            value: U256::ZERO,
+            access_list: Default::default(),
+            input: Bytes::from(vec![0x60, 0x80, 0x60, 0x40, 0x52]),
+        });
+        let signature = Signature::new(
+            "0x28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276"
+                .parse()
+                .unwrap(),
+            "0x67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+                .parse()
+                .unwrap(),
+            false,
+        );
+        let encoded = tx.clone().into_envelope(signature).encoded_2718();
+        let sender = signature
+            .recover_address_from_prehash(&tx.signature_hash())
+            .unwrap();
+
+        let wrapper =
+            EthereumTransactionWrapper::from_string(&format!("0x{}", hex::encode(&encoded)))
+                .unwrap();
+        assert_eq!(
+            wrapper,
+            EthereumTransactionWrapper::new(tx).with_signature(signature)
+        );
+        assert_eq!(wrapper.sender(), Some(sender));
+        assert_eq!(
+            wrapper.tx_hash(),
+            Some(alloy_primitives::keccak256(&encoded))
+        );
+
+        // A signed deployment's address follows from the recovered sender
+        let payload = EthereumVisualSignConverter::default()
+            .to_visual_sign_payload(wrapper, VisualSignOptions::default())
+            .unwrap();
+        assert_eq!(
+            payload.fields.last().unwrap().fallback_text(),
+            &format!("Deploy a contract at {:?}", sender.create(7))
         );
     }
 
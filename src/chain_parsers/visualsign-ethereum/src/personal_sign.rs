@@ -0,0 +1,400 @@
+//! EIP-191 messages, as requested by `personal_sign`.
+//!
+//! The input is the message body, either `0x`-prefixed hex or plain UTF-8 text.
+//! Text is shown within the payload's ASCII charset, with anything else escaped
+//! and flagged. Sign-In with Ethereum (EIP-4361) messages are recognised and
+//! their statement fields are laid out individually.
+
+use alloy_primitives::{Address, eip191_hash_message};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayload, SignablePayloadField,
+    vsptrait::{
+        Transaction, TransactionParseError, VisualSignConverter, VisualSignConverterFromString,
+        VisualSignError, VisualSignOptions,
+    },
+};
+
+use crate::chains;
+use crate::fields::{address_field, plain_text_field, preview_layout, text_field};
+use crate::fmt::escape_non_ascii;
+
+/// The prefix `personal_sign` puts in front of the message before hashing.
+pub const EIP191_PREFIX: &str = "\x19Ethereum Signed Message:\n";
+
+const SIWE_HEADER_SUFFIX: &str = " wants you to sign in with your Ethereum account:";
+
+/// An EIP-191 message body that implements the Transaction trait
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PersonalMessageWrapper {
+    message: Vec<u8>,
+}
+
+impl Transaction for PersonalMessageWrapper {
+    fn from_string(data: &str) -> Result<Self, TransactionParseError> {
+        // Preimages routed here from the transaction converter may be bare hex
+        let hex_body = data.strip_prefix("0x").or_else(|| {
+            (is_personal_message(data) && !data.starts_with(EIP191_PREFIX)).then_some(data)
+        });
+        let message = match hex_body {
+            Some(hex_body) => hex::decode(hex_body)
+                .map_err(|e| TransactionParseError::DecodeError(format!("Invalid hex: {e}")))?,
+            None => data.as_bytes().to_vec(),
+        };
+        Ok(Self::new(message))
+    }
+
+    fn transaction_type(&self) -> String {
+        "EthereumPersonalMessage".to_string()
+    }
+}
+
+impl PersonalMessageWrapper {
+    /// Wraps a message body. If the bytes already carry the EIP-191 prefix and
+    /// length, they are stripped so the message isn't prefixed twice.
+    pub fn new(message: Vec<u8>) -> Self {
+        let message = strip_eip191_prefix(&message)
+            .map(<[u8]>::to_vec)
+            .unwrap_or(message);
+        Self { message }
+    }
+
+    pub fn message(&self) -> &[u8] {
+        &self.message
+    }
+}
+
+/// Returns the message body if `data` is a full EIP-191 `personal_sign`
+/// preimage whose length matches the body.
+fn strip_eip191_prefix(data: &[u8]) -> Option<&[u8]> {
+    let rest = data.strip_prefix(EIP191_PREFIX.as_bytes())?;
+    let digits = rest.iter().take_while(|b| b.is_ascii_digit()).count();
+    let length: usize = std::str::from_utf8(&rest[..digits]).ok()?.parse().ok()?;
+    let body = &rest[digits..];
+    (body.len() == length).then_some(body)
+}
+
+/// Returns true if `data` is an EIP-191 preimage rather than an RLP
+/// transaction, either as text or as hex. The `0x19` prefix byte is never a
+/// valid transaction type, so the two can't be confused.
+pub fn is_personal_message(data: &str) -> bool {
+    if data.starts_with(EIP191_PREFIX) {
+        return true;
+    }
+    let hex_prefix = hex::encode(EIP191_PREFIX);
+    let hex_body = data.strip_prefix("0x").unwrap_or(data);
+    hex_body
+        .get(..hex_prefix.len())
+        .is_some_and(|start| start.eq_ignore_ascii_case(&hex_prefix))
+}
+
+/// Converter that knows how to format `personal_sign` messages for VisualSign
+pub struct PersonalMessageVisualSignConverter;
+
+impl VisualSignConverter<PersonalMessageWrapper> for PersonalMessageVisualSignConverter {
+    fn to_visual_sign_payload(
+        &self,
+        wrapper: PersonalMessageWrapper,
+        options: VisualSignOptions,
+    ) -> Result<SignablePayload, VisualSignError> {
+        Ok(convert_to_visual_sign_payload(wrapper.message(), options))
+    }
+}
+
+impl VisualSignConverterFromString<PersonalMessageWrapper> for PersonalMessageVisualSignConverter {}
+
+fn convert_to_visual_sign_payload(message: &[u8], options: VisualSignOptions) -> SignablePayload {
+    let mut fields = Vec::new();
+    let mut title = "Sign Message".to_string();
+    let mut subtitle = None;
+
+    match std::str::from_utf8(message) {
+        Ok(text) => {
+            let (escaped, changed) = escape_non_ascii(text);
+            if changed {
+                fields.push(plain_text_field(
+                    "Warning",
+                    "This message contains non-ASCII or control characters. They are shown as <U+XXXX>; check for look-alike text.",
+                ));
+            }
+            match SiweMessage::parse(&escaped) {
+                Some(siwe) => {
+                    title = "Sign In With Ethereum".to_string();
+                    subtitle = Some(siwe.domain.to_string());
+                    fields.extend(siwe.fields());
+                }
+                None if escaped.contains(SIWE_HEADER_SUFFIX) => {
+                    fields.push(plain_text_field(
+                        "Warning",
+                        "This message looks like a Sign-In with Ethereum request but is not well formed.",
+                    ));
+                }
+                None => {}
+            }
+            fields.push(plain_text_field("Message", &escaped));
+        }
+        Err(_) => {
+            fields.push(plain_text_field(
+                "Message (hex)",
+                &format!("0x{}", hex::encode(message)),
+            ));
+        }
+    }
+    fields.push(plain_text_field(
+        "Message Hash",
+        &eip191_hash_message(message).to_string(),
+    ));
+
+    SignablePayload::new(
+        0,
+        options.transaction_name.unwrap_or(title),
+        subtitle,
+        fields,
+        "EthereumPersonalMessage".to_string(),
+    )
+}
+
+/// The parts of an EIP-4361 message that are shown as fields.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct SiweMessage<'a> {
+    scheme: Option<&'a str>,
+    domain: &'a str,
+    address: &'a str,
+    statement: Option<&'a str>,
+    uri: &'a str,
+    version: &'a str,
+    chain_id: u64,
+    nonce: &'a str,
+    issued_at: &'a str,
+    expiration_time: Option<&'a str>,
+    not_before: Option<&'a str>,
+    request_id: Option<&'a str>,
+    resources: Vec<&'a str>,
+}
+
+impl<'a> SiweMessage<'a> {
+    /// Parses `text` following the EIP-4361 message format. Returns `None`
+    /// unless the header, address and all required fields are present.
+    fn parse(text: &'a str) -> Option<Self> {
+        let mut lines = text.lines();
+        let origin = lines.next()?.strip_suffix(SIWE_HEADER_SUFFIX)?;
+        let (scheme, domain) = match origin.split_once("://") {
+            Some((scheme, domain)) => (Some(scheme), domain),
+            None => (None, origin),
+        };
+        let address = lines.next()?;
+        address.parse::<Address>().ok()?;
+        let mut message = Self {
+            scheme,
+            domain,
+            address,
+            ..Self::default()
+        };
+
+        // A blank line, then an optional statement and another blank line. Some
+        // libraries drop the second blank line when there is no statement.
+        if !lines.next()?.is_empty() {
+            return None;
+        }
+        let mut lines = lines.peekable();
+        if lines.peek().is_some_and(|line| !line.starts_with("URI: ")) {
+            message.statement = lines.next().filter(|line| !line.is_empty());
+            if message.statement.is_some() && !lines.next()?.is_empty() {
+                return None;
+            }
+        }
+
+        let (mut uri, mut version, mut chain_id, mut nonce, mut issued_at) =
+            (None, None, None, None, None);
+        while let Some(line) = lines.next() {
+            if line == "Resources:" {
+                for resource in lines.by_ref() {
+                    message.resources.push(resource.strip_prefix("- ")?);
+                }
+                break;
+            }
+            let (key, value) = line.split_once(": ")?;
+            match key {
+                "URI" => uri = Some(value),
+                "Version" => version = Some(value),
+                "Chain ID" => chain_id = Some(value.parse().ok()?),
+                "Nonce" => nonce = Some(value),
+                "Issued At" => issued_at = Some(value),
+                "Expiration Time" => message.expiration_time = Some(value),
+                "Not Before" => message.not_before = Some(value),
+                "Request ID" => message.request_id = Some(value),
+                _ => return None,
+            }
+        }
+        message.uri = uri?;
+        message.version = version?;
+        message.chain_id = chain_id?;
+        message.nonce = nonce?;
+        message.issued_at = issued_at?;
+        Some(message)
+    }
+
+    fn fields(&self) -> Vec<SignablePayloadField> {
+        let mut fields = vec![
+            plain_text_field("Domain", self.domain),
+            address_field("Account", self.address, None, None).signable_payload_field,
+        ];
+        fields.extend(self.statement.map(|s| plain_text_field("Statement", s)));
+        fields.push(plain_text_field("URI", self.uri));
+        fields.push(plain_text_field(
+            "Network",
+            &chains::get_chain_name(Some(self.chain_id)),
+        ));
+        fields.push(plain_text_field("Nonce", self.nonce));
+        fields.push(plain_text_field("Issued At", self.issued_at));
+        fields.push(plain_text_field(
+            "Expiration Time",
+            self.expiration_time.unwrap_or("Never"),
+        ));
+        fields.extend(self.not_before.map(|s| plain_text_field("Not Before", s)));
+
+        let mut details: Vec<AnnotatedPayloadField> = Vec::new();
+        details.extend(self.scheme.map(|scheme| text_field("Scheme", scheme)));
+        details.push(text_field("Version", self.version));
+        details.push(text_field("Chain ID", &self.chain_id.to_string()));
+        details.extend(self.request_id.map(|id| text_field("Request ID", id)));
+        for (i, resource) in self.resources.iter().enumerate() {
+            details.push(text_field(&format!("Resource {}", i + 1), resource));
+        }
+        let subtitle = format!("Version {}, chain {}", self.version, self.chain_id);
+        fields.push(preview_layout(
+            "Sign-In Details",
+            "Sign-In Details",
+            &subtitle,
+            None,
+            details,
+        ));
+        fields
+    }
+}
+
+// Public API functions for ease of use
+pub fn personal_message_to_visual_sign(
+    message: &[u8],
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    PersonalMessageVisualSignConverter
+        .to_visual_sign_payload(PersonalMessageWrapper::new(message.to_vec()), options)
+}
+
+pub fn personal_message_string_to_visual_sign(
+    message: &str,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    PersonalMessageVisualSignConverter.to_visual_sign_payload_from_string(message, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EthereumVisualSignConverter;
+
+    const SIWE: &str = "https://example.com wants you to sign in with your Ethereum account:
+0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2
+
+I accept the ExampleOrg Terms of Service: https://example.com/tos
+
+URI: https://example.com/login
+Version: 1
+Chain ID: 1
+Nonce: 32891756
+Issued At: 2021-09-30T16:25:24Z
+Resources:
+- ipfs://bafybeiemxf5abjwjbikoz4mc3a3dla6ual3jsgpdr4cjr3oz3evfyavhwq/
+- https://example.com/my-web2-claim.json";
+
+    fn find<'a>(payload: &'a SignablePayload, label: &str) -> &'a SignablePayloadField {
+        payload.fields.iter().find(|f| f.label() == label).unwrap()
+    }
+
+    #[test]
+    fn test_plain_message_hex_and_text() {
+        let options = VisualSignOptions::default();
+        let from_text =
+            personal_message_string_to_visual_sign("Hello world", options.clone()).unwrap();
+        let from_hex =
+            personal_message_string_to_visual_sign("0x48656c6c6f20776f726c64", options).unwrap();
+        assert_eq!(from_text, from_hex);
+        assert_eq!(from_text.title, "Sign Message");
+        assert_eq!(find(&from_text, "Message").fallback_text(), "Hello world");
+        // keccak256("\x19Ethereum Signed Message:\n11Hello world")
+        assert_eq!(
+            find(&from_text, "Message Hash").fallback_text(),
+            "0x8144a6fa26be252b86456491fbcd43c1de7e022241845ffea1c3df066f7cfede"
+        );
+    }
+
+    #[test]
+    fn test_non_ascii_is_escaped_and_flagged() {
+        let payload =
+            personal_message_string_to_visual_sign("pay\u{200b} me", VisualSignOptions::default())
+                .unwrap();
+        assert_eq!(payload.fields[0].label(), "Warning");
+        assert_eq!(find(&payload, "Message").fallback_text(), "pay<U+200B> me");
+        assert!(payload.validate_charset().is_ok());
+
+        // Bytes that aren't UTF-8 are shown as hex
+        let payload =
+            personal_message_to_visual_sign(&[0xff, 0x00], VisualSignOptions::default()).unwrap();
+        assert_eq!(find(&payload, "Message (hex)").fallback_text(), "0xff00");
+    }
+
+    #[test]
+    fn test_siwe_message() {
+        let payload =
+            personal_message_string_to_visual_sign(SIWE, VisualSignOptions::default()).unwrap();
+        assert_eq!(payload.title, "Sign In With Ethereum");
+        assert_eq!(payload.subtitle.as_deref(), Some("example.com"));
+        assert_eq!(find(&payload, "Domain").fallback_text(), "example.com");
+        assert_eq!(
+            find(&payload, "Account").fallback_text(),
+            "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"
+        );
+        assert_eq!(
+            find(&payload, "Statement").fallback_text(),
+            "I accept the ExampleOrg Terms of Service: https://example.com/tos"
+        );
+        assert_eq!(
+            find(&payload, "URI").fallback_text(),
+            "https://example.com/login"
+        );
+        assert_eq!(
+            find(&payload, "Network").fallback_text(),
+            "Ethereum Mainnet"
+        );
+        assert_eq!(find(&payload, "Nonce").fallback_text(), "32891756");
+        assert_eq!(find(&payload, "Expiration Time").fallback_text(), "Never");
+        assert_eq!(
+            find(&payload, "Sign-In Details").fallback_text(),
+            "Version 1, chain 1"
+        );
+
+        // A message that breaks the format is shown as text with a warning
+        let broken = SIWE.replace("Nonce: 32891756\n", "");
+        let payload =
+            personal_message_string_to_visual_sign(&broken, VisualSignOptions::default()).unwrap();
+        assert_eq!(payload.title, "Sign Message");
+        assert_eq!(payload.fields[0].label(), "Warning");
+    }
+
+    #[test]
+    fn test_routed_from_transaction_converter() {
+        let preimage = format!("{EIP191_PREFIX}11Hello world");
+        assert!(is_personal_message(&preimage));
+        assert!(is_personal_message(&hex::encode(&preimage)));
+        assert!(!is_personal_message("02f86c0180"));
+
+        let payload = EthereumVisualSignConverter::default()
+            .to_visual_sign_payload_from_string(
+                &format!("0x{}", hex::encode(&preimage)),
+                VisualSignOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(payload.payload_type, "EthereumPersonalMessage");
+        assert_eq!(find(&payload, "Message").fallback_text(), "Hello world");
+    }
+}
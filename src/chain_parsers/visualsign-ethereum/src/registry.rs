@@ -0,0 +1,404 @@
+//! Known contract deployments, keyed by chain id and address.
+//!
+//! Protocol visualizers only decode calls sent to a deployment listed here, so
+//! a contract that merely shares a protocol's function selectors isn't shown
+//! under that protocol's name. The built-in deployments live in
+//! `data/contracts.json`; integrators can add their own at runtime with
+//! [`ContractRegistry::extend_from_json`].
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use alloy_primitives::Address;
+use serde::Deserialize;
+use visualsign::SignablePayloadField;
+
+use crate::contracts;
+use crate::tokens::TokenRegistry;
+
+const BUILTIN_CONTRACTS: &str = include_str!("../data/contracts.json");
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum ContractRegistryError {
+    #[error("Invalid contract registry: {0}")]
+    InvalidJson(String),
+}
+
+/// The protocol a deployment belongs to, which decides the visualizer its
+/// calldata is routed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContractKind {
+    UniswapUniversalRouter,
+    UniswapV2Router,
+    /// Uniswap's V3 `SwapRouter` and `SwapRouter02`.
+    UniswapV3Router,
+    UniswapV3PositionManager,
+    /// Uniswap's Permit2, which holds token allowances on behalf of other spenders.
+    Permit2,
+    EigenLayer,
+    /// Safe's `MultiSend` libraries, which Safes delegatecall to batch calls.
+    SafeMultiSend,
+    /// Lido's stETH, withdrawal queue and wstETH.
+    Lido,
+    /// Rocket Pool's deposit pool and rETH.
+    RocketPool,
+    /// Coinbase Wrapped Staked ETH.
+    CoinbaseStakedEth,
+    AaveV3Pool,
+    /// A Compound v3 market; each base asset has its own Comet.
+    CompoundV3Comet,
+    /// 1inch's AggregationRouter v5 and v6.
+    OneInchRouter,
+    ZeroExExchangeProxy,
+    /// ParaSwap's Augustus v5 and v6.2.
+    ParaswapAugustus,
+    /// WETH9 and its clones wrapping other chains' native currency.
+    Weth,
+    /// OP Mainnet's `L1StandardBridge` on Ethereum.
+    OptimismBridge,
+    /// Base's `L1StandardBridge` on Ethereum.
+    BaseBridge,
+    /// Arbitrum One's delayed inbox and L1 gateway router.
+    ArbitrumBridge,
+    /// The zkSync Era diamond proxy, whose mailbox takes deposits.
+    ZkSyncBridge,
+    /// ENS's `.eth` registrar controllers, current and legacy.
+    EnsRegistrarController,
+    EnsPublicResolver,
+}
+
+impl ContractKind {
+    /// Every kind, in the order visualizers are tried for unknown addresses.
+    pub const ALL: [ContractKind; 22] = [
+        ContractKind::EigenLayer,
+        ContractKind::UniswapUniversalRouter,
+        ContractKind::UniswapV2Router,
+        ContractKind::UniswapV3Router,
+        ContractKind::UniswapV3PositionManager,
+        ContractKind::Permit2,
+        ContractKind::SafeMultiSend,
+        ContractKind::Lido,
+        ContractKind::RocketPool,
+        ContractKind::CoinbaseStakedEth,
+        ContractKind::AaveV3Pool,
+        ContractKind::CompoundV3Comet,
+        ContractKind::OneInchRouter,
+        ContractKind::ZeroExExchangeProxy,
+        ContractKind::ParaswapAugustus,
+        ContractKind::Weth,
+        ContractKind::OptimismBridge,
+        ContractKind::BaseBridge,
+        ContractKind::ArbitrumBridge,
+        ContractKind::ZkSyncBridge,
+        ContractKind::EnsRegistrarController,
+        ContractKind::EnsPublicResolver,
+    ];
+
+    pub fn protocol_name(self) -> &'static str {
+        match self {
+            ContractKind::UniswapUniversalRouter => "Uniswap Universal Router",
+            ContractKind::UniswapV2Router => "Uniswap V2 Router",
+            ContractKind::UniswapV3Router => "Uniswap V3 Router",
+            ContractKind::UniswapV3PositionManager => "Uniswap V3 Position Manager",
+            ContractKind::Permit2 => "Permit2",
+            ContractKind::EigenLayer => "EigenLayer",
+            ContractKind::SafeMultiSend => "Safe MultiSend",
+            ContractKind::Lido => "Lido",
+            ContractKind::RocketPool => "Rocket Pool",
+            ContractKind::CoinbaseStakedEth => "Coinbase cbETH",
+            ContractKind::AaveV3Pool => "Aave v3",
+            ContractKind::CompoundV3Comet => "Compound v3",
+            ContractKind::OneInchRouter => "1inch Aggregation Router",
+            ContractKind::ZeroExExchangeProxy => "0x Exchange Proxy",
+            ContractKind::ParaswapAugustus => "ParaSwap Augustus",
+            ContractKind::Weth => "Wrapped Native Token",
+            ContractKind::OptimismBridge => "OP Mainnet Bridge",
+            ContractKind::BaseBridge => "Base Bridge",
+            ContractKind::ArbitrumBridge => "Arbitrum Bridge",
+            ContractKind::ZkSyncBridge => "zkSync Era Bridge",
+            ContractKind::EnsRegistrarController => "ENS Registrar Controller",
+            ContractKind::EnsPublicResolver => "ENS Public Resolver",
+        }
+    }
+
+    /// The chain a canonical bridge deposits into.
+    pub(crate) fn bridge_destination(self) -> Option<u64> {
+        match self {
+            ContractKind::OptimismBridge => Some(10),
+            ContractKind::BaseBridge => Some(8453),
+            ContractKind::ArbitrumBridge => Some(42161),
+            ContractKind::ZkSyncBridge => Some(324),
+            _ => None,
+        }
+    }
+
+    /// Whether deployments of this kind are ERC721 collections, whose
+    /// `approve` takes a token ID rather than an amount.
+    pub(crate) fn is_nft(self) -> bool {
+        matches!(self, ContractKind::UniswapV3PositionManager)
+    }
+
+    /// Whether this protocol's selectors are distinctive enough that a call
+    /// using them elsewhere is worth flagging. Staking entry points such as
+    /// `deposit()`, `wrap` and `burn`, WETH's `withdraw(uint256)`, Comet's
+    /// `supply(address,uint256)`, the position manager's `burn(uint256)` or ENS's
+    /// `commit(bytes32)` and `multicall(bytes[])`, are shared by countless
+    /// unrelated contracts.
+    fn flags_lookalikes(self) -> bool {
+        match self {
+            ContractKind::UniswapUniversalRouter
+            | ContractKind::UniswapV2Router
+            | ContractKind::UniswapV3Router
+            | ContractKind::EigenLayer
+            | ContractKind::SafeMultiSend
+            | ContractKind::AaveV3Pool
+            | ContractKind::OneInchRouter
+            | ContractKind::ZeroExExchangeProxy
+            | ContractKind::ParaswapAugustus
+            | ContractKind::OptimismBridge
+            | ContractKind::BaseBridge
+            | ContractKind::ArbitrumBridge
+            | ContractKind::ZkSyncBridge => true,
+            ContractKind::UniswapV3PositionManager
+            | ContractKind::Permit2
+            | ContractKind::Lido
+            | ContractKind::RocketPool
+            | ContractKind::CoinbaseStakedEth
+            | ContractKind::CompoundV3Comet
+            | ContractKind::Weth
+            | ContractKind::EnsRegistrarController
+            | ContractKind::EnsPublicResolver => false,
+        }
+    }
+
+    /// Decodes `input`, a call on `chain_id`, with this protocol's visualizer.
+    pub(crate) fn visualize(
+        self,
+        input: &[u8],
+        chain_id: Option<u64>,
+        tokens: &TokenRegistry,
+    ) -> Option<SignablePayloadField> {
+        match self {
+            ContractKind::UniswapUniversalRouter => (contracts::uniswap::UniswapV4Visualizer {})
+                .visualize_tx_commands_on_chain(input, chain_id, tokens),
+            ContractKind::UniswapV2Router => (contracts::uniswap::UniswapV2RouterVisualizer {})
+                .visualize_tx_commands(input, chain_id, tokens),
+            ContractKind::UniswapV3Router => (contracts::uniswap::UniswapV3RouterVisualizer {})
+                .visualize_tx_commands(input, chain_id, tokens),
+            ContractKind::UniswapV3PositionManager => {
+                (contracts::uniswap::NonfungiblePositionManagerVisualizer {})
+                    .visualize_tx_commands(input, chain_id, tokens)
+            }
+            // Only named as a spender; its own calls are left to the ABI fallbacks
+            ContractKind::Permit2 => None,
+            ContractKind::EigenLayer => {
+                (contracts::eigenlayer::EigenLayerVisualizer {}).visualize_tx_commands(input)
+            }
+            // Batches are decoded by the dispatcher, which can recurse into their calls
+            ContractKind::SafeMultiSend => None,
+            ContractKind::Lido => {
+                (contracts::liquid_staking::LidoVisualizer {}).visualize_tx_commands(input)
+            }
+            ContractKind::RocketPool => {
+                (contracts::liquid_staking::RocketPoolVisualizer {}).visualize_tx_commands(input)
+            }
+            ContractKind::CoinbaseStakedEth => {
+                (contracts::liquid_staking::CbEthVisualizer {}).visualize_tx_commands(input)
+            }
+            ContractKind::AaveV3Pool => (contracts::lending::AaveV3Visualizer {})
+                .visualize_tx_commands(input, chain_id, tokens),
+            ContractKind::CompoundV3Comet => (contracts::lending::CompoundV3Visualizer {})
+                .visualize_tx_commands(input, chain_id, tokens),
+            ContractKind::OneInchRouter => (contracts::aggregators::OneInchVisualizer {})
+                .visualize_tx_commands(input, chain_id, tokens),
+            ContractKind::ZeroExExchangeProxy => (contracts::aggregators::ZeroExVisualizer {})
+                .visualize_tx_commands(input, chain_id, tokens),
+            ContractKind::ParaswapAugustus => (contracts::aggregators::ParaswapVisualizer {})
+                .visualize_tx_commands(input, chain_id, tokens),
+            ContractKind::Weth => {
+                (contracts::weth::WethVisualizer {}).visualize_tx_commands(input, chain_id)
+            }
+            ContractKind::OptimismBridge
+            | ContractKind::BaseBridge
+            | ContractKind::ArbitrumBridge
+            | ContractKind::ZkSyncBridge => (contracts::bridges::BridgeVisualizer {})
+                .visualize_tx_commands(input, self, chain_id, tokens),
+            ContractKind::EnsRegistrarController => {
+                (contracts::ens::EnsControllerVisualizer {}).visualize_tx_commands(input)
+            }
+            ContractKind::EnsPublicResolver => {
+                (contracts::ens::EnsResolverVisualizer {}).visualize_tx_commands(input)
+            }
+        }
+    }
+
+    /// The first protocol whose visualizer accepts `input`, used to flag calls
+    /// that look like a known protocol but go to an address we don't know.
+    pub(crate) fn matching_selector(input: &[u8]) -> Option<ContractKind> {
+        let tokens = TokenRegistry::empty();
+        Self::ALL
+            .into_iter()
+            .filter(|kind| kind.flags_lookalikes())
+            .find(|kind| kind.visualize(input, None, &tokens).is_some())
+    }
+}
+
+/// What the registry knows about a deployment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractInfo {
+    pub name: String,
+    pub kind: ContractKind,
+}
+
+/// One entry of the registry's JSON format.
+#[derive(Debug, Deserialize)]
+struct ContractEntry {
+    chain_id: u64,
+    address: Address,
+    name: String,
+    kind: ContractKind,
+}
+
+/// Deployments the Ethereum parser trusts, keyed by `(chain id, address)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractRegistry {
+    contracts: HashMap<(u64, Address), ContractInfo>,
+}
+
+impl Default for ContractRegistry {
+    /// A registry holding the built-in deployments.
+    fn default() -> Self {
+        Self::builtin().clone()
+    }
+}
+
+impl ContractRegistry {
+    /// The built-in deployments, parsed once.
+    pub fn builtin() -> &'static Self {
+        static BUILTIN: OnceLock<ContractRegistry> = OnceLock::new();
+        BUILTIN.get_or_init(|| {
+            let mut registry = Self::empty();
+            registry
+                .extend_from_json(BUILTIN_CONTRACTS)
+                .expect("built-in contract registry is valid");
+            registry
+        })
+    }
+
+    /// A registry with no deployments at all.
+    pub fn empty() -> Self {
+        Self {
+            contracts: HashMap::new(),
+        }
+    }
+
+    pub fn register(&mut self, chain_id: u64, address: Address, info: ContractInfo) {
+        self.contracts.insert((chain_id, address), info);
+    }
+
+    /// Adds the deployments in `json`, an array of
+    /// `{"chain_id", "address", "name", "kind"}` objects. Entries for an
+    /// address that is already registered replace it.
+    pub fn extend_from_json(&mut self, json: &str) -> Result<(), ContractRegistryError> {
+        let entries: Vec<ContractEntry> = serde_json::from_str(json)
+            .map_err(|e| ContractRegistryError::InvalidJson(e.to_string()))?;
+        for entry in entries {
+            self.register(
+                entry.chain_id,
+                entry.address,
+                ContractInfo {
+                    name: entry.name,
+                    kind: entry.kind,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// The deployment at `address` on `chain_id`. Transactions without a
+    /// chain id never match, since they can be replayed on any chain.
+    pub fn lookup(&self, chain_id: Option<u64>, address: Address) -> Option<&ContractInfo> {
+        self.contracts.get(&(chain_id?, address))
+    }
+
+    pub fn len(&self) -> usize {
+        self.contracts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.contracts.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    const UNIVERSAL_ROUTER: Address = address!("0x66a9893cc07d91d95644aedd05d03f95e1dba8af");
+
+    #[test]
+    fn test_builtin_registry() {
+        let registry = ContractRegistry::default();
+        let router = registry.lookup(Some(1), UNIVERSAL_ROUTER).unwrap();
+        assert_eq!(router.kind, ContractKind::UniswapUniversalRouter);
+        assert_eq!(router.name, "Uniswap Universal Router");
+
+        // Same address on another chain, or without a chain id, is unknown
+        assert!(registry.lookup(Some(56), UNIVERSAL_ROUTER).is_none());
+        assert!(registry.lookup(None, UNIVERSAL_ROUTER).is_none());
+    }
+
+    #[test]
+    fn test_generic_selectors_are_not_lookalikes() {
+        use crate::contracts::liquid_staking::{ILido, IRocketDepositPool};
+        use alloy_sol_types::SolCall;
+
+        let lido = address!("0xae7ab96520de3a18e5e111b5eaab095312d7fe84");
+        assert_eq!(
+            ContractRegistry::default()
+                .lookup(Some(1), lido)
+                .unwrap()
+                .kind,
+            ContractKind::Lido
+        );
+        // WETH's deposit() has the same selector as Rocket Pool's
+        let deposit = IRocketDepositPool::depositCall {}.abi_encode();
+        assert!(
+            ContractKind::RocketPool
+                .visualize(&deposit, Some(1), &TokenRegistry::empty())
+                .is_some()
+        );
+        assert_eq!(ContractKind::matching_selector(&deposit), None);
+        let submit = ILido::submitCall {
+            _referral: Address::ZERO,
+        }
+        .abi_encode();
+        assert_eq!(ContractKind::matching_selector(&submit), None);
+    }
+
+    #[test]
+    fn test_extend_from_json() {
+        let mut registry = ContractRegistry::empty();
+        registry
+            .extend_from_json(
+                r#"[{"chain_id": 11155111, "address": "0x3a9d48ab9751398bbfa63ad67599bb04e4bdf98b",
+                     "name": "Sepolia Router", "kind": "uniswap_universal_router"}]"#,
+            )
+            .unwrap();
+        let address = address!("0x3a9d48ab9751398bbfa63ad67599bb04e4bdf98b");
+        assert_eq!(
+            registry.lookup(Some(11155111), address).unwrap().name,
+            "Sepolia Router"
+        );
+
+        let err = registry
+            .extend_from_json(
+                r#"[{"chain_id": 1, "address": "0x00", "name": "x", "kind": "nope"}]"#,
+            )
+            .unwrap_err();
+        assert!(matches!(err, ContractRegistryError::InvalidJson(_)));
+        assert_eq!(registry.len(), 1);
+    }
+}
@@ -0,0 +1,232 @@
+//! An offline database of function signatures, keyed by 4-byte selector.
+//!
+//! When no visualizer or caller-supplied ABI recognizes a call, its selector is
+//! looked up here to guess which function it invokes. A selector is only a
+//! hash prefix, so unrelated signatures can share one and any contract can
+//! reuse a well-known name: a guess is never shown as anything but a guess.
+//! The signatures live in `data/selectors.json`, a hand-curated list of
+//! common token, NFT, governance, proxy, router and wallet functions. It also
+//! keeps two real 4byte registrations that collide with `transfer` and `burn`,
+//! `many_msg_babbage(bytes1)` and `collate_propagate_storage(bytes16)`, so a
+//! guess always shows its collisions. `examples/generate_selectors.rs` merges
+//! it with OpenChain or 4byte exports to build a larger list. Selectors are
+//! computed from the signatures rather than stored.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use alloy_dyn_abi::JsonAbiExt;
+use alloy_json_abi::Function;
+use alloy_primitives::Selector;
+use visualsign::SignablePayloadField;
+
+use crate::abi::argument_fields;
+use crate::fields::{preview_layout, text_field, with_annotation};
+
+const BUILTIN_SIGNATURES: &str = include_str!("../data/selectors.json");
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum SelectorDatabaseError {
+    #[error("Invalid selector database: {0}")]
+    InvalidJson(String),
+    #[error("Invalid function signature {0}: {1}")]
+    InvalidSignature(String, String),
+}
+
+/// Candidate functions for each selector, in the order they were listed.
+#[derive(Debug, Clone, Default)]
+pub struct SelectorDatabase {
+    functions: HashMap<Selector, Vec<Function>>,
+}
+
+impl SelectorDatabase {
+    /// The curated signatures bundled with the parser.
+    pub fn builtin() -> &'static Self {
+        static BUILTIN: OnceLock<SelectorDatabase> = OnceLock::new();
+        BUILTIN.get_or_init(|| {
+            Self::from_json(BUILTIN_SIGNATURES).expect("built-in selector database is valid")
+        })
+    }
+
+    /// A database of the signatures in `json`, an array of strings such as
+    /// `"transfer(address,uint256)"`.
+    pub fn from_json(json: &str) -> Result<Self, SelectorDatabaseError> {
+        let signatures: Vec<String> = serde_json::from_str(json)
+            .map_err(|e| SelectorDatabaseError::InvalidJson(e.to_string()))?;
+        let mut database = Self::default();
+        for signature in signatures {
+            let function = Function::parse(&signature).map_err(|e| {
+                SelectorDatabaseError::InvalidSignature(signature.clone(), e.to_string())
+            })?;
+            database
+                .functions
+                .entry(function.selector())
+                .or_default()
+                .push(function);
+        }
+        Ok(database)
+    }
+
+    /// Every known function with `selector`.
+    pub fn candidates(&self, selector: Selector) -> &[Function] {
+        self.functions.get(&selector).map_or(&[], Vec::as_slice)
+    }
+
+    /// Number of distinct selectors.
+    pub fn len(&self) -> usize {
+        self.functions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty()
+    }
+
+    /// The candidate `input` is best explained by, with the calldata left over
+    /// after its arguments: the first whose arguments re-encode to exactly the
+    /// calldata, or failing that the first whose arguments re-encode to a
+    /// prefix of it.
+    fn best_match<'a>(
+        &'a self,
+        input: &'a [u8],
+    ) -> Option<(&'a Function, &'a [Function], &'a [u8])> {
+        let candidates = self.candidates(Selector::try_from(input.get(..4)?).ok()?);
+        let encoded_len = |function: &Function| {
+            let values = function.abi_decode_input(&input[4..]).ok()?;
+            let encoded = function.abi_encode_input(&values).ok()?;
+            input.starts_with(&encoded).then_some(encoded.len())
+        };
+        let matches: Vec<(&Function, usize)> = candidates
+            .iter()
+            .filter_map(|function| Some((function, encoded_len(function)?)))
+            .collect();
+        let (function, len) = matches
+            .iter()
+            .find(|(_, len)| *len == input.len())
+            .or_else(|| matches.first())
+            .copied()?;
+        Some((function, candidates, &input[len..]))
+    }
+}
+
+/// A layout for `input` decoded with the signature its selector most likely
+/// stands for, flagged as unverified and listing any colliding signatures.
+pub(crate) fn signature_guess(input: &[u8]) -> Option<SignablePayloadField> {
+    let (function, candidates, trailing) = SelectorDatabase::builtin().best_match(input)?;
+    let signature = function.signature();
+
+    let mut fields = vec![with_annotation(
+        text_field("Signature", &signature),
+        "Unverified signature guess: looked up from the 4-byte selector, so the contract's actual function may differ",
+    )];
+    let others: Vec<String> = candidates
+        .iter()
+        .map(Function::signature)
+        .filter(|other| *other != signature)
+        .collect();
+    if !others.is_empty() {
+        fields.push(text_field("Selector Collisions", &others.join(", ")));
+    }
+    fields.extend(argument_fields(function, input)?);
+    // Some contracts read data appended after the arguments, but it also fits a wrong guess
+    if !trailing.is_empty() {
+        fields.push(with_annotation(
+            text_field("Trailing Data", &format!("0x{}", hex::encode(trailing))),
+            &format!(
+                "{} bytes after the arguments aren't part of this signature",
+                trailing.len()
+            ),
+        ));
+    }
+
+    Some(preview_layout(
+        "Unverified Signature Guess",
+        &function.name,
+        &format!("{signature} (unverified signature guess)"),
+        None,
+        fields,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Address, U256};
+    use alloy_sol_types::{SolCall, sol};
+
+    sol! {
+        function burn(uint256 amount);
+        function pause();
+    }
+
+    #[test]
+    fn test_builtin_collisions() {
+        let database = SelectorDatabase::builtin();
+        let transfer = database.candidates(Selector::from([0xa9, 0x05, 0x9c, 0xbb]));
+        let signatures: Vec<String> = transfer.iter().map(Function::signature).collect();
+        assert_eq!(
+            signatures,
+            vec!["transfer(address,uint256)", "many_msg_babbage(bytes1)"]
+        );
+        assert!(database.candidates(Selector::ZERO).is_empty());
+    }
+
+    #[test]
+    fn test_guess_picks_the_signature_that_fits() {
+        let input = burnCall {
+            amount: U256::from(5u64),
+        }
+        .abi_encode();
+        let SignablePayloadField::PreviewLayout {
+            common,
+            preview_layout,
+        } = signature_guess(&input).unwrap()
+        else {
+            panic!("expected a preview layout");
+        };
+        assert_eq!(common.label, "Unverified Signature Guess");
+        assert_eq!(
+            common.fallback_text,
+            "burn(uint256) (unverified signature guess)"
+        );
+        let fields = preview_layout.expanded.unwrap().fields;
+        assert_eq!(
+            fields[1],
+            text_field("Selector Collisions", "collate_propagate_storage(bytes16)")
+        );
+        assert_eq!(fields[2].signable_payload_field.fallback_text(), "5");
+
+        // Extra bytes after the arguments are shown, and flagged, after them
+        let mut padded = input.clone();
+        padded.extend_from_slice(Address::ZERO.as_slice());
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } =
+            signature_guess(&padded).unwrap()
+        else {
+            panic!("expected a preview layout");
+        };
+        let fields = preview_layout.expanded.unwrap().fields;
+        assert_eq!(fields[2].signable_payload_field.fallback_text(), "5");
+        assert_eq!(
+            fields[3],
+            with_annotation(
+                text_field("Trailing Data", &format!("0x{}", "00".repeat(20))),
+                "20 bytes after the arguments aren't part of this signature",
+            )
+        );
+
+        assert_eq!(
+            signature_guess(&pauseCall {}.abi_encode()).unwrap().label(),
+            "Unverified Signature Guess"
+        );
+        assert_eq!(signature_guess(&[0xde, 0xad, 0xbe, 0xef]), None);
+    }
+
+    #[test]
+    fn test_from_json_rejects_bad_signatures() {
+        let err = SelectorDatabase::from_json(r#"["transfer(address,"]"#).unwrap_err();
+        assert!(matches!(err, SelectorDatabaseError::InvalidSignature(..)));
+        assert!(matches!(
+            SelectorDatabase::from_json("{}").unwrap_err(),
+            SelectorDatabaseError::InvalidJson(_)
+        ));
+    }
+}
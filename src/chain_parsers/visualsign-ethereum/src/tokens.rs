@@ -0,0 +1,213 @@
+//! Symbols and decimals of known ERC20 tokens, keyed by chain id and address,
+//! so amounts can be shown in display units instead of raw integers.
+//!
+//! The built-in tokens live in `data/tokens.json`; integrators can add their
+//! own with [`TokenRegistry::extend_from_json`].
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use alloy_primitives::{Address, U256};
+use serde::Deserialize;
+
+use crate::chains;
+use crate::fields::addr;
+use crate::fmt::format_token_amount;
+
+const BUILTIN_TOKENS: &str = include_str!("../data/tokens.json");
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum TokenRegistryError {
+    #[error("Invalid token registry: {0}")]
+    InvalidJson(String),
+}
+
+/// What the registry knows about a token contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenInfo {
+    pub symbol: String,
+    pub name: String,
+    pub decimals: u8,
+}
+
+/// One entry of the registry's JSON format.
+#[derive(Debug, Deserialize)]
+struct TokenEntry {
+    chain_id: u64,
+    address: Address,
+    symbol: String,
+    name: String,
+    decimals: u8,
+}
+
+/// Tokens the Ethereum parser can name, keyed by `(chain id, address)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenRegistry {
+    tokens: HashMap<(u64, Address), TokenInfo>,
+}
+
+impl Default for TokenRegistry {
+    /// A registry holding the built-in tokens.
+    fn default() -> Self {
+        Self::builtin().clone()
+    }
+}
+
+impl TokenRegistry {
+    /// The built-in tokens, parsed once.
+    pub fn builtin() -> &'static Self {
+        static BUILTIN: OnceLock<TokenRegistry> = OnceLock::new();
+        BUILTIN.get_or_init(|| {
+            let mut registry = Self::empty();
+            registry
+                .extend_from_json(BUILTIN_TOKENS)
+                .expect("built-in token registry is valid");
+            registry
+        })
+    }
+
+    /// A registry with no tokens at all.
+    pub fn empty() -> Self {
+        Self {
+            tokens: HashMap::new(),
+        }
+    }
+
+    pub fn register(&mut self, chain_id: u64, address: Address, info: TokenInfo) {
+        self.tokens.insert((chain_id, address), info);
+    }
+
+    /// Adds the tokens in `json`, an array of
+    /// `{"chain_id", "address", "symbol", "name", "decimals"}` objects. Entries
+    /// for an address that is already registered replace it.
+    pub fn extend_from_json(&mut self, json: &str) -> Result<(), TokenRegistryError> {
+        let entries: Vec<TokenEntry> = serde_json::from_str(json)
+            .map_err(|e| TokenRegistryError::InvalidJson(e.to_string()))?;
+        for entry in entries {
+            self.register(
+                entry.chain_id,
+                entry.address,
+                TokenInfo {
+                    symbol: entry.symbol,
+                    name: entry.name,
+                    decimals: entry.decimals,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// The token at `address` on `chain_id`. Without a chain id nothing
+    /// matches, since the same address holds different contracts on
+    /// different chains.
+    pub fn lookup(&self, chain_id: Option<u64>, address: Address) -> Option<&TokenInfo> {
+        self.tokens.get(&(chain_id?, address))
+    }
+
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    pub(crate) fn token(&self, chain_id: Option<u64>, address: Address) -> Option<Token<'_>> {
+        self.lookup(chain_id, address).map(|info| Token {
+            symbol: &info.symbol,
+            decimals: info.decimals,
+        })
+    }
+
+    /// Like [`Self::token`], but the zero address stands for the chain's native
+    /// currency, as it does in Uniswap v4 pool keys.
+    pub(crate) fn currency(&self, chain_id: Option<u64>, address: Address) -> Option<Token<'_>> {
+        if address == Address::ZERO {
+            let (symbol, decimals) = chains::native_currency(chain_id);
+            return Some(Token { symbol, decimals });
+        }
+        self.token(chain_id, address)
+    }
+}
+
+/// The parts of a token needed to format an amount of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Token<'a> {
+    pub symbol: &'a str,
+    pub decimals: u8,
+}
+
+/// Short name for a token: its symbol when known, otherwise its address.
+pub(crate) fn token_name(token: Option<Token<'_>>, address: Address) -> String {
+    token.map_or_else(|| addr(address), |token| token.symbol.to_string())
+}
+
+/// `amount` of a token, such as `1.5 WETH`. Amounts of unknown tokens are shown
+/// raw, followed by the token's address.
+pub(crate) fn format_amount(token: Option<Token<'_>>, address: Address, amount: U256) -> String {
+    match token {
+        Some(token) => format!(
+            "{} {}",
+            format_token_amount(amount, token.decimals),
+            token.symbol
+        ),
+        None => format!("{amount} of {}", addr(address)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    #[test]
+    fn test_builtin_tokens() {
+        let registry = TokenRegistry::default();
+        let usdc = address!("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+        assert_eq!(registry.lookup(Some(1), usdc).unwrap().name, "USD Coin");
+        let token = registry.token(Some(1), usdc);
+        assert_eq!(
+            format_amount(token, usdc, U256::from(2_500_000u64)),
+            "2.5 USDC"
+        );
+
+        // The same address means nothing on another chain, or without one
+        assert_eq!(registry.token(Some(8453), usdc), None);
+        assert_eq!(registry.token(None, usdc), None);
+        assert_eq!(
+            format_amount(None, usdc, U256::from(7u64)),
+            "7 of 0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+        );
+        assert_eq!(
+            registry
+                .currency(Some(137), Address::ZERO)
+                .map(|t| t.symbol),
+            Some("POL")
+        );
+    }
+
+    #[test]
+    fn test_extend_from_json() {
+        let mut registry = TokenRegistry::empty();
+        registry
+            .extend_from_json(
+                r#"[{"chain_id": 11155111, "address": "0x1c7d4b196cb0c7b01d743fbc6116a902379c7238",
+                     "symbol": "USDC", "name": "USDC", "decimals": 6}]"#,
+            )
+            .unwrap();
+        let address = address!("0x1c7d4b196cb0c7b01d743fbc6116a902379c7238");
+        assert_eq!(
+            registry.token(Some(11155111), address),
+            Some(Token {
+                symbol: "USDC",
+                decimals: 6
+            })
+        );
+
+        let err = registry
+            .extend_from_json(r#"[{"chain_id": 1, "address": "0x01", "symbol": "X"}]"#)
+            .unwrap_err();
+        assert!(matches!(err, TokenRegistryError::InvalidJson(_)));
+        assert_eq!(registry.len(), 1);
+    }
+}
@@ -0,0 +1,315 @@
+//! Fields for the parts of EIP-2718 typed transactions that legacy and
+//! EIP-1559 transactions don't have: EIP-2930 access lists, EIP-4844 blobs and
+//! EIP-7702 authorization lists.
+
+use alloy_consensus::{Transaction as _, TypedTransaction};
+use alloy_eips::eip2930::{AccessList, AccessListItem};
+use alloy_eips::eip7702::SignedAuthorization;
+use alloy_primitives::{Address, B256};
+use visualsign::{AnnotatedPayloadField, SignablePayloadField};
+
+use crate::fields::{
+    addr, address_field, annotated, number_field, plain_text_field, preview_layout, text_field,
+    with_annotation,
+};
+use crate::fmt::format_gwei;
+
+/// Shown above everything else when a transaction carries EIP-7702 authorizations.
+pub(crate) const DELEGATION_WARNING: &str = "This transaction sets code on your account (EIP-7702). The delegate contract gains full control of the account and all of its assets.";
+
+/// Fields for the access list, blobs and authorizations of `transaction`,
+/// in display order.
+pub(crate) fn typed_transaction_fields(
+    transaction: &TypedTransaction,
+) -> Vec<SignablePayloadField> {
+    let mut fields = Vec::new();
+    if let Some(max_fee_per_blob_gas) = transaction.max_fee_per_blob_gas() {
+        let text = format!("{} gwei", format_gwei(max_fee_per_blob_gas));
+        fields.push(plain_text_field("Max Fee Per Blob Gas", &text));
+    }
+    fields.extend(
+        transaction
+            .blob_versioned_hashes()
+            .filter(|hashes| !hashes.is_empty())
+            .map(blob_hashes_field),
+    );
+    fields.extend(
+        transaction
+            .access_list()
+            .filter(|list| !list.is_empty())
+            .map(access_list_field),
+    );
+    fields.extend(
+        transaction
+            .authorization_list()
+            .filter(|list| !list.is_empty())
+            .map(authorization_list_field),
+    );
+    fields
+}
+
+/// The warning to put at the top of the payload, if `transaction` delegates
+/// the signing account.
+pub(crate) fn delegation_warning(transaction: &TypedTransaction) -> Option<SignablePayloadField> {
+    transaction
+        .authorization_list()
+        .filter(|list| !list.is_empty())
+        .map(|_| plain_text_field("Warning", DELEGATION_WARNING))
+}
+
+fn count_of(count: usize, singular: &str, plural: &str) -> String {
+    format!("{count} {}", if count == 1 { singular } else { plural })
+}
+
+fn blob_hashes_field(hashes: &[B256]) -> SignablePayloadField {
+    let expanded = hashes
+        .iter()
+        .enumerate()
+        .map(|(i, hash)| text_field(&format!("Blob {}", i + 1), &hash.to_string()))
+        .collect();
+    let subtitle = count_of(hashes.len(), "blob", "blobs");
+    preview_layout(
+        "Blob Versioned Hashes",
+        "Blob Versioned Hashes",
+        &subtitle,
+        None,
+        expanded,
+    )
+}
+
+fn access_list_field(access_list: &AccessList) -> SignablePayloadField {
+    let storage_keys: usize = access_list.iter().map(|item| item.storage_keys.len()).sum();
+    let expanded = access_list.iter().map(access_list_item_field).collect();
+    let subtitle = format!(
+        "{}, {}",
+        count_of(access_list.len(), "address", "addresses"),
+        count_of(storage_keys, "storage key", "storage keys")
+    );
+    preview_layout("Access List", "Access List", &subtitle, None, expanded)
+}
+
+fn access_list_item_field(item: &AccessListItem) -> AnnotatedPayloadField {
+    let address = addr(item.address);
+    let mut fields = vec![address_field("Address", &address, None, None)];
+    for (i, key) in item.storage_keys.iter().enumerate() {
+        fields.push(text_field(
+            &format!("Storage Key {}", i + 1),
+            &key.to_string(),
+        ));
+    }
+    let subtitle = count_of(item.storage_keys.len(), "storage key", "storage keys");
+    annotated(preview_layout(&address, &address, &subtitle, None, fields))
+}
+
+fn authorization_list_field(authorizations: &[SignedAuthorization]) -> SignablePayloadField {
+    let expanded = authorizations
+        .iter()
+        .enumerate()
+        .map(|(i, authorization)| authorization_field(i, authorization))
+        .collect();
+    let subtitle = count_of(authorizations.len(), "authorization", "authorizations");
+    preview_layout(
+        "Authorization List",
+        "EIP-7702 Delegation",
+        &subtitle,
+        None,
+        expanded,
+    )
+}
+
+fn authorization_field(index: usize, authorization: &SignedAuthorization) -> AnnotatedPayloadField {
+    let delegate = *authorization.address();
+    let chain_id = authorization.chain_id();
+    // A zero chain id makes the authorization valid on every chain
+    let chain_text = if chain_id.is_zero() {
+        "0 (any chain)".to_string()
+    } else {
+        chain_id.to_string()
+    };
+    // Delegating to the zero address clears an existing delegation
+    let (title, delegate_field) = if delegate == Address::ZERO {
+        (
+            "Clear delegation".to_string(),
+            address_field("Delegate", &addr(delegate), None, Some("Revoke")),
+        )
+    } else {
+        let address = addr(delegate);
+        (
+            format!("Delegate to {address}"),
+            with_annotation(
+                address_field("Delegate", &address, None, None),
+                "All calls to your account will run this contract's code",
+            ),
+        )
+    };
+
+    let chain_field = text_field("Chain ID", &chain_text);
+    let chain_field = if chain_id.is_zero() {
+        with_annotation(chain_field, "Valid on every chain")
+    } else {
+        chain_field
+    };
+    let expanded = vec![
+        delegate_field,
+        chain_field,
+        number_field("Nonce", &authorization.nonce().to_string()),
+    ];
+    let label = format!("Authorization {}", index + 1);
+    let subtitle = format!("Chain {chain_text}, nonce {}", authorization.nonce());
+    annotated(preview_layout(&label, &title, &subtitle, None, expanded))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction_to_visual_sign;
+    use alloy_consensus::{TxEip2930, TxEip4844, TxEip4844Variant, TxEip7702};
+    use alloy_eips::eip7702::Authorization;
+    use alloy_primitives::{Bytes, TxKind, U256};
+    use visualsign::vsptrait::VisualSignOptions;
+
+    fn expanded_fields(field: &SignablePayloadField) -> &[AnnotatedPayloadField] {
+        match field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => {
+                &preview_layout.expanded.as_ref().unwrap().fields
+            }
+            other => panic!("expected preview layout, got {other:?}"),
+        }
+    }
+
+    fn find<'a>(fields: &'a [SignablePayloadField], label: &str) -> &'a SignablePayloadField {
+        fields.iter().find(|f| f.label() == label).unwrap()
+    }
+
+    fn authorization(chain_id: u64, address: Address, nonce: u64) -> SignedAuthorization {
+        SignedAuthorization::new_unchecked(
+            Authorization {
+                chain_id: U256::from(chain_id),
+                address,
+                nonce,
+            },
+            0,
+            U256::from(1),
+            U256::from(1),
+        )
+    }
+
+    #[test]
+    fn test_eip7702_authorizations_and_warning() {
+        let delegate = Address::repeat_byte(0x77);
+        let tx = TypedTransaction::Eip7702(TxEip7702 {
+            chain_id: 1,
+            nonce: 3,
+            gas_limit: 60_000,
+            max_fee_per_gas: 30_000_000_000,
+            max_priority_fee_per_gas: 1_000_000_000,
+            to: Address::repeat_byte(0x01),
+            value: U256::ZERO,
+            access_list: Default::default(),
+            authorization_list: vec![
+                authorization(1, delegate, 7),
+                authorization(0, Address::ZERO, 8),
+            ],
+            input: Bytes::new(),
+        });
+        let payload = transaction_to_visual_sign(tx, VisualSignOptions::default()).unwrap();
+
+        assert_eq!(payload.fields[0].label(), "Warning");
+        assert_eq!(payload.fields[0].fallback_text(), DELEGATION_WARNING);
+
+        let list = find(&payload.fields, "Authorization List");
+        assert_eq!(list.fallback_text(), "2 authorizations");
+        let authorizations = expanded_fields(list);
+
+        let first = &authorizations[0].signable_payload_field;
+        assert_eq!(first.label(), "Authorization 1");
+        assert_eq!(first.fallback_text(), "Chain 1, nonce 7");
+        let details = expanded_fields(first);
+        assert_eq!(
+            details[0].signable_payload_field,
+            address_field(
+                "Delegate",
+                "0x7777777777777777777777777777777777777777",
+                None,
+                None
+            )
+            .signable_payload_field
+        );
+        assert!(details[0].static_annotation.is_some());
+        assert_eq!(details[1], text_field("Chain ID", "1"));
+        assert_eq!(details[2], number_field("Nonce", "7"));
+
+        // Chain id 0 applies everywhere, and the zero address revokes
+        let second = &authorizations[1].signable_payload_field;
+        assert_eq!(second.fallback_text(), "Chain 0 (any chain), nonce 8");
+        let details = expanded_fields(second);
+        assert_eq!(
+            details[0],
+            address_field(
+                "Delegate",
+                "0x0000000000000000000000000000000000000000",
+                None,
+                Some("Revoke")
+            )
+        );
+        assert_eq!(
+            details[1],
+            with_annotation(
+                text_field("Chain ID", "0 (any chain)"),
+                "Valid on every chain"
+            )
+        );
+    }
+
+    #[test]
+    fn test_eip4844_blobs_and_eip2930_access_list() {
+        let blob_hash = B256::repeat_byte(0x01);
+        let tx = TypedTransaction::Eip4844(TxEip4844Variant::TxEip4844(TxEip4844 {
+            chain_id: 1,
+            nonce: 1,
+            gas_limit: 21_000,
+            max_fee_per_gas: 30_000_000_000,
+            max_priority_fee_per_gas: 2_000_000_000,
+            to: Address::ZERO,
+            value: U256::ZERO,
+            access_list: Default::default(),
+            blob_versioned_hashes: vec![blob_hash],
+            max_fee_per_blob_gas: 10_000_000_000,
+            input: Bytes::new(),
+        }));
+        let fields = typed_transaction_fields(&tx);
+        assert_eq!(fields[0].label(), "Max Fee Per Blob Gas");
+        assert_eq!(fields[0].fallback_text(), "10 gwei");
+        assert_eq!(fields[1].fallback_text(), "1 blob");
+        assert_eq!(
+            expanded_fields(&fields[1])[0],
+            text_field("Blob 1", &blob_hash.to_string())
+        );
+        assert!(delegation_warning(&tx).is_none());
+
+        let storage_key = B256::with_last_byte(2);
+        let tx = TypedTransaction::Eip2930(TxEip2930 {
+            chain_id: 1,
+            nonce: 1,
+            gas_price: 20_000_000_000,
+            gas_limit: 21_000,
+            to: TxKind::Call(Address::ZERO),
+            value: U256::ZERO,
+            access_list: AccessList(vec![AccessListItem {
+                address: Address::repeat_byte(0xaa),
+                storage_keys: vec![B256::ZERO, storage_key],
+            }]),
+            input: Bytes::new(),
+        });
+        let fields = typed_transaction_fields(&tx);
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].label(), "Access List");
+        assert_eq!(fields[0].fallback_text(), "1 address, 2 storage keys");
+        let item = &expanded_fields(&fields[0])[0].signable_payload_field;
+        assert_eq!(item.fallback_text(), "2 storage keys");
+        assert_eq!(
+            expanded_fields(item)[2],
+            text_field("Storage Key 2", &storage_key.to_string())
+        );
+    }
+}
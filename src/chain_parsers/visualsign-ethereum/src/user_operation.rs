@@ -0,0 +1,820 @@
+//! ERC-4337 UserOperations, as signed by smart-account wallets.
+//!
+//! The input is the UserOperation JSON passed to `eth_sendUserOperation`:
+//! EntryPoint v0.6's shape, v0.7's unpacked shape, or v0.7's packed
+//! `PackedUserOperation`. It may be wrapped as
+//! `{"userOperation": ..., "entryPoint": ..., "chainId": ...}`; the chain id is
+//! needed for the hash the account actually signs.
+
+use std::cell::RefCell;
+
+use alloy_primitives::{Address, B256, Bytes, U64, U256, address, keccak256};
+use alloy_sol_types::SolValue;
+use serde::Deserialize;
+use visualsign::{
+    SignablePayload,
+    vsptrait::{
+        Transaction, TransactionParseError, VisualSignConverter, VisualSignError, VisualSignOptions,
+    },
+};
+
+use crate::contracts::smart_account::SmartAccountVisualizer;
+use crate::dispatch::CallDispatcher;
+use crate::fields::{addr, address_field, amount_field, plain_text_field, preview_layout};
+use crate::fmt::format_token_amount;
+use crate::{EthereumVisualSignConverter, chains, format_gas_price};
+
+/// The canonical EntryPoint deployments, at the same address on every chain.
+pub const ENTRY_POINT_V06: Address = address!("0x5ff137d4b0fdcd49dca30c7cf57e578a026d2789");
+pub const ENTRY_POINT_V07: Address = address!("0x0000000071727de22e5e9d8baf0edac6f37da032");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryPointVersion {
+    V06,
+    V07,
+}
+
+impl EntryPointVersion {
+    pub fn from_address(entry_point: Address) -> Option<Self> {
+        match entry_point {
+            ENTRY_POINT_V06 => Some(Self::V06),
+            ENTRY_POINT_V07 => Some(Self::V07),
+            _ => None,
+        }
+    }
+
+    pub fn entry_point(self) -> Address {
+        match self {
+            Self::V06 => ENTRY_POINT_V06,
+            Self::V07 => ENTRY_POINT_V07,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::V06 => "EntryPoint v0.6",
+            Self::V07 => "EntryPoint v0.7",
+        }
+    }
+}
+
+/// A UserOperation in any of its JSON shapes; unused fields are absent.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcUserOperation {
+    sender: Address,
+    nonce: U256,
+    // v0.6 and v0.7 packed
+    init_code: Option<Bytes>,
+    paymaster_and_data: Option<Bytes>,
+    // v0.7 unpacked
+    factory: Option<Address>,
+    factory_data: Option<Bytes>,
+    paymaster: Option<Address>,
+    paymaster_verification_gas_limit: Option<U256>,
+    paymaster_post_op_gas_limit: Option<U256>,
+    paymaster_data: Option<Bytes>,
+    call_data: Bytes,
+    pre_verification_gas: U256,
+    // v0.6 and v0.7 unpacked
+    call_gas_limit: Option<U256>,
+    verification_gas_limit: Option<U256>,
+    max_fee_per_gas: Option<U256>,
+    max_priority_fee_per_gas: Option<U256>,
+    // v0.7 packed
+    account_gas_limits: Option<B256>,
+    gas_fees: Option<B256>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UserOperationRequest {
+    user_operation: RpcUserOperation,
+    entry_point: Option<Address>,
+    chain_id: Option<U64>,
+}
+
+/// A UserOperation, with the packed fields of v0.6 and v0.7 split apart.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserOperation {
+    pub version: EntryPointVersion,
+    pub sender: Address,
+    pub nonce: U256,
+    pub factory: Option<Address>,
+    pub factory_data: Bytes,
+    pub call_data: Bytes,
+    pub call_gas_limit: u128,
+    pub verification_gas_limit: u128,
+    pub pre_verification_gas: u128,
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+    pub paymaster: Option<Address>,
+    /// Always zero before v0.7, where the paymaster shares the account's limit
+    pub paymaster_verification_gas_limit: u128,
+    pub paymaster_post_op_gas_limit: u128,
+    pub paymaster_data: Bytes,
+}
+
+fn invalid(message: String) -> TransactionParseError {
+    TransactionParseError::InvalidFormat(format!("Invalid user operation: {message}"))
+}
+
+fn quantity(value: Option<U256>, name: &str) -> Result<u128, TransactionParseError> {
+    let value = value.ok_or_else(|| invalid(format!("missing {name}")))?;
+    u128::try_from(value).map_err(|_| invalid(format!("{name} is too large")))
+}
+
+/// Splits an address-prefixed field such as `initCode` into the address and
+/// the rest. Empty means no address.
+fn split_address(
+    packed: &[u8],
+    name: &str,
+) -> Result<(Option<Address>, Bytes), TransactionParseError> {
+    match packed.len() {
+        0 => Ok((None, Bytes::new())),
+        1..20 => Err(invalid(format!("{name} is shorter than an address"))),
+        _ => Ok((
+            Some(Address::from_slice(&packed[..20])),
+            Bytes::copy_from_slice(&packed[20..]),
+        )),
+    }
+}
+
+/// The two `uint128`s packed into one word, high half first.
+fn split_u128s(packed: B256) -> (u128, u128) {
+    let high = u128::from_be_bytes(packed[..16].try_into().unwrap());
+    let low = u128::from_be_bytes(packed[16..].try_into().unwrap());
+    (high, low)
+}
+
+fn pack_u128s(high: u128, low: u128) -> B256 {
+    let mut packed = B256::ZERO;
+    packed[..16].copy_from_slice(&high.to_be_bytes());
+    packed[16..].copy_from_slice(&low.to_be_bytes());
+    packed
+}
+
+impl UserOperation {
+    fn from_rpc(
+        op: RpcUserOperation,
+        entry_point: Option<Address>,
+    ) -> Result<Self, TransactionParseError> {
+        let packed = op.account_gas_limits.is_some() || op.gas_fees.is_some();
+        let version = match entry_point.and_then(EntryPointVersion::from_address) {
+            Some(EntryPointVersion::V06) if packed => {
+                return Err(invalid(
+                    "packed gas fields are not used by EntryPoint v0.6".to_string(),
+                ));
+            }
+            Some(version) => version,
+            // v0.6 always carries initCode and paymasterAndData, if only as "0x"
+            None if packed || (op.init_code.is_none() && op.paymaster_and_data.is_none()) => {
+                EntryPointVersion::V07
+            }
+            None => EntryPointVersion::V06,
+        };
+
+        let (factory, factory_data) = match op.init_code {
+            Some(init_code) => split_address(&init_code, "initCode")?,
+            None => (op.factory, op.factory_data.unwrap_or_default()),
+        };
+        let (verification_gas_limit, call_gas_limit) = match op.account_gas_limits {
+            Some(limits) => split_u128s(limits),
+            None => (
+                quantity(op.verification_gas_limit, "verificationGasLimit")?,
+                quantity(op.call_gas_limit, "callGasLimit")?,
+            ),
+        };
+        let (max_priority_fee_per_gas, max_fee_per_gas) = match op.gas_fees {
+            Some(fees) => split_u128s(fees),
+            None => (
+                quantity(op.max_priority_fee_per_gas, "maxPriorityFeePerGas")?,
+                quantity(op.max_fee_per_gas, "maxFeePerGas")?,
+            ),
+        };
+        let (
+            paymaster,
+            paymaster_verification_gas_limit,
+            paymaster_post_op_gas_limit,
+            paymaster_data,
+        ) = match (version, op.paymaster_and_data) {
+            (EntryPointVersion::V06, Some(packed)) => {
+                let (paymaster, data) = split_address(&packed, "paymasterAndData")?;
+                (paymaster, 0, 0, data)
+            }
+            // paymaster, verification gas limit, post-op gas limit, data
+            (EntryPointVersion::V07, Some(packed)) if !packed.is_empty() => {
+                if packed.len() < 52 {
+                    return Err(invalid(
+                        "paymasterAndData is shorter than its gas limits".to_string(),
+                    ));
+                }
+                let (verification, post_op) = split_u128s(B256::from_slice(&packed[20..52]));
+                (
+                    Some(Address::from_slice(&packed[..20])),
+                    verification,
+                    post_op,
+                    Bytes::copy_from_slice(&packed[52..]),
+                )
+            }
+            (_, _) if op.paymaster.is_some() => (
+                op.paymaster,
+                quantity(
+                    op.paymaster_verification_gas_limit,
+                    "paymasterVerificationGasLimit",
+                )?,
+                quantity(op.paymaster_post_op_gas_limit, "paymasterPostOpGasLimit")?,
+                op.paymaster_data.unwrap_or_default(),
+            ),
+            (_, _) => (None, 0, 0, Bytes::new()),
+        };
+
+        Ok(Self {
+            version,
+            sender: op.sender,
+            nonce: op.nonce,
+            factory,
+            factory_data,
+            call_data: op.call_data,
+            call_gas_limit,
+            verification_gas_limit,
+            pre_verification_gas: quantity(Some(op.pre_verification_gas), "preVerificationGas")?,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            paymaster,
+            paymaster_verification_gas_limit,
+            paymaster_post_op_gas_limit,
+            paymaster_data,
+        })
+    }
+
+    /// `initCode`: the factory followed by its calldata.
+    pub fn init_code(&self) -> Vec<u8> {
+        self.factory
+            .map(|factory| [factory.as_slice(), &self.factory_data].concat())
+            .unwrap_or_default()
+    }
+
+    /// `paymasterAndData`: the paymaster, from v0.7 its gas limits, then its data.
+    pub fn paymaster_and_data(&self) -> Vec<u8> {
+        let Some(paymaster) = self.paymaster else {
+            return Vec::new();
+        };
+        let limits = match self.version {
+            EntryPointVersion::V06 => Vec::new(),
+            EntryPointVersion::V07 => pack_u128s(
+                self.paymaster_verification_gas_limit,
+                self.paymaster_post_op_gas_limit,
+            )
+            .to_vec(),
+        };
+        [paymaster.as_slice(), &limits, &self.paymaster_data].concat()
+    }
+
+    /// The hash the account signs, as computed by `EntryPoint.getUserOpHash`.
+    pub fn hash(&self, entry_point: Address, chain_id: u64) -> B256 {
+        let init_code = keccak256(self.init_code());
+        let call_data = keccak256(&self.call_data);
+        let paymaster_and_data = keccak256(self.paymaster_and_data());
+        let packed = match self.version {
+            EntryPointVersion::V06 => (
+                self.sender,
+                self.nonce,
+                init_code,
+                call_data,
+                U256::from(self.call_gas_limit),
+                U256::from(self.verification_gas_limit),
+                U256::from(self.pre_verification_gas),
+                U256::from(self.max_fee_per_gas),
+                U256::from(self.max_priority_fee_per_gas),
+                paymaster_and_data,
+            )
+                .abi_encode(),
+            EntryPointVersion::V07 => (
+                self.sender,
+                self.nonce,
+                init_code,
+                call_data,
+                pack_u128s(self.verification_gas_limit, self.call_gas_limit),
+                U256::from(self.pre_verification_gas),
+                pack_u128s(self.max_priority_fee_per_gas, self.max_fee_per_gas),
+                paymaster_and_data,
+            )
+                .abi_encode(),
+        };
+        keccak256((keccak256(packed), entry_point, U256::from(chain_id)).abi_encode())
+    }
+
+    /// The most gas this operation can be charged for: the prefund the
+    /// EntryPoint takes from the account, or from the paymaster.
+    pub fn max_gas_cost(&self) -> U256 {
+        let verification = match (self.version, self.paymaster) {
+            // v0.6 reuses the verification limit for the paymaster's
+            // validation and its post-op
+            (EntryPointVersion::V06, Some(_)) => {
+                U256::from(self.verification_gas_limit) * U256::from(3)
+            }
+            _ => U256::from(self.verification_gas_limit),
+        };
+        let gas = verification
+            + U256::from(self.call_gas_limit)
+            + U256::from(self.pre_verification_gas)
+            + U256::from(self.paymaster_verification_gas_limit)
+            + U256::from(self.paymaster_post_op_gas_limit);
+        gas * U256::from(self.max_fee_per_gas)
+    }
+
+    /// The 192-bit nonce key and the 64-bit sequence number within it.
+    pub fn nonce_key_and_sequence(&self) -> (U256, u64) {
+        (self.nonce >> 64, self.nonce.as_limbs()[0])
+    }
+}
+
+/// A UserOperation and where it will be submitted, implementing the
+/// Transaction trait
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserOperationWrapper {
+    user_operation: UserOperation,
+    entry_point: Address,
+    chain_id: Option<u64>,
+}
+
+impl Transaction for UserOperationWrapper {
+    fn from_string(data: &str) -> Result<Self, TransactionParseError> {
+        let value: serde_json::Value = serde_json::from_str(data)
+            .map_err(|e| TransactionParseError::InvalidFormat(format!("Invalid JSON: {e}")))?;
+        let request = if value.get("userOperation").is_some() {
+            serde_json::from_value(value)
+        } else {
+            serde_json::from_value(value).map(|user_operation| UserOperationRequest {
+                user_operation,
+                entry_point: None,
+                chain_id: None,
+            })
+        }
+        .map_err(|e| invalid(e.to_string()))?;
+
+        let user_operation = UserOperation::from_rpc(request.user_operation, request.entry_point)?;
+        let entry_point = request
+            .entry_point
+            .unwrap_or_else(|| user_operation.version.entry_point());
+        Ok(Self {
+            user_operation,
+            entry_point,
+            chain_id: request.chain_id.map(|chain_id| chain_id.to()),
+        })
+    }
+
+    fn transaction_type(&self) -> String {
+        "EthereumUserOperation".to_string()
+    }
+}
+
+impl UserOperationWrapper {
+    /// A UserOperation for the canonical EntryPoint of its version.
+    pub fn new(user_operation: UserOperation, chain_id: Option<u64>) -> Self {
+        Self {
+            entry_point: user_operation.version.entry_point(),
+            user_operation,
+            chain_id,
+        }
+    }
+
+    pub fn inner(&self) -> &UserOperation {
+        &self.user_operation
+    }
+
+    pub fn entry_point(&self) -> Address {
+        self.entry_point
+    }
+
+    pub fn chain_id(&self) -> Option<u64> {
+        self.chain_id
+    }
+
+    /// The hash the account signs; it depends on the chain.
+    pub fn user_op_hash(&self) -> Option<B256> {
+        self.chain_id
+            .map(|chain_id| self.user_operation.hash(self.entry_point, chain_id))
+    }
+}
+
+/// Returns true if `data` is UserOperation JSON, bare or wrapped, rather than
+/// typed data or a transaction.
+pub fn is_user_operation(data: &str) -> bool {
+    let Ok(serde_json::Value::Object(object)) = serde_json::from_str(data) else {
+        return false;
+    };
+    object.contains_key("userOperation")
+        || (object.contains_key("sender") && object.contains_key("callData"))
+}
+
+impl VisualSignConverter<UserOperationWrapper> for EthereumVisualSignConverter {
+    fn to_visual_sign_payload(
+        &self,
+        wrapper: UserOperationWrapper,
+        options: VisualSignOptions,
+    ) -> Result<SignablePayload, VisualSignError> {
+        Ok(convert_to_visual_sign_payload(&wrapper, self, options))
+    }
+}
+
+fn convert_to_visual_sign_payload(
+    wrapper: &UserOperationWrapper,
+    converter: &EthereumVisualSignConverter,
+    options: VisualSignOptions,
+) -> SignablePayload {
+    let op = wrapper.inner();
+    let chain_id = wrapper.chain_id;
+    let currency = chains::native_currency(chain_id);
+    let (symbol, decimals) = currency;
+    let name_of = |address| {
+        converter
+            .contracts
+            .lookup(chain_id, address)
+            .map(|info| info.name.as_str())
+    };
+
+    let mut fields = Vec::new();
+    if let Some(chain_id) = chain_id {
+        let network = chains::get_chain_name(Some(chain_id));
+        fields.push(plain_text_field("Network", &network));
+    }
+    let entry_point = match EntryPointVersion::from_address(wrapper.entry_point) {
+        Some(version) => address_field(
+            "Entry Point",
+            &addr(wrapper.entry_point),
+            Some(version.name()),
+            None,
+        ),
+        None => address_field(
+            "Entry Point",
+            &addr(wrapper.entry_point),
+            None,
+            Some("Unverified contract"),
+        ),
+    };
+    fields.push(entry_point.signable_payload_field);
+    fields.push(
+        address_field("Sender", &addr(op.sender), name_of(op.sender), None).signable_payload_field,
+    );
+    let (nonce_key, sequence) = op.nonce_key_and_sequence();
+    fields.push(plain_text_field("Nonce", &sequence.to_string()));
+    if !nonce_key.is_zero() {
+        fields.push(plain_text_field("Nonce Key", &nonce_key.to_string()));
+    }
+
+    // The account makes the calls in callData, so their recipients are
+    // checked against it
+    let warnings = RefCell::new(Vec::new());
+    let dispatcher = CallDispatcher {
+        contracts: &converter.contracts,
+        tokens: &converter.tokens,
+        chain_id,
+        options: &options,
+        depth: 0,
+        account: Some(op.sender),
+        warnings: &warnings,
+    };
+    let decode_call = |to, data: &[u8]| dispatcher.nested_call_fields(to, data);
+
+    // The account doesn't exist yet; its factory deploys it first
+    if let Some(factory) = op.factory {
+        let mut deployment = vec![address_field(
+            "Factory",
+            &addr(factory),
+            name_of(factory),
+            None,
+        )];
+        if !op.factory_data.is_empty() {
+            deployment.extend(decode_call(factory, &op.factory_data));
+        }
+        fields.push(preview_layout(
+            "Account Deployment",
+            "Account Deployment",
+            &format!("Deploy {:?} with factory {factory:?}", op.sender),
+            None,
+            deployment,
+        ));
+    }
+
+    if let Some(paymaster) = op.paymaster {
+        fields.push(
+            address_field(
+                "Paymaster",
+                &addr(paymaster),
+                name_of(paymaster),
+                Some("Sponsored"),
+            )
+            .signable_payload_field,
+        );
+        if !op.paymaster_data.is_empty() {
+            fields.push(plain_text_field(
+                "Paymaster Data",
+                &format!("0x{}", hex::encode(&op.paymaster_data)),
+            ));
+        }
+    }
+
+    let mut gas_limits = vec![
+        ("Call Gas Limit", op.call_gas_limit),
+        ("Verification Gas Limit", op.verification_gas_limit),
+        ("Pre-Verification Gas", op.pre_verification_gas),
+    ];
+    if op.version == EntryPointVersion::V07 && op.paymaster.is_some() {
+        gas_limits.extend([
+            (
+                "Paymaster Verification Gas Limit",
+                op.paymaster_verification_gas_limit,
+            ),
+            (
+                "Paymaster Post-Op Gas Limit",
+                op.paymaster_post_op_gas_limit,
+            ),
+        ]);
+    }
+    for (label, limit) in gas_limits {
+        fields.push(plain_text_field(label, &limit.to_string()));
+    }
+    fields.push(plain_text_field(
+        "Max Fee Per Gas",
+        &format_gas_price(op.max_fee_per_gas, currency),
+    ));
+    fields.push(plain_text_field(
+        "Max Priority Fee Per Gas",
+        &format_gas_price(op.max_priority_fee_per_gas, currency),
+    ));
+    let max_gas_cost = format_token_amount(op.max_gas_cost(), decimals);
+    let payer = if op.paymaster.is_some() {
+        "Max Gas Cost (Paymaster)"
+    } else {
+        "Max Gas Cost"
+    };
+    fields.push(amount_field(payer, &max_gas_cost, Some(symbol)).signable_payload_field);
+
+    // The EntryPoint calls the account with callData; the account's execute
+    // functions say what it calls in turn, on the account's behalf
+    if !op.call_data.is_empty() {
+        match (SmartAccountVisualizer {}).visualize_tx_commands(
+            &op.call_data,
+            chain_id,
+            &decode_call,
+        ) {
+            Some(field) => fields.push(field),
+            None => fields.extend(dispatcher.call_fields(Some(op.sender), &op.call_data)),
+        }
+    }
+
+    // Like a transaction's, the inner calls' warnings lead the payload
+    fields.splice(0..0, warnings.into_inner());
+
+    if let Some(hash) = wrapper.user_op_hash() {
+        fields.push(plain_text_field(
+            "User Operation Hash",
+            &format!("{hash:?}"),
+        ));
+    }
+
+    SignablePayload::new(
+        0,
+        options
+            .transaction_name
+            .unwrap_or_else(|| "User Operation".to_string()),
+        Some(op.version.name().to_string()),
+        fields,
+        "EthereumUserOperation".to_string(),
+    )
+}
+
+// Public API functions for ease of use
+pub fn user_operation_to_visual_sign(
+    wrapper: UserOperationWrapper,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    EthereumVisualSignConverter::default().to_visual_sign_payload(wrapper, options)
+}
+
+pub fn user_operation_string_to_visual_sign(
+    user_operation: &str,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let wrapper =
+        UserOperationWrapper::from_string(user_operation).map_err(VisualSignError::ParseError)?;
+    EthereumVisualSignConverter::default().to_validated_visual_sign_payload(wrapper, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::erc20::IERC20;
+    use crate::contracts::smart_account::ISimpleAccount;
+    use alloy_sol_types::SolCall;
+    use visualsign::SignablePayloadField;
+
+    const SENDER: Address = Address::repeat_byte(0x11);
+    const PAYMASTER: Address = Address::repeat_byte(0x22);
+    const FACTORY: Address = Address::repeat_byte(0x33);
+    const USDC: Address = address!("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+
+    fn transfer_call_data() -> Bytes {
+        let transfer = IERC20::transferCall {
+            to: Address::repeat_byte(0x44),
+            amount: U256::from(2_500_000u64),
+        }
+        .abi_encode();
+        ISimpleAccount::executeCall {
+            dest: USDC,
+            value: U256::ZERO,
+            func: Bytes::from(transfer),
+        }
+        .abi_encode()
+        .into()
+    }
+
+    fn v07_unpacked() -> serde_json::Value {
+        serde_json::json!({
+            "sender": SENDER,
+            "nonce": "0x10000000000000003",
+            "factory": FACTORY,
+            "factoryData": "0xdeadbeef",
+            "callData": transfer_call_data(),
+            "callGasLimit": "0x186a0",
+            "verificationGasLimit": "0x30d40",
+            "preVerificationGas": "0xc350",
+            "maxFeePerGas": "0x6fc23ac00",
+            "maxPriorityFeePerGas": "0x3b9aca00",
+            "paymaster": PAYMASTER,
+            "paymasterVerificationGasLimit": "0x7530",
+            "paymasterPostOpGasLimit": "0x2710",
+            "paymasterData": "0x01",
+            "signature": "0x",
+        })
+    }
+
+    #[test]
+    fn test_packed_and_unpacked_forms_agree() {
+        let unpacked = UserOperationWrapper::from_string(&v07_unpacked().to_string()).unwrap();
+        let op = unpacked.inner();
+        assert_eq!(op.version, EntryPointVersion::V07);
+        assert_eq!(op.nonce_key_and_sequence(), (U256::from(1u64), 3));
+        assert_eq!(
+            op.init_code(),
+            [FACTORY.as_slice(), &[0xde, 0xad, 0xbe, 0xef]].concat()
+        );
+
+        let packed = serde_json::json!({
+            "sender": SENDER,
+            "nonce": "0x10000000000000003",
+            "initCode": Bytes::from(op.init_code()),
+            "callData": transfer_call_data(),
+            "accountGasLimits": pack_u128s(200_000, 100_000),
+            "preVerificationGas": "0xc350",
+            "gasFees": pack_u128s(1_000_000_000, 30_000_000_000),
+            "paymasterAndData": Bytes::from(op.paymaster_and_data()),
+            "signature": "0x",
+        });
+        let request = serde_json::json!({
+            "userOperation": packed,
+            "entryPoint": ENTRY_POINT_V07,
+            "chainId": 1,
+        });
+        let packed = UserOperationWrapper::from_string(&request.to_string()).unwrap();
+        assert_eq!(packed.inner(), op);
+
+        let hash = packed.user_op_hash().unwrap();
+        assert_ne!(
+            hash,
+            op.hash(ENTRY_POINT_V07, 10),
+            "the hash commits to the chain"
+        );
+        assert_eq!(unpacked.user_op_hash(), None);
+        // 200k + 100k + 50k + 30k + 10k gas at 30 gwei
+        assert_eq!(
+            op.max_gas_cost(),
+            U256::from(390_000u64 * 30_000_000_000u64)
+        );
+    }
+
+    #[test]
+    fn test_v06_operation_payload() {
+        let request = serde_json::json!({
+            "userOperation": {
+                "sender": SENDER,
+                "nonce": "0x0",
+                "initCode": "0x",
+                "callData": transfer_call_data(),
+                "callGasLimit": 100000,
+                "verificationGasLimit": 150000,
+                "preVerificationGas": 50000,
+                "maxFeePerGas": "0x6fc23ac00",
+                "maxPriorityFeePerGas": "0x3b9aca00",
+                "paymasterAndData": PAYMASTER,
+                "signature": "0x",
+            },
+            "entryPoint": ENTRY_POINT_V06,
+            "chainId": "0x1",
+        })
+        .to_string();
+        assert!(is_user_operation(&request));
+
+        // Routed here from the transaction converter, like typed data
+        let payload = crate::transaction_string_to_visual_sign(
+            &request,
+            VisualSignOptions {
+                decode_transfers: true,
+                ..VisualSignOptions::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(payload.title, "User Operation");
+        assert_eq!(payload.subtitle.as_deref(), Some("EntryPoint v0.6"));
+        let field = |label: &str| {
+            payload
+                .fields
+                .iter()
+                .find(|field| field.label() == label)
+                .unwrap_or_else(|| panic!("missing {label}"))
+                .clone()
+        };
+        let SignablePayloadField::AddressV2 { address_v2, .. } = field("Paymaster") else {
+            panic!("expected an address field");
+        };
+        // The paymaster pays for gas, which is all the badge claims
+        assert_eq!(address_v2.badge_text.as_deref(), Some("Sponsored"));
+        // 100k + 3 * 150k + 50k gas at 30 gwei
+        assert_eq!(
+            field("Max Gas Cost (Paymaster)").fallback_text(),
+            "0.018 ETH"
+        );
+        assert_eq!(
+            field("SimpleAccount Execute").fallback_text(),
+            "1 call: ERC20 Transfer"
+        );
+        assert!(
+            field("User Operation Hash")
+                .fallback_text()
+                .starts_with("0x")
+        );
+        assert!(
+            payload
+                .fields
+                .iter()
+                .all(|f| f.label() != "Account Deployment")
+        );
+    }
+
+    #[test]
+    fn test_inner_call_warnings_lead_payload() {
+        let approve = IERC20::approveCall {
+            spender: Address::repeat_byte(0x42),
+            amount: U256::MAX,
+        }
+        .abi_encode();
+        let call_data: Bytes = ISimpleAccount::executeCall {
+            dest: USDC,
+            value: U256::ZERO,
+            func: Bytes::from(approve),
+        }
+        .abi_encode()
+        .into();
+        let mut op = v07_unpacked();
+        op["callData"] = serde_json::json!(call_data);
+        let request = serde_json::json!({
+            "userOperation": op,
+            "entryPoint": ENTRY_POINT_V07,
+            "chainId": 1,
+        });
+
+        let payload = crate::transaction_string_to_visual_sign(
+            &request.to_string(),
+            VisualSignOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(payload.fields[0].label(), "Approval Warning");
+        assert!(
+            payload.fields[0]
+                .fallback_text()
+                .starts_with("Unlimited approval: 0x4242424242424242424242424242424242424242")
+        );
+        assert_eq!(payload.fields[1].label(), "Network");
+    }
+
+    #[test]
+    fn test_rejects_malformed_operations() {
+        let mut op = v07_unpacked();
+        op["maxFeePerGas"] = serde_json::json!(U256::MAX);
+        assert!(matches!(
+            UserOperationWrapper::from_string(&op.to_string()),
+            Err(TransactionParseError::InvalidFormat(message)) if message.contains("maxFeePerGas")
+        ));
+
+        let mut op = v07_unpacked();
+        op.as_object_mut().unwrap().remove("callGasLimit");
+        assert!(UserOperationWrapper::from_string(&op.to_string()).is_err());
+
+        assert!(!is_user_operation(
+            r#"{"types": {}, "primaryType": "Mail"}"#
+        ));
+        assert!(!is_user_operation("0x02c0"));
+    }
+}
@@ -53,16 +53,22 @@ fn collect_visualizers() -> Vec<String> {
     })
     .collect();
 
-    // Partition: specific visualizers first, unknown_program visualizer last (it's a catch-all)
-    let (unknown, specific): (Vec<_>, Vec<_>) = all_visualizers
-        .into_iter()
-        .partition(|(name, _)| name == "unknown_program");
-
-    specific
-        .into_iter()
-        .map(|(_, vis)| vis)
-        .chain(unknown.into_iter().map(|(_, vis)| vis))
-        .collect()
+    // Specific visualizers first, sorted so the order doesn't depend on the filesystem.
+    // The caller-supplied IDL comes after them so it can never shadow a built-in program,
+    // and unknown_program goes last (it's a catch-all).
+    let fallback_rank = |name: &str| match name {
+        "anchor_idl" => 1,
+        "unknown_program" => 2,
+        _ => 0,
+    };
+    let mut all_visualizers = all_visualizers;
+    all_visualizers.sort_by(|(a, _), (b, _)| {
+        fallback_rank(a)
+            .cmp(&fallback_rank(b))
+            .then_with(|| a.cmp(b))
+    });
+
+    all_visualizers.into_iter().map(|(_, vis)| vis).collect()
 }
 
 fn to_pascal_case(s: &str) -> String {
@@ -106,6 +112,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_collect_visualizers_anchor_idl_after_builtins() {
+        let visualizers = collect_visualizers();
+        let position = |name: &str| visualizers.iter().position(|v| v.contains(name));
+
+        let anchor_idl = position("AnchorIdlVisualizer").expect("anchor_idl preset exists");
+        assert_eq!(anchor_idl, visualizers.len() - 2);
+        for builtin in ["SystemVisualizer", "JupiterSwapVisualizer"] {
+            assert!(position(builtin).unwrap() < anchor_idl);
+        }
+    }
+
     #[test]
     fn test_collect_visualizers_not_empty() {
         let visualizers = collect_visualizers();
@@ -0,0 +1,737 @@
+//! Anchor IDL support for instructions of programs without a dedicated visualizer.
+//!
+//! The IDL is supplied by the caller through `SolanaMetadata.idl`. Both the
+//! current (Anchor >= 0.30, spec 0.1.0) and the legacy IDL layouts are accepted:
+//! legacy IDLs don't carry explicit discriminators, so those are derived the
+//! same way Anchor does, from `sha256("global:<snake_case_name>")`.
+
+use serde::Deserialize;
+use solana_sdk::pubkey::Pubkey;
+use visualsign::errors::VisualSignError;
+use visualsign::vsptrait::{ChainMetadata, SolanaIdlType, chain_metadata};
+
+/// Anchor instruction discriminators are the first 8 bytes of instruction data.
+pub const ANCHOR_DISCRIMINATOR_LEN: usize = 8;
+
+// Guards against malicious IDLs with self-referencing types
+const MAX_TYPE_DEPTH: usize = 32;
+
+// Guards against malicious IDLs with long arrays of types that read no data
+const MAX_DECODED_VALUES: usize = 10_000;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AnchorIdl {
+    #[serde(default)]
+    pub address: Option<String>,
+    #[serde(default)]
+    pub metadata: Option<IdlMetadata>,
+    /// Program name in legacy IDLs; newer IDLs keep it in `metadata`.
+    #[serde(default)]
+    pub name: Option<String>,
+    pub instructions: Vec<IdlInstruction>,
+    #[serde(default)]
+    pub types: Vec<IdlTypeDef>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlMetadata {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub address: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlInstruction {
+    pub name: String,
+    #[serde(default)]
+    pub discriminator: Option<Vec<u8>>,
+    #[serde(default)]
+    pub accounts: Vec<IdlAccountItem>,
+    #[serde(default)]
+    pub args: Vec<IdlField>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum IdlAccountItem {
+    Composite {
+        name: String,
+        accounts: Vec<IdlAccountItem>,
+    },
+    Single {
+        name: String,
+    },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: IdlType,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum IdlType {
+    Primitive(String),
+    Vec { vec: Box<IdlType> },
+    Option { option: Box<IdlType> },
+    COption { coption: Box<IdlType> },
+    Array { array: (Box<IdlType>, IdlArrayLen) },
+    Defined { defined: IdlDefined },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum IdlArrayLen {
+    Value(usize),
+    /// Generic lengths (`{"generic": "N"}`) can't be resolved without instantiation.
+    Generic(serde_json::Value),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum IdlDefined {
+    Name(String),
+    Named { name: String },
+}
+
+impl IdlDefined {
+    fn name(&self) -> &str {
+        match self {
+            Self::Name(name) | Self::Named { name } => name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlTypeDef {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: IdlTypeDefTy,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum IdlTypeDefTy {
+    Struct {
+        #[serde(default)]
+        fields: Option<IdlDefinedFields>,
+    },
+    Enum {
+        variants: Vec<IdlEnumVariant>,
+    },
+    Type {
+        alias: IdlType,
+    },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum IdlDefinedFields {
+    Named(Vec<IdlField>),
+    Tuple(Vec<IdlType>),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlEnumVariant {
+    pub name: String,
+    #[serde(default)]
+    pub fields: Option<IdlDefinedFields>,
+}
+
+/// A Borsh value decoded according to an IDL type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdlValue {
+    Bool(bool),
+    /// Integers are kept as their decimal representation.
+    Number(String),
+    /// Floats are rendered as text since they may be NaN or infinite.
+    Float(String),
+    String(String),
+    Bytes(Vec<u8>),
+    Pubkey(String),
+    None,
+    List(Vec<IdlValue>),
+    Struct(Vec<(String, IdlValue)>),
+    Enum {
+        variant: String,
+        fields: Vec<(String, IdlValue)>,
+    },
+}
+
+/// An instruction decoded against the IDL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedIdlInstruction {
+    pub name: String,
+    pub args: Vec<(String, IdlValue)>,
+    /// IDL account names in the order the instruction expects them.
+    pub account_names: Vec<String>,
+    /// Instruction data left over after the args.
+    pub trailing: Vec<u8>,
+}
+
+impl AnchorIdl {
+    pub fn from_json(json: &str) -> Result<Self, VisualSignError> {
+        serde_json::from_str(json)
+            .map_err(|e| VisualSignError::DecodeError(format!("Invalid Anchor IDL: {e}")))
+    }
+
+    /// Returns the Anchor IDL carried in `metadata`, if any.
+    ///
+    /// IDLs explicitly typed as something other than Anchor are ignored, as are
+    /// IDLs that fail to parse or don't name the program they describe, so that
+    /// decoding falls back to the other visualizers.
+    pub fn from_chain_metadata(metadata: &ChainMetadata) -> Option<Self> {
+        let chain_metadata::Metadata::Solana(solana) = metadata.metadata.as_ref()? else {
+            return None;
+        };
+        let idl = solana.idl.as_ref()?;
+        if idl
+            .idl_type
+            .is_some_and(|idl_type| idl_type != SolanaIdlType::Anchor as i32)
+        {
+            return None;
+        }
+        Self::from_json(&idl.value)
+            .ok()
+            .filter(|idl| idl.program_address().is_some())
+    }
+
+    pub fn program_name(&self) -> Option<&str> {
+        self.metadata
+            .as_ref()
+            .and_then(|metadata| metadata.name.as_deref())
+            .or(self.name.as_deref())
+    }
+
+    pub fn program_address(&self) -> Option<&str> {
+        self.address.as_deref().or(self
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.address.as_deref()))
+    }
+
+    /// Whether this IDL describes `program_id`. IDLs without an address describe no program.
+    pub fn describes_program(&self, program_id: &str) -> bool {
+        self.program_address() == Some(program_id)
+    }
+
+    /// Finds the instruction whose discriminator prefixes `data`.
+    pub fn find_instruction(&self, data: &[u8]) -> Option<&IdlInstruction> {
+        let discriminator = data.get(..ANCHOR_DISCRIMINATOR_LEN)?;
+        self.instructions
+            .iter()
+            .find(|instruction| instruction.discriminator() == discriminator)
+    }
+
+    pub fn decode_instruction(
+        &self,
+        data: &[u8],
+    ) -> Result<DecodedIdlInstruction, VisualSignError> {
+        let instruction = self.find_instruction(data).ok_or_else(|| {
+            VisualSignError::DecodeError("No IDL instruction matches the discriminator".into())
+        })?;
+
+        let mut reader = BorshReader::new(&data[ANCHOR_DISCRIMINATOR_LEN..]);
+        let args = instruction
+            .args
+            .iter()
+            .map(|arg| Ok((arg.name.clone(), self.decode_type(&arg.ty, &mut reader, 0)?)))
+            .collect::<Result<Vec<_>, VisualSignError>>()?;
+
+        let mut account_names = Vec::new();
+        flatten_account_names(&instruction.accounts, None, &mut account_names);
+
+        Ok(DecodedIdlInstruction {
+            name: instruction.name.clone(),
+            args,
+            account_names,
+            trailing: reader.data.to_vec(),
+        })
+    }
+
+    fn find_type(&self, name: &str) -> Result<&IdlTypeDef, VisualSignError> {
+        self.types
+            .iter()
+            .find(|def| def.name == name)
+            .ok_or_else(|| VisualSignError::DecodeError(format!("Undefined IDL type: {name}")))
+    }
+
+    fn decode_type(
+        &self,
+        ty: &IdlType,
+        reader: &mut BorshReader,
+        depth: usize,
+    ) -> Result<IdlValue, VisualSignError> {
+        if depth > MAX_TYPE_DEPTH {
+            return Err(VisualSignError::DecodeError(
+                "IDL type nesting is too deep".into(),
+            ));
+        }
+        reader.count_value()?;
+        match ty {
+            IdlType::Primitive(name) => reader.read_primitive(name),
+            IdlType::Vec { vec } => {
+                let len = reader.read_u32()? as usize;
+                // Every element takes at least one byte, so a longer claimed length is malformed
+                if len > reader.remaining() {
+                    return Err(VisualSignError::DecodeError(format!(
+                        "Vector length {len} exceeds remaining data"
+                    )));
+                }
+                (0..len)
+                    .map(|_| self.decode_type(vec, reader, depth + 1))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(IdlValue::List)
+            }
+            IdlType::Option { option } => match reader.read_u8()? {
+                0 => Ok(IdlValue::None),
+                1 => self.decode_type(option, reader, depth + 1),
+                tag => Err(VisualSignError::DecodeError(format!(
+                    "Invalid option tag {tag}"
+                ))),
+            },
+            IdlType::COption { coption } => match reader.read_u32()? {
+                0 => Ok(IdlValue::None),
+                1 => self.decode_type(coption, reader, depth + 1),
+                tag => Err(VisualSignError::DecodeError(format!(
+                    "Invalid COption tag {tag}"
+                ))),
+            },
+            IdlType::Array {
+                array: (inner, len),
+            } => {
+                let IdlArrayLen::Value(len) = len else {
+                    return Err(VisualSignError::DecodeError(
+                        "Generic array lengths are not supported".into(),
+                    ));
+                };
+                if matches!(inner.as_ref(), IdlType::Primitive(name) if name == "u8") {
+                    return reader
+                        .read_bytes(*len)
+                        .map(|bytes| IdlValue::Bytes(bytes.to_vec()));
+                }
+                // Elements may read no data at all, so only the value budget bounds them
+                if *len > MAX_DECODED_VALUES {
+                    return Err(VisualSignError::DecodeError(format!(
+                        "Array length {len} exceeds the decoding limit"
+                    )));
+                }
+                (0..*len)
+                    .map(|_| self.decode_type(inner, reader, depth + 1))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(IdlValue::List)
+            }
+            IdlType::Defined { defined } => {
+                let def = self.find_type(defined.name())?;
+                match &def.ty {
+                    IdlTypeDefTy::Struct { fields } => self
+                        .decode_fields(fields.as_ref(), reader, depth + 1)
+                        .map(IdlValue::Struct),
+                    IdlTypeDefTy::Enum { variants } => {
+                        let index = reader.read_u8()? as usize;
+                        let variant = variants.get(index).ok_or_else(|| {
+                            VisualSignError::DecodeError(format!(
+                                "Invalid variant index {index} for {}",
+                                def.name
+                            ))
+                        })?;
+                        Ok(IdlValue::Enum {
+                            variant: variant.name.clone(),
+                            fields: self.decode_fields(
+                                variant.fields.as_ref(),
+                                reader,
+                                depth + 1,
+                            )?,
+                        })
+                    }
+                    IdlTypeDefTy::Type { alias } => self.decode_type(alias, reader, depth + 1),
+                }
+            }
+        }
+    }
+
+    fn decode_fields(
+        &self,
+        fields: Option<&IdlDefinedFields>,
+        reader: &mut BorshReader,
+        depth: usize,
+    ) -> Result<Vec<(String, IdlValue)>, VisualSignError> {
+        match fields {
+            None => Ok(Vec::new()),
+            Some(IdlDefinedFields::Named(fields)) => fields
+                .iter()
+                .map(|field| {
+                    Ok((
+                        field.name.clone(),
+                        self.decode_type(&field.ty, reader, depth)?,
+                    ))
+                })
+                .collect(),
+            Some(IdlDefinedFields::Tuple(types)) => types
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| Ok((i.to_string(), self.decode_type(ty, reader, depth)?)))
+                .collect(),
+        }
+    }
+}
+
+impl IdlInstruction {
+    /// The explicit discriminator, or the one Anchor derives for legacy IDLs.
+    pub fn discriminator(&self) -> Vec<u8> {
+        match &self.discriminator {
+            Some(discriminator) => discriminator.clone(),
+            None => {
+                let preimage = format!("global:{}", to_snake_case(&self.name));
+                solana_sdk::hash::hash(preimage.as_bytes()).to_bytes()[..ANCHOR_DISCRIMINATOR_LEN]
+                    .to_vec()
+            }
+        }
+    }
+}
+
+fn flatten_account_names(items: &[IdlAccountItem], prefix: Option<&str>, out: &mut Vec<String>) {
+    for item in items {
+        match item {
+            IdlAccountItem::Single { name } => out.push(match prefix {
+                Some(prefix) => format!("{prefix}.{name}"),
+                None => name.clone(),
+            }),
+            IdlAccountItem::Composite { name, accounts } => {
+                let nested = match prefix {
+                    Some(prefix) => format!("{prefix}.{name}"),
+                    None => name.clone(),
+                };
+                flatten_account_names(accounts, Some(&nested), out);
+            }
+        }
+    }
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+struct BorshReader<'a> {
+    data: &'a [u8],
+    /// Values decoded so far, capped at `MAX_DECODED_VALUES`.
+    values: usize,
+}
+
+impl<'a> BorshReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, values: 0 }
+    }
+
+    fn count_value(&mut self) -> Result<(), VisualSignError> {
+        self.values += 1;
+        if self.values > MAX_DECODED_VALUES {
+            return Err(VisualSignError::DecodeError(
+                "Instruction data decodes to too many values".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn remaining(&self) -> usize {
+        self.data.len()
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], VisualSignError> {
+        if len > self.data.len() {
+            return Err(VisualSignError::DecodeError(
+                "Unexpected end of instruction data".into(),
+            ));
+        }
+        let (head, tail) = self.data.split_at(len);
+        self.data = tail;
+        Ok(head)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], VisualSignError> {
+        let mut out = [0u8; N];
+        out.copy_from_slice(self.read_bytes(N)?);
+        Ok(out)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, VisualSignError> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    fn read_u32(&mut self) -> Result<u32, VisualSignError> {
+        Ok(u32::from_le_bytes(self.read_array()?))
+    }
+
+    fn read_primitive(&mut self, name: &str) -> Result<IdlValue, VisualSignError> {
+        let number = |n: String| Ok(IdlValue::Number(n));
+        match name {
+            "bool" => match self.read_u8()? {
+                0 => Ok(IdlValue::Bool(false)),
+                1 => Ok(IdlValue::Bool(true)),
+                other => Err(VisualSignError::DecodeError(format!(
+                    "Invalid bool value {other}"
+                ))),
+            },
+            "u8" => number(self.read_u8()?.to_string()),
+            "i8" => number(i8::from_le_bytes(self.read_array()?).to_string()),
+            "u16" => number(u16::from_le_bytes(self.read_array()?).to_string()),
+            "i16" => number(i16::from_le_bytes(self.read_array()?).to_string()),
+            "u32" => number(self.read_u32()?.to_string()),
+            "i32" => number(i32::from_le_bytes(self.read_array()?).to_string()),
+            "u64" => number(u64::from_le_bytes(self.read_array()?).to_string()),
+            "i64" => number(i64::from_le_bytes(self.read_array()?).to_string()),
+            "u128" => number(u128::from_le_bytes(self.read_array()?).to_string()),
+            "i128" => number(i128::from_le_bytes(self.read_array()?).to_string()),
+            "f32" => Ok(IdlValue::Float(
+                f32::from_le_bytes(self.read_array()?).to_string(),
+            )),
+            "f64" => Ok(IdlValue::Float(
+                f64::from_le_bytes(self.read_array()?).to_string(),
+            )),
+            "string" => {
+                let len = self.read_u32()? as usize;
+                let bytes = self.read_bytes(len)?;
+                String::from_utf8(bytes.to_vec())
+                    .map(IdlValue::String)
+                    .map_err(|e| VisualSignError::DecodeError(format!("Invalid string: {e}")))
+            }
+            "bytes" => {
+                let len = self.read_u32()? as usize;
+                Ok(IdlValue::Bytes(self.read_bytes(len)?.to_vec()))
+            }
+            // "publicKey" is the legacy spelling
+            "pubkey" | "publicKey" => Ok(IdlValue::Pubkey(
+                Pubkey::new_from_array(self.read_array()?).to_string(),
+            )),
+            other => Err(VisualSignError::DecodeError(format!(
+                "Unsupported IDL type: {other}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use visualsign::vsptrait::{Idl, SolanaMetadata};
+
+    const ESCROW_IDL: &str = r#"{
+        "address": "Escrow1111111111111111111111111111111111111",
+        "metadata": {"name": "escrow", "version": "0.1.0", "spec": "0.1.0"},
+        "instructions": [
+            {
+                "name": "make_offer",
+                "discriminator": [214, 98, 97, 35, 59, 12, 44, 178],
+                "accounts": [
+                    {"name": "maker", "writable": true, "signer": true},
+                    {"name": "vault", "accounts": [
+                        {"name": "token_account", "writable": true},
+                        {"name": "mint"}
+                    ]}
+                ],
+                "args": [
+                    {"name": "id", "type": "u64"},
+                    {"name": "terms", "type": {"defined": {"name": "Terms"}}},
+                    {"name": "memo", "type": {"option": "string"}},
+                    {"name": "recipients", "type": {"vec": "pubkey"}},
+                    {"name": "side", "type": {"defined": {"name": "Side"}}}
+                ]
+            }
+        ],
+        "types": [
+            {"name": "Terms", "type": {"kind": "struct", "fields": [
+                {"name": "amount", "type": "u64"},
+                {"name": "expires_at", "type": "i64"}
+            ]}},
+            {"name": "Side", "type": {"kind": "enum", "variants": [
+                {"name": "Bid"},
+                {"name": "Ask", "fields": [{"name": "limit", "type": "u32"}]}
+            ]}}
+        ]
+    }"#;
+
+    fn make_offer_data() -> Vec<u8> {
+        let mut data = vec![214, 98, 97, 35, 59, 12, 44, 178];
+        data.extend(7u64.to_le_bytes()); // id
+        data.extend(1_000_000u64.to_le_bytes()); // terms.amount
+        data.extend((-1i64).to_le_bytes()); // terms.expires_at
+        data.push(1); // memo: Some
+        data.extend(2u32.to_le_bytes());
+        data.extend(b"hi");
+        data.extend(1u32.to_le_bytes()); // recipients: 1 pubkey
+        data.extend([0u8; 32]);
+        data.push(1); // side: Ask
+        data.extend(250u32.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_decode_instruction_with_nested_types() {
+        let idl = AnchorIdl::from_json(ESCROW_IDL).unwrap();
+        assert_eq!(idl.program_name(), Some("escrow"));
+        assert!(idl.describes_program("Escrow1111111111111111111111111111111111111"));
+        assert!(!idl.describes_program("11111111111111111111111111111111"));
+
+        let decoded = idl.decode_instruction(&make_offer_data()).unwrap();
+        assert_eq!(decoded.name, "make_offer");
+        assert_eq!(
+            decoded.account_names,
+            vec!["maker", "vault.token_account", "vault.mint"]
+        );
+        assert_eq!(
+            decoded.args,
+            vec![
+                ("id".to_string(), IdlValue::Number("7".to_string())),
+                (
+                    "terms".to_string(),
+                    IdlValue::Struct(vec![
+                        (
+                            "amount".to_string(),
+                            IdlValue::Number("1000000".to_string())
+                        ),
+                        ("expires_at".to_string(), IdlValue::Number("-1".to_string())),
+                    ])
+                ),
+                ("memo".to_string(), IdlValue::String("hi".to_string())),
+                (
+                    "recipients".to_string(),
+                    IdlValue::List(vec![IdlValue::Pubkey(
+                        "11111111111111111111111111111111".to_string()
+                    )])
+                ),
+                (
+                    "side".to_string(),
+                    IdlValue::Enum {
+                        variant: "Ask".to_string(),
+                        fields: vec![("limit".to_string(), IdlValue::Number("250".to_string()))],
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_truncated_or_unknown_data() {
+        let idl = AnchorIdl::from_json(ESCROW_IDL).unwrap();
+        let data = make_offer_data();
+        assert!(idl.decode_instruction(&data[..20]).is_err());
+        assert!(idl.decode_instruction(&[0u8; 16]).is_err());
+        assert!(idl.decode_instruction(&data[..4]).is_err());
+    }
+
+    #[test]
+    fn test_decode_bounds_zero_size_arrays() {
+        let idl = AnchorIdl::from_json(
+            r#"{
+                "address": "Empty11111111111111111111111111111111111111",
+                "instructions": [
+                    {"name": "spin", "discriminator": [1, 2, 3, 4, 5, 6, 7, 8],
+                     "args": [{"name": "nothing", "type": {"array": [{"array": [{"defined": "Empty"}, 200]}, 200]}}]},
+                    {"name": "few", "discriminator": [8, 7, 6, 5, 4, 3, 2, 1],
+                     "args": [{"name": "nothing", "type": {"array": [{"defined": "Empty"}, 3]}}]}
+                ],
+                "types": [{"name": "Empty", "type": {"kind": "struct"}}]
+            }"#,
+        )
+        .unwrap();
+        assert!(idl.decode_instruction(&[1, 2, 3, 4, 5, 6, 7, 8]).is_err());
+
+        let decoded = idl.decode_instruction(&[8, 7, 6, 5, 4, 3, 2, 1]).unwrap();
+        assert_eq!(
+            decoded.args[0].1,
+            IdlValue::List(vec![IdlValue::Struct(vec![]); 3])
+        );
+        assert!(decoded.trailing.is_empty());
+    }
+
+    #[test]
+    fn test_decode_keeps_trailing_data() {
+        let idl = AnchorIdl::from_json(ESCROW_IDL).unwrap();
+        let mut data = make_offer_data();
+        data.extend([0xde, 0xad]);
+        let decoded = idl.decode_instruction(&data).unwrap();
+        assert_eq!(decoded.trailing, vec![0xde, 0xad]);
+    }
+
+    #[test]
+    fn test_legacy_idl_discriminator() {
+        let idl = AnchorIdl::from_json(
+            r#"{
+                "version": "0.1.0",
+                "name": "counter",
+                "instructions": [
+                    {"name": "incrementBy", "accounts": [{"name": "counter", "isMut": true, "isSigner": false}],
+                     "args": [{"name": "amount", "type": "u16"}, {"name": "owner", "type": "publicKey"}]}
+                ],
+                "metadata": {"address": "Counter111111111111111111111111111111111111"}
+            }"#,
+        )
+        .unwrap();
+        let mut data = solana_sdk::hash::hash(b"global:increment_by").to_bytes()[..8].to_vec();
+        data.extend(3u16.to_le_bytes());
+        data.extend([0u8; 32]);
+
+        let decoded = idl.decode_instruction(&data).unwrap();
+        assert_eq!(decoded.name, "incrementBy");
+        assert_eq!(decoded.args[0].1, IdlValue::Number("3".to_string()));
+        assert_eq!(idl.program_name(), Some("counter"));
+        assert_eq!(
+            idl.program_address(),
+            Some("Counter111111111111111111111111111111111111")
+        );
+    }
+
+    #[test]
+    fn test_from_chain_metadata() {
+        let metadata = |idl_type: Option<i32>, value: &str| ChainMetadata {
+            metadata: Some(chain_metadata::Metadata::Solana(SolanaMetadata {
+                idl: Some(Idl {
+                    value: value.to_string(),
+                    idl_type,
+                    idl_version: None,
+                    signature: None,
+                }),
+            })),
+        };
+        assert!(AnchorIdl::from_chain_metadata(&metadata(None, ESCROW_IDL)).is_some());
+        assert!(
+            AnchorIdl::from_chain_metadata(&metadata(
+                Some(SolanaIdlType::Anchor as i32),
+                ESCROW_IDL
+            ))
+            .is_some()
+        );
+        assert!(
+            AnchorIdl::from_chain_metadata(&metadata(
+                Some(SolanaIdlType::Unspecified as i32),
+                ESCROW_IDL
+            ))
+            .is_none()
+        );
+        assert!(AnchorIdl::from_chain_metadata(&metadata(None, "{}")).is_none());
+
+        // An IDL that doesn't name its program would match every program
+        let unaddressed = r#"{"instructions": [{"name": "initialize", "discriminator": [1, 2, 3, 4, 5, 6, 7, 8]}]}"#;
+        assert!(AnchorIdl::from_json(unaddressed).is_ok());
+        assert!(AnchorIdl::from_chain_metadata(&metadata(None, unaddressed)).is_none());
+        assert!(
+            !AnchorIdl::from_json(unaddressed)
+                .unwrap()
+                .describes_program("11111111111111111111111111111111")
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::core::{InstructionVisualizer, VisualizerContext, visualize_with_any};
+use crate::core::{AnchorIdl, InstructionVisualizer, VisualizerContext, visualize_with_any};
 use solana_parser::solana::parser::parse_transaction;
 use solana_parser::solana::structs::SolanaAccount;
 use solana_sdk::instruction::Instruction;
@@ -14,6 +14,7 @@ include!(concat!(env!("OUT_DIR"), "/generated_visualizers.rs"));
 /// Visualizes all the instructions and related fields in a transaction/message
 pub fn decode_instructions(
     transaction: &SolanaTransaction,
+    idl: Option<&AnchorIdl>,
 ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
     // TODO: add comment that available_visualizers is generated
     let visualizers: Vec<Box<dyn InstructionVisualizer>> = available_visualizers();
@@ -54,7 +55,8 @@ pub fn decode_instructions(
                 writable: false,
             };
 
-            let context = VisualizerContext::new(&sender, instruction_index, &instructions);
+            let context =
+                VisualizerContext::new(&sender, instruction_index, &instructions).with_idl(idl);
 
             // Try to visualize with available visualizers (including unknown_program fallback)
             visualize_with_any(&visualizers_refs, &context)
@@ -6,11 +6,13 @@ use solana_parser::solana::structs::SolanaAccount;
 use solana_sdk::instruction::Instruction;
 
 mod accounts;
+mod idl;
 mod instructions;
 mod txtypes;
 mod visualsign;
 
 pub use accounts::*;
+pub use idl::*;
 pub use instructions::*;
 pub use txtypes::*;
 pub use visualsign::*;
@@ -41,6 +43,8 @@ pub struct VisualizerContext<'a> {
     /// All instruction in the transaction.
     /// Instruction struct contains data
     instructions: &'a Vec<Instruction>,
+    /// Anchor IDL supplied with the request, if any.
+    idl: Option<&'a AnchorIdl>,
 }
 
 impl<'a> VisualizerContext<'a> {
@@ -54,9 +58,16 @@ impl<'a> VisualizerContext<'a> {
             sender,
             instruction_index,
             instructions,
+            idl: None,
         }
     }
 
+    /// Attaches the Anchor IDL supplied with the request.
+    pub fn with_idl(mut self, idl: Option<&'a AnchorIdl>) -> Self {
+        self.idl = idl;
+        self
+    }
+
     /// Returns the sender address.
     pub fn sender(&self) -> &SolanaAccount {
         self.sender
@@ -76,6 +87,11 @@ impl<'a> VisualizerContext<'a> {
     pub fn current_instruction(&self) -> Option<&Instruction> {
         self.instructions.get(self.instruction_index)
     }
+
+    /// Returns the Anchor IDL supplied with the request, if any.
+    pub fn idl(&self) -> Option<&AnchorIdl> {
+        self.idl
+    }
 }
 
 pub struct SolanaIntegrationConfigData {
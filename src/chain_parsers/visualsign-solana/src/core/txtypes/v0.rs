@@ -1,5 +1,5 @@
 use crate::core::{
-    InstructionVisualizer, SolanaAccount, VisualizerContext, available_visualizers,
+    AnchorIdl, InstructionVisualizer, SolanaAccount, VisualizerContext, available_visualizers,
     visualize_with_any,
 };
 use solana_sdk::instruction::{AccountMeta, Instruction};
@@ -113,6 +113,7 @@ pub fn decode_v0_transfers(
 /// This works for all V0 transactions, including those with lookup tables
 pub fn decode_v0_instructions(
     v0_message: &solana_sdk::message::v0::Message,
+    idl: Option<&AnchorIdl>,
 ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
     // Get visualizers
     let visualizers: Vec<Box<dyn InstructionVisualizer>> = available_visualizers();
@@ -182,7 +183,7 @@ pub fn decode_v0_instructions(
 
             visualize_with_any(
                 &visualizers_refs,
-                &VisualizerContext::new(&sender, instruction_index, &instructions),
+                &VisualizerContext::new(&sender, instruction_index, &instructions).with_idl(idl),
             )
         })
         .map(|res| res.map(|viz_result| viz_result.field))
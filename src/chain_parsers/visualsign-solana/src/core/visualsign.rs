@@ -2,7 +2,8 @@ use crate::core::txtypes::{
     create_address_lookup_table_field, decode_v0_instructions, decode_v0_transfers,
 };
 use crate::core::{
-    create_accounts_advanced_preview_layout, decode_accounts, decode_v0_accounts, instructions,
+    AnchorIdl, create_accounts_advanced_preview_layout, decode_accounts, decode_v0_accounts,
+    instructions,
 };
 use base64::{self, Engine};
 use solana_sdk::{
@@ -94,6 +95,11 @@ impl VisualSignConverter<SolanaTransactionWrapper> for SolanaVisualSignConverter
         transaction_wrapper: SolanaTransactionWrapper,
         options: VisualSignOptions,
     ) -> Result<SignablePayload, VisualSignError> {
+        // An IDL that doesn't parse is ignored rather than failing the whole request
+        let idl = options
+            .metadata
+            .as_ref()
+            .and_then(AnchorIdl::from_chain_metadata);
         match transaction_wrapper {
             SolanaTransactionWrapper::Legacy(transaction) => {
                 // Convert the legacy transaction to a VisualSign payload
@@ -101,6 +107,7 @@ impl VisualSignConverter<SolanaTransactionWrapper> for SolanaVisualSignConverter
                     &transaction,
                     options.decode_transfers,
                     options.transaction_name,
+                    idl.as_ref(),
                 )
             }
             SolanaTransactionWrapper::Versioned(versioned_tx) => {
@@ -109,6 +116,7 @@ impl VisualSignConverter<SolanaTransactionWrapper> for SolanaVisualSignConverter
                     &versioned_tx,
                     options.decode_transfers,
                     options.transaction_name,
+                    idl.as_ref(),
                 )
             }
         }
@@ -150,6 +158,7 @@ fn convert_to_visual_sign_payload(
     transaction: &SolanaTransaction,
     decode_transfers: bool,
     title: Option<String>,
+    idl: Option<&AnchorIdl>,
 ) -> Result<SignablePayload, VisualSignError> {
     let message = &transaction.message;
 
@@ -174,7 +183,7 @@ fn convert_to_visual_sign_payload(
 
     // Process instructions with visualizers
     fields.extend(
-        instructions::decode_instructions(transaction)?
+        instructions::decode_instructions(transaction, idl)?
             .iter()
             .map(|e| e.signable_payload_field.clone()),
     );
@@ -201,6 +210,7 @@ fn convert_versioned_to_visual_sign_payload(
     versioned_tx: &VersionedTransaction,
     decode_transfers: bool,
     title: Option<String>,
+    idl: Option<&AnchorIdl>,
 ) -> Result<SignablePayload, VisualSignError> {
     match &versioned_tx.message {
         VersionedMessage::Legacy(legacy_message) => {
@@ -209,11 +219,17 @@ fn convert_versioned_to_visual_sign_payload(
                 signatures: versioned_tx.signatures.clone(),
                 message: legacy_message.clone(),
             };
-            convert_to_visual_sign_payload(&legacy_tx, decode_transfers, title)
+            convert_to_visual_sign_payload(&legacy_tx, decode_transfers, title, idl)
         }
         VersionedMessage::V0(v0_message) => {
             // Handle V0 transactions - try to use the same instruction processing pipeline
-            convert_v0_to_visual_sign_payload(versioned_tx, v0_message, decode_transfers, title)
+            convert_v0_to_visual_sign_payload(
+                versioned_tx,
+                v0_message,
+                decode_transfers,
+                title,
+                idl,
+            )
         }
     }
 }
@@ -224,6 +240,7 @@ fn convert_v0_to_visual_sign_payload(
     v0_message: &solana_sdk::message::v0::Message,
     decode_transfers: bool,
     title: Option<String>,
+    idl: Option<&AnchorIdl>,
 ) -> Result<SignablePayload, VisualSignError> {
     // Decode and sort accounts using the dedicated function
     let accounts = decode_v0_accounts(v0_message)?;
@@ -246,7 +263,7 @@ fn convert_v0_to_visual_sign_payload(
 
     // Directly process V0 instructions using the visualizer framework
     // This approach works for all V0 transactions, including those with lookup tables
-    match decode_v0_instructions(v0_message) {
+    match decode_v0_instructions(v0_message, idl) {
         Ok(instruction_fields) => {
             for (index, instruction_field) in instruction_fields.iter().enumerate() {
                 tracing::debug!(
@@ -0,0 +1,20 @@
+//! Configuration for the IDL-backed visualizer
+//! It has no static program list: whether it applies depends on the IDL supplied with the request
+
+use crate::core::{SolanaIntegrationConfig, SolanaIntegrationConfigData};
+use std::collections::HashMap;
+
+pub struct AnchorIdlConfig;
+
+impl SolanaIntegrationConfig for AnchorIdlConfig {
+    fn new() -> Self {
+        Self
+    }
+
+    fn data(&self) -> &SolanaIntegrationConfigData {
+        static DATA: std::sync::OnceLock<SolanaIntegrationConfigData> = std::sync::OnceLock::new();
+        DATA.get_or_init(|| SolanaIntegrationConfigData {
+            programs: HashMap::new(),
+        })
+    }
+}
@@ -0,0 +1,347 @@
+//! Visualizer for Anchor programs described by an IDL supplied in `SolanaMetadata.idl`
+//! Instructions are matched by their 8-byte discriminator and their args Borsh-decoded per the IDL
+
+mod config;
+
+use crate::core::{
+    AnchorIdl, DecodedIdlInstruction, IdlValue, InstructionVisualizer, SolanaIntegrationConfig,
+    VisualizerContext, VisualizerKind,
+};
+use config::AnchorIdlConfig;
+use solana_sdk::instruction::Instruction;
+use visualsign::errors::VisualSignError;
+use visualsign::field_builders::{
+    create_address_field, create_number_field, create_raw_data_field, create_text_field,
+};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon,
+    SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout,
+    SignablePayloadFieldStaticAnnotation, SignablePayloadFieldTextV2,
+};
+
+// Create a static instance that we can reference
+static ANCHOR_IDL_CONFIG: AnchorIdlConfig = AnchorIdlConfig;
+
+pub struct AnchorIdlVisualizer;
+
+impl InstructionVisualizer for AnchorIdlVisualizer {
+    fn visualize_tx_commands(
+        &self,
+        context: &VisualizerContext,
+    ) -> Result<AnnotatedPayloadField, VisualSignError> {
+        let instruction = context
+            .current_instruction()
+            .ok_or_else(|| VisualSignError::MissingData("No instruction found".into()))?;
+        let idl = context
+            .idl()
+            .ok_or_else(|| VisualSignError::MissingData("No IDL supplied".into()))?;
+
+        let decoded = idl.decode_instruction(&instruction.data)?;
+        create_idl_instruction_preview_layout(instruction, idl, &decoded, context)
+    }
+
+    fn get_config(&self) -> Option<&dyn SolanaIntegrationConfig> {
+        Some(&ANCHOR_IDL_CONFIG)
+    }
+
+    fn kind(&self) -> VisualizerKind {
+        VisualizerKind::Payments("AnchorIdl")
+    }
+
+    // Only handles instructions of the program the IDL describes, and only when the
+    // discriminator is known, so everything else still reaches the other visualizers.
+    fn can_handle(&self, context: &VisualizerContext) -> bool {
+        let (Some(idl), Some(instruction)) = (context.idl(), context.current_instruction()) else {
+            return false;
+        };
+        idl.describes_program(&instruction.program_id.to_string())
+            && idl.find_instruction(&instruction.data).is_some()
+    }
+}
+
+fn create_idl_instruction_preview_layout(
+    instruction: &Instruction,
+    idl: &AnchorIdl,
+    decoded: &DecodedIdlInstruction,
+    context: &VisualizerContext,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let program_id = instruction.program_id.to_string();
+    let program_name = idl.program_name().unwrap_or(&program_id);
+    let title = format!("{program_name}: {}", decoded.name);
+
+    let condensed_fields = vec![
+        create_text_field("Program", program_name)?,
+        create_text_field("Instruction", &decoded.name)?,
+    ];
+
+    let mut expanded_fields = vec![
+        create_text_field("Program ID", &program_id)?,
+        create_text_field("Instruction", &decoded.name)?,
+    ];
+    for (name, value) in &decoded.args {
+        expanded_fields.push(create_idl_value_field(name, value)?);
+    }
+    // The program may read bytes past the IDL's args, or the IDL may not match it
+    if !decoded.trailing.is_empty() {
+        let mut trailing = create_text_field("Trailing Data", &hex::encode(&decoded.trailing))?;
+        trailing.static_annotation = Some(SignablePayloadFieldStaticAnnotation {
+            text: format!(
+                "{} bytes after the args aren't described by the IDL",
+                decoded.trailing.len()
+            ),
+        });
+        expanded_fields.push(trailing);
+    }
+
+    // Accounts beyond the IDL list are the instruction's remaining accounts
+    for (index, account) in instruction.accounts.iter().enumerate() {
+        let label = decoded
+            .account_names
+            .get(index)
+            .cloned()
+            .unwrap_or_else(|| {
+                format!(
+                    "Remaining Account {}",
+                    index + 1 - decoded.account_names.len()
+                )
+            });
+        expanded_fields.push(create_address_field(
+            &label,
+            &account.pubkey.to_string(),
+            None,
+            None,
+            None,
+            None,
+        )?);
+    }
+    expanded_fields.push(create_raw_data_field(&instruction.data, None)?);
+
+    let preview_layout = SignablePayloadFieldPreviewLayout {
+        title: Some(SignablePayloadFieldTextV2 {
+            text: title.clone(),
+        }),
+        subtitle: Some(SignablePayloadFieldTextV2 {
+            text: "Decoded with supplied IDL".to_string(),
+        }),
+        condensed: Some(SignablePayloadFieldListLayout {
+            fields: condensed_fields,
+        }),
+        expanded: Some(SignablePayloadFieldListLayout {
+            fields: expanded_fields,
+        }),
+    };
+
+    Ok(AnnotatedPayloadField {
+        static_annotation: None,
+        dynamic_annotation: None,
+        signable_payload_field: SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                label: format!("Instruction {}", context.instruction_index() + 1),
+                fallback_text: format!("{title}\nProgram ID: {program_id}"),
+            },
+            preview_layout,
+        },
+    })
+}
+
+fn create_idl_value_field(
+    label: &str,
+    value: &IdlValue,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    match value {
+        IdlValue::Bool(flag) => create_text_field(label, &flag.to_string()),
+        IdlValue::Number(number) => create_number_field(label, number, ""),
+        IdlValue::Float(text) | IdlValue::String(text) => create_text_field(label, text),
+        IdlValue::Bytes(bytes) => create_text_field(label, &hex::encode(bytes)),
+        IdlValue::Pubkey(address) => create_address_field(label, address, None, None, None, None),
+        IdlValue::None => create_text_field(label, "None"),
+        IdlValue::List(items) => {
+            let fields = items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| create_idl_value_field(&format!("{label}[{i}]"), item))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(create_nested_layout(
+                label,
+                &format!("{} items", items.len()),
+                fields,
+            ))
+        }
+        IdlValue::Struct(members) => {
+            let fields = members
+                .iter()
+                .map(|(name, member)| create_idl_value_field(name, member))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(create_nested_layout(
+                label,
+                &format!("{} fields", members.len()),
+                fields,
+            ))
+        }
+        IdlValue::Enum { variant, fields } => {
+            if fields.is_empty() {
+                return create_text_field(label, variant);
+            }
+            let fields = fields
+                .iter()
+                .map(|(name, member)| create_idl_value_field(name, member))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(create_nested_layout(label, variant, fields))
+        }
+    }
+}
+
+fn create_nested_layout(
+    label: &str,
+    subtitle: &str,
+    fields: Vec<AnnotatedPayloadField>,
+) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        static_annotation: None,
+        dynamic_annotation: None,
+        signable_payload_field: SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                label: label.to_string(),
+                fallback_text: format!("{label}: {subtitle}"),
+            },
+            preview_layout: SignablePayloadFieldPreviewLayout {
+                title: Some(SignablePayloadFieldTextV2 {
+                    text: label.to_string(),
+                }),
+                subtitle: Some(SignablePayloadFieldTextV2 {
+                    text: subtitle.to_string(),
+                }),
+                condensed: None,
+                expanded: Some(SignablePayloadFieldListLayout { fields }),
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_parser::solana::structs::SolanaAccount;
+    use solana_sdk::instruction::AccountMeta;
+    use solana_sdk::pubkey::Pubkey;
+
+    const COUNTER_IDL: &str = r#"{
+        "address": "Counter111111111111111111111111111111111111",
+        "metadata": {"name": "counter", "version": "0.1.0", "spec": "0.1.0"},
+        "instructions": [
+            {
+                "name": "increment",
+                "discriminator": [11, 18, 104, 9, 104, 174, 59, 33],
+                "accounts": [{"name": "counter", "writable": true}, {"name": "authority", "signer": true}],
+                "args": [{"name": "by", "type": "u64"}, {"name": "note", "type": {"option": "string"}}]
+            }
+        ]
+    }"#;
+
+    fn counter_program() -> Pubkey {
+        "Counter111111111111111111111111111111111111"
+            .parse()
+            .unwrap()
+    }
+
+    fn increment_instruction(program_id: Pubkey) -> Instruction {
+        let mut data = vec![11, 18, 104, 9, 104, 174, 59, 33];
+        data.extend(5u64.to_le_bytes());
+        data.push(0); // note: None
+        Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(Pubkey::new_from_array([1; 32]), false),
+                AccountMeta::new_readonly(Pubkey::new_from_array([2; 32]), false),
+                AccountMeta::new_readonly(Pubkey::new_from_array([3; 32]), false),
+            ],
+            data,
+        }
+    }
+
+    fn sender() -> SolanaAccount {
+        SolanaAccount {
+            account_key: Pubkey::new_from_array([1; 32]).to_string(),
+            signer: false,
+            writable: false,
+        }
+    }
+
+    fn expanded_labels(field: &AnnotatedPayloadField) -> Vec<String> {
+        match &field.signable_payload_field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => preview_layout
+                .expanded
+                .as_ref()
+                .unwrap()
+                .fields
+                .iter()
+                .map(|f| f.signable_payload_field.label().clone())
+                .collect(),
+            other => panic!("expected preview layout, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_visualize_instruction_with_idl() {
+        let idl = AnchorIdl::from_json(COUNTER_IDL).unwrap();
+        let instructions = vec![increment_instruction(counter_program())];
+        let sender = sender();
+        let context = VisualizerContext::new(&sender, 0, &instructions).with_idl(Some(&idl));
+
+        assert!(AnchorIdlVisualizer.can_handle(&context));
+        let field = AnchorIdlVisualizer.visualize_tx_commands(&context).unwrap();
+
+        assert_eq!(field.signable_payload_field.label(), "Instruction 1");
+        assert_eq!(
+            expanded_labels(&field),
+            vec![
+                "Program ID",
+                "Instruction",
+                "by",
+                "note",
+                "counter",
+                "authority",
+                "Remaining Account 1",
+                "Raw Data",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_trailing_data_is_flagged() {
+        let idl = AnchorIdl::from_json(COUNTER_IDL).unwrap();
+        let mut instruction = increment_instruction(counter_program());
+        instruction.data.extend([0xab, 0xcd]);
+        let instructions = vec![instruction];
+        let sender = sender();
+        let context = VisualizerContext::new(&sender, 0, &instructions).with_idl(Some(&idl));
+
+        let field = AnchorIdlVisualizer.visualize_tx_commands(&context).unwrap();
+        let labels = expanded_labels(&field);
+        assert_eq!(labels[4], "Trailing Data");
+        assert_eq!(labels.last().unwrap(), "Raw Data");
+    }
+
+    #[test]
+    fn test_can_handle_requires_matching_idl() {
+        let idl = AnchorIdl::from_json(COUNTER_IDL).unwrap();
+        let sender = sender();
+
+        // No IDL supplied
+        let instructions = vec![increment_instruction(counter_program())];
+        let context = VisualizerContext::new(&sender, 0, &instructions);
+        assert!(!AnchorIdlVisualizer.can_handle(&context));
+
+        // IDL describes a different program
+        let instructions = vec![increment_instruction(Pubkey::new_from_array([9; 32]))];
+        let context = VisualizerContext::new(&sender, 0, &instructions).with_idl(Some(&idl));
+        assert!(!AnchorIdlVisualizer.can_handle(&context));
+
+        // Unknown discriminator
+        let mut instruction = increment_instruction(counter_program());
+        instruction.data[0] = 0;
+        let instructions = vec![instruction];
+        let context = VisualizerContext::new(&sender, 0, &instructions).with_idl(Some(&idl));
+        assert!(!AnchorIdlVisualizer.can_handle(&context));
+    }
+}
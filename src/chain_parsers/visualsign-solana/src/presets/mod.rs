@@ -1,3 +1,4 @@
+pub mod anchor_idl;
 pub mod associated_token_account;
 pub mod compute_budget;
 pub mod jupiter_swap;
@@ -194,6 +194,9 @@ pub struct Signature {
 pub struct EthereumMetadata {
     #[prost(message, optional, tag = "1")]
     pub abi: ::core::option::Option<Abi>,
+    /// Hex address of the account signing the transaction
+    #[prost(string, optional, tag = "2")]
+    pub sender: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[cfg_attr(
     feature = "serde_derive",
@@ -188,7 +188,10 @@ fn test_ethereum_abi_with_secp256k1_signature() {
     };
 
     // Create ParseRequest with EthereumMetadata containing signed ABI
-    let ethereum_metadata = EthereumMetadata { abi: Some(abi) };
+    let ethereum_metadata = EthereumMetadata {
+        abi: Some(abi),
+        sender: None,
+    };
     let parse_request = ParseRequest {
         unsigned_payload: "0x".to_string(),
         chain: Chain::Ethereum as i32,
@@ -339,7 +342,10 @@ fn test_signature_tampering_detection() {
         signature: Some(signature_metadata.clone()),
     };
 
-    let ethereum_metadata = EthereumMetadata { abi: Some(abi) };
+    let ethereum_metadata = EthereumMetadata {
+        abi: Some(abi),
+        sender: None,
+    };
     let parse_request = ParseRequest {
         unsigned_payload: "0x".to_string(),
         chain: Chain::Ethereum as i32,
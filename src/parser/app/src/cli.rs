@@ -8,6 +8,15 @@ use qos_core::{
     server::SocketServer,
 };
 
+use visualsign::registry::TransactionConverterRegistry;
+
+use crate::metadata_verification::{MetadataVerifier, UnverifiedMetadataPolicy};
+use crate::registry::{create_registry, create_registry_with_ethereum_contracts};
+
+const TRUSTED_METADATA_SIGNERS: &str = "trusted-metadata-signers";
+const UNVERIFIED_METADATA_POLICY: &str = "unverified-metadata-policy";
+const ETHEREUM_CONTRACTS: &str = "ethereum-contracts";
+
 /// CLI options for starting up the app server.
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct ParserOpts {
@@ -39,6 +48,36 @@ impl ParserOpts {
             .expect("has a default value.")
             .clone()
     }
+
+    fn metadata_verifier(&self) -> MetadataVerifier {
+        let trusted_signers = self
+            .parsed
+            .single(TRUSTED_METADATA_SIGNERS)
+            .map(|list| MetadataVerifier::parse_trusted_signers(list))
+            .transpose()
+            .expect("Parser: invalid trusted metadata signers")
+            .unwrap_or_default();
+        let policy: UnverifiedMetadataPolicy = self
+            .parsed
+            .single(UNVERIFIED_METADATA_POLICY)
+            .expect("has a default value.")
+            .parse()
+            .expect("Parser: invalid unverified metadata policy");
+
+        MetadataVerifier::new(trusted_signers, policy)
+    }
+
+    fn converter_registry(&self) -> TransactionConverterRegistry {
+        match self.parsed.single(ETHEREUM_CONTRACTS) {
+            Some(path) => {
+                let json = std::fs::read_to_string(path)
+                    .expect("Parser: unable to read ethereum contracts file");
+                create_registry_with_ethereum_contracts(&json)
+                    .expect("Parser: invalid ethereum contracts file")
+            }
+            None => create_registry(),
+        }
+    }
 }
 
 struct ParserParser;
@@ -59,6 +98,28 @@ impl GetParserForOptions for ParserParser {
                 .takes_value(true)
                 .default_value(EPHEMERAL_KEY_FILE),
             )
+            .token(
+                Token::new(
+                    TRUSTED_METADATA_SIGNERS,
+                    "comma separated `<algorithm>:<hex public key>` signers trusted to sign ABI/IDL metadata. Algorithms: secp256k1, ed25519, p256.",
+                )
+                .takes_value(true),
+            )
+            .token(
+                Token::new(
+                    UNVERIFIED_METADATA_POLICY,
+                    "what to do with ABI/IDL metadata not signed by a trusted signer: `reject` the request or `downgrade` to parsing without it.",
+                )
+                .takes_value(true)
+                .default_value("downgrade"),
+            )
+            .token(
+                Token::new(
+                    ETHEREUM_CONTRACTS,
+                    "path to a JSON file of Ethereum contract deployments to recognise in addition to the built-in ones.",
+                )
+                .takes_value(true),
+            )
     }
 }
 
@@ -80,8 +141,11 @@ impl Cli {
         } else if opts.parsed.help() {
             println!("{}", opts.parsed.info());
         } else {
-            let processor =
-                crate::service::Processor::new(EphemeralKeyHandle::new(opts.ephemeral_file()));
+            let processor = crate::service::Processor::new(
+                EphemeralKeyHandle::new(opts.ephemeral_file()),
+                opts.metadata_verifier(),
+                opts.converter_registry(),
+            );
 
             println!("---- Starting Parser server -----");
             let _server = SocketServer::listen_all(
@@ -14,6 +14,8 @@ pub mod chain_conversion;
 
 pub mod registry;
 
+pub mod metadata_verification;
+
 mod routes {
     pub(crate) mod parse;
 }
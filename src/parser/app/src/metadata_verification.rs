@@ -0,0 +1,574 @@
+//! Verification of the `SignatureMetadata` attached to ABI/IDL chain metadata.
+//!
+//! Callers may ship an ABI (Ethereum) or IDL (Solana) alongside the transaction
+//! so that the parser can decode calls it has no built-in visualizer for. Since
+//! that metadata drives what the user is shown, the enclave only trusts it when
+//! it carries a signature from a configured signer. The signature covers the
+//! SHA-256 digest of the ABI/IDL `value`, and the `SignatureMetadata` entries
+//! name the `algorithm` and the signer's `public_key` (hex).
+use std::fmt;
+use std::str::FromStr;
+
+use generated::google::rpc::Code;
+use generated::parser::{ChainMetadata, SignatureMetadata, chain_metadata};
+use sha2::{Digest, Sha256};
+use visualsign::{SignablePayloadField, SignablePayloadFieldCommon, SignablePayloadFieldTextV2};
+
+use crate::errors::GrpcError;
+
+/// Label of the field recording the verification outcome in the payload.
+pub const METADATA_SIGNATURE_LABEL: &str = "Metadata Signature";
+
+const ALGORITHM_KEY: &str = "algorithm";
+const PUBLIC_KEY_KEY: &str = "public_key";
+
+/// Signature schemes accepted for metadata signatures.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignatureAlgorithm {
+    /// ECDSA over secp256k1, DER encoded signature
+    Secp256k1,
+    /// Ed25519, 64 byte signature
+    Ed25519,
+    /// ECDSA over NIST P-256, DER or fixed-size signature
+    P256,
+}
+
+impl SignatureAlgorithm {
+    /// The name used for this algorithm in `SignatureMetadata`.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Secp256k1 => "secp256k1",
+            Self::Ed25519 => "ed25519",
+            Self::P256 => "p256",
+        }
+    }
+}
+
+impl fmt::Display for SignatureAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SignatureAlgorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "secp256k1" => Ok(Self::Secp256k1),
+            "ed25519" => Ok(Self::Ed25519),
+            "p256" | "p-256" | "secp256r1" => Ok(Self::P256),
+            other => Err(format!("unsupported signature algorithm: {other}")),
+        }
+    }
+}
+
+/// A public key whose metadata signatures the enclave accepts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TrustedSigner {
+    /// Algorithm the key signs with
+    pub algorithm: SignatureAlgorithm,
+    /// Raw public key bytes (SEC1 for the ECDSA curves, 32 bytes for ed25519)
+    pub public_key: Vec<u8>,
+}
+
+impl FromStr for TrustedSigner {
+    type Err = String;
+
+    /// Parses `<algorithm>:<hex public key>`, e.g. `ed25519:8a88e3dd...`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (algorithm, public_key) = s
+            .split_once(':')
+            .ok_or_else(|| format!("expected <algorithm>:<public key>, got {s}"))?;
+        Ok(Self {
+            algorithm: algorithm.trim().parse()?,
+            public_key: decode_hex(public_key.trim())?,
+        })
+    }
+}
+
+/// What to do with metadata whose signature can't be verified.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UnverifiedMetadataPolicy {
+    /// Fail the parse request
+    Reject,
+    /// Parse without the ABI/IDL, as if none had been supplied
+    #[default]
+    Downgrade,
+}
+
+impl FromStr for UnverifiedMetadataPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "reject" => Ok(Self::Reject),
+            "downgrade" => Ok(Self::Downgrade),
+            other => Err(format!("unknown unverified metadata policy: {other}")),
+        }
+    }
+}
+
+/// Result of checking the signature on a request's ABI/IDL.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VerificationOutcome {
+    /// Signed by one of the trusted signers
+    Verified {
+        /// Algorithm of the matching signer
+        algorithm: SignatureAlgorithm,
+        /// Hex encoded public key of the matching signer
+        signer: String,
+    },
+    /// Not signed, or not signed by a trusted signer; the ABI/IDL was dropped
+    Unverified {
+        /// Why verification failed
+        reason: String,
+    },
+}
+
+impl VerificationOutcome {
+    /// Renders the outcome as a payload field so that it is covered by the
+    /// enclave's signature over the payload.
+    #[must_use]
+    pub fn to_field(&self) -> SignablePayloadField {
+        let text = match self {
+            Self::Verified { algorithm, signer } => {
+                format!("Verified ({algorithm}, signer {signer})")
+            }
+            Self::Unverified { reason } => format!("Unverified, ABI/IDL ignored: {reason}"),
+        };
+        SignablePayloadField::TextV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: text.clone(),
+                label: METADATA_SIGNATURE_LABEL.to_string(),
+            },
+            text_v2: SignablePayloadFieldTextV2 { text },
+        }
+    }
+}
+
+/// Chain metadata after verification, ready to hand to the converters.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VerifiedMetadata {
+    /// Metadata to parse with, without the ABI/IDL if it was downgraded
+    pub metadata: Option<ChainMetadata>,
+    /// Outcome to record in the payload; `None` if no ABI/IDL was supplied
+    pub outcome: Option<VerificationOutcome>,
+}
+
+/// Checks ABI/IDL signatures against a set of trusted signers.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MetadataVerifier {
+    trusted_signers: Vec<TrustedSigner>,
+    policy: UnverifiedMetadataPolicy,
+}
+
+impl MetadataVerifier {
+    /// Creates a verifier trusting `trusted_signers`.
+    #[must_use]
+    pub fn new(trusted_signers: Vec<TrustedSigner>, policy: UnverifiedMetadataPolicy) -> Self {
+        Self {
+            trusted_signers,
+            policy,
+        }
+    }
+
+    /// Parses a comma separated list of `<algorithm>:<hex public key>` entries.
+    pub fn parse_trusted_signers(list: &str) -> Result<Vec<TrustedSigner>, String> {
+        list.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(str::parse)
+            .collect()
+    }
+
+    /// Verifies the ABI/IDL in `metadata` and applies the policy to it.
+    ///
+    /// Metadata without an ABI/IDL passes through untouched. Under
+    /// [`UnverifiedMetadataPolicy::Reject`] an unverified ABI/IDL fails the request;
+    /// under [`UnverifiedMetadataPolicy::Downgrade`] only the ABI/IDL is removed.
+    pub fn verify(&self, metadata: Option<ChainMetadata>) -> Result<VerifiedMetadata, GrpcError> {
+        let Some((content, signature)) = metadata.as_ref().and_then(signed_content) else {
+            return Ok(VerifiedMetadata {
+                metadata,
+                outcome: None,
+            });
+        };
+
+        match self.check(content, signature) {
+            Ok(signer) => Ok(VerifiedMetadata {
+                metadata,
+                outcome: Some(VerificationOutcome::Verified {
+                    algorithm: signer.algorithm,
+                    signer: qos_hex::encode(&signer.public_key),
+                }),
+            }),
+            Err(reason) => match self.policy {
+                UnverifiedMetadataPolicy::Reject => Err(GrpcError::new(
+                    Code::PermissionDenied,
+                    &format!("chain metadata signature not verified: {reason}"),
+                )),
+                UnverifiedMetadataPolicy::Downgrade => Ok(VerifiedMetadata {
+                    metadata: metadata.map(without_signed_content),
+                    outcome: Some(VerificationOutcome::Unverified { reason }),
+                }),
+            },
+        }
+    }
+
+    /// Returns the trusted signer that produced `signature` over `content`.
+    fn check(
+        &self,
+        content: &str,
+        signature: Option<&SignatureMetadata>,
+    ) -> Result<&TrustedSigner, String> {
+        let signature = signature.ok_or("metadata is not signed")?;
+        let algorithm: SignatureAlgorithm = metadata_entry(signature, ALGORITHM_KEY)
+            .ok_or("signature has no algorithm")?
+            .parse()?;
+        let claimed_key = metadata_entry(signature, PUBLIC_KEY_KEY)
+            .map(decode_hex)
+            .transpose()?;
+        let signature_bytes = decode_hex(&signature.value)?;
+        let digest: [u8; 32] = Sha256::digest(content.as_bytes()).into();
+
+        let mut candidates = self
+            .trusted_signers
+            .iter()
+            .filter(|signer| signer.algorithm == algorithm)
+            .filter(|signer| {
+                claimed_key
+                    .as_ref()
+                    .is_none_or(|key| *key == signer.public_key)
+            })
+            .peekable();
+        if candidates.peek().is_none() {
+            return Err(format!("signer is not trusted for {algorithm}"));
+        }
+        candidates
+            .find(|signer| verify_signature(signer, &digest, &signature_bytes))
+            .ok_or_else(|| format!("invalid {algorithm} signature"))
+    }
+}
+
+/// The ABI/IDL content and its signature, if the metadata carries one.
+fn signed_content(metadata: &ChainMetadata) -> Option<(&str, Option<&SignatureMetadata>)> {
+    match metadata.metadata.as_ref()? {
+        chain_metadata::Metadata::Ethereum(ethereum) => {
+            let abi = ethereum.abi.as_ref()?;
+            Some((abi.value.as_str(), abi.signature.as_ref()))
+        }
+        chain_metadata::Metadata::Solana(solana) => {
+            let idl = solana.idl.as_ref()?;
+            Some((idl.value.as_str(), idl.signature.as_ref()))
+        }
+    }
+}
+
+/// `metadata` with its ABI/IDL removed and everything else kept.
+fn without_signed_content(mut metadata: ChainMetadata) -> ChainMetadata {
+    match metadata.metadata.as_mut() {
+        Some(chain_metadata::Metadata::Ethereum(ethereum)) => ethereum.abi = None,
+        Some(chain_metadata::Metadata::Solana(solana)) => solana.idl = None,
+        None => {}
+    }
+    metadata
+}
+
+fn metadata_entry<'a>(signature: &'a SignatureMetadata, key: &str) -> Option<&'a str> {
+    signature
+        .metadata
+        .iter()
+        .find(|entry| entry.key == key)
+        .map(|entry| entry.value.as_str())
+}
+
+fn decode_hex(value: &str) -> Result<Vec<u8>, String> {
+    qos_hex::decode(value.trim_start_matches("0x")).map_err(|e| format!("invalid hex: {e:?}"))
+}
+
+fn verify_signature(signer: &TrustedSigner, digest: &[u8; 32], signature: &[u8]) -> bool {
+    match signer.algorithm {
+        SignatureAlgorithm::Secp256k1 => {
+            use k256::ecdsa::{Signature, VerifyingKey, signature::Verifier};
+            let Ok(key) = VerifyingKey::from_sec1_bytes(&signer.public_key) else {
+                return false;
+            };
+            Signature::from_der(signature)
+                .or_else(|_| Signature::from_slice(signature))
+                .is_ok_and(|signature| key.verify(digest, &signature).is_ok())
+        }
+        SignatureAlgorithm::P256 => {
+            use p256::ecdsa::{Signature, VerifyingKey, signature::Verifier};
+            let Ok(key) = VerifyingKey::from_sec1_bytes(&signer.public_key) else {
+                return false;
+            };
+            Signature::from_der(signature)
+                .or_else(|_| Signature::from_slice(signature))
+                .is_ok_and(|signature| key.verify(digest, &signature).is_ok())
+        }
+        SignatureAlgorithm::Ed25519 => {
+            use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+            let Ok(key_bytes) = <[u8; 32]>::try_from(signer.public_key.as_slice()) else {
+                return false;
+            };
+            let Ok(key) = VerifyingKey::from_bytes(&key_bytes) else {
+                return false;
+            };
+            Signature::from_slice(signature)
+                .is_ok_and(|signature| key.verify(digest, &signature).is_ok())
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use generated::parser::{Abi, EthereumMetadata, Idl, Metadata, SolanaMetadata};
+
+    const ABI: &str = r#"[{"type":"function","name":"transfer","inputs":[{"name":"to","type":"address"},{"name":"amount","type":"uint256"}],"outputs":[]}]"#;
+
+    fn digest(content: &str) -> [u8; 32] {
+        Sha256::digest(content.as_bytes()).into()
+    }
+
+    fn signature_metadata(
+        signature: &[u8],
+        algorithm: &str,
+        public_key: &[u8],
+    ) -> SignatureMetadata {
+        SignatureMetadata {
+            value: qos_hex::encode(signature),
+            metadata: vec![
+                Metadata {
+                    key: ALGORITHM_KEY.to_string(),
+                    value: algorithm.to_string(),
+                },
+                Metadata {
+                    key: PUBLIC_KEY_KEY.to_string(),
+                    value: qos_hex::encode(public_key),
+                },
+            ],
+        }
+    }
+
+    fn ethereum(signature: Option<SignatureMetadata>) -> ChainMetadata {
+        ChainMetadata {
+            metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
+                abi: Some(Abi {
+                    value: ABI.to_string(),
+                    signature,
+                }),
+                sender: None,
+            })),
+        }
+    }
+
+    fn without_abi(sender: Option<&str>) -> ChainMetadata {
+        ChainMetadata {
+            metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
+                abi: None,
+                sender: sender.map(str::to_string),
+            })),
+        }
+    }
+
+    fn secp256k1_signed() -> (TrustedSigner, ChainMetadata) {
+        use k256::ecdsa::{Signature, SigningKey, signature::Signer};
+        let key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+        let public_key = key
+            .verifying_key()
+            .to_encoded_point(false)
+            .as_bytes()
+            .to_vec();
+        let signature: Signature = key.sign(&digest(ABI));
+        let metadata = ethereum(Some(signature_metadata(
+            signature.to_der().as_bytes(),
+            "secp256k1",
+            &public_key,
+        )));
+        let signer = TrustedSigner {
+            algorithm: SignatureAlgorithm::Secp256k1,
+            public_key,
+        };
+        (signer, metadata)
+    }
+
+    #[test]
+    fn test_verifies_trusted_secp256k1_signature() {
+        let (signer, metadata) = secp256k1_signed();
+        let strict = MetadataVerifier::new(vec![signer.clone()], UnverifiedMetadataPolicy::Reject);
+
+        let verified = strict.verify(Some(metadata.clone())).unwrap();
+        assert_eq!(verified.metadata, Some(metadata));
+        let outcome = verified.outcome.unwrap();
+        assert_eq!(
+            outcome,
+            VerificationOutcome::Verified {
+                algorithm: SignatureAlgorithm::Secp256k1,
+                signer: qos_hex::encode(&signer.public_key),
+            }
+        );
+
+        let field = outcome.to_field();
+        assert_eq!(field.label(), METADATA_SIGNATURE_LABEL);
+        assert_eq!(
+            field.fallback_text(),
+            &format!(
+                "Verified (secp256k1, signer {})",
+                qos_hex::encode(&signer.public_key)
+            )
+        );
+    }
+
+    #[test]
+    fn test_verifies_ed25519_and_p256_signatures() {
+        let idl = r#"{"name":"example","instructions":[]}"#;
+
+        let ed_key = ed25519_dalek::SigningKey::from_bytes(&[0x11; 32]);
+        let ed_public = ed_key.verifying_key().to_bytes().to_vec();
+        let ed_signature = ed25519_dalek::Signer::sign(&ed_key, &digest(idl));
+        let solana = ChainMetadata {
+            metadata: Some(chain_metadata::Metadata::Solana(SolanaMetadata {
+                idl: Some(Idl {
+                    value: idl.to_string(),
+                    idl_type: None,
+                    idl_version: None,
+                    signature: Some(signature_metadata(
+                        &ed_signature.to_bytes(),
+                        "ed25519",
+                        &ed_public,
+                    )),
+                }),
+            })),
+        };
+
+        let p256_key = p256::ecdsa::SigningKey::from_slice(&[0x07; 32]).unwrap();
+        let p256_public = p256_key
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes()
+            .to_vec();
+        let p256_signature: p256::ecdsa::Signature =
+            p256::ecdsa::signature::Signer::sign(&p256_key, &digest(ABI));
+        let ethereum = ethereum(Some(signature_metadata(
+            &p256_signature.to_bytes(),
+            "secp256r1",
+            &p256_public,
+        )));
+
+        let verifier = MetadataVerifier::new(
+            vec![
+                TrustedSigner {
+                    algorithm: SignatureAlgorithm::Ed25519,
+                    public_key: ed_public,
+                },
+                TrustedSigner {
+                    algorithm: SignatureAlgorithm::P256,
+                    public_key: p256_public,
+                },
+            ],
+            UnverifiedMetadataPolicy::Reject,
+        );
+        assert!(matches!(
+            verifier.verify(Some(solana)).unwrap().outcome,
+            Some(VerificationOutcome::Verified {
+                algorithm: SignatureAlgorithm::Ed25519,
+                ..
+            })
+        ));
+        assert!(matches!(
+            verifier.verify(Some(ethereum)).unwrap().outcome,
+            Some(VerificationOutcome::Verified {
+                algorithm: SignatureAlgorithm::P256,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_untrusted_or_tampered_metadata_is_downgraded() {
+        let (signer, metadata) = secp256k1_signed();
+        let untrusted = MetadataVerifier::default();
+        let verified = untrusted.verify(Some(metadata.clone())).unwrap();
+        assert_eq!(verified.metadata, Some(without_abi(None)));
+        assert!(matches!(
+            verified.outcome,
+            Some(VerificationOutcome::Unverified { .. })
+        ));
+
+        let mut tampered = metadata;
+        if let Some(chain_metadata::Metadata::Ethereum(ethereum)) = tampered.metadata.as_mut() {
+            ethereum.abi.as_mut().unwrap().value.push(' ');
+        }
+        let trusted = MetadataVerifier::new(vec![signer], UnverifiedMetadataPolicy::Downgrade);
+        let verified = trusted.verify(Some(tampered)).unwrap();
+        assert_eq!(verified.metadata, Some(without_abi(None)));
+        assert_eq!(
+            verified.outcome,
+            Some(VerificationOutcome::Unverified {
+                reason: "invalid secp256k1 signature".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_downgrade_keeps_the_rest_of_the_metadata() {
+        let sender = "0x2222222222222222222222222222222222222222";
+        let mut metadata = ethereum(None);
+        if let Some(chain_metadata::Metadata::Ethereum(ethereum)) = metadata.metadata.as_mut() {
+            ethereum.sender = Some(sender.to_string());
+        }
+        let verified = MetadataVerifier::default().verify(Some(metadata)).unwrap();
+        assert_eq!(verified.metadata, Some(without_abi(Some(sender))));
+        assert_eq!(
+            verified.outcome,
+            Some(VerificationOutcome::Unverified {
+                reason: "metadata is not signed".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_reject_policy_and_unsigned_metadata() {
+        let verifier = MetadataVerifier::new(vec![], UnverifiedMetadataPolicy::Reject);
+        let err = verifier.verify(Some(ethereum(None))).unwrap_err();
+        assert_eq!(err.code, Code::PermissionDenied);
+        assert!(err.message.contains("metadata is not signed"));
+
+        // Nothing to verify
+        assert_eq!(verifier.verify(None).unwrap(), VerifiedMetadata::default());
+        let empty = ChainMetadata { metadata: None };
+        assert_eq!(
+            verifier.verify(Some(empty.clone())).unwrap().metadata,
+            Some(empty)
+        );
+    }
+
+    #[test]
+    fn test_parse_trusted_signers() {
+        let signers =
+            MetadataVerifier::parse_trusted_signers("ed25519:0a0b, secp256r1:0x0c,").unwrap();
+        assert_eq!(
+            signers,
+            vec![
+                TrustedSigner {
+                    algorithm: SignatureAlgorithm::Ed25519,
+                    public_key: vec![0x0a, 0x0b],
+                },
+                TrustedSigner {
+                    algorithm: SignatureAlgorithm::P256,
+                    public_key: vec![0x0c],
+                },
+            ]
+        );
+        assert!(MetadataVerifier::parse_trusted_signers("rsa:00").is_err());
+        assert!(MetadataVerifier::parse_trusted_signers("ed25519").is_err());
+        assert_eq!(
+            "reject".parse::<UnverifiedMetadataPolicy>(),
+            Ok(UnverifiedMetadataPolicy::Reject)
+        );
+    }
+}
@@ -6,10 +6,35 @@
 /// Returns a registry with converters for Solana and Unspecified transaction types.
 #[must_use]
 pub fn create_registry() -> visualsign::registry::TransactionConverterRegistry {
+    build_registry(visualsign_ethereum::EthereumVisualSignConverter::default())
+}
+
+/// Like [`create_registry`], with the Ethereum contract deployments in
+/// `contracts_json` added to the built-in ones.
+///
+/// # Errors
+///
+/// Returns an error if `contracts_json` is not a valid contract registry.
+pub fn create_registry_with_ethereum_contracts(
+    contracts_json: &str,
+) -> Result<
+    visualsign::registry::TransactionConverterRegistry,
+    visualsign_ethereum::registry::ContractRegistryError,
+> {
+    let mut contracts = visualsign_ethereum::registry::ContractRegistry::default();
+    contracts.extend_from_json(contracts_json)?;
+    Ok(build_registry(
+        visualsign_ethereum::EthereumVisualSignConverter::with_contract_registry(contracts),
+    ))
+}
+
+fn build_registry(
+    ethereum: visualsign_ethereum::EthereumVisualSignConverter,
+) -> visualsign::registry::TransactionConverterRegistry {
     let mut registry = visualsign::registry::TransactionConverterRegistry::new();
     registry.register::<visualsign_ethereum::EthereumTransactionWrapper, _>(
         visualsign::registry::Chain::Ethereum,
-        visualsign_ethereum::EthereumVisualSignConverter,
+        ethereum,
     );
     registry.register::<visualsign_solana::SolanaTransactionWrapper, _>(
         visualsign::registry::Chain::Solana,
@@ -1,6 +1,6 @@
 //! Parsing endpoint for `VisualSign`
 
-use crate::{chain_conversion, errors::GrpcError, registry::create_registry};
+use crate::{chain_conversion, errors::GrpcError, metadata_verification::MetadataVerifier};
 use generated::parser::Chain as ProtoChain;
 use generated::{
     google::rpc::Code,
@@ -12,12 +12,14 @@ use generated::{
 use qos_crypto::sha_256;
 use qos_p256::P256Pair;
 
-use visualsign::registry::Chain as VisualSignRegistryChain;
+use visualsign::registry::{Chain as VisualSignRegistryChain, TransactionConverterRegistry};
 use visualsign::vsptrait::VisualSignOptions;
 
 pub fn parse(
     parse_request: ParseRequest,
     ephemeral_key: &P256Pair,
+    metadata_verifier: &MetadataVerifier,
+    registry: &TransactionConverterRegistry,
 ) -> Result<ParseResponse, GrpcError> {
     let request_payload = parse_request.unsigned_payload;
     if request_payload.is_empty() {
@@ -27,21 +29,27 @@ pub fn parse(
         ));
     }
 
+    let verified_metadata = metadata_verifier.verify(parse_request.chain_metadata)?;
+
     // todo: make these request args or metadata
     let options = VisualSignOptions {
         decode_transfers: true,
         transaction_name: None,
-        metadata: parse_request.chain_metadata.clone(),
+        metadata: verified_metadata.metadata,
     };
-    let registry = create_registry();
     let proto_chain = ProtoChain::from_i32(parse_request.chain)
         .ok_or_else(|| GrpcError::new(Code::InvalidArgument, "invalid chain"))?;
     let registry_chain: VisualSignRegistryChain = chain_conversion::proto_to_registry(proto_chain);
 
-    let signable_payload_str = registry
+    let mut signable_payload_str = registry
         .convert_transaction(&registry_chain, request_payload.as_str(), options)
         .map_err(|e| GrpcError::new(Code::InvalidArgument, &format!("{e}")))?;
 
+    // Record how the supplied ABI/IDL was treated so the signer can see it
+    if let Some(outcome) = verified_metadata.outcome {
+        signable_payload_str.fields.push(outcome.to_field());
+    }
+
     // Convert SignablePayload to String (assuming you want JSON)
     let signable_payload = serde_json::to_string(&signable_payload_str).map_err(|e| {
         GrpcError::new(Code::Internal, &format!("Failed to serialize payload: {e}"))
@@ -9,17 +9,32 @@ use generated::{
 };
 use qos_core::{handles::EphemeralKeyHandle, server::RequestProcessor};
 use tokio::sync::RwLock;
+use visualsign::registry::TransactionConverterRegistry;
+
+use crate::metadata_verification::MetadataVerifier;
 
 /// Struct holding a request processor for QOS
 pub struct Processor {
     handle: EphemeralKeyHandle,
+    metadata_verifier: MetadataVerifier,
+    registry: TransactionConverterRegistry,
 }
 
 impl Processor {
-    /// Creates a new request processor. The only argument needed is an ephemeral key handle.
+    /// Creates a new request processor from an ephemeral key handle, the
+    /// verifier applied to chain metadata supplied with parse requests, and
+    /// the converters transactions are parsed with.
     #[must_use]
-    pub fn new(handle: EphemeralKeyHandle) -> Arc<RwLock<Self>> {
-        Arc::new(RwLock::new(Self { handle }))
+    pub fn new(
+        handle: EphemeralKeyHandle,
+        metadata_verifier: MetadataVerifier,
+        registry: TransactionConverterRegistry,
+    ) -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(Self {
+            handle,
+            metadata_verifier,
+            registry,
+        }))
     }
 }
 
@@ -76,15 +91,20 @@ impl RequestProcessor for Processor {
 
             let output = match input {
                 qos_parser_request::Input::ParseRequest(parse_request) => {
-                    match crate::routes::parse::parse(parse_request, &ephemeral_key)
-                        .map(qos_parser_response::Output::ParseResponse)
-                        .map_err(|e| {
-                            qos_parser_response::Output::Status(Status {
-                                code: e.code as i32,
-                                message: e.message,
-                                details: vec![],
-                            })
-                        }) {
+                    match crate::routes::parse::parse(
+                        parse_request,
+                        &ephemeral_key,
+                        &self.metadata_verifier,
+                        &self.registry,
+                    )
+                    .map(qos_parser_response::Output::ParseResponse)
+                    .map_err(|e| {
+                        qos_parser_response::Output::Status(Status {
+                            code: e.code as i32,
+                            message: e.message,
+                            details: vec![],
+                        })
+                    }) {
                         Ok(o) | Err(o) => o,
                     }
                 }
@@ -1,7 +1,7 @@
 use crate::chains;
 use chains::parse_chain;
 use clap::Parser;
-use parser_app::registry::create_registry;
+use parser_app::registry::{create_registry, create_registry_with_ethereum_contracts};
 use visualsign::vsptrait::VisualSignOptions;
 use visualsign::{SignablePayload, SignablePayloadField};
 
@@ -29,6 +29,13 @@ struct Args {
         help = "Show only condensed view (what hardware wallets display)"
     )]
     condensed_only: bool,
+
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "JSON file of extra Ethereum contract deployments to recognise"
+    )]
+    ethereum_contracts: Option<std::path::PathBuf>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -211,10 +218,10 @@ fn parse_and_display(
     options: VisualSignOptions,
     output_format: OutputFormat,
     condensed_only: bool,
+    registry: &visualsign::registry::TransactionConverterRegistry,
 ) {
     let registry_chain = parse_chain(chain);
 
-    let registry = create_registry();
     let signable_payload_str = registry.convert_transaction(&registry_chain, raw_tx, options);
     match signable_payload_str {
         Ok(payload) => match output_format {
@@ -261,12 +268,31 @@ impl Cli {
             metadata: None,
         };
 
+        let registry = match &args.ethereum_contracts {
+            Some(path) => {
+                let loaded = std::fs::read_to_string(path)
+                    .map_err(|e| format!("Failed to read {}: {e}", path.display()))
+                    .and_then(|json| {
+                        create_registry_with_ethereum_contracts(&json).map_err(|e| e.to_string())
+                    });
+                match loaded {
+                    Ok(registry) => registry,
+                    Err(err) => {
+                        eprintln!("Error: {err}");
+                        return;
+                    }
+                }
+            }
+            None => create_registry(),
+        };
+
         parse_and_display(
             &args.chain,
             &args.transaction,
             options,
             args.output,
             args.condensed_only,
+            &registry,
         );
     }
 }
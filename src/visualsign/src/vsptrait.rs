@@ -3,7 +3,10 @@ use std::fmt::Debug;
 use crate::SignablePayload;
 
 pub use crate::errors::{TransactionParseError, VisualSignError};
-pub use generated::parser::ChainMetadata;
+pub use generated::parser::{
+    Abi, ChainMetadata, EthereumMetadata, Idl, SignatureMetadata, SolanaIdlType, SolanaMetadata,
+    chain_metadata,
+};
 
 #[derive(Default, Debug, Clone)]
 pub struct VisualSignOptions {